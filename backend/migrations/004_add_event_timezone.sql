@@ -0,0 +1,2 @@
+-- Store the IANA time zone the organiser scheduled the event in
+ALTER TABLE events ADD COLUMN timezone VARCHAR(64) NOT NULL DEFAULT 'UTC';
\ No newline at end of file
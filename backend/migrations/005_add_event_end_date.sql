@@ -0,0 +1,5 @@
+-- Events get an explicit end time; existing events are assumed to last two hours
+ALTER TABLE events ADD COLUMN end_date TIMESTAMP WITH TIME ZONE;
+UPDATE events SET end_date = date + INTERVAL '2 hours' WHERE end_date IS NULL;
+ALTER TABLE events ALTER COLUMN end_date SET NOT NULL;
+ALTER TABLE events ADD CONSTRAINT events_end_after_start CHECK (end_date > date);
\ No newline at end of file
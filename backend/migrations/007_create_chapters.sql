@@ -0,0 +1,31 @@
+-- Local chapters (Berlin, Lisbon, Warsaw...) led by a Chapter Lead
+CREATE TABLE chapters (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL UNIQUE,
+    country VARCHAR(100) NOT NULL,
+    city VARCHAR(100) NOT NULL,
+    lead_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER chapters_set_updated_at
+    BEFORE UPDATE ON chapters
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+CREATE INDEX idx_chapters_lead_id ON chapters(lead_id);
+CREATE INDEX idx_chapters_country ON chapters(country);
+
+-- Ambassadors (and leads) belonging to a chapter
+CREATE TABLE chapter_members (
+    chapter_id INTEGER NOT NULL REFERENCES chapters(id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    joined_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (chapter_id, user_id)
+);
+
+CREATE INDEX idx_chapter_members_user_id ON chapter_members(user_id);
+
+-- Events can be organised on behalf of a chapter
+ALTER TABLE events ADD COLUMN chapter_id INTEGER REFERENCES chapters(id) ON DELETE SET NULL;
+CREATE INDEX idx_events_chapter_id ON events(chapter_id);
\ No newline at end of file
@@ -0,0 +1,27 @@
+-- What events actually achieved, recorded after they take place
+ALTER TABLE events ADD COLUMN actual_monthly_active_ambassadors INTEGER;
+ALTER TABLE events ADD COLUMN actual_monthly_active_accounts INTEGER;
+ALTER TABLE events ADD COLUMN actual_scf_referrals INTEGER;
+ALTER TABLE events ADD COLUMN actual_content_produced INTEGER;
+ALTER TABLE events ADD COLUMN actual_attendance INTEGER;
+ALTER TABLE events ADD COLUMN actual_social_growth INTEGER;
+
+-- KPI targets a chapter sets for each quarter
+CREATE TABLE chapter_kpi_targets (
+    chapter_id INTEGER NOT NULL REFERENCES chapters(id) ON DELETE CASCADE,
+    year INTEGER NOT NULL,
+    quarter INTEGER NOT NULL CHECK (quarter BETWEEN 1 AND 4),
+    monthly_active_ambassadors INTEGER,
+    monthly_active_accounts INTEGER,
+    scf_referrals INTEGER,
+    content_produced INTEGER,
+    attendance INTEGER,
+    social_growth INTEGER,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (chapter_id, year, quarter)
+);
+
+CREATE TRIGGER chapter_kpi_targets_set_updated_at
+    BEFORE UPDATE ON chapter_kpi_targets
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
\ No newline at end of file
@@ -0,0 +1,48 @@
+-- Plans a chapter submits for the quarterly budget review
+CREATE TABLE quarterly_plans (
+    id SERIAL PRIMARY KEY,
+    chapter_id INTEGER NOT NULL REFERENCES chapters(id) ON DELETE CASCADE,
+    year INTEGER NOT NULL,
+    quarter INTEGER NOT NULL CHECK (quarter BETWEEN 1 AND 4),
+    strategic_purpose TEXT NOT NULL,
+    quarterly_goals TEXT NOT NULL,
+    status VARCHAR(50) NOT NULL DEFAULT 'Draft'
+        CHECK (status IN ('Draft', 'Submitted', 'Approved', 'ChangesRequested')),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (chapter_id, year, quarter)
+);
+
+CREATE TRIGGER quarterly_plans_set_updated_at
+    BEFORE UPDATE ON quarterly_plans
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+CREATE INDEX idx_quarterly_plans_status ON quarterly_plans(status);
+
+-- Events planned for the quarter; an event belongs to at most one plan
+ALTER TABLE events ADD COLUMN plan_id INTEGER REFERENCES quarterly_plans(id) ON DELETE SET NULL;
+CREATE INDEX idx_events_plan_id ON events(plan_id);
+
+-- Reviewer discussion on a plan
+CREATE TABLE plan_comments (
+    id SERIAL PRIMARY KEY,
+    plan_id INTEGER NOT NULL REFERENCES quarterly_plans(id) ON DELETE CASCADE,
+    author_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    body TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_plan_comments_plan_id ON plan_comments(plan_id);
+
+-- Audit trail of every status change
+CREATE TABLE plan_transitions (
+    id SERIAL PRIMARY KEY,
+    plan_id INTEGER NOT NULL REFERENCES quarterly_plans(id) ON DELETE CASCADE,
+    from_status VARCHAR(50) NOT NULL,
+    to_status VARCHAR(50) NOT NULL,
+    actor_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    comment TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_plan_transitions_plan_id ON plan_transitions(plan_id);
\ No newline at end of file
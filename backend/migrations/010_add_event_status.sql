@@ -0,0 +1,11 @@
+-- Event lifecycle: Draft -> Submitted -> Approved -> Published -> Completed, or Cancelled
+ALTER TABLE events ADD COLUMN status VARCHAR(50) NOT NULL DEFAULT 'Draft'
+    CHECK (status IN ('Draft', 'Submitted', 'Approved', 'Published', 'Cancelled', 'Completed'));
+-- Set once an event is approved, so cancellations of public events stay listed
+ALTER TABLE events ADD COLUMN approved_at TIMESTAMP WITH TIME ZONE;
+ALTER TABLE events ADD COLUMN cancellation_reason TEXT;
+
+-- Everything posted so far went live immediately
+UPDATE events SET status = 'Published', approved_at = COALESCE(created_at, NOW());
+
+CREATE INDEX idx_events_status ON events(status);
\ No newline at end of file
@@ -0,0 +1,20 @@
+-- Budget line items of an event. Amounts are exact decimals in the item's currency;
+-- approved and spent stay NULL until a reviewer approves or the organiser reports spending
+CREATE TABLE event_budget_items (
+    id SERIAL PRIMARY KEY,
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    category VARCHAR(50) NOT NULL CHECK (category IN ('Venue', 'Food', 'Swag', 'Travel', 'Other')),
+    description TEXT NOT NULL DEFAULT '',
+    currency VARCHAR(10) NOT NULL CHECK (currency IN ('EUR', 'USD', 'GBP', 'CHF', 'USDC', 'XLM')),
+    requested_amount NUMERIC(20, 7) NOT NULL CHECK (requested_amount >= 0),
+    approved_amount NUMERIC(20, 7) CHECK (approved_amount >= 0),
+    spent_amount NUMERIC(20, 7) CHECK (spent_amount >= 0),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER event_budget_items_set_updated_at
+    BEFORE UPDATE ON event_budget_items
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+CREATE INDEX idx_event_budget_items_event_id ON event_budget_items(event_id);
\ No newline at end of file
@@ -0,0 +1,27 @@
+-- Stellar payouts of budget items' spending to the event organiser. The unsigned
+-- transaction is kept so the signed one can be checked against it before submission
+CREATE TABLE expense_reimbursements (
+    id SERIAL PRIMARY KEY,
+    budget_item_id INTEGER NOT NULL UNIQUE REFERENCES event_budget_items(id) ON DELETE RESTRICT,
+    recipient_wallet_address VARCHAR(56) NOT NULL,
+    asset VARCHAR(10) NOT NULL CHECK (asset IN ('USDC', 'XLM')),
+    amount NUMERIC(20, 7) NOT NULL CHECK (amount > 0),
+    status VARCHAR(20) NOT NULL DEFAULT 'AwaitingSignature'
+        CHECK (status IN ('AwaitingSignature', 'Submitted', 'Paid', 'Failed')),
+    prepared_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    transaction_xdr TEXT NOT NULL,
+    transaction_hash VARCHAR(64) NOT NULL,
+    signed_transaction_xdr TEXT,
+    ledger INTEGER,
+    error TEXT,
+    submitted_at TIMESTAMP WITH TIME ZONE,
+    paid_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER expense_reimbursements_set_updated_at
+    BEFORE UPDATE ON expense_reimbursements
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+CREATE INDEX idx_expense_reimbursements_status ON expense_reimbursements(status);
\ No newline at end of file
@@ -0,0 +1,18 @@
+-- Receipts, photos and slides uploaded for an event. The file itself lives in object
+-- storage under storage_key; receipts can point at the budget item they justify
+CREATE TABLE attachments (
+    id SERIAL PRIMARY KEY,
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    budget_item_id INTEGER REFERENCES event_budget_items(id) ON DELETE SET NULL,
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('Receipt', 'Photo', 'Slides', 'Document')),
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(100) NOT NULL,
+    size_bytes BIGINT NOT NULL CHECK (size_bytes > 0),
+    storage_key VARCHAR(255) NOT NULL UNIQUE,
+    thumbnail_key VARCHAR(255),
+    uploaded_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_attachments_event_id ON attachments(event_id);
+CREATE INDEX idx_attachments_budget_item_id ON attachments(budget_item_id);
\ No newline at end of file
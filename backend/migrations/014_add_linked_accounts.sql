@@ -0,0 +1,17 @@
+-- Stellar accounts attendees created at an event. A background job checks each one
+-- on Horizon; verified accounts replace the organiser's estimate of monthly active accounts
+CREATE TABLE event_linked_accounts (
+    id SERIAL PRIMARY KEY,
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    account_id VARCHAR(56) NOT NULL UNIQUE,
+    linked_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'Pending' CHECK (status IN ('Pending', 'Verified', 'Unverified')),
+    funded_at TIMESTAMP WITH TIME ZONE,
+    last_transaction_at TIMESTAMP WITH TIME ZONE,
+    checked_at TIMESTAMP WITH TIME ZONE,
+    check_error TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_event_linked_accounts_event_id ON event_linked_accounts(event_id);
+CREATE INDEX idx_event_linked_accounts_pending ON event_linked_accounts(status) WHERE status = 'Pending';
\ No newline at end of file
@@ -0,0 +1,41 @@
+-- Proof-of-attendance tokens. Organisers check attendees in; a programme admin then
+-- signs one transaction per batch creating a claimable balance of the event's asset
+-- for each of them. The unsigned transaction is kept so the signed one can be checked
+CREATE TABLE attendance_issuances (
+    id SERIAL PRIMARY KEY,
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE RESTRICT,
+    asset_code VARCHAR(12) NOT NULL,
+    issuer VARCHAR(56) NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'AwaitingSignature'
+        CHECK (status IN ('AwaitingSignature', 'Submitted', 'Issued', 'Failed')),
+    prepared_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    transaction_xdr TEXT NOT NULL,
+    transaction_hash VARCHAR(64) NOT NULL,
+    signed_transaction_xdr TEXT,
+    ledger INTEGER,
+    error TEXT,
+    submitted_at TIMESTAMP WITH TIME ZONE,
+    issued_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER attendance_issuances_set_updated_at
+    BEFORE UPDATE ON attendance_issuances
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+CREATE INDEX idx_attendance_issuances_event_id ON attendance_issuances(event_id);
+
+CREATE TABLE event_check_ins (
+    id SERIAL PRIMARY KEY,
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    checked_in_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    -- The latest issuance that included the attendee; none until a token is prepared
+    issuance_id INTEGER REFERENCES attendance_issuances(id) ON DELETE SET NULL,
+    checked_in_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (event_id, user_id)
+);
+
+CREATE INDEX idx_event_check_ins_user_id ON event_check_ins(user_id);
+CREATE INDEX idx_event_check_ins_issuance_id ON event_check_ins(issuance_id);
\ No newline at end of file
@@ -0,0 +1,11 @@
+-- Nonces handed to wallets about to sign up. Signing one proves the user controls the
+-- wallet; each is accepted once, and only until it expires
+CREATE TABLE signup_challenges (
+    nonce UUID PRIMARY KEY,
+    wallet_address VARCHAR(56) NOT NULL,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    used_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_signup_challenges_expires_at ON signup_challenges(expires_at);
\ No newline at end of file
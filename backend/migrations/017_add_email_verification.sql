@@ -0,0 +1,19 @@
+-- Email verification. Accounts start unverified and cannot create events until the
+-- user follows the link mailed to them. Accounts from before verification existed are
+-- taken as verified, so nobody is locked out of events they already run
+ALTER TABLE users ADD COLUMN email_verified_at TIMESTAMP WITH TIME ZONE;
+UPDATE users SET email_verified_at = COALESCE(created_at, NOW());
+
+-- Only a hash of each token is kept; the token itself is only in the mailed link
+CREATE TABLE email_verifications (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    -- The address the link was sent to, so changing it later does not verify the new one
+    email VARCHAR(255) NOT NULL,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    used_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_email_verifications_user_id ON email_verifications(user_id);
\ No newline at end of file
@@ -0,0 +1,22 @@
+-- Background jobs. Workers claim due jobs with FOR UPDATE SKIP LOCKED, so any number
+-- of them can poll the table without running a job twice. Finished jobs are deleted;
+-- ones that failed too often stay behind as Dead for someone to look at
+CREATE TABLE jobs (
+    id BIGSERIAL PRIMARY KEY,
+    kind VARCHAR(60) NOT NULL,
+    payload JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'Queued' CHECK (status IN ('Queued', 'Running', 'Dead')),
+    -- A queued job with the same key is replaced rather than queued twice
+    unique_key TEXT,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL CHECK (max_attempts > 0),
+    run_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    -- When a worker claimed the job; a Running job locked for too long is claimed again
+    locked_at TIMESTAMP WITH TIME ZONE,
+    last_error TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    failed_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_jobs_due ON jobs(run_at) WHERE status = 'Queued';
+CREATE UNIQUE INDEX idx_jobs_queued_unique_key ON jobs(unique_key) WHERE status = 'Queued';
\ No newline at end of file
@@ -0,0 +1,11 @@
+-- Reminders mailed before events, one row per reminder and start time. A reminder is
+-- recorded in the same transaction that queues it, so it is never sent twice; an event
+-- moved to another date gets its reminders again
+CREATE TABLE event_reminders (
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    -- How long before the start the reminder is due
+    minutes_before INTEGER NOT NULL CHECK (minutes_before > 0),
+    event_date TIMESTAMP WITH TIME ZONE NOT NULL,
+    sent_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (event_id, minutes_before, event_date)
+);
\ No newline at end of file
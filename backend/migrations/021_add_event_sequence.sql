@@ -0,0 +1,20 @@
+-- Revision number of each event's calendar entry (iCalendar SEQUENCE), bumped whenever
+-- something the entry shows changes so subscribed calendars pick up edits and cancellations
+ALTER TABLE events ADD COLUMN sequence INTEGER NOT NULL DEFAULT 0;
+
+CREATE OR REPLACE FUNCTION bump_event_sequence() RETURNS TRIGGER AS $$
+BEGIN
+    IF (NEW.title, NEW.description, NEW.event_type, NEW.date, NEW.end_date, NEW.timezone, NEW.location,
+        NEW.contact_email, NEW.external_link, NEW.status, NEW.cancellation_reason, NEW.strategic_focus_areas)
+       IS DISTINCT FROM
+       (OLD.title, OLD.description, OLD.event_type, OLD.date, OLD.end_date, OLD.timezone, OLD.location,
+        OLD.contact_email, OLD.external_link, OLD.status, OLD.cancellation_reason, OLD.strategic_focus_areas) THEN
+        NEW.sequence = OLD.sequence + 1;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER events_bump_sequence
+    BEFORE UPDATE ON events
+    FOR EACH ROW EXECUTE FUNCTION bump_event_sequence();
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- Sessions started by signing a nonce from signup_challenges with a registered wallet.
+-- The token is sent as a bearer token; expired sessions are cleared out as new ones start
+CREATE TABLE sessions (
+    token UUID PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_sessions_expires_at ON sessions(expires_at);
\ No newline at end of file
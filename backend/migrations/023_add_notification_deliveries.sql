@@ -0,0 +1,10 @@
+-- Who has been told of each published notification. A recipient is recorded in the
+-- same transaction that stores their inbox entry and queues their mail, so a job run
+-- again after a failure, or claimed again after timing out, never tells anyone twice
+CREATE TABLE notification_deliveries (
+    -- Made when the notification is queued and kept by every run of its job
+    notification_key UUID NOT NULL,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    delivered_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (notification_key, user_id)
+);
\ No newline at end of file
@@ -0,0 +1,126 @@
+use crate::config::{CorsConfig, VersioningConfig};
+use crate::handlers;
+use crate::middleware::reject_disallowed_preflight;
+use crate::openapi::{ApiDoc, DOCS_UI_PATH, OPENAPI_JSON_PATH};
+use crate::routes::ApiRoutes;
+use crate::state::AppState;
+use crate::versioning::{mount, ApiVersion, Deprecation, MountedApi, LEGACY_API_PREFIX};
+use axum::{
+    extract::DefaultBodyLimit,
+    http::Method,
+    middleware::from_fn_with_state,
+    routing::post,
+    Router,
+};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+pub fn create_app(state: AppState, cors: &CorsConfig, versioning: &VersioningConfig) -> Router {
+    mount(mounted_apis(versioning))
+        .merge(unversioned_routes().into_router())
+        .merge(SwaggerUi::new(DOCS_UI_PATH).url(OPENAPI_JSON_PATH, ApiDoc::openapi()))
+        .layer(cors.layer())
+        .layer(from_fn_with_state(cors.allowed_origins.clone(), reject_disallowed_preflight))
+        .with_state(state)
+}
+
+/// Every version of the API [`create_app`] serves.
+fn mounted_apis(versioning: &VersioningConfig) -> Vec<MountedApi> {
+    vec![
+        MountedApi::new(ApiVersion::V1, api_v1_routes()),
+        MountedApi::at(LEGACY_API_PREFIX, api_v1_routes()).deprecated(Deprecation::legacy(versioning)),
+    ]
+}
+
+/// Routes outside the versioned API.
+fn unversioned_routes() -> ApiRoutes {
+    ApiRoutes::new().get("/health", handlers::health)
+}
+
+/// Method and full path of every API route [`create_app`] serves.
+pub fn route_table() -> Vec<(Method, String)> {
+    let mut table = unversioned_routes().paths();
+    for api in mounted_apis(&VersioningConfig::default()) {
+        table.extend(api.routes.paths().into_iter().map(|(method, path)| (method, format!("{}{}", api.prefix, path))));
+    }
+    table
+}
+
+/// Version 1 of the API, relative to `/api/v1`.
+pub fn api_v1() -> Router<AppState> {
+    api_v1_routes().into_router()
+}
+
+/// The routes of [`api_v1`].
+pub fn api_v1_routes() -> ApiRoutes {
+    ApiRoutes::new()
+        .post("/signup", handlers::signup)
+        .post("/signup/challenge", handlers::signup_challenge)
+        .post("/sessions", handlers::create_session)
+        .post("/sessions/challenge", handlers::signin_challenge)
+        .post("/email/verify", handlers::verify_email)
+        .post("/email/verification", handlers::resend_verification_email)
+        .post("/events", handlers::create_event)
+        .get("/events", handlers::list_events)
+        .get("/events/{event_id}/calendar.ics", handlers::event_calendar)
+        .get("/events/{event_file}", handlers::event_calendar_file)
+        .put("/events/{event_id}/actuals", handlers::record_event_actuals)
+        .post("/events/{event_id}/status", handlers::update_event_status)
+        .put("/events/{event_id}/details", handlers::update_event)
+        .get("/events/{event_id}/registrations", handlers::list_registrations)
+        .post("/events/{event_id}/registrations", handlers::register_for_event)
+        .delete("/events/{event_id}/registrations/{registration_id}", handlers::cancel_registration)
+        .get("/events/{event_id}/budget", handlers::get_event_budget)
+        .post("/events/{event_id}/budget", handlers::add_budget_item)
+        .put("/events/{event_id}/budget/{item_id}", handlers::update_budget_item)
+        .delete("/events/{event_id}/budget/{item_id}", handlers::delete_budget_item)
+        .put("/events/{event_id}/budget/{item_id}/approved", handlers::approve_budget_item)
+        .put("/events/{event_id}/budget/{item_id}/spent", handlers::record_budget_spending)
+        .get("/events/{event_id}/budget/{item_id}/reimbursement", handlers::get_reimbursement)
+        .post("/events/{event_id}/budget/{item_id}/reimbursement", handlers::prepare_reimbursement)
+        .post("/events/{event_id}/budget/{item_id}/reimbursement/submit", handlers::submit_reimbursement)
+        .get("/events/{event_id}/accounts", handlers::list_linked_accounts)
+        .post("/events/{event_id}/accounts", handlers::link_account)
+        .delete("/events/{event_id}/accounts/{link_id}", handlers::unlink_account)
+        .get("/events/{event_id}/attendance", handlers::get_event_attendance)
+        .post("/events/{event_id}/attendance", handlers::check_in_attendee)
+        .delete("/events/{event_id}/attendance/{check_in_id}", handlers::remove_check_in)
+        .post("/events/{event_id}/attendance/tokens", handlers::prepare_attendance_tokens)
+        .post("/events/{event_id}/attendance/tokens/{issuance_id}/submit", handlers::submit_attendance_tokens)
+        .get("/users/{wallet_address}/attendance", handlers::get_user_attendance)
+        .get("/users/{wallet_address}/notifications", handlers::list_notifications)
+        .post("/users/{wallet_address}/notifications/read", handlers::mark_notifications_read)
+        .get("/users/{wallet_address}/notification-preferences", handlers::get_notification_preferences)
+        .put("/users/{wallet_address}/notification-preferences", handlers::update_notification_preferences)
+        .get("/stellar/network", handlers::get_stellar_network)
+        .get("/events/{event_id}/attachments", handlers::list_attachments)
+        // The upload handler limits the body to the configured file size, plus the form around it, itself
+        .route(
+            Method::POST,
+            "/events/{event_id}/attachments",
+            post(handlers::upload_attachment).layer(DefaultBodyLimit::disable()),
+        )
+        .delete("/events/{event_id}/attachments/{attachment_id}", handlers::delete_attachment)
+        .get("/events/{event_id}/attachments/{attachment_id}/content", handlers::attachment_content)
+        .get("/events/{event_id}/attachments/{attachment_id}/thumbnail", handlers::attachment_thumbnail)
+        .get("/calendar.ics", handlers::calendar_feed)
+        .get("/feeds/events.atom", handlers::atom_feed)
+        .get("/feeds/events.rss", handlers::rss_feed)
+        .get("/chapters", handlers::list_chapters)
+        .post("/chapters", handlers::create_chapter)
+        .get("/chapters/{chapter_id}", handlers::get_chapter)
+        .put("/chapters/{chapter_id}", handlers::update_chapter)
+        .delete("/chapters/{chapter_id}", handlers::delete_chapter)
+        .get("/chapters/{chapter_id}/members", handlers::list_chapter_members)
+        .post("/chapters/{chapter_id}/members", handlers::add_chapter_member)
+        .delete("/chapters/{chapter_id}/members/{user_id}", handlers::remove_chapter_member)
+        .get("/chapters/{chapter_id}/targets/{year}/{quarter}", handlers::get_chapter_targets)
+        .put("/chapters/{chapter_id}/targets/{year}/{quarter}", handlers::update_chapter_targets)
+        .get("/chapters/{chapter_id}/dashboard", handlers::chapter_dashboard)
+        .get("/plans", handlers::list_plans)
+        .post("/plans", handlers::create_plan)
+        .get("/plans/{plan_id}", handlers::get_plan)
+        .put("/plans/{plan_id}", handlers::update_plan)
+        .post("/plans/{plan_id}/transitions", handlers::transition_plan)
+        .post("/plans/{plan_id}/comments", handlers::add_plan_comment)
+}
@@ -0,0 +1,58 @@
+use backend::database::repositories::JobRepository;
+use backend::jobs;
+use backend::mail::open_mailer;
+use backend::{create_pool, AppState, JobsConfig, MailConfig, RemindersConfig, StellarConfig, StorageConfig};
+use chrono::Utc;
+
+const USAGE: &str = "usage: worker [run | dead | retry <job id>]";
+
+/// Runs background jobs apart from the API server, which is then started with
+/// `JOBS_WORKER=off`. Also lists dead jobs and queues them again.
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+    dotenvy::dotenv().ok();
+
+    let pool = create_pool().await?;
+    sqlx::migrate!("./migrations").run(&pool).await?;
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        [] | ["run"] => {
+            let stellar = StellarConfig::from_env()?;
+            let storage = StorageConfig::from_env()?;
+            let mail = MailConfig::from_env()?;
+            let mailer = open_mailer(&mail)?;
+            let jobs_config = JobsConfig::from_env()?;
+            let reminders = RemindersConfig::from_env()?;
+            let state = AppState::new(pool, stellar)
+                .with_storage_config(&storage)
+                .with_mailer(mailer, &mail.app_url)
+                .with_reminders(&reminders);
+
+            jobs::schedule_recurring(&state).await?;
+            println!("⚙️ Job worker started, {} jobs at a time", jobs_config.concurrency);
+            jobs::worker(state, &jobs_config).run().await;
+        }
+        ["dead"] => {
+            let dead = JobRepository::list_dead(&pool).await?;
+            if dead.is_empty() {
+                println!("No dead jobs");
+            }
+            for job in dead {
+                let failed_at = job.failed_at.map(|at| at.to_rfc3339()).unwrap_or_default();
+                println!("{}\t{}\t{} attempts\t{}\t{}", job.id, job.kind, job.attempts, failed_at, job.last_error.unwrap_or_default());
+            }
+        }
+        ["retry", id] => {
+            let id: i64 = id.parse().map_err(|_| anyhow::anyhow!("{} is not a job id\n{}", id, USAGE))?;
+            match JobRepository::requeue(&pool, id, Utc::now()).await? {
+                Some(job) => println!("Job {} ({}) queued again", job.id, job.kind),
+                None => anyhow::bail!("Job {} is not dead, or one like it is already queued", id),
+            }
+        }
+        _ => anyhow::bail!(USAGE),
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,561 @@
+use anyhow::{bail, Context, Result};
+use axum::http::{header, HeaderName, HeaderValue, Method};
+use chrono::{DateTime, TimeZone, Utc};
+use std::env;
+use std::path::PathBuf;
+use std::time::Duration;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+const DEFAULT_ALLOWED_ORIGINS: &[&str] = &["http://127.0.0.1:8000", "http://localhost:8000"];
+
+/// Cross-origin policy for the API, read from `CORS_*` environment variables.
+#[derive(Debug, Clone)]
+pub struct CorsConfig {
+    pub allowed_origins: Vec<HeaderValue>,
+    pub allowed_methods: Vec<Method>,
+    pub allowed_headers: Vec<HeaderName>,
+    pub allow_credentials: bool,
+    pub max_age: Duration,
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self {
+            allowed_origins: DEFAULT_ALLOWED_ORIGINS
+                .iter()
+                .map(|origin| HeaderValue::from_static(origin))
+                .collect(),
+            allowed_methods: vec![Method::GET, Method::POST, Method::PUT, Method::DELETE],
+            allowed_headers: vec![header::CONTENT_TYPE, header::AUTHORIZATION, header::ACCEPT],
+            allow_credentials: true,
+            max_age: Duration::from_secs(600),
+        }
+    }
+}
+
+impl CorsConfig {
+    /// Comma-separated lists override the defaults, e.g.
+    /// `CORS_ALLOWED_ORIGINS=https://stellareurope.org,http://localhost:8000`.
+    pub fn from_env() -> Result<Self> {
+        let mut config = Self::default();
+
+        if let Some(origins) = env_list("CORS_ALLOWED_ORIGINS") {
+            config.allowed_origins = origins
+                .iter()
+                .map(|origin| {
+                    if origin == "*" {
+                        bail!("CORS_ALLOWED_ORIGINS must list explicit origins, '*' is not allowed");
+                    }
+                    HeaderValue::from_str(origin)
+                        .with_context(|| format!("Invalid origin in CORS_ALLOWED_ORIGINS: {}", origin))
+                })
+                .collect::<Result<_>>()?;
+        }
+
+        if let Some(methods) = env_list("CORS_ALLOWED_METHODS") {
+            config.allowed_methods = methods
+                .iter()
+                .map(|method| {
+                    if method == "*" {
+                        bail!("CORS_ALLOWED_METHODS must list explicit methods, '*' is not allowed");
+                    }
+                    Method::from_bytes(method.to_uppercase().as_bytes())
+                        .with_context(|| format!("Invalid method in CORS_ALLOWED_METHODS: {}", method))
+                })
+                .collect::<Result<_>>()?;
+        }
+
+        if let Some(headers) = env_list("CORS_ALLOWED_HEADERS") {
+            config.allowed_headers = headers
+                .iter()
+                .map(|name| {
+                    if name == "*" {
+                        bail!("CORS_ALLOWED_HEADERS must list explicit headers, '*' is not allowed");
+                    }
+                    HeaderName::from_bytes(name.as_bytes())
+                        .with_context(|| format!("Invalid header in CORS_ALLOWED_HEADERS: {}", name))
+                })
+                .collect::<Result<_>>()?;
+        }
+
+        if let Ok(value) = env::var("CORS_ALLOW_CREDENTIALS") {
+            config.allow_credentials = value
+                .parse()
+                .with_context(|| format!("CORS_ALLOW_CREDENTIALS must be true or false, got {}", value))?;
+        }
+
+        if let Ok(value) = env::var("CORS_MAX_AGE_SECS") {
+            let secs = value
+                .parse()
+                .with_context(|| format!("CORS_MAX_AGE_SECS must be a number of seconds, got {}", value))?;
+            config.max_age = Duration::from_secs(secs);
+        }
+
+        Ok(config)
+    }
+
+    pub fn layer(&self) -> CorsLayer {
+        CorsLayer::new()
+            .allow_origin(AllowOrigin::list(self.allowed_origins.clone()))
+            .allow_methods(self.allowed_methods.clone())
+            .allow_headers(self.allowed_headers.clone())
+            .allow_credentials(self.allow_credentials)
+            // Lets the frontend see when it is talking to a deprecated API version
+            .expose_headers([
+                HeaderName::from_static("deprecation"),
+                HeaderName::from_static("sunset"),
+                header::LINK,
+            ])
+            .max_age(self.max_age)
+    }
+}
+
+fn env_list(key: &str) -> Option<Vec<String>> {
+    let value = env::var(key).ok()?;
+    let items: Vec<String> = value
+        .split(',')
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect();
+
+    if items.is_empty() {
+        None
+    } else {
+        Some(items)
+    }
+}
+
+
+const TESTNET_PASSPHRASE: &str = "Test SDF Network ; September 2015";
+const PUBLIC_PASSPHRASE: &str = "Public Global Stellar Network ; September 2015";
+// Circle's USDC issuers on each network
+const TESTNET_USDC_ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+const PUBLIC_USDC_ISSUER: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+
+/// Where and from which account expense reimbursements are paid, read from
+/// `STELLAR_*` environment variables. Defaults to testnet with payouts disabled.
+#[derive(Debug, Clone)]
+pub struct StellarConfig {
+    pub network_passphrase: String,
+    pub horizon_url: String,
+    pub usdc_issuer: String,
+    /// Programme account reimbursements are paid from; payouts are disabled without one
+    pub payout_account: Option<String>,
+    /// Programme account issuing proof-of-attendance tokens; issuance is disabled without one
+    pub attendance_issuer: Option<String>,
+    /// Wallet addresses of the programme admins who prepare and sign payouts and token issuance
+    pub admin_wallets: Vec<String>,
+    /// How often accounts linked to events are checked on Horizon
+    pub account_check_interval: Duration,
+}
+
+impl Default for StellarConfig {
+    fn default() -> Self {
+        Self {
+            network_passphrase: TESTNET_PASSPHRASE.to_string(),
+            horizon_url: "https://horizon-testnet.stellar.org".to_string(),
+            usdc_issuer: TESTNET_USDC_ISSUER.to_string(),
+            payout_account: None,
+            attendance_issuer: None,
+            admin_wallets: Vec::new(),
+            account_check_interval: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+impl StellarConfig {
+    /// `STELLAR_NETWORK=public` switches every default to the public network;
+    /// `STELLAR_HORIZON_URL`, `STELLAR_NETWORK_PASSPHRASE` and `STELLAR_USDC_ISSUER`
+    /// override them one by one.
+    pub fn from_env() -> Result<Self> {
+        let mut config = match env::var("STELLAR_NETWORK").as_deref() {
+            Err(_) | Ok("testnet") => Self::default(),
+            Ok("public") => Self {
+                network_passphrase: PUBLIC_PASSPHRASE.to_string(),
+                horizon_url: "https://horizon.stellar.org".to_string(),
+                usdc_issuer: PUBLIC_USDC_ISSUER.to_string(),
+                ..Self::default()
+            },
+            Ok(other) => bail!("STELLAR_NETWORK must be testnet or public, got {}", other),
+        };
+
+        if let Ok(url) = env::var("STELLAR_HORIZON_URL") {
+            config.horizon_url = url.trim_end_matches('/').to_string();
+        }
+        if let Ok(passphrase) = env::var("STELLAR_NETWORK_PASSPHRASE") {
+            config.network_passphrase = passphrase;
+        }
+        if let Ok(issuer) = env::var("STELLAR_USDC_ISSUER") {
+            config.usdc_issuer = issuer;
+        }
+        config.payout_account = env::var("STELLAR_PAYOUT_ACCOUNT").ok().filter(|account| !account.trim().is_empty());
+        config.attendance_issuer = env::var("STELLAR_ATTENDANCE_ISSUER").ok().filter(|account| !account.trim().is_empty());
+        config.admin_wallets = env_list("PROGRAMME_ADMIN_WALLETS").unwrap_or_default();
+        if let Ok(value) = env::var("STELLAR_ACCOUNT_CHECK_INTERVAL_SECS") {
+            let secs: u64 = value
+                .parse()
+                .ok()
+                .filter(|secs| *secs > 0)
+                .with_context(|| format!("STELLAR_ACCOUNT_CHECK_INTERVAL_SECS must be a positive number of seconds, got {}", value))?;
+            config.account_check_interval = Duration::from_secs(secs);
+        }
+
+        for (key, account) in [
+            ("STELLAR_USDC_ISSUER", Some(&config.usdc_issuer)),
+            ("STELLAR_PAYOUT_ACCOUNT", config.payout_account.as_ref()),
+            ("STELLAR_ATTENDANCE_ISSUER", config.attendance_issuer.as_ref()),
+        ] {
+            if let Some(account) = account {
+                account
+                    .parse::<stellar_xdr::curr::AccountId>()
+                    .map_err(|_| anyhow::anyhow!("{} must be a Stellar account id (G...), got {}", key, account))?;
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn is_admin(&self, wallet_address: &str) -> bool {
+        self.admin_wallets.iter().any(|admin| admin == wallet_address.trim())
+    }
+
+    /// The network's name as wallets such as Freighter report it: `PUBLIC`, `TESTNET`,
+    /// or `CUSTOM` for any other passphrase.
+    pub fn network_name(&self) -> &'static str {
+        match self.network_passphrase.as_str() {
+            PUBLIC_PASSPHRASE => "PUBLIC",
+            TESTNET_PASSPHRASE => "TESTNET",
+            _ => "CUSTOM",
+        }
+    }
+}
+
+/// Where uploaded receipts, photos and slides are kept
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageBackend {
+    /// Files under a directory on the server
+    Local { root: PathBuf },
+    /// A bucket on an S3-compatible service such as MinIO
+    S3 { endpoint: reqwest::Url, bucket: String, region: String, access_key_id: String, secret_access_key: String },
+}
+
+/// Upload storage and limits, read from `STORAGE_*`, `S3_*` and `UPLOAD_*`
+/// environment variables. Defaults to a local `uploads` directory.
+#[derive(Debug, Clone)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+    /// Largest file accepted by the upload endpoint
+    pub max_upload_bytes: usize,
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        Self { backend: StorageBackend::Local { root: PathBuf::from("uploads") }, max_upload_bytes: 10 * 1024 * 1024 }
+    }
+}
+
+impl StorageConfig {
+    /// `STORAGE_BACKEND=local` (the default) writes under `STORAGE_LOCAL_DIR`;
+    /// `STORAGE_BACKEND=s3` needs `S3_ENDPOINT`, `S3_BUCKET`, `S3_ACCESS_KEY_ID` and
+    /// `S3_SECRET_ACCESS_KEY`, with `S3_REGION` defaulting to `us-east-1`.
+    pub fn from_env() -> Result<Self> {
+        let backend = match env::var("STORAGE_BACKEND").as_deref() {
+            Err(_) | Ok("local") => StorageBackend::Local {
+                root: env::var("STORAGE_LOCAL_DIR").map(PathBuf::from).unwrap_or_else(|_| PathBuf::from("uploads")),
+            },
+            Ok("s3") => {
+                let required = |key: &str| env::var(key).with_context(|| format!("{} must be set when STORAGE_BACKEND=s3", key));
+                StorageBackend::S3 {
+                    endpoint: required("S3_ENDPOINT")?
+                        .parse()
+                        .context("S3_ENDPOINT must be a URL such as http://localhost:9000")?,
+                    bucket: required("S3_BUCKET")?,
+                    region: env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+                    access_key_id: required("S3_ACCESS_KEY_ID")?,
+                    secret_access_key: required("S3_SECRET_ACCESS_KEY")?,
+                }
+            }
+            Ok(other) => bail!("STORAGE_BACKEND must be local or s3, got {}", other),
+        };
+        let mut config = Self { backend, ..Self::default() };
+
+        if let Ok(value) = env::var("UPLOAD_MAX_BYTES") {
+            config.max_upload_bytes = value
+                .parse()
+                .with_context(|| format!("UPLOAD_MAX_BYTES must be a number of bytes, got {}", value))?;
+        }
+
+        Ok(config)
+    }
+}
+
+/// How an SMTP connection is secured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpSecurity {
+    /// Plain text, for a local mail catcher only
+    None,
+    /// Upgrades a plain connection, usually on port 587
+    StartTls,
+    /// TLS from the start, usually on port 465
+    Tls,
+}
+
+/// Where outgoing mail goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MailTransport {
+    /// Logs each message, and also writes it as an `.eml` file under `dir` when one is given
+    Log { dir: Option<PathBuf> },
+    Smtp { host: String, port: u16, security: SmtpSecurity, username: Option<String>, password: Option<String> },
+}
+
+/// Outgoing mail, read from `MAIL_*`, `SMTP_*` and `APP_URL` environment variables.
+/// Defaults to logging messages, for development.
+#[derive(Debug, Clone)]
+pub struct MailConfig {
+    pub transport: MailTransport,
+    /// Sender of every message, e.g. `Stellar Europe <noreply@example.org>`
+    pub from: String,
+    /// Where the frontend is served; links in messages point there
+    pub app_url: String,
+}
+
+impl Default for MailConfig {
+    fn default() -> Self {
+        Self {
+            transport: MailTransport::Log { dir: None },
+            from: "Stellar Europe <noreply@localhost>".to_string(),
+            app_url: DEFAULT_ALLOWED_ORIGINS[0].to_string(),
+        }
+    }
+}
+
+impl MailConfig {
+    /// `MAIL_TRANSPORT=log` (the default) logs messages and writes them under `MAIL_LOG_DIR`
+    /// if set; `MAIL_TRANSPORT=smtp` needs `SMTP_HOST`, with `SMTP_SECURITY` (`starttls`,
+    /// `tls` or `none`, default `starttls`), `SMTP_PORT` defaulting to the security's usual
+    /// port, and optional `SMTP_USERNAME` and `SMTP_PASSWORD`.
+    pub fn from_env() -> Result<Self> {
+        let transport = match env::var("MAIL_TRANSPORT").as_deref() {
+            Err(_) | Ok("log") => MailTransport::Log { dir: env::var("MAIL_LOG_DIR").ok().map(PathBuf::from) },
+            Ok("smtp") => {
+                let security = match env::var("SMTP_SECURITY").as_deref() {
+                    Err(_) | Ok("starttls") => SmtpSecurity::StartTls,
+                    Ok("tls") => SmtpSecurity::Tls,
+                    Ok("none") => SmtpSecurity::None,
+                    Ok(other) => bail!("SMTP_SECURITY must be starttls, tls or none, got {}", other),
+                };
+                let port = match env::var("SMTP_PORT") {
+                    Ok(value) => value.parse().with_context(|| format!("SMTP_PORT must be a port number, got {}", value))?,
+                    Err(_) => match security {
+                        SmtpSecurity::None => 25,
+                        SmtpSecurity::StartTls => 587,
+                        SmtpSecurity::Tls => 465,
+                    },
+                };
+                MailTransport::Smtp {
+                    host: env::var("SMTP_HOST").context("SMTP_HOST must be set when MAIL_TRANSPORT=smtp")?,
+                    port,
+                    security,
+                    username: env::var("SMTP_USERNAME").ok().filter(|username| !username.is_empty()),
+                    password: env::var("SMTP_PASSWORD").ok(),
+                }
+            }
+            Ok(other) => bail!("MAIL_TRANSPORT must be log or smtp, got {}", other),
+        };
+        let mut config = Self { transport, ..Self::default() };
+
+        if let Ok(from) = env::var("MAIL_FROM") {
+            config.from = from;
+        }
+        if let Ok(app_url) = env::var("APP_URL") {
+            config.app_url = app_url.trim_end_matches('/').to_string();
+        }
+
+        Ok(config)
+    }
+}
+
+/// When the unversioned `/api/*` routes were deprecated and when they stop being served,
+/// read from `LEGACY_API_*` environment variables.
+#[derive(Debug, Clone)]
+pub struct VersioningConfig {
+    pub legacy_deprecated_at: DateTime<Utc>,
+    pub legacy_sunset: Option<DateTime<Utc>>,
+}
+
+impl Default for VersioningConfig {
+    fn default() -> Self {
+        Self {
+            // The release that introduced `/api/v1`
+            legacy_deprecated_at: Utc.with_ymd_and_hms(2026, 10, 19, 0, 0, 0).unwrap(),
+            legacy_sunset: None,
+        }
+    }
+}
+
+impl VersioningConfig {
+    /// `LEGACY_API_DEPRECATED_AT` and `LEGACY_API_SUNSET` take RFC 3339 timestamps, e.g.
+    /// `2027-04-01T00:00:00Z`. Without a sunset no `Sunset` header is sent.
+    pub fn from_env() -> Result<Self> {
+        let mut config = Self::default();
+        if let Some(at) = env_timestamp("LEGACY_API_DEPRECATED_AT")? {
+            config.legacy_deprecated_at = at;
+        }
+        config.legacy_sunset = env_timestamp("LEGACY_API_SUNSET")?;
+        if config.legacy_sunset.is_some_and(|sunset| sunset <= config.legacy_deprecated_at) {
+            bail!("LEGACY_API_SUNSET must be after LEGACY_API_DEPRECATED_AT");
+        }
+        Ok(config)
+    }
+}
+
+fn env_timestamp(key: &str) -> Result<Option<DateTime<Utc>>> {
+    match env::var(key) {
+        Ok(value) if !value.trim().is_empty() => DateTime::parse_from_rfc3339(value.trim())
+            .map(|at| Some(at.with_timezone(&Utc)))
+            .with_context(|| format!("{} must be an RFC 3339 timestamp, got {}", key, value)),
+        _ => Ok(None),
+    }
+}
+
+/// Background job processing, read from `JOBS_*` environment variables.
+#[derive(Debug, Clone)]
+pub struct JobsConfig {
+    /// Whether the backend runs jobs itself; off when they are left to the `worker` binary
+    pub run_worker: bool,
+    /// Jobs one worker runs at the same time
+    pub concurrency: usize,
+    /// How long an idle worker waits before looking for due jobs again
+    pub poll_interval: Duration,
+    /// How long a job may run before it counts as failed. A job still marked running
+    /// after twice this long lost its worker and is claimed again.
+    pub job_timeout: Duration,
+}
+
+impl Default for JobsConfig {
+    fn default() -> Self {
+        Self {
+            run_worker: true,
+            concurrency: 4,
+            poll_interval: Duration::from_secs(1),
+            job_timeout: Duration::from_secs(5 * 60),
+        }
+    }
+}
+
+impl JobsConfig {
+    /// `JOBS_WORKER=inline` (the default) runs jobs inside the backend and `JOBS_WORKER=off`
+    /// leaves them to the `worker` binary. `JOBS_CONCURRENCY`, `JOBS_POLL_INTERVAL_MS` and
+    /// `JOBS_TIMEOUT_SECS` override the other defaults.
+    pub fn from_env() -> Result<Self> {
+        let run_worker = match env::var("JOBS_WORKER").as_deref() {
+            Err(_) | Ok("inline") => true,
+            Ok("off") => false,
+            Ok(other) => bail!("JOBS_WORKER must be inline or off, got {}", other),
+        };
+        let mut config = Self { run_worker, ..Self::default() };
+
+        if let Ok(value) = env::var("JOBS_CONCURRENCY") {
+            config.concurrency = value
+                .parse()
+                .ok()
+                .filter(|concurrency| *concurrency > 0)
+                .with_context(|| format!("JOBS_CONCURRENCY must be a positive number, got {}", value))?;
+        }
+        if let Ok(value) = env::var("JOBS_POLL_INTERVAL_MS") {
+            let millis = value
+                .parse()
+                .ok()
+                .filter(|millis| *millis > 0)
+                .with_context(|| format!("JOBS_POLL_INTERVAL_MS must be a positive number of milliseconds, got {}", value))?;
+            config.poll_interval = Duration::from_millis(millis);
+        }
+        if let Ok(value) = env::var("JOBS_TIMEOUT_SECS") {
+            let secs = value
+                .parse()
+                .ok()
+                .filter(|secs| *secs > 0)
+                .with_context(|| format!("JOBS_TIMEOUT_SECS must be a positive number of seconds, got {}", value))?;
+            config.job_timeout = Duration::from_secs(secs);
+        }
+
+        Ok(config)
+    }
+}
+
+/// Reminders sent to registered attendees before an event, read from `EVENT_REMINDERS`.
+#[derive(Debug, Clone)]
+pub struct RemindersConfig {
+    /// How long before the start each reminder goes out, longest first
+    pub before: Vec<Duration>,
+}
+
+impl Default for RemindersConfig {
+    fn default() -> Self {
+        Self { before: vec![Duration::from_secs(7 * 24 * 60 * 60), Duration::from_secs(24 * 60 * 60)] }
+    }
+}
+
+impl RemindersConfig {
+    /// `EVENT_REMINDERS` lists the times before an event to remind attendees at, in days,
+    /// hours or minutes, e.g. `7d,24h,30m` (the default is `7d,24h`); `off` sends none.
+    pub fn from_env() -> Result<Self> {
+        if env::var("EVENT_REMINDERS").as_deref() == Ok("off") {
+            return Ok(Self { before: Vec::new() });
+        }
+        let Some(items) = env_list("EVENT_REMINDERS") else {
+            return Ok(Self::default());
+        };
+
+        let mut before = items
+            .iter()
+            .map(|item| {
+                parse_reminder(item)
+                    .with_context(|| format!("EVENT_REMINDERS must list times like 7d, 24h or 30m, got {}", item))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        before.sort_unstable_by(|a, b| b.cmp(a));
+        before.dedup();
+        Ok(Self { before })
+    }
+}
+
+fn parse_reminder(item: &str) -> Option<Duration> {
+    let unit = item.chars().last()?;
+    let minutes_per_unit = match unit {
+        'd' => 24 * 60,
+        'h' => 60,
+        'm' => 1,
+        _ => return None,
+    };
+    let amount: u64 = item[..item.len() - 1].parse().ok().filter(|amount| *amount > 0)?;
+    Some(Duration::from_secs(amount.checked_mul(minutes_per_unit * 60)?))
+}
+
+/// Settings for events, read from `EVENT_*` environment variables.
+#[derive(Debug, Clone)]
+pub struct EventsConfig {
+    /// IANA zone of events created without one, by clients from before events had a zone
+    pub default_timezone: String,
+}
+
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self { default_timezone: "UTC".to_string() }
+    }
+}
+
+impl EventsConfig {
+    /// `EVENT_DEFAULT_TIMEZONE` takes an IANA zone such as `Europe/Brussels`.
+    pub fn from_env() -> Result<Self> {
+        let mut config = Self::default();
+        if let Ok(zone) = env::var("EVENT_DEFAULT_TIMEZONE") {
+            let zone = zone.trim();
+            if zone.parse::<chrono_tz::Tz>().is_err() {
+                bail!("EVENT_DEFAULT_TIMEZONE must be an IANA time zone, got {}", zone);
+            }
+            config.default_timezone = zone.to_string();
+        }
+        Ok(config)
+    }
+}
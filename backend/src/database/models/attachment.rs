@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// A file uploaded for an event, optionally tied to one of its budget items.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: i32,
+    pub event_id: i32,
+    pub budget_item_id: Option<i32>,
+    pub kind: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+    pub thumbnail_key: Option<String>,
+    pub uploaded_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
\ No newline at end of file
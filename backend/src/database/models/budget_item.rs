@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// One line of an event's budget, in a single currency.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct BudgetItem {
+    pub id: i32,
+    pub event_id: i32,
+    pub category: String,
+    pub description: String,
+    pub currency: String,
+    pub requested_amount: Decimal,
+    pub approved_amount: Option<Decimal>,
+    pub spent_amount: Option<Decimal>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
\ No newline at end of file
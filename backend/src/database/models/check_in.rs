@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// An attendee an organiser checked in at an event.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct CheckIn {
+    pub id: i32,
+    pub event_id: i32,
+    pub user_id: i32,
+    pub checked_in_by: Option<i32>,
+    pub issuance_id: Option<i32>,
+    pub checked_in_at: DateTime<Utc>,
+}
+
+/// One transaction creating proof-of-attendance claimable balances for checked-in attendees.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AttendanceIssuance {
+    pub id: i32,
+    pub event_id: i32,
+    pub asset_code: String,
+    pub issuer: String,
+    pub status: String,
+    pub prepared_by: Option<i32>,
+    pub transaction_xdr: String,
+    pub transaction_hash: String,
+    pub signed_transaction_xdr: Option<String>,
+    pub ledger: Option<i32>,
+    pub error: Option<String>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub issued_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A checked-in attendee still without a token, and the wallet it goes to.
+#[derive(Debug, Clone)]
+pub struct TokenRecipient {
+    pub check_in_id: i32,
+    pub wallet_address: String,
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// A link mailed to a user to verify their address. Only the token's hash is kept.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct EmailVerification {
+    pub id: i32,
+    pub user_id: i32,
+    /// The address the link was sent to
+    pub email: String,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
\ No newline at end of file
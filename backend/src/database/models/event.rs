@@ -9,13 +9,23 @@ pub struct Event {
     pub description: String,
     pub event_type: String,
     pub date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub timezone: String,
     pub location: String,
     pub max_participants: Option<i32>,
     pub registration_required: bool,
     pub contact_email: String,
     pub external_link: Option<String>,
     pub organizer_id: i32,
+    pub chapter_id: Option<i32>,
+    pub plan_id: Option<i32>,
+    pub status: String, // "Draft", "Submitted", "Approved", "Published", "Cancelled" or "Completed"
+    pub approved_at: Option<DateTime<Utc>>,
+    pub cancellation_reason: Option<String>,
     pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+    /// Revision of what the event's calendar entry shows, bumped on every change to it
+    pub sequence: i32,
     // KPI Planning fields
     pub strategic_focus_areas: Option<Vec<String>>,
     pub monthly_active_ambassadors: Option<i32>,
@@ -28,20 +38,31 @@ pub struct Event {
     pub quarterly_goals: String,
     pub strategic_purpose: String,
     pub success_metrics: Option<String>,
+    // KPI actuals, recorded after the event
+    pub actual_monthly_active_ambassadors: Option<i32>,
+    pub actual_monthly_active_accounts: Option<i32>,
+    pub actual_scf_referrals: Option<i32>,
+    pub actual_content_produced: Option<i32>,
+    pub actual_attendance: Option<i32>,
+    pub actual_social_growth: Option<i32>,
 }
 
 impl Event {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         title: String,
         description: String,
         event_type: String,
         date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+        timezone: String,
         location: String,
         max_participants: Option<i32>,
         registration_required: bool,
         contact_email: String,
         external_link: Option<String>,
         organizer_id: i32,
+        chapter_id: Option<i32>,
         strategic_focus_areas: Option<Vec<String>>,
         monthly_active_ambassadors: Option<i32>,
         monthly_active_accounts: Option<i32>,
@@ -61,13 +82,22 @@ impl Event {
             description,
             event_type,
             date,
+            end_date,
+            timezone,
             location,
             max_participants,
             registration_required,
             contact_email,
             external_link,
             organizer_id,
+            chapter_id,
+            plan_id: None,
+            status: "Draft".to_string(),
+            approved_at: None,
+            cancellation_reason: None,
             created_at: Some(now),
+            updated_at: now,
+            sequence: 0,
             strategic_focus_areas,
             monthly_active_ambassadors,
             monthly_active_accounts,
@@ -79,6 +109,12 @@ impl Event {
             quarterly_goals,
             strategic_purpose,
             success_metrics,
+            actual_monthly_active_ambassadors: None,
+            actual_monthly_active_accounts: None,
+            actual_scf_referrals: None,
+            actual_content_produced: None,
+            actual_attendance: None,
+            actual_social_growth: None,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// A background job in the queue. `payload` is the job itself, as JSON.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct QueuedJob {
+    pub id: i64,
+    pub kind: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub unique_key: Option<String>,
+    /// Runs so far, counting the one in progress
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub run_at: DateTime<Utc>,
+    pub locked_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// When the job was given up on
+    pub failed_at: Option<DateTime<Utc>>,
+}
\ No newline at end of file
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// KPI targets a chapter set for one quarter of a year.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct KpiTarget {
+    pub chapter_id: i32,
+    pub year: i32,
+    pub quarter: i32,
+    pub monthly_active_ambassadors: Option<i32>,
+    pub monthly_active_accounts: Option<i32>,
+    pub scf_referrals: Option<i32>,
+    pub content_produced: Option<i32>,
+    pub attendance: Option<i32>,
+    pub social_growth: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// A Stellar account an attendee created at an event, and what Horizon said about it.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct LinkedAccount {
+    pub id: i32,
+    pub event_id: i32,
+    pub account_id: String,
+    pub linked_by: Option<i32>,
+    pub status: String,
+    pub funded_at: Option<DateTime<Utc>>,
+    pub last_transaction_at: Option<DateTime<Utc>>,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub check_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A pending link with the date of its event, which sets the month the account is checked in.
+#[derive(Debug, Clone)]
+pub struct PendingAccountCheck {
+    pub link: LinkedAccount,
+    pub event_date: DateTime<Utc>,
+}
\ No newline at end of file
@@ -1,5 +1,31 @@
 pub mod user;
 pub mod event;
+pub mod chapter;
+pub mod kpi_target;
+pub mod quarterly_plan;
+pub mod budget_item;
+pub mod reimbursement;
+pub mod attachment;
+pub mod linked_account;
+pub mod check_in;
+pub mod signup_challenge;
+pub mod email_verification;
+pub mod registration;
+pub mod notification;
+pub mod job;
 
 pub use user::*;
-pub use event::*;
\ No newline at end of file
+pub use event::*;
+pub use chapter::*;
+pub use kpi_target::*;
+pub use quarterly_plan::*;
+pub use budget_item::*;
+pub use reimbursement::*;
+pub use attachment::*;
+pub use linked_account::*;
+pub use check_in::*;
+pub use signup_challenge::*;
+pub use email_verification::*;
+pub use registration::*;
+pub use notification::*;
+pub use job::*;
\ No newline at end of file
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// A rendered notification in a user's inbox.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: i32,
+    pub user_id: i32,
+    pub kind: String,
+    pub subject: String,
+    pub body: String,
+    pub link: Option<String>,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Someone a notification is for, with their language and how they want this kind
+/// of notification. Users without a stored preference get both channels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationRecipient {
+    pub user_id: i32,
+    pub username: String,
+    pub email: String,
+    pub email_verified: bool,
+    pub locale: String,
+    pub by_email: bool,
+    pub in_app: bool,
+}
+
+/// A stored preference; kinds without one use the defaults.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct NotificationPreferenceRow {
+    pub kind: String,
+    pub email: bool,
+    pub in_app: bool,
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct QuarterlyPlan {
+    pub id: i32,
+    pub chapter_id: i32,
+    pub year: i32,
+    pub quarter: i32,
+    pub strategic_purpose: String,
+    pub quarterly_goals: String,
+    pub status: String, // "Draft", "Submitted", "Approved" or "ChangesRequested"
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct PlanCommentRecord {
+    pub id: i32,
+    pub plan_id: i32,
+    pub author_id: Option<i32>,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct PlanTransitionRecord {
+    pub id: i32,
+    pub plan_id: i32,
+    pub from_status: String,
+    pub to_status: String,
+    pub actor_id: Option<i32>,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
\ No newline at end of file
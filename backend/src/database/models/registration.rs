@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// A user's place, or place in line, at an event.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Registration {
+    pub id: i32,
+    pub event_id: i32,
+    pub user_id: i32,
+    pub status: String,
+    pub registered_at: DateTime<Utc>,
+    pub promoted_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// A Stellar payout of one budget item's spending.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Reimbursement {
+    pub id: i32,
+    pub budget_item_id: i32,
+    pub recipient_wallet_address: String,
+    pub asset: String,
+    pub amount: Decimal,
+    pub status: String,
+    pub prepared_by: Option<i32>,
+    pub transaction_xdr: String,
+    pub transaction_hash: String,
+    pub signed_transaction_xdr: Option<String>,
+    pub ledger: Option<i32>,
+    pub error: Option<String>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
\ No newline at end of file
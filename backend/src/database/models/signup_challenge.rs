@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A nonce issued to a wallet about to sign up, which it signs to prove it is the user's.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SignupChallenge {
+    pub nonce: Uuid,
+    pub wallet_address: String,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
\ No newline at end of file
@@ -12,6 +12,8 @@ pub struct User {
     pub organization: Option<String>,
     pub bio: Option<String>,
     pub created_at: Option<DateTime<Utc>>,
+    /// When the user followed the link mailed to their address; unverified users cannot create events
+    pub email_verified_at: Option<DateTime<Utc>>,
 }
 
 impl User {
@@ -33,6 +35,7 @@ impl User {
             organization,
             bio,
             created_at: Some(now),
+            email_verified_at: None,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,128 @@
+use crate::database::models::Attachment;
+use crate::database::connection::DbPool;
+use sqlx::{Error as SqlxError};
+
+pub struct AttachmentRepository;
+
+impl AttachmentRepository {
+    /// An event's attachments, newest first; only those of one budget item when `budget_item_id` is given.
+    pub async fn list_for_event(
+        pool: &DbPool,
+        event_id: i32,
+        budget_item_id: Option<i32>,
+    ) -> Result<Vec<Attachment>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, event_id, budget_item_id, kind, file_name, content_type, size_bytes, storage_key,
+                   thumbnail_key, uploaded_by, created_at
+            FROM attachments
+            WHERE event_id = $1 AND ($2::INTEGER IS NULL OR budget_item_id = $2)
+            ORDER BY created_at DESC, id DESC
+            "#,
+            event_id,
+            budget_item_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| Attachment {
+            id: row.id,
+            event_id: row.event_id,
+            budget_item_id: row.budget_item_id,
+            kind: row.kind,
+            file_name: row.file_name,
+            content_type: row.content_type,
+            size_bytes: row.size_bytes,
+            storage_key: row.storage_key,
+            thumbnail_key: row.thumbnail_key,
+            uploaded_by: row.uploaded_by,
+            created_at: row.created_at,
+        }).collect())
+    }
+
+    pub async fn find(pool: &DbPool, event_id: i32, attachment_id: i32) -> Result<Option<Attachment>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, event_id, budget_item_id, kind, file_name, content_type, size_bytes, storage_key,
+                   thumbnail_key, uploaded_by, created_at
+            FROM attachments
+            WHERE id = $1 AND event_id = $2
+            "#,
+            attachment_id,
+            event_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| Attachment {
+            id: row.id,
+            event_id: row.event_id,
+            budget_item_id: row.budget_item_id,
+            kind: row.kind,
+            file_name: row.file_name,
+            content_type: row.content_type,
+            size_bytes: row.size_bytes,
+            storage_key: row.storage_key,
+            thumbnail_key: row.thumbnail_key,
+            uploaded_by: row.uploaded_by,
+            created_at: row.created_at,
+        }))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        pool: &DbPool,
+        event_id: i32,
+        budget_item_id: Option<i32>,
+        kind: &str,
+        file_name: &str,
+        content_type: &str,
+        size_bytes: i64,
+        storage_key: &str,
+        thumbnail_key: Option<&str>,
+        uploaded_by: i32,
+    ) -> Result<Attachment, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO attachments (event_id, budget_item_id, kind, file_name, content_type, size_bytes, storage_key,
+                                     thumbnail_key, uploaded_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, event_id, budget_item_id, kind, file_name, content_type, size_bytes, storage_key,
+                      thumbnail_key, uploaded_by, created_at
+            "#,
+            event_id,
+            budget_item_id,
+            kind,
+            file_name,
+            content_type,
+            size_bytes,
+            storage_key,
+            thumbnail_key,
+            uploaded_by
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(Attachment {
+            id: row.id,
+            event_id: row.event_id,
+            budget_item_id: row.budget_item_id,
+            kind: row.kind,
+            file_name: row.file_name,
+            content_type: row.content_type,
+            size_bytes: row.size_bytes,
+            storage_key: row.storage_key,
+            thumbnail_key: row.thumbnail_key,
+            uploaded_by: row.uploaded_by,
+            created_at: row.created_at,
+        })
+    }
+
+    pub async fn delete(pool: &DbPool, attachment_id: i32) -> Result<bool, SqlxError> {
+        let result = sqlx::query!("DELETE FROM attachments WHERE id = $1", attachment_id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
\ No newline at end of file
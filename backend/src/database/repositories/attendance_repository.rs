@@ -0,0 +1,411 @@
+use crate::database::models::{AttendanceIssuance, CheckIn, TokenRecipient};
+use crate::database::connection::DbPool;
+use sqlx::{Error as SqlxError};
+
+pub struct AttendanceRepository;
+
+impl AttendanceRepository {
+    /// Checks the user in. Returns `None` if they already are.
+    pub async fn check_in(pool: &DbPool, event_id: i32, user_id: i32, checked_in_by: i32) -> Result<Option<CheckIn>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO event_check_ins (event_id, user_id, checked_in_by)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (event_id, user_id) DO NOTHING
+            RETURNING id, event_id, user_id, checked_in_by, issuance_id, checked_in_at
+            "#,
+            event_id,
+            user_id,
+            checked_in_by
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| CheckIn {
+            id: row.id,
+            event_id: row.event_id,
+            user_id: row.user_id,
+            checked_in_by: row.checked_in_by,
+            issuance_id: row.issuance_id,
+            checked_in_at: row.checked_in_at,
+        }))
+    }
+
+    pub async fn list_check_ins(pool: &DbPool, event_id: i32) -> Result<Vec<CheckIn>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, event_id, user_id, checked_in_by, issuance_id, checked_in_at
+            FROM event_check_ins
+            WHERE event_id = $1
+            ORDER BY checked_in_at, id
+            "#,
+            event_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| CheckIn {
+            id: row.id,
+            event_id: row.event_id,
+            user_id: row.user_id,
+            checked_in_by: row.checked_in_by,
+            issuance_id: row.issuance_id,
+            checked_in_at: row.checked_in_at,
+        }).collect())
+    }
+
+    /// Every event the user was checked in at, most recent first.
+    pub async fn list_check_ins_for_user(pool: &DbPool, user_id: i32) -> Result<Vec<CheckIn>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, event_id, user_id, checked_in_by, issuance_id, checked_in_at
+            FROM event_check_ins
+            WHERE user_id = $1
+            ORDER BY checked_in_at DESC, id DESC
+            "#,
+            user_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| CheckIn {
+            id: row.id,
+            event_id: row.event_id,
+            user_id: row.user_id,
+            checked_in_by: row.checked_in_by,
+            issuance_id: row.issuance_id,
+            checked_in_at: row.checked_in_at,
+        }).collect())
+    }
+
+    pub async fn find_check_in(pool: &DbPool, event_id: i32, check_in_id: i32) -> Result<Option<CheckIn>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, event_id, user_id, checked_in_by, issuance_id, checked_in_at
+            FROM event_check_ins
+            WHERE id = $1 AND event_id = $2
+            "#,
+            check_in_id,
+            event_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| CheckIn {
+            id: row.id,
+            event_id: row.event_id,
+            user_id: row.user_id,
+            checked_in_by: row.checked_in_by,
+            issuance_id: row.issuance_id,
+            checked_in_at: row.checked_in_at,
+        }))
+    }
+
+    pub async fn delete_check_in(pool: &DbPool, check_in_id: i32) -> Result<bool, SqlxError> {
+        let result = sqlx::query!("DELETE FROM event_check_ins WHERE id = $1", check_in_id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Checked-in attendees of the event whose token was never prepared, is still unsigned
+    /// or failed, in check-in order.
+    pub async fn list_token_recipients(pool: &DbPool, event_id: i32) -> Result<Vec<TokenRecipient>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT c.id, u.wallet_address
+            FROM event_check_ins c
+            JOIN users u ON u.id = c.user_id
+            LEFT JOIN attendance_issuances i ON i.id = c.issuance_id
+            WHERE c.event_id = $1 AND (i.id IS NULL OR i.status IN ('AwaitingSignature', 'Failed'))
+            ORDER BY c.checked_in_at, c.id
+            "#,
+            event_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| TokenRecipient {
+            check_in_id: row.id,
+            wallet_address: row.wallet_address,
+        }).collect())
+    }
+
+    /// The event's issuances, newest first.
+    pub async fn list_issuances(pool: &DbPool, event_id: i32) -> Result<Vec<AttendanceIssuance>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, event_id, asset_code, issuer, status, prepared_by, transaction_xdr, transaction_hash, signed_transaction_xdr,
+                   ledger, error, submitted_at, issued_at, created_at, updated_at
+            FROM attendance_issuances
+            WHERE event_id = $1
+            ORDER BY created_at DESC, id DESC
+            "#,
+            event_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| AttendanceIssuance {
+            id: row.id,
+            event_id: row.event_id,
+            asset_code: row.asset_code,
+            issuer: row.issuer,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            issued_at: row.issued_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }).collect())
+    }
+
+    pub async fn find_issuance(pool: &DbPool, event_id: i32, issuance_id: i32) -> Result<Option<AttendanceIssuance>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, event_id, asset_code, issuer, status, prepared_by, transaction_xdr, transaction_hash, signed_transaction_xdr,
+                   ledger, error, submitted_at, issued_at, created_at, updated_at
+            FROM attendance_issuances
+            WHERE id = $1 AND event_id = $2
+            "#,
+            issuance_id,
+            event_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| AttendanceIssuance {
+            id: row.id,
+            event_id: row.event_id,
+            asset_code: row.asset_code,
+            issuer: row.issuer,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            issued_at: row.issued_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Records a freshly built issuance for the given check-ins. Issuances of the event that
+    /// were never signed are discarded, so their attendees only ever have one pending token.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn prepare_issuance(
+        pool: &DbPool,
+        event_id: i32,
+        asset_code: &str,
+        issuer: &str,
+        prepared_by: i32,
+        transaction_xdr: &str,
+        transaction_hash: &str,
+        check_in_ids: &[i32],
+    ) -> Result<AttendanceIssuance, SqlxError> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query!(
+            "DELETE FROM attendance_issuances WHERE event_id = $1 AND status = 'AwaitingSignature'",
+            event_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO attendance_issuances (event_id, asset_code, issuer, prepared_by, transaction_xdr, transaction_hash)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, event_id, asset_code, issuer, status, prepared_by, transaction_xdr, transaction_hash, signed_transaction_xdr,
+                      ledger, error, submitted_at, issued_at, created_at, updated_at
+            "#,
+            event_id,
+            asset_code,
+            issuer,
+            prepared_by,
+            transaction_xdr,
+            transaction_hash
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE event_check_ins SET issuance_id = $1 WHERE event_id = $2 AND id = ANY($3)",
+            row.id,
+            event_id,
+            check_in_ids
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(AttendanceIssuance {
+            id: row.id,
+            event_id: row.event_id,
+            asset_code: row.asset_code,
+            issuer: row.issuer,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            issued_at: row.issued_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+
+    /// Stores the signed transaction about to be submitted. `None` unless the issuance
+    /// awaits its signature or an earlier submission's outcome is unknown.
+    pub async fn mark_submitted(pool: &DbPool, id: i32, signed_transaction_xdr: &str) -> Result<Option<AttendanceIssuance>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE attendance_issuances
+            SET status = 'Submitted', signed_transaction_xdr = $2, submitted_at = NOW(), error = NULL
+            WHERE id = $1 AND status IN ('AwaitingSignature', 'Submitted')
+            RETURNING id, event_id, asset_code, issuer, status, prepared_by, transaction_xdr, transaction_hash, signed_transaction_xdr,
+                      ledger, error, submitted_at, issued_at, created_at, updated_at
+            "#,
+            id,
+            signed_transaction_xdr
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| AttendanceIssuance {
+            id: row.id,
+            event_id: row.event_id,
+            asset_code: row.asset_code,
+            issuer: row.issuer,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            issued_at: row.issued_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// An issuance found in a ledger is issued, whatever an overlapping submission concluded.
+    pub async fn mark_issued(pool: &DbPool, id: i32, ledger: i32) -> Result<Option<AttendanceIssuance>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE attendance_issuances
+            SET status = 'Issued', ledger = $2, issued_at = NOW(), error = NULL
+            WHERE id = $1 AND status IN ('Submitted', 'Failed')
+            RETURNING id, event_id, asset_code, issuer, status, prepared_by, transaction_xdr, transaction_hash, signed_transaction_xdr,
+                      ledger, error, submitted_at, issued_at, created_at, updated_at
+            "#,
+            id,
+            ledger
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| AttendanceIssuance {
+            id: row.id,
+            event_id: row.event_id,
+            asset_code: row.asset_code,
+            issuer: row.issuer,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            issued_at: row.issued_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// The network refused the transaction; its attendees can be included in a new one.
+    pub async fn mark_failed(pool: &DbPool, id: i32, error: &str) -> Result<Option<AttendanceIssuance>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE attendance_issuances
+            SET status = 'Failed', error = $2
+            WHERE id = $1 AND status = 'Submitted'
+            RETURNING id, event_id, asset_code, issuer, status, prepared_by, transaction_xdr, transaction_hash, signed_transaction_xdr,
+                      ledger, error, submitted_at, issued_at, created_at, updated_at
+            "#,
+            id,
+            error
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| AttendanceIssuance {
+            id: row.id,
+            event_id: row.event_id,
+            asset_code: row.asset_code,
+            issuer: row.issuer,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            issued_at: row.issued_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Notes why a submitted issuance's outcome is still unknown.
+    pub async fn record_pending(pool: &DbPool, id: i32, error: &str) -> Result<Option<AttendanceIssuance>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE attendance_issuances
+            SET error = $2
+            WHERE id = $1 AND status = 'Submitted'
+            RETURNING id, event_id, asset_code, issuer, status, prepared_by, transaction_xdr, transaction_hash, signed_transaction_xdr,
+                      ledger, error, submitted_at, issued_at, created_at, updated_at
+            "#,
+            id,
+            error
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| AttendanceIssuance {
+            id: row.id,
+            event_id: row.event_id,
+            asset_code: row.asset_code,
+            issuer: row.issuer,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            issued_at: row.issued_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+}
\ No newline at end of file
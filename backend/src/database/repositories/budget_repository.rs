@@ -0,0 +1,246 @@
+use crate::database::models::BudgetItem;
+use crate::database::connection::DbPool;
+use rust_decimal::Decimal;
+use sqlx::{Error as SqlxError};
+
+pub struct BudgetRepository;
+
+impl BudgetRepository {
+    pub async fn list_for_event(pool: &DbPool, event_id: i32) -> Result<Vec<BudgetItem>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, event_id, category, description, currency, requested_amount, approved_amount,
+                   spent_amount, created_at, updated_at
+            FROM event_budget_items
+            WHERE event_id = $1
+            ORDER BY id ASC
+            "#,
+            event_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| BudgetItem {
+            id: row.id,
+            event_id: row.event_id,
+            category: row.category,
+            description: row.description,
+            currency: row.currency,
+            requested_amount: row.requested_amount,
+            approved_amount: row.approved_amount,
+            spent_amount: row.spent_amount,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }).collect())
+    }
+
+    /// Budget items of all the given events, for rollups over a quarter.
+    pub async fn list_for_events(pool: &DbPool, event_ids: &[i32]) -> Result<Vec<BudgetItem>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, event_id, category, description, currency, requested_amount, approved_amount,
+                   spent_amount, created_at, updated_at
+            FROM event_budget_items
+            WHERE event_id = ANY($1)
+            ORDER BY event_id ASC, id ASC
+            "#,
+            event_ids
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| BudgetItem {
+            id: row.id,
+            event_id: row.event_id,
+            category: row.category,
+            description: row.description,
+            currency: row.currency,
+            requested_amount: row.requested_amount,
+            approved_amount: row.approved_amount,
+            spent_amount: row.spent_amount,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }).collect())
+    }
+
+    pub async fn find(pool: &DbPool, event_id: i32, item_id: i32) -> Result<Option<BudgetItem>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, event_id, category, description, currency, requested_amount, approved_amount,
+                   spent_amount, created_at, updated_at
+            FROM event_budget_items
+            WHERE id = $1 AND event_id = $2
+            "#,
+            item_id,
+            event_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| BudgetItem {
+            id: row.id,
+            event_id: row.event_id,
+            category: row.category,
+            description: row.description,
+            currency: row.currency,
+            requested_amount: row.requested_amount,
+            approved_amount: row.approved_amount,
+            spent_amount: row.spent_amount,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    pub async fn create(
+        pool: &DbPool,
+        event_id: i32,
+        category: &str,
+        description: &str,
+        currency: &str,
+        requested_amount: Decimal,
+    ) -> Result<BudgetItem, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO event_budget_items (event_id, category, description, currency, requested_amount)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, event_id, category, description, currency, requested_amount, approved_amount,
+                      spent_amount, created_at, updated_at
+            "#,
+            event_id,
+            category,
+            description,
+            currency,
+            requested_amount
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(BudgetItem {
+            id: row.id,
+            event_id: row.event_id,
+            category: row.category,
+            description: row.description,
+            currency: row.currency,
+            requested_amount: row.requested_amount,
+            approved_amount: row.approved_amount,
+            spent_amount: row.spent_amount,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+
+    /// Replaces what an item requests. Any approved amount is cleared, since it was
+    /// approved for the old request.
+    pub async fn update_request(
+        pool: &DbPool,
+        item_id: i32,
+        category: &str,
+        description: &str,
+        currency: &str,
+        requested_amount: Decimal,
+    ) -> Result<Option<BudgetItem>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE event_budget_items
+            SET category = $2, description = $3, currency = $4, requested_amount = $5, approved_amount = NULL
+            WHERE id = $1
+            RETURNING id, event_id, category, description, currency, requested_amount, approved_amount,
+                      spent_amount, created_at, updated_at
+            "#,
+            item_id,
+            category,
+            description,
+            currency,
+            requested_amount
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| BudgetItem {
+            id: row.id,
+            event_id: row.event_id,
+            category: row.category,
+            description: row.description,
+            currency: row.currency,
+            requested_amount: row.requested_amount,
+            approved_amount: row.approved_amount,
+            spent_amount: row.spent_amount,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    pub async fn set_approved(
+        pool: &DbPool,
+        item_id: i32,
+        approved_amount: Option<Decimal>,
+    ) -> Result<Option<BudgetItem>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE event_budget_items
+            SET approved_amount = $2
+            WHERE id = $1
+            RETURNING id, event_id, category, description, currency, requested_amount, approved_amount,
+                      spent_amount, created_at, updated_at
+            "#,
+            item_id,
+            approved_amount
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| BudgetItem {
+            id: row.id,
+            event_id: row.event_id,
+            category: row.category,
+            description: row.description,
+            currency: row.currency,
+            requested_amount: row.requested_amount,
+            approved_amount: row.approved_amount,
+            spent_amount: row.spent_amount,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    pub async fn set_spent(
+        pool: &DbPool,
+        item_id: i32,
+        spent_amount: Option<Decimal>,
+    ) -> Result<Option<BudgetItem>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE event_budget_items
+            SET spent_amount = $2
+            WHERE id = $1
+            RETURNING id, event_id, category, description, currency, requested_amount, approved_amount,
+                      spent_amount, created_at, updated_at
+            "#,
+            item_id,
+            spent_amount
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| BudgetItem {
+            id: row.id,
+            event_id: row.event_id,
+            category: row.category,
+            description: row.description,
+            currency: row.currency,
+            requested_amount: row.requested_amount,
+            approved_amount: row.approved_amount,
+            spent_amount: row.spent_amount,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    pub async fn delete(pool: &DbPool, item_id: i32) -> Result<bool, SqlxError> {
+        let result = sqlx::query!("DELETE FROM event_budget_items WHERE id = $1", item_id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
\ No newline at end of file
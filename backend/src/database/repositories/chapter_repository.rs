@@ -0,0 +1,232 @@
+use crate::database::models::{Chapter, User};
+use crate::database::connection::DbPool;
+use sqlx::{Error as SqlxError};
+
+pub struct ChapterRepository;
+
+impl ChapterRepository {
+    pub async fn create_chapter(
+        pool: &DbPool,
+        name: &str,
+        country: &str,
+        city: &str,
+        lead_id: Option<i32>,
+    ) -> Result<Chapter, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO chapters (name, country, city, lead_id, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, NOW(), NOW())
+            RETURNING id, name, country, city, lead_id, created_at, updated_at
+            "#,
+            name,
+            country,
+            city,
+            lead_id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(Chapter {
+            id: row.id,
+            name: row.name,
+            country: row.country,
+            city: row.city,
+            lead_id: row.lead_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+
+    pub async fn list_chapters(pool: &DbPool) -> Result<Vec<Chapter>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, name, country, city, lead_id, created_at, updated_at
+            FROM chapters
+            ORDER BY country ASC, city ASC, name ASC
+            "#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let chapters = rows.into_iter().map(|row| Chapter {
+            id: row.id,
+            name: row.name,
+            country: row.country,
+            city: row.city,
+            lead_id: row.lead_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }).collect();
+
+        Ok(chapters)
+    }
+
+    pub async fn find_by_id(
+        pool: &DbPool,
+        chapter_id: i32,
+    ) -> Result<Option<Chapter>, SqlxError> {
+        let row = sqlx::query!(
+            "SELECT id, name, country, city, lead_id, created_at, updated_at FROM chapters WHERE id = $1",
+            chapter_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| Chapter {
+            id: row.id,
+            name: row.name,
+            country: row.country,
+            city: row.city,
+            lead_id: row.lead_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    pub async fn find_by_name(
+        pool: &DbPool,
+        name: &str,
+    ) -> Result<Option<Chapter>, SqlxError> {
+        let row = sqlx::query!(
+            "SELECT id, name, country, city, lead_id, created_at, updated_at FROM chapters WHERE LOWER(name) = LOWER($1)",
+            name
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| Chapter {
+            id: row.id,
+            name: row.name,
+            country: row.country,
+            city: row.city,
+            lead_id: row.lead_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Returns `None` when no chapter has this id.
+    pub async fn update_chapter(
+        pool: &DbPool,
+        chapter_id: i32,
+        name: &str,
+        country: &str,
+        city: &str,
+        lead_id: Option<i32>,
+    ) -> Result<Option<Chapter>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE chapters SET name = $2, country = $3, city = $4, lead_id = $5
+            WHERE id = $1
+            RETURNING id, name, country, city, lead_id, created_at, updated_at
+            "#,
+            chapter_id,
+            name,
+            country,
+            city,
+            lead_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| Chapter {
+            id: row.id,
+            name: row.name,
+            country: row.country,
+            city: row.city,
+            lead_id: row.lead_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Deletes the chapter and its memberships; its events stay, unassigned.
+    pub async fn delete_chapter(pool: &DbPool, chapter_id: i32) -> Result<bool, SqlxError> {
+        let result = sqlx::query!("DELETE FROM chapters WHERE id = $1", chapter_id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Adds the user to the chapter. Returns `false` if they were already a member.
+    pub async fn add_member(pool: &DbPool, chapter_id: i32, user_id: i32) -> Result<bool, SqlxError> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO chapter_members (chapter_id, user_id, joined_at)
+            VALUES ($1, $2, NOW())
+            ON CONFLICT (chapter_id, user_id) DO NOTHING
+            "#,
+            chapter_id,
+            user_id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Returns `false` if the user was not a member.
+    pub async fn remove_member(pool: &DbPool, chapter_id: i32, user_id: i32) -> Result<bool, SqlxError> {
+        let result = sqlx::query!(
+            "DELETE FROM chapter_members WHERE chapter_id = $1 AND user_id = $2",
+            chapter_id,
+            user_id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn list_members(pool: &DbPool, chapter_id: i32) -> Result<Vec<User>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT u.id, u.username, u.email, u.wallet_address, u.user_type, u.organization, u.bio, u.created_at, u.email_verified_at
+            FROM chapter_members m
+            JOIN users u ON u.id = m.user_id
+            WHERE m.chapter_id = $1
+            ORDER BY m.joined_at ASC
+            "#,
+            chapter_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let members = rows.into_iter().map(|row| User {
+            id: row.id,
+            username: row.username,
+            email: row.email,
+            wallet_address: row.wallet_address,
+            user_type: row.user_type,
+            organization: row.organization,
+            bio: row.bio,
+            created_at: row.created_at,
+            email_verified_at: row.email_verified_at,
+        }).collect();
+
+        Ok(members)
+    }
+
+    pub async fn count_members(pool: &DbPool, chapter_id: i32) -> Result<i64, SqlxError> {
+        let row = sqlx::query!(
+            r#"SELECT COUNT(*) AS "count!" FROM chapter_members WHERE chapter_id = $1"#,
+            chapter_id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(row.count)
+    }
+    pub async fn is_member(pool: &DbPool, chapter_id: i32, user_id: i32) -> Result<bool, SqlxError> {
+        let row = sqlx::query!(
+            r#"SELECT EXISTS (SELECT 1 FROM chapter_members WHERE chapter_id = $1 AND user_id = $2) AS "exists!""#,
+            chapter_id,
+            user_id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(row.exists)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,125 @@
+use crate::database::models::EmailVerification;
+use crate::database::connection::DbPool;
+use chrono::{DateTime, Utc};
+use sqlx::{Error as SqlxError};
+
+pub struct EmailVerificationRepository;
+
+impl EmailVerificationRepository {
+    pub async fn create(
+        pool: &DbPool,
+        user_id: i32,
+        email: &str,
+        token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<EmailVerification, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO email_verifications (user_id, email, token_hash, expires_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, user_id, email, token_hash, expires_at, used_at, created_at
+            "#,
+            user_id,
+            email,
+            token_hash,
+            expires_at
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(EmailVerification {
+            id: row.id,
+            user_id: row.user_id,
+            email: row.email,
+            token_hash: row.token_hash,
+            expires_at: row.expires_at,
+            used_at: row.used_at,
+            created_at: row.created_at,
+        })
+    }
+
+    pub async fn find_by_token_hash(pool: &DbPool, token_hash: &str) -> Result<Option<EmailVerification>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, user_id, email, token_hash, expires_at, used_at, created_at
+            FROM email_verifications
+            WHERE token_hash = $1
+            "#,
+            token_hash
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| EmailVerification {
+            id: row.id,
+            user_id: row.user_id,
+            email: row.email,
+            token_hash: row.token_hash,
+            expires_at: row.expires_at,
+            used_at: row.used_at,
+            created_at: row.created_at,
+        }))
+    }
+
+    /// The most recent link sent to the user, if any.
+    pub async fn latest_for_user(pool: &DbPool, user_id: i32) -> Result<Option<EmailVerification>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, user_id, email, token_hash, expires_at, used_at, created_at
+            FROM email_verifications
+            WHERE user_id = $1
+            ORDER BY created_at DESC, id DESC
+            LIMIT 1
+            "#,
+            user_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| EmailVerification {
+            id: row.id,
+            user_id: row.user_id,
+            email: row.email,
+            token_hash: row.token_hash,
+            expires_at: row.expires_at,
+            used_at: row.used_at,
+            created_at: row.created_at,
+        }))
+    }
+
+    /// Uses the link up and marks the user's address verified, as long as the link is unused
+    /// and unexpired and the user still has the address it was sent to. Returns `false` otherwise.
+    pub async fn verify(pool: &DbPool, verification: &EmailVerification) -> Result<bool, SqlxError> {
+        let mut tx = pool.begin().await?;
+
+        let used = sqlx::query!(
+            r#"
+            UPDATE email_verifications
+            SET used_at = NOW()
+            WHERE id = $1 AND used_at IS NULL AND expires_at > NOW()
+            "#,
+            verification.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let verified = sqlx::query!(
+            r#"
+            UPDATE users
+            SET email_verified_at = NOW()
+            WHERE id = $1 AND email = $2
+            "#,
+            verification.user_id,
+            verification.email
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if used.rows_affected() != 1 || verified.rows_affected() != 1 {
+            tx.rollback().await?;
+            return Ok(false);
+        }
+        tx.commit().await?;
+        Ok(true)
+    }
+}
\ No newline at end of file
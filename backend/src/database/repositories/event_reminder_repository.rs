@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Error as SqlxError, PgExecutor};
+
+pub struct EventReminderRepository;
+
+impl EventReminderRepository {
+    /// Records that the reminder `minutes_before` the event starting at `event_date` goes
+    /// out. Returns false if it already has, in which case it must not be sent again.
+    /// Takes a transaction so the record is only kept if the reminder is queued with it.
+    pub async fn record(
+        executor: impl PgExecutor<'_>,
+        event_id: i32,
+        minutes_before: i32,
+        event_date: DateTime<Utc>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO event_reminders (event_id, minutes_before, event_date)
+            VALUES ($1, $2, $3)
+            ON CONFLICT DO NOTHING
+            "#,
+            event_id,
+            minutes_before,
+            event_date
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
\ No newline at end of file
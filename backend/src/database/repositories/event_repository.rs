@@ -6,18 +6,22 @@ use chrono::{DateTime, Utc};
 pub struct EventRepository;
 
 impl EventRepository {
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_event(
         pool: &DbPool,
         title: &str,
         description: &str,
         event_type: &str,
         date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+        timezone: &str,
         location: &str,
         max_participants: Option<i32>,
         registration_required: bool,
         contact_email: &str,
         external_link: Option<&str>,
         organizer_id: i32,
+        chapter_id: Option<i32>,
         strategic_focus_areas: Option<&Vec<String>>,
         monthly_active_ambassadors: Option<i32>,
         monthly_active_accounts: Option<i32>,
@@ -32,24 +36,29 @@ impl EventRepository {
     ) -> Result<Event, SqlxError> {
         let row = sqlx::query!(
             r#"
-            INSERT INTO events (title, description, event_type, date, location, max_participants, registration_required, contact_email, external_link, organizer_id,
+            INSERT INTO events (title, description, event_type, date, end_date, timezone, location, max_participants, registration_required, contact_email, external_link, organizer_id, chapter_id,
                               strategic_focus_areas, monthly_active_ambassadors, monthly_active_accounts, scf_referrals, content_produced, expected_attendance,
-                              social_growth_target, target_audience, quarterly_goals, strategic_purpose, success_metrics, created_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, NOW())
-            RETURNING id, title, description, event_type, date, location, max_participants, registration_required, contact_email, external_link, organizer_id,
+                              social_growth_target, target_audience, quarterly_goals, strategic_purpose, success_metrics, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, NOW(), NOW())
+            RETURNING id, title, description, event_type, date, end_date, timezone, location, max_participants, registration_required, contact_email, external_link, organizer_id, chapter_id, plan_id, status, approved_at, cancellation_reason,
                       strategic_focus_areas, monthly_active_ambassadors, monthly_active_accounts, scf_referrals, content_produced, expected_attendance,
-                      social_growth_target, target_audience, quarterly_goals, strategic_purpose, success_metrics, created_at
+                      social_growth_target, target_audience, quarterly_goals, strategic_purpose, success_metrics, created_at, updated_at, sequence,
+                      actual_monthly_active_ambassadors, actual_monthly_active_accounts, actual_scf_referrals,
+                      actual_content_produced, actual_attendance, actual_social_growth
             "#,
             title,
             description,
             event_type,
             date,
+            end_date,
+            timezone,
             location,
             max_participants,
             registration_required,
             contact_email,
             external_link,
             organizer_id,
+            chapter_id,
             strategic_focus_areas.map(|v| v.as_slice()),
             monthly_active_ambassadors,
             monthly_active_accounts,
@@ -71,13 +80,22 @@ impl EventRepository {
             description: row.description,
             event_type: row.event_type,
             date: row.date,
+            end_date: row.end_date,
+            timezone: row.timezone,
             location: row.location,
             max_participants: row.max_participants,
             registration_required: row.registration_required,
             contact_email: row.contact_email,
             external_link: row.external_link,
             organizer_id: row.organizer_id,
+            chapter_id: row.chapter_id,
+            plan_id: row.plan_id,
+            status: row.status,
+            approved_at: row.approved_at,
+            cancellation_reason: row.cancellation_reason,
             created_at: row.created_at,
+            updated_at: row.updated_at,
+            sequence: row.sequence,
             strategic_focus_areas: row.strategic_focus_areas,
             monthly_active_ambassadors: row.monthly_active_ambassadors,
             monthly_active_accounts: row.monthly_active_accounts,
@@ -89,11 +107,25 @@ impl EventRepository {
             quarterly_goals: row.quarterly_goals,
             strategic_purpose: row.strategic_purpose,
             success_metrics: row.success_metrics,
+            actual_monthly_active_ambassadors: row.actual_monthly_active_ambassadors,
+            actual_monthly_active_accounts: row.actual_monthly_active_accounts,
+            actual_scf_referrals: row.actual_scf_referrals,
+            actual_content_produced: row.actual_content_produced,
+            actual_attendance: row.actual_attendance,
+            actual_social_growth: row.actual_social_growth,
         })
     }
 
+    /// Lists events by date, optionally only those of one chapter.
+    /// Publicly listed events: approved, published or completed ones, and cancellations of
+    /// those. The signed-in `viewer_id` also sees their own events in any status, and a
+    /// `reviewer` the events awaiting review; `status` narrows the listing.
     pub async fn list_events(
         pool: &DbPool,
+        chapter_id: Option<i32>,
+        viewer_id: Option<i32>,
+        reviewer: bool,
+        status: Option<&str>,
         limit: Option<i64>,
         offset: Option<i64>,
     ) -> Result<Vec<Event>, SqlxError> {
@@ -102,15 +134,28 @@ impl EventRepository {
 
         let rows = sqlx::query!(
             r#"
-            SELECT id, title, description, event_type, date, location, max_participants,
-                   registration_required, contact_email, external_link, organizer_id, created_at,
+            SELECT id, title, description, event_type, date, end_date, timezone, location, max_participants,
+                   registration_required, contact_email, external_link, organizer_id, chapter_id, plan_id, created_at, updated_at, sequence,
+                   status, approved_at, cancellation_reason,
                    strategic_focus_areas, monthly_active_ambassadors, monthly_active_accounts, scf_referrals,
                    content_produced, expected_attendance, social_growth_target, target_audience,
-                   quarterly_goals, strategic_purpose, success_metrics
+                   quarterly_goals, strategic_purpose, success_metrics,
+                   actual_monthly_active_ambassadors, actual_monthly_active_accounts, actual_scf_referrals,
+                   actual_content_produced, actual_attendance, actual_social_growth
             FROM events
+            WHERE ($1::INTEGER IS NULL OR chapter_id = $1)
+              AND (status IN ('Approved', 'Published', 'Completed')
+                   OR (status = 'Cancelled' AND approved_at IS NOT NULL)
+                   OR ($2::INTEGER IS NOT NULL AND organizer_id = $2)
+                   OR ($3 AND status = 'Submitted'))
+              AND ($4::TEXT IS NULL OR status = $4)
             ORDER BY date ASC
-            LIMIT $1 OFFSET $2
+            LIMIT $5 OFFSET $6
             "#,
+            chapter_id,
+            viewer_id,
+            reviewer,
+            status,
             limit,
             offset
         )
@@ -123,13 +168,22 @@ impl EventRepository {
             description: row.description,
             event_type: row.event_type,
             date: row.date,
+            end_date: row.end_date,
+            timezone: row.timezone,
             location: row.location,
             max_participants: row.max_participants,
             registration_required: row.registration_required,
             contact_email: row.contact_email,
             external_link: row.external_link,
             organizer_id: row.organizer_id,
+            chapter_id: row.chapter_id,
+            plan_id: row.plan_id,
+            status: row.status,
+            approved_at: row.approved_at,
+            cancellation_reason: row.cancellation_reason,
             created_at: row.created_at,
+            updated_at: row.updated_at,
+            sequence: row.sequence,
             strategic_focus_areas: row.strategic_focus_areas,
             monthly_active_ambassadors: row.monthly_active_ambassadors,
             monthly_active_accounts: row.monthly_active_accounts,
@@ -141,22 +195,524 @@ impl EventRepository {
             quarterly_goals: row.quarterly_goals,
             strategic_purpose: row.strategic_purpose,
             success_metrics: row.success_metrics,
+            actual_monthly_active_ambassadors: row.actual_monthly_active_ambassadors,
+            actual_monthly_active_accounts: row.actual_monthly_active_accounts,
+            actual_scf_referrals: row.actual_scf_referrals,
+            actual_content_produced: row.actual_content_produced,
+            actual_attendance: row.actual_attendance,
+            actual_social_growth: row.actual_social_growth,
         }).collect();
 
         Ok(events)
     }
 
+    /// Events for calendar exports: everything that ended in the last 30 days or
+    /// later, optionally narrowed to one chapter, event type and/or strategic focus area.
+    pub async fn list_for_calendar(
+        pool: &DbPool,
+        chapter_id: Option<i32>,
+        event_type: Option<&str>,
+        focus_area: Option<&str>,
+    ) -> Result<Vec<Event>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, title, description, event_type, date, end_date, timezone, location, max_participants,
+                   registration_required, contact_email, external_link, organizer_id, chapter_id, plan_id, created_at, updated_at, sequence,
+                   status, approved_at, cancellation_reason,
+                   strategic_focus_areas, monthly_active_ambassadors, monthly_active_accounts, scf_referrals,
+                   content_produced, expected_attendance, social_growth_target, target_audience,
+                   quarterly_goals, strategic_purpose, success_metrics,
+                   actual_monthly_active_ambassadors, actual_monthly_active_accounts, actual_scf_referrals,
+                   actual_content_produced, actual_attendance, actual_social_growth
+            FROM events
+            WHERE end_date >= NOW() - INTERVAL '30 days'
+              AND (status IN ('Approved', 'Published', 'Completed')
+                   OR (status = 'Cancelled' AND approved_at IS NOT NULL))
+              AND ($1::INTEGER IS NULL OR chapter_id = $1)
+              AND ($2::TEXT IS NULL OR event_type = $2)
+              AND ($3::TEXT IS NULL OR $3 = ANY(strategic_focus_areas))
+            ORDER BY date ASC
+            LIMIT 500
+            "#,
+            chapter_id,
+            event_type,
+            focus_area
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let events = rows.into_iter().map(|row| Event {
+            id: row.id,
+            title: row.title,
+            description: row.description,
+            event_type: row.event_type,
+            date: row.date,
+            end_date: row.end_date,
+            timezone: row.timezone,
+            location: row.location,
+            max_participants: row.max_participants,
+            registration_required: row.registration_required,
+            contact_email: row.contact_email,
+            external_link: row.external_link,
+            organizer_id: row.organizer_id,
+            chapter_id: row.chapter_id,
+            plan_id: row.plan_id,
+            status: row.status,
+            approved_at: row.approved_at,
+            cancellation_reason: row.cancellation_reason,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            sequence: row.sequence,
+            strategic_focus_areas: row.strategic_focus_areas,
+            monthly_active_ambassadors: row.monthly_active_ambassadors,
+            monthly_active_accounts: row.monthly_active_accounts,
+            scf_referrals: row.scf_referrals,
+            content_produced: row.content_produced,
+            expected_attendance: row.expected_attendance,
+            social_growth_target: row.social_growth_target,
+            target_audience: row.target_audience,
+            quarterly_goals: row.quarterly_goals,
+            strategic_purpose: row.strategic_purpose,
+            success_metrics: row.success_metrics,
+            actual_monthly_active_ambassadors: row.actual_monthly_active_ambassadors,
+            actual_monthly_active_accounts: row.actual_monthly_active_accounts,
+            actual_scf_referrals: row.actual_scf_referrals,
+            actual_content_produced: row.actual_content_produced,
+            actual_attendance: row.actual_attendance,
+            actual_social_growth: row.actual_social_growth,
+        }).collect();
+
+        Ok(events)
+    }
+
+    /// Publicly listed events that have not ended yet, soonest first.
+    pub async fn list_upcoming(
+        pool: &DbPool,
+        limit: i64,
+    ) -> Result<Vec<Event>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, title, description, event_type, date, end_date, timezone, location, max_participants,
+                   registration_required, contact_email, external_link, organizer_id, chapter_id, plan_id, created_at, updated_at, sequence,
+                   status, approved_at, cancellation_reason,
+                   strategic_focus_areas, monthly_active_ambassadors, monthly_active_accounts, scf_referrals,
+                   content_produced, expected_attendance, social_growth_target, target_audience,
+                   quarterly_goals, strategic_purpose, success_metrics,
+                   actual_monthly_active_ambassadors, actual_monthly_active_accounts, actual_scf_referrals,
+                   actual_content_produced, actual_attendance, actual_social_growth
+            FROM events
+            WHERE end_date >= NOW()
+              AND (status IN ('Approved', 'Published', 'Completed')
+                   OR (status = 'Cancelled' AND approved_at IS NOT NULL))
+            ORDER BY date ASC
+            LIMIT $1
+            "#,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let events = rows.into_iter().map(|row| Event {
+            id: row.id,
+            title: row.title,
+            description: row.description,
+            event_type: row.event_type,
+            date: row.date,
+            end_date: row.end_date,
+            timezone: row.timezone,
+            location: row.location,
+            max_participants: row.max_participants,
+            registration_required: row.registration_required,
+            contact_email: row.contact_email,
+            external_link: row.external_link,
+            organizer_id: row.organizer_id,
+            chapter_id: row.chapter_id,
+            plan_id: row.plan_id,
+            status: row.status,
+            approved_at: row.approved_at,
+            cancellation_reason: row.cancellation_reason,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            sequence: row.sequence,
+            strategic_focus_areas: row.strategic_focus_areas,
+            monthly_active_ambassadors: row.monthly_active_ambassadors,
+            monthly_active_accounts: row.monthly_active_accounts,
+            scf_referrals: row.scf_referrals,
+            content_produced: row.content_produced,
+            expected_attendance: row.expected_attendance,
+            social_growth_target: row.social_growth_target,
+            target_audience: row.target_audience,
+            quarterly_goals: row.quarterly_goals,
+            strategic_purpose: row.strategic_purpose,
+            success_metrics: row.success_metrics,
+            actual_monthly_active_ambassadors: row.actual_monthly_active_ambassadors,
+            actual_monthly_active_accounts: row.actual_monthly_active_accounts,
+            actual_scf_referrals: row.actual_scf_referrals,
+            actual_content_produced: row.actual_content_produced,
+            actual_attendance: row.actual_attendance,
+            actual_social_growth: row.actual_social_growth,
+        }).collect();
+
+        Ok(events)
+    }
+
+    /// Published events starting after `after`, soonest first.
+    pub async fn list_published_starting_after(
+        pool: &DbPool,
+        after: DateTime<Utc>,
+    ) -> Result<Vec<Event>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, title, description, event_type, date, end_date, timezone, location, max_participants,
+                   registration_required, contact_email, external_link, organizer_id, chapter_id, plan_id, created_at, updated_at, sequence,
+                   status, approved_at, cancellation_reason,
+                   strategic_focus_areas, monthly_active_ambassadors, monthly_active_accounts, scf_referrals,
+                   content_produced, expected_attendance, social_growth_target, target_audience,
+                   quarterly_goals, strategic_purpose, success_metrics,
+                   actual_monthly_active_ambassadors, actual_monthly_active_accounts, actual_scf_referrals,
+                   actual_content_produced, actual_attendance, actual_social_growth
+            FROM events
+            WHERE status = 'Published' AND date > $1
+            ORDER BY date ASC
+            "#,
+            after
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let events = rows.into_iter().map(|row| Event {
+            id: row.id,
+            title: row.title,
+            description: row.description,
+            event_type: row.event_type,
+            date: row.date,
+            end_date: row.end_date,
+            timezone: row.timezone,
+            location: row.location,
+            max_participants: row.max_participants,
+            registration_required: row.registration_required,
+            contact_email: row.contact_email,
+            external_link: row.external_link,
+            organizer_id: row.organizer_id,
+            chapter_id: row.chapter_id,
+            plan_id: row.plan_id,
+            status: row.status,
+            approved_at: row.approved_at,
+            cancellation_reason: row.cancellation_reason,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            sequence: row.sequence,
+            strategic_focus_areas: row.strategic_focus_areas,
+            monthly_active_ambassadors: row.monthly_active_ambassadors,
+            monthly_active_accounts: row.monthly_active_accounts,
+            scf_referrals: row.scf_referrals,
+            content_produced: row.content_produced,
+            expected_attendance: row.expected_attendance,
+            social_growth_target: row.social_growth_target,
+            target_audience: row.target_audience,
+            quarterly_goals: row.quarterly_goals,
+            strategic_purpose: row.strategic_purpose,
+            success_metrics: row.success_metrics,
+            actual_monthly_active_ambassadors: row.actual_monthly_active_ambassadors,
+            actual_monthly_active_accounts: row.actual_monthly_active_accounts,
+            actual_scf_referrals: row.actual_scf_referrals,
+            actual_content_produced: row.actual_content_produced,
+            actual_attendance: row.actual_attendance,
+            actual_social_growth: row.actual_social_growth,
+        }).collect();
+
+        Ok(events)
+    }
+
+    /// A chapter's events starting in `[from, to)`, earliest first, leaving out cancelled ones.
+    pub async fn list_for_chapter_between(
+        pool: &DbPool,
+        chapter_id: i32,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Event>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, title, description, event_type, date, end_date, timezone, location, max_participants,
+                   registration_required, contact_email, external_link, organizer_id, chapter_id, plan_id, created_at, updated_at, sequence,
+                   status, approved_at, cancellation_reason,
+                   strategic_focus_areas, monthly_active_ambassadors, monthly_active_accounts, scf_referrals,
+                   content_produced, expected_attendance, social_growth_target, target_audience,
+                   quarterly_goals, strategic_purpose, success_metrics,
+                   actual_monthly_active_ambassadors, actual_monthly_active_accounts, actual_scf_referrals,
+                   actual_content_produced, actual_attendance, actual_social_growth
+            FROM events
+            WHERE chapter_id = $1 AND date >= $2 AND date < $3 AND status <> 'Cancelled'
+            ORDER BY date ASC
+            "#,
+            chapter_id,
+            from,
+            to
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let events = rows.into_iter().map(|row| Event {
+            id: row.id,
+            title: row.title,
+            description: row.description,
+            event_type: row.event_type,
+            date: row.date,
+            end_date: row.end_date,
+            timezone: row.timezone,
+            location: row.location,
+            max_participants: row.max_participants,
+            registration_required: row.registration_required,
+            contact_email: row.contact_email,
+            external_link: row.external_link,
+            organizer_id: row.organizer_id,
+            chapter_id: row.chapter_id,
+            plan_id: row.plan_id,
+            status: row.status,
+            approved_at: row.approved_at,
+            cancellation_reason: row.cancellation_reason,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            sequence: row.sequence,
+            strategic_focus_areas: row.strategic_focus_areas,
+            monthly_active_ambassadors: row.monthly_active_ambassadors,
+            monthly_active_accounts: row.monthly_active_accounts,
+            scf_referrals: row.scf_referrals,
+            content_produced: row.content_produced,
+            expected_attendance: row.expected_attendance,
+            social_growth_target: row.social_growth_target,
+            target_audience: row.target_audience,
+            quarterly_goals: row.quarterly_goals,
+            strategic_purpose: row.strategic_purpose,
+            success_metrics: row.success_metrics,
+            actual_monthly_active_ambassadors: row.actual_monthly_active_ambassadors,
+            actual_monthly_active_accounts: row.actual_monthly_active_accounts,
+            actual_scf_referrals: row.actual_scf_referrals,
+            actual_content_produced: row.actual_content_produced,
+            actual_attendance: row.actual_attendance,
+            actual_social_growth: row.actual_social_growth,
+        }).collect();
+
+        Ok(events)
+    }
+
+    /// Events grouped into a quarterly plan, earliest first.
+    pub async fn list_for_plan(
+        pool: &DbPool,
+        plan_id: i32,
+    ) -> Result<Vec<Event>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, title, description, event_type, date, end_date, timezone, location, max_participants,
+                   registration_required, contact_email, external_link, organizer_id, chapter_id, plan_id, created_at, updated_at, sequence,
+                   status, approved_at, cancellation_reason,
+                   strategic_focus_areas, monthly_active_ambassadors, monthly_active_accounts, scf_referrals,
+                   content_produced, expected_attendance, social_growth_target, target_audience,
+                   quarterly_goals, strategic_purpose, success_metrics,
+                   actual_monthly_active_ambassadors, actual_monthly_active_accounts, actual_scf_referrals,
+                   actual_content_produced, actual_attendance, actual_social_growth
+            FROM events
+            WHERE plan_id = $1
+            ORDER BY date ASC
+            "#,
+            plan_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let events = rows.into_iter().map(|row| Event {
+            id: row.id,
+            title: row.title,
+            description: row.description,
+            event_type: row.event_type,
+            date: row.date,
+            end_date: row.end_date,
+            timezone: row.timezone,
+            location: row.location,
+            max_participants: row.max_participants,
+            registration_required: row.registration_required,
+            contact_email: row.contact_email,
+            external_link: row.external_link,
+            organizer_id: row.organizer_id,
+            chapter_id: row.chapter_id,
+            plan_id: row.plan_id,
+            status: row.status,
+            approved_at: row.approved_at,
+            cancellation_reason: row.cancellation_reason,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            sequence: row.sequence,
+            strategic_focus_areas: row.strategic_focus_areas,
+            monthly_active_ambassadors: row.monthly_active_ambassadors,
+            monthly_active_accounts: row.monthly_active_accounts,
+            scf_referrals: row.scf_referrals,
+            content_produced: row.content_produced,
+            expected_attendance: row.expected_attendance,
+            social_growth_target: row.social_growth_target,
+            target_audience: row.target_audience,
+            quarterly_goals: row.quarterly_goals,
+            strategic_purpose: row.strategic_purpose,
+            success_metrics: row.success_metrics,
+            actual_monthly_active_ambassadors: row.actual_monthly_active_ambassadors,
+            actual_monthly_active_accounts: row.actual_monthly_active_accounts,
+            actual_scf_referrals: row.actual_scf_referrals,
+            actual_content_produced: row.actual_content_produced,
+            actual_attendance: row.actual_attendance,
+            actual_social_growth: row.actual_social_growth,
+        }).collect();
+
+        Ok(events)
+    }
+
+    /// Records what an event achieved. Returns `None` if the event does not exist. Once any
+    /// linked account is verified, the verified count stands in for `monthly_active_accounts`.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn record_actuals(
+        pool: &DbPool,
+        event_id: i32,
+        monthly_active_ambassadors: Option<i32>,
+        monthly_active_accounts: Option<i32>,
+        scf_referrals: Option<i32>,
+        content_produced: Option<i32>,
+        attendance: Option<i32>,
+        social_growth: Option<i32>,
+    ) -> Result<Option<Event>, SqlxError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE events
+            SET actual_monthly_active_ambassadors = $2,
+                actual_monthly_active_accounts = COALESCE(
+                    (SELECT NULLIF(COUNT(*), 0)::INTEGER FROM event_linked_accounts
+                     WHERE event_id = $1 AND status = 'Verified'),
+                    $3),
+                actual_scf_referrals = $4, actual_content_produced = $5,
+                actual_attendance = $6, actual_social_growth = $7
+            WHERE id = $1
+            "#,
+            event_id,
+            monthly_active_ambassadors,
+            monthly_active_accounts,
+            scf_referrals,
+            content_produced,
+            attendance,
+            social_growth
+        )
+        .execute(pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        Self::find_by_id(pool, event_id).await
+    }
+
+    /// Replaces the event's monthly active accounts with the number of verified linked
+    /// accounts; leaves the recorded figure alone while none are verified.
+    pub async fn set_verified_accounts(pool: &DbPool, event_id: i32) -> Result<(), SqlxError> {
+        sqlx::query!(
+            r#"
+            UPDATE events
+            SET actual_monthly_active_accounts = COALESCE(
+                (SELECT NULLIF(COUNT(*), 0)::INTEGER FROM event_linked_accounts
+                 WHERE event_id = $1 AND status = 'Verified'),
+                actual_monthly_active_accounts)
+            WHERE id = $1
+            "#,
+            event_id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Moves the event from `from_status` to `to_status`. Returns `false` without changing
+    /// anything if the event is no longer in `from_status`.
+    pub async fn update_status(
+        pool: &DbPool,
+        event_id: i32,
+        from_status: &str,
+        to_status: &str,
+        cancellation_reason: Option<&str>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE events
+            SET status = $3::VARCHAR,
+                approved_at = CASE WHEN $3::VARCHAR = 'Approved' THEN COALESCE(approved_at, NOW()) ELSE approved_at END,
+                cancellation_reason = CASE WHEN $3::VARCHAR = 'Cancelled' THEN $4 ELSE cancellation_reason END
+            WHERE id = $1 AND status = $2
+            "#,
+            event_id,
+            from_status,
+            to_status,
+            cancellation_reason
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Replaces the event's details. Returns `false` without changing anything if the
+    /// event is no longer in `status`.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_details(
+        pool: &DbPool,
+        event_id: i32,
+        status: &str,
+        title: &str,
+        description: &str,
+        date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+        timezone: &str,
+        location: &str,
+        max_participants: Option<i32>,
+        registration_required: bool,
+        contact_email: &str,
+        external_link: Option<&str>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE events
+            SET title = $3, description = $4, date = $5, end_date = $6, timezone = $7, location = $8,
+                max_participants = $9, registration_required = $10, contact_email = $11, external_link = $12
+            WHERE id = $1 AND status = $2
+            "#,
+            event_id,
+            status,
+            title,
+            description,
+            date,
+            end_date,
+            timezone,
+            location,
+            max_participants,
+            registration_required,
+            contact_email,
+            external_link
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     pub async fn find_by_id(
         pool: &DbPool,
         event_id: i32,
     ) -> Result<Option<Event>, SqlxError> {
         let row = sqlx::query!(
             r#"
-            SELECT id, title, description, event_type, date, location, max_participants,
-                   registration_required, contact_email, external_link, organizer_id, created_at,
+            SELECT id, title, description, event_type, date, end_date, timezone, location, max_participants,
+                   registration_required, contact_email, external_link, organizer_id, chapter_id, plan_id, created_at, updated_at, sequence,
+                   status, approved_at, cancellation_reason,
                    strategic_focus_areas, monthly_active_ambassadors, monthly_active_accounts, scf_referrals,
                    content_produced, expected_attendance, social_growth_target, target_audience,
-                   quarterly_goals, strategic_purpose, success_metrics
+                   quarterly_goals, strategic_purpose, success_metrics,
+                   actual_monthly_active_ambassadors, actual_monthly_active_accounts, actual_scf_referrals,
+                   actual_content_produced, actual_attendance, actual_social_growth
             FROM events WHERE id = $1
             "#,
             event_id
@@ -171,13 +727,22 @@ impl EventRepository {
                 description: row.description,
                 event_type: row.event_type,
                 date: row.date,
+                end_date: row.end_date,
+                timezone: row.timezone,
                 location: row.location,
                 max_participants: row.max_participants,
                 registration_required: row.registration_required,
                 contact_email: row.contact_email,
                 external_link: row.external_link,
                 organizer_id: row.organizer_id,
+                chapter_id: row.chapter_id,
+                plan_id: row.plan_id,
+                status: row.status,
+                approved_at: row.approved_at,
+                cancellation_reason: row.cancellation_reason,
                 created_at: row.created_at,
+                updated_at: row.updated_at,
+                sequence: row.sequence,
                 strategic_focus_areas: row.strategic_focus_areas,
                 monthly_active_ambassadors: row.monthly_active_ambassadors,
                 monthly_active_accounts: row.monthly_active_accounts,
@@ -189,6 +754,12 @@ impl EventRepository {
                 quarterly_goals: row.quarterly_goals,
                 strategic_purpose: row.strategic_purpose,
                 success_metrics: row.success_metrics,
+                actual_monthly_active_ambassadors: row.actual_monthly_active_ambassadors,
+                actual_monthly_active_accounts: row.actual_monthly_active_accounts,
+                actual_scf_referrals: row.actual_scf_referrals,
+                actual_content_produced: row.actual_content_produced,
+                actual_attendance: row.actual_attendance,
+                actual_social_growth: row.actual_social_growth,
             }))
         } else {
             Ok(None)
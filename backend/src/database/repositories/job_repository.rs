@@ -0,0 +1,309 @@
+use crate::database::models::QueuedJob;
+use crate::database::connection::DbPool;
+use chrono::{DateTime, Utc};
+use sqlx::{Error as SqlxError, PgExecutor};
+
+pub struct JobRepository;
+
+impl JobRepository {
+    /// Queues a job to run from `run_at`. A job queued with the same `unique_key` is
+    /// replaced instead: it takes the new payload and time. Given a transaction, the job
+    /// is only queued if it commits.
+    pub async fn enqueue(
+        executor: impl PgExecutor<'_>,
+        kind: &str,
+        payload: &serde_json::Value,
+        unique_key: Option<&str>,
+        max_attempts: i32,
+        run_at: DateTime<Utc>,
+    ) -> Result<QueuedJob, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO jobs (kind, payload, unique_key, max_attempts, run_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (unique_key) WHERE status = 'Queued'
+            DO UPDATE SET payload = EXCLUDED.payload, max_attempts = EXCLUDED.max_attempts, run_at = EXCLUDED.run_at
+            RETURNING id, kind, payload, status, unique_key, attempts, max_attempts, run_at, locked_at,
+                      last_error, created_at, failed_at
+            "#,
+            kind,
+            payload,
+            unique_key,
+            max_attempts,
+            run_at
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(QueuedJob {
+            id: row.id,
+            kind: row.kind,
+            payload: row.payload,
+            status: row.status,
+            unique_key: row.unique_key,
+            attempts: row.attempts,
+            max_attempts: row.max_attempts,
+            run_at: row.run_at,
+            locked_at: row.locked_at,
+            last_error: row.last_error,
+            created_at: row.created_at,
+            failed_at: row.failed_at,
+        })
+    }
+
+    /// Drops the queued job with `unique_key`, if there is one. Returns whether there was.
+    pub async fn unschedule(pool: &DbPool, unique_key: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query!(
+            "DELETE FROM jobs WHERE unique_key = $1 AND status = 'Queued'",
+            unique_key
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Takes the next job of one of `kinds` due at `now` and marks it Running, counting
+    /// the attempt. Jobs other workers hold are skipped rather than waited for; a job
+    /// Running since before `stale_before` is taken to have lost its worker and is
+    /// claimed again.
+    pub async fn claim(
+        pool: &DbPool,
+        kinds: &[String],
+        now: DateTime<Utc>,
+        stale_before: DateTime<Utc>,
+    ) -> Result<Option<QueuedJob>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE jobs
+            SET status = 'Running', attempts = attempts + 1, locked_at = $2
+            WHERE id = (
+                SELECT id FROM jobs
+                WHERE kind = ANY($1)
+                  AND ((status = 'Queued' AND run_at <= $2) OR (status = 'Running' AND locked_at < $3))
+                ORDER BY run_at, id
+                LIMIT 1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING id, kind, payload, status, unique_key, attempts, max_attempts, run_at, locked_at,
+                      last_error, created_at, failed_at
+            "#,
+            kinds,
+            now,
+            stale_before
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| QueuedJob {
+            id: row.id,
+            kind: row.kind,
+            payload: row.payload,
+            status: row.status,
+            unique_key: row.unique_key,
+            attempts: row.attempts,
+            max_attempts: row.max_attempts,
+            run_at: row.run_at,
+            locked_at: row.locked_at,
+            last_error: row.last_error,
+            created_at: row.created_at,
+            failed_at: row.failed_at,
+        }))
+    }
+
+    /// Removes a job that ran successfully. The job must still be on the attempt the
+    /// worker claimed, so a worker that lost its claim cannot finish someone else's run.
+    pub async fn complete(pool: &DbPool, id: i64, attempts: i32) -> Result<bool, SqlxError> {
+        let result = sqlx::query!(
+            "DELETE FROM jobs WHERE id = $1 AND status = 'Running' AND attempts = $2",
+            id,
+            attempts
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Puts a failed job back in the queue to run again from `run_at`. Returns false,
+    /// leaving the job alone, if a job with the same key was queued in the meantime.
+    pub async fn retry(
+        pool: &DbPool,
+        id: i64,
+        attempts: i32,
+        error: &str,
+        run_at: DateTime<Utc>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE jobs
+            SET status = 'Queued', run_at = $4, locked_at = NULL, last_error = $3
+            WHERE id = $1 AND status = 'Running' AND attempts = $2
+              AND NOT EXISTS (
+                  SELECT 1 FROM jobs queued
+                  WHERE queued.unique_key = jobs.unique_key AND queued.status = 'Queued'
+              )
+            "#,
+            id,
+            attempts,
+            error,
+            run_at
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Gives up on a job, keeping it as Dead with the error that ended it.
+    pub async fn bury(
+        pool: &DbPool,
+        id: i64,
+        attempts: i32,
+        error: &str,
+        now: DateTime<Utc>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE jobs
+            SET status = 'Dead', locked_at = NULL, last_error = $3, failed_at = $4
+            WHERE id = $1 AND status = 'Running' AND attempts = $2
+            "#,
+            id,
+            attempts,
+            error,
+            now
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn find(pool: &DbPool, id: i64) -> Result<Option<QueuedJob>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, kind, payload, status, unique_key, attempts, max_attempts, run_at, locked_at,
+                   last_error, created_at, failed_at
+            FROM jobs
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| QueuedJob {
+            id: row.id,
+            kind: row.kind,
+            payload: row.payload,
+            status: row.status,
+            unique_key: row.unique_key,
+            attempts: row.attempts,
+            max_attempts: row.max_attempts,
+            run_at: row.run_at,
+            locked_at: row.locked_at,
+            last_error: row.last_error,
+            created_at: row.created_at,
+            failed_at: row.failed_at,
+        }))
+    }
+
+    /// Jobs of `kind` still to run, soonest first.
+    pub async fn list_queued(pool: &DbPool, kind: &str) -> Result<Vec<QueuedJob>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, kind, payload, status, unique_key, attempts, max_attempts, run_at, locked_at,
+                   last_error, created_at, failed_at
+            FROM jobs
+            WHERE kind = $1 AND status = 'Queued'
+            ORDER BY run_at, id
+            "#,
+            kind
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| QueuedJob {
+            id: row.id,
+            kind: row.kind,
+            payload: row.payload,
+            status: row.status,
+            unique_key: row.unique_key,
+            attempts: row.attempts,
+            max_attempts: row.max_attempts,
+            run_at: row.run_at,
+            locked_at: row.locked_at,
+            last_error: row.last_error,
+            created_at: row.created_at,
+            failed_at: row.failed_at,
+        }).collect())
+    }
+
+    /// Dead jobs, most recently given up on first.
+    pub async fn list_dead(pool: &DbPool) -> Result<Vec<QueuedJob>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, kind, payload, status, unique_key, attempts, max_attempts, run_at, locked_at,
+                   last_error, created_at, failed_at
+            FROM jobs
+            WHERE status = 'Dead'
+            ORDER BY failed_at DESC, id DESC
+            "#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| QueuedJob {
+            id: row.id,
+            kind: row.kind,
+            payload: row.payload,
+            status: row.status,
+            unique_key: row.unique_key,
+            attempts: row.attempts,
+            max_attempts: row.max_attempts,
+            run_at: row.run_at,
+            locked_at: row.locked_at,
+            last_error: row.last_error,
+            created_at: row.created_at,
+            failed_at: row.failed_at,
+        }).collect())
+    }
+
+    /// Queues a dead job again with a fresh set of attempts. Returns `None` if the job is
+    /// not dead, or another job with its key is already queued.
+    pub async fn requeue(pool: &DbPool, id: i64, now: DateTime<Utc>) -> Result<Option<QueuedJob>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE jobs
+            SET status = 'Queued', attempts = 0, run_at = $2, failed_at = NULL
+            WHERE id = $1 AND status = 'Dead'
+              AND NOT EXISTS (
+                  SELECT 1 FROM jobs queued
+                  WHERE queued.unique_key = jobs.unique_key AND queued.status = 'Queued'
+              )
+            RETURNING id, kind, payload, status, unique_key, attempts, max_attempts, run_at, locked_at,
+                      last_error, created_at, failed_at
+            "#,
+            id,
+            now
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| QueuedJob {
+            id: row.id,
+            kind: row.kind,
+            payload: row.payload,
+            status: row.status,
+            unique_key: row.unique_key,
+            attempts: row.attempts,
+            max_attempts: row.max_attempts,
+            run_at: row.run_at,
+            locked_at: row.locked_at,
+            last_error: row.last_error,
+            created_at: row.created_at,
+            failed_at: row.failed_at,
+        }))
+    }
+}
\ No newline at end of file
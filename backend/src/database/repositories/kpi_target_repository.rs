@@ -0,0 +1,101 @@
+use crate::database::models::KpiTarget;
+use crate::database::connection::DbPool;
+use sqlx::{Error as SqlxError};
+
+pub struct KpiTargetRepository;
+
+impl KpiTargetRepository {
+    pub async fn find(
+        pool: &DbPool,
+        chapter_id: i32,
+        year: i32,
+        quarter: i32,
+    ) -> Result<Option<KpiTarget>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT chapter_id, year, quarter, monthly_active_ambassadors, monthly_active_accounts,
+                   scf_referrals, content_produced, attendance, social_growth, created_at, updated_at
+            FROM chapter_kpi_targets
+            WHERE chapter_id = $1 AND year = $2 AND quarter = $3
+            "#,
+            chapter_id,
+            year,
+            quarter
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| KpiTarget {
+            chapter_id: row.chapter_id,
+            year: row.year,
+            quarter: row.quarter,
+            monthly_active_ambassadors: row.monthly_active_ambassadors,
+            monthly_active_accounts: row.monthly_active_accounts,
+            scf_referrals: row.scf_referrals,
+            content_produced: row.content_produced,
+            attendance: row.attendance,
+            social_growth: row.social_growth,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Creates or replaces the targets of a chapter's quarter.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn upsert(
+        pool: &DbPool,
+        chapter_id: i32,
+        year: i32,
+        quarter: i32,
+        monthly_active_ambassadors: Option<i32>,
+        monthly_active_accounts: Option<i32>,
+        scf_referrals: Option<i32>,
+        content_produced: Option<i32>,
+        attendance: Option<i32>,
+        social_growth: Option<i32>,
+    ) -> Result<KpiTarget, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO chapter_kpi_targets (
+                chapter_id, year, quarter, monthly_active_ambassadors, monthly_active_accounts,
+                scf_referrals, content_produced, attendance, social_growth, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, NOW(), NOW())
+            ON CONFLICT (chapter_id, year, quarter) DO UPDATE SET
+                monthly_active_ambassadors = EXCLUDED.monthly_active_ambassadors,
+                monthly_active_accounts = EXCLUDED.monthly_active_accounts,
+                scf_referrals = EXCLUDED.scf_referrals,
+                content_produced = EXCLUDED.content_produced,
+                attendance = EXCLUDED.attendance,
+                social_growth = EXCLUDED.social_growth
+            RETURNING chapter_id, year, quarter, monthly_active_ambassadors, monthly_active_accounts,
+                      scf_referrals, content_produced, attendance, social_growth, created_at, updated_at
+            "#,
+            chapter_id,
+            year,
+            quarter,
+            monthly_active_ambassadors,
+            monthly_active_accounts,
+            scf_referrals,
+            content_produced,
+            attendance,
+            social_growth
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(KpiTarget {
+            chapter_id: row.chapter_id,
+            year: row.year,
+            quarter: row.quarter,
+            monthly_active_ambassadors: row.monthly_active_ambassadors,
+            monthly_active_accounts: row.monthly_active_accounts,
+            scf_referrals: row.scf_referrals,
+            content_produced: row.content_produced,
+            attendance: row.attendance,
+            social_growth: row.social_growth,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
\ No newline at end of file
@@ -0,0 +1,165 @@
+use crate::database::models::{LinkedAccount, PendingAccountCheck};
+use crate::database::connection::DbPool;
+use chrono::{DateTime, Utc};
+use sqlx::{Error as SqlxError};
+
+pub struct LinkedAccountRepository;
+
+impl LinkedAccountRepository {
+    pub async fn list_for_event(pool: &DbPool, event_id: i32) -> Result<Vec<LinkedAccount>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, event_id, account_id, linked_by, status, funded_at, last_transaction_at, checked_at, check_error, created_at
+            FROM event_linked_accounts
+            WHERE event_id = $1
+            ORDER BY created_at, id
+            "#,
+            event_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| LinkedAccount {
+            id: row.id,
+            event_id: row.event_id,
+            account_id: row.account_id,
+            linked_by: row.linked_by,
+            status: row.status,
+            funded_at: row.funded_at,
+            last_transaction_at: row.last_transaction_at,
+            checked_at: row.checked_at,
+            check_error: row.check_error,
+            created_at: row.created_at,
+        }).collect())
+    }
+
+    pub async fn find(pool: &DbPool, event_id: i32, link_id: i32) -> Result<Option<LinkedAccount>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, event_id, account_id, linked_by, status, funded_at, last_transaction_at, checked_at, check_error, created_at
+            FROM event_linked_accounts
+            WHERE id = $1 AND event_id = $2
+            "#,
+            link_id,
+            event_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| LinkedAccount {
+            id: row.id,
+            event_id: row.event_id,
+            account_id: row.account_id,
+            linked_by: row.linked_by,
+            status: row.status,
+            funded_at: row.funded_at,
+            last_transaction_at: row.last_transaction_at,
+            checked_at: row.checked_at,
+            check_error: row.check_error,
+            created_at: row.created_at,
+        }))
+    }
+
+    /// Links the account to the event. Returns `None` if the account is already linked to any event.
+    pub async fn create(
+        pool: &DbPool,
+        event_id: i32,
+        account_id: &str,
+        linked_by: i32,
+    ) -> Result<Option<LinkedAccount>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO event_linked_accounts (event_id, account_id, linked_by)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (account_id) DO NOTHING
+            RETURNING id, event_id, account_id, linked_by, status, funded_at, last_transaction_at, checked_at, check_error, created_at
+            "#,
+            event_id,
+            account_id,
+            linked_by
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| LinkedAccount {
+            id: row.id,
+            event_id: row.event_id,
+            account_id: row.account_id,
+            linked_by: row.linked_by,
+            status: row.status,
+            funded_at: row.funded_at,
+            last_transaction_at: row.last_transaction_at,
+            checked_at: row.checked_at,
+            check_error: row.check_error,
+            created_at: row.created_at,
+        }))
+    }
+
+    pub async fn delete(pool: &DbPool, link_id: i32) -> Result<bool, SqlxError> {
+        let result = sqlx::query!("DELETE FROM event_linked_accounts WHERE id = $1", link_id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Pending links of events that have started by `now`, least recently checked first.
+    pub async fn list_pending(pool: &DbPool, now: DateTime<Utc>) -> Result<Vec<PendingAccountCheck>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT l.id, l.event_id, l.account_id, l.linked_by, l.status, l.funded_at, l.last_transaction_at,
+                   l.checked_at, l.check_error, l.created_at, e.date AS event_date
+            FROM event_linked_accounts l
+            JOIN events e ON e.id = l.event_id
+            WHERE l.status = 'Pending' AND e.date <= $1
+            ORDER BY l.checked_at NULLS FIRST, l.id
+            "#,
+            now
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| PendingAccountCheck {
+            link: LinkedAccount {
+                id: row.id,
+                event_id: row.event_id,
+                account_id: row.account_id,
+                linked_by: row.linked_by,
+                status: row.status,
+                funded_at: row.funded_at,
+                last_transaction_at: row.last_transaction_at,
+                checked_at: row.checked_at,
+                check_error: row.check_error,
+                created_at: row.created_at,
+            },
+            event_date: row.event_date,
+        }).collect())
+    }
+
+    /// Stores the outcome of checking a link on Horizon.
+    pub async fn record_check(
+        pool: &DbPool,
+        link_id: i32,
+        status: &str,
+        funded_at: Option<DateTime<Utc>>,
+        last_transaction_at: Option<DateTime<Utc>>,
+        check_error: Option<&str>,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            r#"
+            UPDATE event_linked_accounts
+            SET status = $2, funded_at = $3, last_transaction_at = $4, check_error = $5, checked_at = NOW()
+            WHERE id = $1
+            "#,
+            link_id,
+            status,
+            funded_at,
+            last_transaction_at,
+            check_error
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,5 +1,35 @@
 pub mod user_repository;
 pub mod event_repository;
+pub mod chapter_repository;
+pub mod kpi_target_repository;
+pub mod plan_repository;
+pub mod budget_repository;
+pub mod reimbursement_repository;
+pub mod attachment_repository;
+pub mod linked_account_repository;
+pub mod attendance_repository;
+pub mod signup_challenge_repository;
+pub mod email_verification_repository;
+pub mod registration_repository;
+pub mod notification_repository;
+pub mod job_repository;
+pub mod event_reminder_repository;
+pub mod session_repository;
 
 pub use user_repository::*;
-pub use event_repository::*;
\ No newline at end of file
+pub use event_repository::*;
+pub use chapter_repository::*;
+pub use kpi_target_repository::*;
+pub use plan_repository::*;
+pub use budget_repository::*;
+pub use reimbursement_repository::*;
+pub use attachment_repository::*;
+pub use linked_account_repository::*;
+pub use attendance_repository::*;
+pub use signup_challenge_repository::*;
+pub use email_verification_repository::*;
+pub use registration_repository::*;
+pub use notification_repository::*;
+pub use job_repository::*;
+pub use event_reminder_repository::*;
+pub use session_repository::*;
\ No newline at end of file
@@ -0,0 +1,210 @@
+use crate::database::models::{Notification, NotificationPreferenceRow, NotificationRecipient};
+use crate::database::connection::DbPool;
+use sqlx::{Error as SqlxError, PgExecutor};
+use uuid::Uuid;
+
+pub struct NotificationRepository;
+
+impl NotificationRepository {
+    /// The users with these ids, with their language and how they want `kind`.
+    pub async fn recipients(pool: &DbPool, user_ids: &[i32], kind: &str) -> Result<Vec<NotificationRecipient>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT u.id, u.username, u.email, u.locale,
+                   u.email_verified_at IS NOT NULL AS "email_verified!",
+                   COALESCE(p.email, TRUE) AS "by_email!",
+                   COALESCE(p.in_app, TRUE) AS "in_app!"
+            FROM users u
+            LEFT JOIN notification_preferences p ON p.user_id = u.id AND p.kind = $2
+            WHERE u.id = ANY($1)
+            ORDER BY u.id
+            "#,
+            user_ids,
+            kind
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| NotificationRecipient {
+            user_id: row.id,
+            username: row.username,
+            email: row.email,
+            email_verified: row.email_verified,
+            locale: row.locale,
+            by_email: row.by_email,
+            in_app: row.in_app,
+        }).collect())
+    }
+
+    pub async fn create(
+        executor: impl PgExecutor<'_>,
+        user_id: i32,
+        kind: &str,
+        subject: &str,
+        body: &str,
+        link: Option<&str>,
+    ) -> Result<Notification, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO notifications (user_id, kind, subject, body, link)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, user_id, kind, subject, body, link, read_at, created_at
+            "#,
+            user_id,
+            kind,
+            subject,
+            body,
+            link
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(Notification {
+            id: row.id,
+            user_id: row.user_id,
+            kind: row.kind,
+            subject: row.subject,
+            body: row.body,
+            link: row.link,
+            read_at: row.read_at,
+            created_at: row.created_at,
+        })
+    }
+
+    /// Records that the user is told of the notification queued as `notification_key`.
+    /// Returns false if they already have been, in which case they must not be told again.
+    /// Takes a transaction so the record is only kept with what the user is sent.
+    pub async fn record_delivery(
+        executor: impl PgExecutor<'_>,
+        notification_key: Uuid,
+        user_id: i32,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO notification_deliveries (notification_key, user_id)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
+            "#,
+            notification_key,
+            user_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// The user's inbox, newest first.
+    pub async fn list_for_user(pool: &DbPool, user_id: i32, unread_only: bool, limit: i64) -> Result<Vec<Notification>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, user_id, kind, subject, body, link, read_at, created_at
+            FROM notifications
+            WHERE user_id = $1 AND (NOT $2 OR read_at IS NULL)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $3
+            "#,
+            user_id,
+            unread_only,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| Notification {
+            id: row.id,
+            user_id: row.user_id,
+            kind: row.kind,
+            subject: row.subject,
+            body: row.body,
+            link: row.link,
+            read_at: row.read_at,
+            created_at: row.created_at,
+        }).collect())
+    }
+
+    pub async fn count_unread(pool: &DbPool, user_id: i32) -> Result<i64, SqlxError> {
+        let row = sqlx::query!(
+            r#"SELECT COUNT(*) AS "count!" FROM notifications WHERE user_id = $1 AND read_at IS NULL"#,
+            user_id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(row.count)
+    }
+
+    /// Marks the user's notifications with these ids read; all of them when `ids` is empty.
+    pub async fn mark_read(pool: &DbPool, user_id: i32, ids: &[i32]) -> Result<u64, SqlxError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE notifications
+            SET read_at = NOW()
+            WHERE user_id = $1 AND read_at IS NULL AND (cardinality($2::INTEGER[]) = 0 OR id = ANY($2))
+            "#,
+            user_id,
+            ids
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn locale(pool: &DbPool, user_id: i32) -> Result<String, SqlxError> {
+        let row = sqlx::query!("SELECT locale FROM users WHERE id = $1", user_id)
+            .fetch_one(pool)
+            .await?;
+
+        Ok(row.locale)
+    }
+
+    /// The preferences the user has stored; kinds without one use the defaults.
+    pub async fn preferences(pool: &DbPool, user_id: i32) -> Result<Vec<NotificationPreferenceRow>, SqlxError> {
+        let rows = sqlx::query!(
+            "SELECT kind, email, in_app FROM notification_preferences WHERE user_id = $1 ORDER BY kind",
+            user_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| NotificationPreferenceRow {
+            kind: row.kind,
+            email: row.email,
+            in_app: row.in_app,
+        }).collect())
+    }
+
+    /// Stores the language, if given, and the listed preferences together.
+    pub async fn save_preferences(
+        pool: &DbPool,
+        user_id: i32,
+        locale: Option<&str>,
+        preferences: &[NotificationPreferenceRow],
+    ) -> Result<(), SqlxError> {
+        let mut tx = pool.begin().await?;
+
+        if let Some(locale) = locale {
+            sqlx::query!("UPDATE users SET locale = $2 WHERE id = $1", user_id, locale)
+                .execute(&mut *tx)
+                .await?;
+        }
+        for preference in preferences {
+            sqlx::query!(
+                r#"
+                INSERT INTO notification_preferences (user_id, kind, email, in_app)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (user_id, kind) DO UPDATE SET email = EXCLUDED.email, in_app = EXCLUDED.in_app
+                "#,
+                user_id,
+                preference.kind,
+                preference.email,
+                preference.in_app
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
+}
\ No newline at end of file
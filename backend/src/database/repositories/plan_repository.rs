@@ -0,0 +1,311 @@
+use crate::database::models::{PlanCommentRecord, PlanTransitionRecord, QuarterlyPlan};
+use crate::database::connection::DbPool;
+use sqlx::{Error as SqlxError, PgConnection, PgExecutor};
+
+pub struct PlanRepository;
+
+impl PlanRepository {
+    pub async fn create_plan(
+        executor: impl PgExecutor<'_>,
+        chapter_id: i32,
+        year: i32,
+        quarter: i32,
+        strategic_purpose: &str,
+        quarterly_goals: &str,
+    ) -> Result<QuarterlyPlan, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO quarterly_plans (chapter_id, year, quarter, strategic_purpose, quarterly_goals, status, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, 'Draft', NOW(), NOW())
+            RETURNING id, chapter_id, year, quarter, strategic_purpose, quarterly_goals, status, created_at, updated_at
+            "#,
+            chapter_id,
+            year,
+            quarter,
+            strategic_purpose,
+            quarterly_goals
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(QuarterlyPlan {
+            id: row.id,
+            chapter_id: row.chapter_id,
+            year: row.year,
+            quarter: row.quarter,
+            strategic_purpose: row.strategic_purpose,
+            quarterly_goals: row.quarterly_goals,
+            status: row.status,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+
+    pub async fn find_by_id(
+        pool: &DbPool,
+        plan_id: i32,
+    ) -> Result<Option<QuarterlyPlan>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, chapter_id, year, quarter, strategic_purpose, quarterly_goals, status, created_at, updated_at
+            FROM quarterly_plans WHERE id = $1
+            "#,
+            plan_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| QuarterlyPlan {
+            id: row.id,
+            chapter_id: row.chapter_id,
+            year: row.year,
+            quarter: row.quarter,
+            strategic_purpose: row.strategic_purpose,
+            quarterly_goals: row.quarterly_goals,
+            status: row.status,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    pub async fn find_for_quarter(
+        pool: &DbPool,
+        chapter_id: i32,
+        year: i32,
+        quarter: i32,
+    ) -> Result<Option<QuarterlyPlan>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, chapter_id, year, quarter, strategic_purpose, quarterly_goals, status, created_at, updated_at
+            FROM quarterly_plans WHERE chapter_id = $1 AND year = $2 AND quarter = $3
+            "#,
+            chapter_id,
+            year,
+            quarter
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| QuarterlyPlan {
+            id: row.id,
+            chapter_id: row.chapter_id,
+            year: row.year,
+            quarter: row.quarter,
+            strategic_purpose: row.strategic_purpose,
+            quarterly_goals: row.quarterly_goals,
+            status: row.status,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Plans, newest quarter first, optionally limited to a chapter and/or status.
+    pub async fn list_plans(
+        pool: &DbPool,
+        chapter_id: Option<i32>,
+        status: Option<&str>,
+    ) -> Result<Vec<QuarterlyPlan>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, chapter_id, year, quarter, strategic_purpose, quarterly_goals, status, created_at, updated_at
+            FROM quarterly_plans
+            WHERE ($1::INTEGER IS NULL OR chapter_id = $1)
+              AND ($2::TEXT IS NULL OR status = $2)
+            ORDER BY year DESC, quarter DESC, updated_at DESC
+            "#,
+            chapter_id,
+            status
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let plans = rows.into_iter().map(|row| QuarterlyPlan {
+            id: row.id,
+            chapter_id: row.chapter_id,
+            year: row.year,
+            quarter: row.quarter,
+            strategic_purpose: row.strategic_purpose,
+            quarterly_goals: row.quarterly_goals,
+            status: row.status,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }).collect();
+
+        Ok(plans)
+    }
+
+    pub async fn update_summary(
+        executor: impl PgExecutor<'_>,
+        plan_id: i32,
+        strategic_purpose: &str,
+        quarterly_goals: &str,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "UPDATE quarterly_plans SET strategic_purpose = $2, quarterly_goals = $3 WHERE id = $1",
+            plan_id,
+            strategic_purpose,
+            quarterly_goals
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Replaces the events grouped into the plan. Takes the connection of the transaction
+    /// that creates or updates the plan, so the plan and its events change together.
+    pub async fn set_events(
+        conn: &mut PgConnection,
+        plan_id: i32,
+        event_ids: &[i32],
+    ) -> Result<(), SqlxError> {
+        sqlx::query!("UPDATE events SET plan_id = NULL WHERE plan_id = $1", plan_id)
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query!("UPDATE events SET plan_id = $1 WHERE id = ANY($2)", plan_id, event_ids)
+            .execute(&mut *conn)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn count_events(pool: &DbPool, plan_id: i32) -> Result<i64, SqlxError> {
+        let row = sqlx::query!(
+            r#"SELECT COUNT(*) AS "count!" FROM events WHERE plan_id = $1"#,
+            plan_id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(row.count)
+    }
+
+    /// Moves the plan from `from_status` to `to_status` and records the change.
+    /// Returns `false` without changing anything if the plan is no longer in `from_status`.
+    pub async fn transition(
+        pool: &DbPool,
+        plan_id: i32,
+        from_status: &str,
+        to_status: &str,
+        actor_id: i32,
+        comment: Option<&str>,
+    ) -> Result<bool, SqlxError> {
+        let mut tx = pool.begin().await?;
+
+        let result = sqlx::query!(
+            "UPDATE quarterly_plans SET status = $3 WHERE id = $1 AND status = $2",
+            plan_id,
+            from_status,
+            to_status
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query!(
+            r#"
+            INSERT INTO plan_transitions (plan_id, from_status, to_status, actor_id, comment, created_at)
+            VALUES ($1, $2, $3, $4, $5, NOW())
+            "#,
+            plan_id,
+            from_status,
+            to_status,
+            actor_id,
+            comment
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    /// Status changes of the plan, oldest first.
+    pub async fn list_transitions(
+        pool: &DbPool,
+        plan_id: i32,
+    ) -> Result<Vec<PlanTransitionRecord>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, plan_id, from_status, to_status, actor_id, comment, created_at
+            FROM plan_transitions
+            WHERE plan_id = $1
+            ORDER BY created_at ASC, id ASC
+            "#,
+            plan_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let transitions = rows.into_iter().map(|row| PlanTransitionRecord {
+            id: row.id,
+            plan_id: row.plan_id,
+            from_status: row.from_status,
+            to_status: row.to_status,
+            actor_id: row.actor_id,
+            comment: row.comment,
+            created_at: row.created_at,
+        }).collect();
+
+        Ok(transitions)
+    }
+
+    pub async fn add_comment(
+        pool: &DbPool,
+        plan_id: i32,
+        author_id: i32,
+        body: &str,
+    ) -> Result<PlanCommentRecord, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO plan_comments (plan_id, author_id, body, created_at)
+            VALUES ($1, $2, $3, NOW())
+            RETURNING id, plan_id, author_id, body, created_at
+            "#,
+            plan_id,
+            author_id,
+            body
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(PlanCommentRecord {
+            id: row.id,
+            plan_id: row.plan_id,
+            author_id: row.author_id,
+            body: row.body,
+            created_at: row.created_at,
+        })
+    }
+
+    /// Comments on the plan, oldest first.
+    pub async fn list_comments(
+        pool: &DbPool,
+        plan_id: i32,
+    ) -> Result<Vec<PlanCommentRecord>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, plan_id, author_id, body, created_at
+            FROM plan_comments
+            WHERE plan_id = $1
+            ORDER BY created_at ASC, id ASC
+            "#,
+            plan_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let comments = rows.into_iter().map(|row| PlanCommentRecord {
+            id: row.id,
+            plan_id: row.plan_id,
+            author_id: row.author_id,
+            body: row.body,
+            created_at: row.created_at,
+        }).collect();
+
+        Ok(comments)
+    }
+}
\ No newline at end of file
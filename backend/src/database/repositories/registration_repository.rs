@@ -0,0 +1,207 @@
+use crate::database::models::Registration;
+use crate::database::connection::DbPool;
+use sqlx::{Error as SqlxError, Postgres, Transaction};
+
+pub struct RegistrationRepository;
+
+/// Places at an event once it is locked against concurrent registrations.
+struct Places {
+    /// How many more people can register; `None` when places are unlimited
+    free: Option<i64>,
+    waitlisted: bool,
+}
+
+/// Locks the event so registrations and promotions see a settled head count.
+async fn lock_places(tx: &mut Transaction<'_, Postgres>, event_id: i32) -> Result<Places, SqlxError> {
+    let row = sqlx::query!(
+        r#"
+        SELECT max_participants,
+               (SELECT COUNT(*) FROM event_registrations WHERE event_id = $1 AND status = 'Registered') AS "registered!",
+               EXISTS (SELECT 1 FROM event_registrations WHERE event_id = $1 AND status = 'Waitlisted') AS "waitlisted!"
+        FROM events
+        WHERE id = $1
+        FOR UPDATE
+        "#,
+        event_id
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+
+    Ok(Places {
+        free: row.max_participants.map(|max| (i64::from(max) - row.registered).max(0)),
+        waitlisted: row.waitlisted,
+    })
+}
+
+impl RegistrationRepository {
+    /// Registers the user, or puts them on the waitlist when the event is full. A
+    /// cancelled registration is taken up again at the back of the line. Returns `None`
+    /// if the user is already registered or waitlisted.
+    pub async fn register(pool: &DbPool, event_id: i32, user_id: i32) -> Result<Option<Registration>, SqlxError> {
+        let mut tx = pool.begin().await?;
+        let places = lock_places(&mut tx, event_id).await?;
+        // Nobody jumps the waitlist, even when a place has just freed up
+        let status = if places.free == Some(0) || places.waitlisted { "Waitlisted" } else { "Registered" };
+
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO event_registrations (event_id, user_id, status)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (event_id, user_id) DO UPDATE
+            SET status = EXCLUDED.status, registered_at = NOW(), promoted_at = NULL, cancelled_at = NULL
+            WHERE event_registrations.status = 'Cancelled'
+            RETURNING id, event_id, user_id, status, registered_at, promoted_at, cancelled_at
+            "#,
+            event_id,
+            user_id,
+            status
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(row.map(|row| Registration {
+            id: row.id,
+            event_id: row.event_id,
+            user_id: row.user_id,
+            status: row.status,
+            registered_at: row.registered_at,
+            promoted_at: row.promoted_at,
+            cancelled_at: row.cancelled_at,
+        }))
+    }
+
+    /// Registered and waitlisted users, each group in the order they joined.
+    pub async fn list_active(pool: &DbPool, event_id: i32) -> Result<Vec<Registration>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, event_id, user_id, status, registered_at, promoted_at, cancelled_at
+            FROM event_registrations
+            WHERE event_id = $1 AND status <> 'Cancelled'
+            ORDER BY status = 'Waitlisted', registered_at, id
+            "#,
+            event_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| Registration {
+            id: row.id,
+            event_id: row.event_id,
+            user_id: row.user_id,
+            status: row.status,
+            registered_at: row.registered_at,
+            promoted_at: row.promoted_at,
+            cancelled_at: row.cancelled_at,
+        }).collect())
+    }
+
+    pub async fn find(pool: &DbPool, event_id: i32, registration_id: i32) -> Result<Option<Registration>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, event_id, user_id, status, registered_at, promoted_at, cancelled_at
+            FROM event_registrations
+            WHERE event_id = $1 AND id = $2
+            "#,
+            event_id,
+            registration_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| Registration {
+            id: row.id,
+            event_id: row.event_id,
+            user_id: row.user_id,
+            status: row.status,
+            registered_at: row.registered_at,
+            promoted_at: row.promoted_at,
+            cancelled_at: row.cancelled_at,
+        }))
+    }
+
+    /// Cancels the registration. Returns `None` if it already was.
+    pub async fn cancel(pool: &DbPool, registration_id: i32) -> Result<Option<Registration>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE event_registrations
+            SET status = 'Cancelled', cancelled_at = NOW()
+            WHERE id = $1 AND status <> 'Cancelled'
+            RETURNING id, event_id, user_id, status, registered_at, promoted_at, cancelled_at
+            "#,
+            registration_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| Registration {
+            id: row.id,
+            event_id: row.event_id,
+            user_id: row.user_id,
+            status: row.status,
+            registered_at: row.registered_at,
+            promoted_at: row.promoted_at,
+            cancelled_at: row.cancelled_at,
+        }))
+    }
+
+    /// Moves people from the waitlist into the places that are free, longest waiting
+    /// first, and returns the promoted registrations.
+    pub async fn promote_waitlisted(pool: &DbPool, event_id: i32) -> Result<Vec<Registration>, SqlxError> {
+        let mut tx = pool.begin().await?;
+        let places = lock_places(&mut tx, event_id).await?;
+
+        let rows = sqlx::query!(
+            r#"
+            UPDATE event_registrations
+            SET status = 'Registered', promoted_at = NOW()
+            WHERE id IN (
+                SELECT id FROM event_registrations
+                WHERE event_id = $1 AND status = 'Waitlisted'
+                ORDER BY registered_at, id
+                LIMIT $2
+            )
+            RETURNING id, event_id, user_id, status, registered_at, promoted_at, cancelled_at
+            "#,
+            event_id,
+            places.free
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(rows.into_iter().map(|row| Registration {
+            id: row.id,
+            event_id: row.event_id,
+            user_id: row.user_id,
+            status: row.status,
+            registered_at: row.registered_at,
+            promoted_at: row.promoted_at,
+            cancelled_at: row.cancelled_at,
+        }).collect())
+    }
+
+    /// Users registered or waitlisted for the event, who hear about changes to it.
+    pub async fn list_active_user_ids(pool: &DbPool, event_id: i32) -> Result<Vec<i32>, SqlxError> {
+        let rows = sqlx::query!(
+            "SELECT user_id FROM event_registrations WHERE event_id = $1 AND status <> 'Cancelled' ORDER BY id",
+            event_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| row.user_id).collect())
+    }
+
+    /// Users with a place at the event, who are reminded of it.
+    pub async fn list_registered_user_ids(pool: &DbPool, event_id: i32) -> Result<Vec<i32>, SqlxError> {
+        let rows = sqlx::query!(
+            "SELECT user_id FROM event_registrations WHERE event_id = $1 AND status = 'Registered' ORDER BY id",
+            event_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| row.user_id).collect())
+    }
+}
\ No newline at end of file
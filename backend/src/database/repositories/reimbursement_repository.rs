@@ -0,0 +1,290 @@
+use crate::database::models::Reimbursement;
+use crate::database::connection::DbPool;
+use rust_decimal::Decimal;
+use sqlx::{Error as SqlxError};
+
+pub struct ReimbursementRepository;
+
+impl ReimbursementRepository {
+    pub async fn find_for_item(pool: &DbPool, budget_item_id: i32) -> Result<Option<Reimbursement>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, budget_item_id, recipient_wallet_address, asset, amount, status, prepared_by, transaction_xdr,
+                   transaction_hash, signed_transaction_xdr, ledger, error, submitted_at, paid_at, created_at, updated_at
+            FROM expense_reimbursements
+            WHERE budget_item_id = $1
+            "#,
+            budget_item_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| Reimbursement {
+            id: row.id,
+            budget_item_id: row.budget_item_id,
+            recipient_wallet_address: row.recipient_wallet_address,
+            asset: row.asset,
+            amount: row.amount,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            paid_at: row.paid_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Reimbursements of any of the given budget items.
+    pub async fn list_for_items(pool: &DbPool, budget_item_ids: &[i32]) -> Result<Vec<Reimbursement>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, budget_item_id, recipient_wallet_address, asset, amount, status, prepared_by, transaction_xdr,
+                   transaction_hash, signed_transaction_xdr, ledger, error, submitted_at, paid_at, created_at, updated_at
+            FROM expense_reimbursements
+            WHERE budget_item_id = ANY($1)
+            ORDER BY budget_item_id ASC
+            "#,
+            budget_item_ids
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| Reimbursement {
+            id: row.id,
+            budget_item_id: row.budget_item_id,
+            recipient_wallet_address: row.recipient_wallet_address,
+            asset: row.asset,
+            amount: row.amount,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            paid_at: row.paid_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }).collect())
+    }
+
+    /// Records a freshly built payout for an item, replacing one that was never submitted
+    /// or failed. `None` if the item's payout is already submitted or paid.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn prepare(
+        pool: &DbPool,
+        budget_item_id: i32,
+        recipient_wallet_address: &str,
+        asset: &str,
+        amount: Decimal,
+        prepared_by: i32,
+        transaction_xdr: &str,
+        transaction_hash: &str,
+    ) -> Result<Option<Reimbursement>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO expense_reimbursements
+                (budget_item_id, recipient_wallet_address, asset, amount, prepared_by, transaction_xdr, transaction_hash)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (budget_item_id) DO UPDATE
+            SET recipient_wallet_address = EXCLUDED.recipient_wallet_address, asset = EXCLUDED.asset,
+                amount = EXCLUDED.amount, status = 'AwaitingSignature', prepared_by = EXCLUDED.prepared_by,
+                transaction_xdr = EXCLUDED.transaction_xdr, transaction_hash = EXCLUDED.transaction_hash,
+                signed_transaction_xdr = NULL, ledger = NULL, error = NULL, submitted_at = NULL
+            WHERE expense_reimbursements.status IN ('AwaitingSignature', 'Failed')
+            RETURNING id, budget_item_id, recipient_wallet_address, asset, amount, status, prepared_by, transaction_xdr,
+                      transaction_hash, signed_transaction_xdr, ledger, error, submitted_at, paid_at, created_at, updated_at
+            "#,
+            budget_item_id,
+            recipient_wallet_address,
+            asset,
+            amount,
+            prepared_by,
+            transaction_xdr,
+            transaction_hash
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| Reimbursement {
+            id: row.id,
+            budget_item_id: row.budget_item_id,
+            recipient_wallet_address: row.recipient_wallet_address,
+            asset: row.asset,
+            amount: row.amount,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            paid_at: row.paid_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Stores the signed transaction about to be submitted. `None` unless the payout
+    /// awaits its signature or an earlier submission's outcome is unknown.
+    pub async fn mark_submitted(pool: &DbPool, id: i32, signed_transaction_xdr: &str) -> Result<Option<Reimbursement>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE expense_reimbursements
+            SET status = 'Submitted', signed_transaction_xdr = $2, submitted_at = NOW(), error = NULL
+            WHERE id = $1 AND status IN ('AwaitingSignature', 'Submitted')
+            RETURNING id, budget_item_id, recipient_wallet_address, asset, amount, status, prepared_by, transaction_xdr,
+                      transaction_hash, signed_transaction_xdr, ledger, error, submitted_at, paid_at, created_at, updated_at
+            "#,
+            id,
+            signed_transaction_xdr
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| Reimbursement {
+            id: row.id,
+            budget_item_id: row.budget_item_id,
+            recipient_wallet_address: row.recipient_wallet_address,
+            asset: row.asset,
+            amount: row.amount,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            paid_at: row.paid_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// A payout found in a ledger is paid, whatever an overlapping submission concluded.
+    pub async fn mark_paid(pool: &DbPool, id: i32, ledger: i32) -> Result<Option<Reimbursement>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE expense_reimbursements
+            SET status = 'Paid', ledger = $2, paid_at = NOW(), error = NULL
+            WHERE id = $1 AND status IN ('Submitted', 'Failed')
+            RETURNING id, budget_item_id, recipient_wallet_address, asset, amount, status, prepared_by, transaction_xdr,
+                      transaction_hash, signed_transaction_xdr, ledger, error, submitted_at, paid_at, created_at, updated_at
+            "#,
+            id,
+            ledger
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| Reimbursement {
+            id: row.id,
+            budget_item_id: row.budget_item_id,
+            recipient_wallet_address: row.recipient_wallet_address,
+            asset: row.asset,
+            amount: row.amount,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            paid_at: row.paid_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    pub async fn mark_failed(pool: &DbPool, id: i32, error: &str) -> Result<Option<Reimbursement>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE expense_reimbursements
+            SET status = 'Failed', error = $2
+            WHERE id = $1 AND status = 'Submitted'
+            RETURNING id, budget_item_id, recipient_wallet_address, asset, amount, status, prepared_by, transaction_xdr,
+                      transaction_hash, signed_transaction_xdr, ledger, error, submitted_at, paid_at, created_at, updated_at
+            "#,
+            id,
+            error
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| Reimbursement {
+            id: row.id,
+            budget_item_id: row.budget_item_id,
+            recipient_wallet_address: row.recipient_wallet_address,
+            asset: row.asset,
+            amount: row.amount,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            paid_at: row.paid_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Notes why a submitted payout's outcome is still unknown.
+    pub async fn record_pending(pool: &DbPool, id: i32, error: &str) -> Result<Option<Reimbursement>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE expense_reimbursements
+            SET error = $2
+            WHERE id = $1 AND status = 'Submitted'
+            RETURNING id, budget_item_id, recipient_wallet_address, asset, amount, status, prepared_by, transaction_xdr,
+                      transaction_hash, signed_transaction_xdr, ledger, error, submitted_at, paid_at, created_at, updated_at
+            "#,
+            id,
+            error
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| Reimbursement {
+            id: row.id,
+            budget_item_id: row.budget_item_id,
+            recipient_wallet_address: row.recipient_wallet_address,
+            asset: row.asset,
+            amount: row.amount,
+            status: row.status,
+            prepared_by: row.prepared_by,
+            transaction_xdr: row.transaction_xdr,
+            transaction_hash: row.transaction_hash,
+            signed_transaction_xdr: row.signed_transaction_xdr,
+            ledger: row.ledger,
+            error: row.error,
+            submitted_at: row.submitted_at,
+            paid_at: row.paid_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Drops a payout built from amounts that have since changed. Submitted and paid ones stay.
+    pub async fn discard_unsubmitted(pool: &DbPool, budget_item_id: i32) -> Result<bool, SqlxError> {
+        let result = sqlx::query!(
+            "DELETE FROM expense_reimbursements WHERE budget_item_id = $1 AND status IN ('AwaitingSignature', 'Failed')",
+            budget_item_id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use crate::database::connection::DbPool;
+use chrono::{DateTime, Utc};
+use sqlx::{Error as SqlxError};
+use uuid::Uuid;
+
+pub struct SessionRepository;
+
+impl SessionRepository {
+    /// Starts a session for the user and returns its token, clearing out expired sessions.
+    pub async fn create(
+        pool: &DbPool,
+        user_id: i32,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Uuid, SqlxError> {
+        sqlx::query!("DELETE FROM sessions WHERE expires_at < NOW()")
+            .execute(pool)
+            .await?;
+
+        let row = sqlx::query!(
+            "INSERT INTO sessions (token, user_id, expires_at) VALUES ($1, $2, $3) RETURNING token",
+            Uuid::new_v4(),
+            user_id,
+            expires_at
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(row.token)
+    }
+
+    /// The user whose unexpired session has this token.
+    pub async fn find_user_id(pool: &DbPool, token: Uuid) -> Result<Option<i32>, SqlxError> {
+        let row = sqlx::query!(
+            "SELECT user_id FROM sessions WHERE token = $1 AND expires_at > NOW()",
+            token
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| row.user_id))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+use crate::database::models::SignupChallenge;
+use crate::database::connection::DbPool;
+use chrono::{DateTime, Utc};
+use sqlx::{Error as SqlxError};
+use uuid::Uuid;
+
+pub struct SignupChallengeRepository;
+
+impl SignupChallengeRepository {
+    /// Issues a new nonce to the wallet, clearing out challenges that expired a day ago or more.
+    pub async fn create(
+        pool: &DbPool,
+        wallet_address: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<SignupChallenge, SqlxError> {
+        sqlx::query!("DELETE FROM signup_challenges WHERE expires_at < NOW() - INTERVAL '1 day'")
+            .execute(pool)
+            .await?;
+
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO signup_challenges (nonce, wallet_address, expires_at)
+            VALUES ($1, $2, $3)
+            RETURNING nonce, wallet_address, expires_at, used_at, created_at
+            "#,
+            Uuid::new_v4(),
+            wallet_address,
+            expires_at
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(SignupChallenge {
+            nonce: row.nonce,
+            wallet_address: row.wallet_address,
+            expires_at: row.expires_at,
+            used_at: row.used_at,
+            created_at: row.created_at,
+        })
+    }
+
+    pub async fn find(pool: &DbPool, nonce: Uuid) -> Result<Option<SignupChallenge>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT nonce, wallet_address, expires_at, used_at, created_at
+            FROM signup_challenges
+            WHERE nonce = $1
+            "#,
+            nonce
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| SignupChallenge {
+            nonce: row.nonce,
+            wallet_address: row.wallet_address,
+            expires_at: row.expires_at,
+            used_at: row.used_at,
+            created_at: row.created_at,
+        }))
+    }
+
+    /// Marks the nonce used. Returns `false` if it was used already or has expired, so
+    /// two signups racing with the same signature cannot both get through.
+    pub async fn mark_used(pool: &DbPool, nonce: Uuid) -> Result<bool, SqlxError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE signup_challenges
+            SET used_at = NOW()
+            WHERE nonce = $1 AND used_at IS NULL AND expires_at > NOW()
+            "#,
+            nonce
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
\ No newline at end of file
@@ -18,7 +18,7 @@ impl UserRepository {
             r#"
             INSERT INTO users (username, email, wallet_address, user_type, organization, bio, created_at)
             VALUES ($1, $2, $3, $4, $5, $6, NOW())
-            RETURNING id, username, email, wallet_address, user_type, organization, bio, created_at
+            RETURNING id, username, email, wallet_address, user_type, organization, bio, created_at, email_verified_at
             "#,
             username,
             email,
@@ -39,6 +39,7 @@ impl UserRepository {
             organization: row.organization,
             bio: row.bio,
             created_at: row.created_at,
+            email_verified_at: row.email_verified_at,
         })
     }
 
@@ -47,7 +48,7 @@ impl UserRepository {
         wallet_address: &str,
     ) -> Result<Option<User>, SqlxError> {
         let row = sqlx::query!(
-            "SELECT id, username, email, wallet_address, user_type, organization, bio, created_at FROM users WHERE wallet_address = $1",
+            "SELECT id, username, email, wallet_address, user_type, organization, bio, created_at, email_verified_at FROM users WHERE wallet_address = $1",
             wallet_address
         )
         .fetch_optional(pool)
@@ -63,6 +64,7 @@ impl UserRepository {
                 organization: row.organization,
                 bio: row.bio,
                 created_at: row.created_at,
+                email_verified_at: row.email_verified_at,
             }))
         } else {
             Ok(None)
@@ -74,7 +76,7 @@ impl UserRepository {
         email: &str,
     ) -> Result<Option<User>, SqlxError> {
         let row = sqlx::query!(
-            "SELECT id, username, email, wallet_address, user_type, organization, bio, created_at FROM users WHERE email = $1",
+            "SELECT id, username, email, wallet_address, user_type, organization, bio, created_at, email_verified_at FROM users WHERE email = $1",
             email
         )
         .fetch_optional(pool)
@@ -90,6 +92,7 @@ impl UserRepository {
                 organization: row.organization,
                 bio: row.bio,
                 created_at: row.created_at,
+                email_verified_at: row.email_verified_at,
             }))
         } else {
             Ok(None)
@@ -101,7 +104,7 @@ impl UserRepository {
         user_id: i32,
     ) -> Result<Option<User>, SqlxError> {
         let row = sqlx::query!(
-            "SELECT id, username, email, wallet_address, user_type, organization, bio, created_at FROM users WHERE id = $1",
+            "SELECT id, username, email, wallet_address, user_type, organization, bio, created_at, email_verified_at FROM users WHERE id = $1",
             user_id
         )
         .fetch_optional(pool)
@@ -117,6 +120,7 @@ impl UserRepository {
                 organization: row.organization,
                 bio: row.bio,
                 created_at: row.created_at,
+                email_verified_at: row.email_verified_at,
             }))
         } else {
             Ok(None)
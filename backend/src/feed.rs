@@ -0,0 +1,180 @@
+use crate::database::models::Event;
+use chrono::{DateTime, SecondsFormat, Utc};
+use chrono_tz::Tz;
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+
+const FEED_TITLE: &str = "Stellar Europe Events";
+const FEED_SUBTITLE: &str = "Upcoming blockchain events across Europe";
+const FEED_AUTHOR: &str = "Stellar Europe";
+/// Tag URIs (RFC 4151) keep entry ids stable even if the API moves host.
+const TAG_AUTHORITY: &str = "tag:stellareurope.org,2025";
+
+/// Absolute URLs the feeds link to, resolved against the API base URL the
+/// request came in on, e.g. `https://api.stellareurope.org/api/v1`.
+pub struct FeedLinks {
+    pub api_base: String,
+}
+
+impl FeedLinks {
+    pub fn atom(&self) -> String {
+        format!("{}/feeds/events.atom", self.api_base)
+    }
+
+    pub fn rss(&self) -> String {
+        format!("{}/feeds/events.rss", self.api_base)
+    }
+
+    pub fn event_calendar(&self, event_id: i32) -> String {
+        format!("{}/events/{}/calendar.ics", self.api_base, event_id)
+    }
+}
+
+pub fn entry_id(event_id: i32) -> String {
+    format!("{}:event-{}", TAG_AUTHORITY, event_id)
+}
+
+/// Most recent change across `events`, used as the feed's own update time.
+pub fn last_updated(events: &[Event]) -> Option<DateTime<Utc>> {
+    events.iter().map(|event| event.updated_at).max()
+}
+
+/// Entity tag of a feed of `events`: changes whenever an event is edited, enters or leaves
+/// the feed, or the links point elsewhere, and never otherwise.
+pub fn feed_etag(links: &FeedLinks, events: &[Event]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(links.api_base.as_bytes());
+    for event in events {
+        hasher.update(format!("\n{}:{}", event.id, event.updated_at.timestamp_micros()).as_bytes());
+    }
+    let digest: String = hasher.finalize().iter().take(16).map(|byte| format!("{:02x}", byte)).collect();
+    format!("\"{}\"", digest)
+}
+
+/// Renders events as an Atom 1.0 feed (RFC 4287).
+pub fn render_atom(links: &FeedLinks, events: &[Event]) -> String {
+    let updated = last_updated(events).unwrap_or_else(Utc::now);
+    let mut xml = String::new();
+
+    xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    element(&mut xml, 1, "id", &format!("{}:events", TAG_AUTHORITY));
+    element(&mut xml, 1, "title", FEED_TITLE);
+    element(&mut xml, 1, "subtitle", FEED_SUBTITLE);
+    element(&mut xml, 1, "updated", &rfc3339(&updated));
+    let _ = writeln!(xml, "  <link rel=\"self\" type=\"application/atom+xml\" href=\"{}\"/>", escape_xml(&links.atom()));
+    let _ = writeln!(xml, "  <link rel=\"alternate\" type=\"application/rss+xml\" href=\"{}\"/>", escape_xml(&links.rss()));
+    let _ = writeln!(xml, "  <author><name>{}</name></author>", escape_xml(FEED_AUTHOR));
+
+    for event in events {
+        xml.push_str("  <entry>\n");
+        element(&mut xml, 2, "id", &entry_id(event.id));
+        element(&mut xml, 2, "title", &title(event));
+        element(&mut xml, 2, "updated", &rfc3339(&event.updated_at));
+        if let Some(created_at) = &event.created_at {
+            element(&mut xml, 2, "published", &rfc3339(created_at));
+        }
+        if let Some(link) = &event.external_link {
+            let _ = writeln!(xml, "    <link rel=\"alternate\" href=\"{}\"/>", escape_xml(link));
+        }
+        let _ = writeln!(
+            xml,
+            "    <link rel=\"enclosure\" type=\"text/calendar\" href=\"{}\"/>",
+            escape_xml(&links.event_calendar(event.id)),
+        );
+        let _ = writeln!(xml, "    <category term=\"{}\"/>", escape_xml(&event.event_type));
+        for area in event.strategic_focus_areas.iter().flatten() {
+            let _ = writeln!(xml, "    <category term=\"{}\"/>", escape_xml(area));
+        }
+        element(&mut xml, 2, "summary", &summary(event));
+        element(&mut xml, 2, "content", &event.description);
+        xml.push_str("  </entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// Renders events as an RSS 2.0 channel. RSS has no per-item update time, so
+/// edits show up through `lastBuildDate` and the unchanged `guid`.
+pub fn render_rss(links: &FeedLinks, events: &[Event]) -> String {
+    let updated = last_updated(events).unwrap_or_else(Utc::now);
+    let mut xml = String::new();
+
+    xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\">\n");
+    xml.push_str("  <channel>\n");
+    element(&mut xml, 2, "title", FEED_TITLE);
+    element(&mut xml, 2, "description", FEED_SUBTITLE);
+    element(&mut xml, 2, "link", &links.rss());
+    let _ = writeln!(
+        xml,
+        "    <atom:link rel=\"self\" type=\"application/rss+xml\" href=\"{}\"/>",
+        escape_xml(&links.rss()),
+    );
+    element(&mut xml, 2, "lastBuildDate", &updated.to_rfc2822());
+
+    for event in events {
+        xml.push_str("    <item>\n");
+        let _ = writeln!(xml, "      <guid isPermaLink=\"false\">{}</guid>", escape_xml(&entry_id(event.id)));
+        element(&mut xml, 3, "title", &title(event));
+        element(&mut xml, 3, "link", event.external_link.as_deref().unwrap_or(&links.event_calendar(event.id)));
+        element(&mut xml, 3, "description", &format!("{}\n\n{}", summary(event), event.description));
+        element(&mut xml, 3, "pubDate", &event.created_at.unwrap_or(event.updated_at).to_rfc2822());
+        element(&mut xml, 3, "category", &event.event_type);
+        for area in event.strategic_focus_areas.iter().flatten() {
+            element(&mut xml, 3, "category", area);
+        }
+        let _ = writeln!(
+            xml,
+            "      <enclosure url=\"{}\" length=\"0\" type=\"text/calendar\"/>",
+            escape_xml(&links.event_calendar(event.id)),
+        );
+        xml.push_str("    </item>\n");
+    }
+
+    xml.push_str("  </channel>\n");
+    xml.push_str("</rss>\n");
+    xml
+}
+
+/// Cancelled events stay in the feed so subscribers learn about the cancellation.
+fn title(event: &Event) -> String {
+    if event.status == "Cancelled" {
+        format!("Cancelled: {}", event.title)
+    } else {
+        event.title.clone()
+    }
+}
+
+/// One-line "when and where", in the zone the event was scheduled in.
+fn summary(event: &Event) -> String {
+    let zone: Tz = event.timezone.parse().unwrap_or(Tz::UTC);
+    let start = event.date.with_timezone(&zone);
+    let end = event.end_date.with_timezone(&zone);
+
+    let until = if start.date_naive() == end.date_naive() {
+        end.format("%H:%M %Z")
+    } else {
+        end.format("%a %d %b %Y, %H:%M %Z")
+    };
+
+    format!("{}, {} to {} in {}", event.event_type, start.format("%a %d %b %Y, %H:%M"), until, event.location)
+}
+
+fn element(xml: &mut String, depth: usize, name: &str, text: &str) {
+    let _ = writeln!(xml, "{}<{}>{}</{}>", "  ".repeat(depth), name, escape_xml(text), name);
+}
+
+fn rfc3339(instant: &DateTime<Utc>) -> String {
+    instant.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
\ No newline at end of file
@@ -0,0 +1,166 @@
+use crate::database::connection::DbPool;
+use crate::database::models::LinkedAccount;
+use crate::database::repositories::LinkedAccountRepository;
+use crate::handlers::auth::signed_in_user;
+use crate::handlers::budgets::find_event;
+use crate::handlers::chapters::database_error;
+use crate::handlers::error::{api_error, field_error, ApiError, ApiResult};
+use crate::handlers::events::event_status;
+use crate::handlers::plans::find_user;
+use crate::state::AppState;
+use crate::verification::activity_month;
+use axum::{
+    extract::{Json, Path, State},
+    http::{HeaderMap, StatusCode},
+};
+use shared::dto::{
+    ErrorResponse, EventStatus, LinkAccountRequest, LinkedAccountListResponse, LinkedAccountResponse,
+    LinkedAccountStatus,
+};
+use tracing::info;
+
+async fn linked_account_response(pool: &DbPool, link: &LinkedAccount) -> Result<LinkedAccountResponse, ApiError> {
+    Ok(LinkedAccountResponse {
+        id: link.id.to_string(),
+        event_id: link.event_id.to_string(),
+        account_id: link.account_id.clone(),
+        status: LinkedAccountStatus::from_name(&link.status).unwrap_or(LinkedAccountStatus::Pending),
+        linked_by: find_user(pool, link.linked_by).await?,
+        funded_at: link.funded_at,
+        last_transaction_at: link.last_transaction_at,
+        checked_at: link.checked_at,
+        check_error: link.check_error.clone(),
+        created_at: link.created_at,
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}/accounts",
+    tag = "accounts",
+    params(("event_id" = i32, Path, description = "Event id")),
+    responses(
+        (status = 200, description = "Accounts linked to the event, their verification status and the month they must be active in", body = LinkedAccountListResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn list_linked_accounts(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+) -> ApiResult<LinkedAccountListResponse> {
+    let event = find_event(&state.pool, event_id).await?;
+    let links = LinkedAccountRepository::list_for_event(&state.pool, event.id)
+        .await
+        .map_err(|e| database_error("listing linked accounts", e))?;
+
+    let mut accounts = Vec::new();
+    for link in &links {
+        accounts.push(linked_account_response(&state.pool, link).await?);
+    }
+    let verified_count = accounts.iter().filter(|account| account.status == LinkedAccountStatus::Verified).count() as u32;
+    let (window_start, window_end) = activity_month(event.date);
+
+    Ok((
+        StatusCode::OK,
+        Json(LinkedAccountListResponse {
+            event_title: event.title.clone(),
+            event_status: event_status(&event),
+            accounts,
+            verified_count,
+            window_start,
+            window_end,
+        }),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/accounts",
+    tag = "accounts",
+    params(("event_id" = i32, Path, description = "Event id")),
+    request_body = LinkAccountRequest,
+    responses(
+        (status = 201, description = "Account linked; it is checked on Horizon in the background", body = LinkedAccountResponse),
+        (status = 400, description = "Not a Stellar account id", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 409, description = "Event not published yet, or the account is already linked to an event", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn link_account(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+    headers: HeaderMap,
+    Json(req): Json<LinkAccountRequest>,
+) -> ApiResult<LinkedAccountResponse> {
+    let event = find_event(&state.pool, event_id).await?;
+    let actor = signed_in_user(&state.pool, &headers).await?;
+
+    let account_id = req.account_id.trim();
+    if account_id.parse::<stellar_xdr::curr::AccountId>().is_err() {
+        return Err(field_error("account_id", "Must be a Stellar account id (G...)"));
+    }
+    if !matches!(event_status(&event), EventStatus::Published | EventStatus::Completed) {
+        return Err(api_error(StatusCode::CONFLICT, "Accounts can only be linked to published or completed events"));
+    }
+
+    let link = LinkedAccountRepository::create(&state.pool, event.id, account_id, actor.id)
+        .await
+        .map_err(|e| database_error("linking account", e))?
+        .ok_or_else(|| api_error(StatusCode::CONFLICT, "This account is already linked to an event"))?;
+
+    println!("🔗 Account {} linked to event {} by {}", link.account_id, event.id, actor.username);
+    info!("Account {} linked to event {}", link.account_id, event.id);
+
+    Ok((StatusCode::CREATED, Json(linked_account_response(&state.pool, &link).await?)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/events/{event_id}/accounts/{link_id}",
+    tag = "accounts",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("link_id" = i32, Path, description = "Linked account id"),
+    ),
+    responses(
+        (status = 200, description = "Link removed; the remaining accounts", body = LinkedAccountListResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only whoever linked the account, the organizer or a programme admin removes it", body = ErrorResponse),
+        (status = 404, description = "Event or linked account not found", body = ErrorResponse),
+        (status = 409, description = "Verified accounts count towards the event's actuals and stay linked", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn unlink_account(
+    State(state): State<AppState>,
+    Path((event_id, link_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> ApiResult<LinkedAccountListResponse> {
+    let event = find_event(&state.pool, event_id).await?;
+    let link = LinkedAccountRepository::find(&state.pool, event.id, link_id)
+        .await
+        .map_err(|e| database_error("loading linked account", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Linked account not found"))?;
+    let actor = signed_in_user(&state.pool, &headers).await?;
+
+    if link.linked_by != Some(actor.id) && actor.id != event.organizer_id && !state.stellar.is_admin(&actor.wallet_address) {
+        return Err(api_error(
+            StatusCode::FORBIDDEN,
+            "Only whoever linked the account, the organizer or a programme admin removes it",
+        ));
+    }
+    if link.status == LinkedAccountStatus::Verified.to_string() {
+        return Err(api_error(StatusCode::CONFLICT, "Verified accounts count towards the event's actuals and stay linked"));
+    }
+
+    LinkedAccountRepository::delete(&state.pool, link.id)
+        .await
+        .map_err(|e| database_error("unlinking account", e))?;
+
+    list_linked_accounts(State(state), Path(event_id)).await
+}
\ No newline at end of file
@@ -0,0 +1,431 @@
+use crate::database::connection::DbPool;
+use crate::database::models::{Attachment, Event, User};
+use crate::database::repositories::AttachmentRepository;
+use crate::handlers::budgets::{find_event, find_item};
+use crate::handlers::chapters::database_error;
+use crate::handlers::error::{api_error, field_error, ApiError, ApiResult};
+use crate::handlers::auth::{session_user, signed_in_user};
+use crate::handlers::plans::find_user;
+use crate::media::{
+    accepts, clean_file_name, content_disposition, extension, is_image, make_thumbnail, sniff_content_type,
+    thumbnail_content_type,
+};
+use crate::state::AppState;
+use crate::storage::StorageError;
+use axum::{
+    body::{Body, Bytes},
+    extract::{multipart::Field, FromRequest, Json, Multipart, Path, Query, Request, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use http_body_util::Limited;
+use serde::Deserialize;
+use shared::dto::{AttachmentKind, AttachmentListResponse, AttachmentResponse, ErrorResponse};
+use tracing::{error, info, warn};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// Text fields of an upload are short; anything longer is not a kind or an id.
+const MAX_TEXT_FIELD_BYTES: usize = 1024;
+/// Room an upload gets on top of its file for the other fields, part headers and boundaries.
+const MAX_FORM_OVERHEAD_BYTES: usize = 16 * 1024;
+
+/// The multipart form the upload endpoint reads.
+#[derive(ToSchema)]
+pub struct AttachmentUploadForm {
+    pub kind: AttachmentKind,
+    /// Budget item a receipt is for
+    pub budget_item_id: Option<i32>,
+    /// JPEG, PNG, WebP, PDF, PowerPoint or OpenDocument presentation
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct AttachmentListQuery {
+    /// Only the attachments of this budget item
+    pub budget_item_id: Option<i32>,
+}
+
+fn attachment_kind(attachment: &Attachment) -> AttachmentKind {
+    AttachmentKind::from_name(&attachment.kind).unwrap_or(AttachmentKind::Document)
+}
+
+async fn attachment_response(pool: &DbPool, attachment: &Attachment) -> Result<AttachmentResponse, ApiError> {
+    Ok(AttachmentResponse {
+        id: attachment.id.to_string(),
+        event_id: attachment.event_id.to_string(),
+        budget_item_id: attachment.budget_item_id.map(|id| id.to_string()),
+        kind: attachment_kind(attachment),
+        file_name: attachment.file_name.clone(),
+        content_type: attachment.content_type.clone(),
+        size_bytes: attachment.size_bytes.max(0) as u64,
+        has_thumbnail: attachment.thumbnail_key.is_some(),
+        uploaded_by: find_user(pool, attachment.uploaded_by).await?,
+        created_at: attachment.created_at,
+    })
+}
+
+async fn find_attachment(pool: &DbPool, event_id: i32, attachment_id: i32) -> Result<Attachment, ApiError> {
+    AttachmentRepository::find(pool, event_id, attachment_id)
+        .await
+        .map_err(|e| database_error("loading attachment", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Attachment not found"))
+}
+
+fn manages(state: &AppState, user: &User, event: &Event) -> bool {
+    user.id == event.organizer_id || state.stellar.is_admin(&user.wallet_address)
+}
+
+/// Files of an event are managed by its organizer and the programme admins.
+async fn find_manager(state: &AppState, headers: &HeaderMap, event: &Event) -> Result<User, ApiError> {
+    let actor = signed_in_user(&state.pool, headers).await?;
+    if !manages(state, &actor, event) {
+        return Err(api_error(
+            StatusCode::FORBIDDEN,
+            "Only the organizer or a programme admin manages this event's files",
+        ));
+    }
+    Ok(actor)
+}
+
+/// Receipts carry personal and payment details, so only the event's managers open them.
+async fn ensure_visible(state: &AppState, headers: &HeaderMap, attachment: &Attachment) -> Result<(), ApiError> {
+    if attachment_kind(attachment) == AttachmentKind::Receipt {
+        let event = find_event(&state.pool, attachment.event_id).await?;
+        find_manager(state, headers, &event).await?;
+    }
+    Ok(())
+}
+
+fn storage_error(context: &str, e: StorageError) -> ApiError {
+    match e {
+        StorageError::NotFound(_) => api_error(StatusCode::NOT_FOUND, "The stored file is missing"),
+        e => {
+            error!("Storage error {}: {}", context, e);
+            api_error(StatusCode::SERVICE_UNAVAILABLE, format!("File storage unavailable while {}", context))
+        }
+    }
+}
+
+/// Reads a form field chunk by chunk, refusing it as soon as it grows past `limit`.
+async fn read_field(mut field: Field<'_>, limit: usize) -> Result<Bytes, ApiError> {
+    let name = field.name().unwrap_or_default().to_string();
+    let mut bytes = Vec::new();
+    while let Some(chunk) = field.chunk().await.map_err(|e| api_error(e.status(), e.body_text()))? {
+        if bytes.len() + chunk.len() > limit {
+            return Err(api_error(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!("The {} field is larger than the {} bytes allowed", name, limit),
+            ));
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(Bytes::from(bytes))
+}
+
+async fn read_text_field(field: Field<'_>) -> Result<String, ApiError> {
+    let name = field.name().unwrap_or_default().to_string();
+    let bytes = read_field(field, MAX_TEXT_FIELD_BYTES).await?;
+    String::from_utf8(bytes.to_vec())
+        .map(|text| text.trim().to_string())
+        .map_err(|_| field_error(&name, "Must be UTF-8 text"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}/attachments",
+    tag = "attachments",
+    params(("event_id" = i32, Path, description = "Event id"), AttachmentListQuery),
+    responses(
+        (status = 200, description = "The event's photos and slides, newest first; receipts too for the organizer and programme admins", body = AttachmentListResponse),
+        (status = 401, description = "The session has expired", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security((), ("session" = []))
+)]
+pub async fn list_attachments(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+    Query(params): Query<AttachmentListQuery>,
+    headers: HeaderMap,
+) -> ApiResult<AttachmentListResponse> {
+    let event = find_event(&state.pool, event_id).await?;
+    let viewer = session_user(&state.pool, &headers).await?;
+    let sees_receipts = viewer.is_some_and(|viewer| manages(&state, &viewer, &event));
+    let attachments = AttachmentRepository::list_for_event(&state.pool, event.id, params.budget_item_id)
+        .await
+        .map_err(|e| database_error("listing attachments", e))?;
+
+    let mut responses = Vec::new();
+    for attachment in &attachments {
+        if !sees_receipts && attachment_kind(attachment) == AttachmentKind::Receipt {
+            continue;
+        }
+        responses.push(attachment_response(&state.pool, attachment).await?);
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(AttachmentListResponse { attachments: responses, max_upload_bytes: state.max_upload_bytes as u64 }),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/attachments",
+    tag = "attachments",
+    params(("event_id" = i32, Path, description = "Event id")),
+    request_body(content = AttachmentUploadForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "File stored, with a thumbnail if it is an image", body = AttachmentResponse),
+        (status = 400, description = "Missing field, or a budget item on something other than a receipt", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the organizer or a programme admin uploads files", body = ErrorResponse),
+        (status = 404, description = "Event or budget item not found", body = ErrorResponse),
+        (status = 413, description = "File or form larger than the upload limit", body = ErrorResponse),
+        (status = 415, description = "File type not accepted for this kind of attachment", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+        (status = 503, description = "File storage unavailable", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn upload_attachment(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+    request: Request,
+) -> ApiResult<AttachmentResponse> {
+    // Nothing of the form is read until the actor may upload, and never more than one file's worth
+    let event = find_event(&state.pool, event_id).await?;
+    let actor = find_manager(&state, request.headers(), &event).await?;
+    let limit = state.max_upload_bytes + MAX_FORM_OVERHEAD_BYTES;
+    let mut multipart = Multipart::from_request(request.map(|body| Body::new(Limited::new(body, limit))), &state)
+        .await
+        .map_err(|e| api_error(e.status(), e.body_text()))?;
+
+    let mut kind = None;
+    let mut budget_item_id = None;
+    let mut file = None;
+
+    while let Some(field) = multipart.next_field().await.map_err(|e| api_error(e.status(), e.body_text()))? {
+        match field.name().unwrap_or_default() {
+            "kind" => {
+                let name = read_text_field(field).await?;
+                kind = Some(AttachmentKind::from_name(&name).ok_or_else(|| {
+                    field_error("kind", "Kind must be Receipt, Photo, Slides or Document")
+                })?);
+            }
+            "budget_item_id" => {
+                let id = read_text_field(field).await?;
+                if !id.is_empty() {
+                    budget_item_id =
+                        Some(id.parse::<i32>().map_err(|_| field_error("budget_item_id", "Must be a budget item id"))?);
+                }
+            }
+            "file" => {
+                let file_name = clean_file_name(field.file_name());
+                file = Some((file_name, read_field(field, state.max_upload_bytes).await?));
+            }
+            _ => {
+                read_field(field, MAX_TEXT_FIELD_BYTES).await?;
+            }
+        }
+    }
+
+    let kind = kind.ok_or_else(|| field_error("kind", "Kind is required"))?;
+    let (file_name, bytes) = file
+        .filter(|(_, bytes)| !bytes.is_empty())
+        .ok_or_else(|| field_error("file", "A non-empty file is required"))?;
+
+    let content_type = sniff_content_type(&bytes)
+        .filter(|content_type| accepts(kind, content_type))
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                format!("This file type is not accepted for a {}", kind.to_string().to_lowercase()),
+            )
+        })?;
+
+    println!("📎 UPLOAD for event {}: {} {} ({} bytes)", event_id, kind, file_name, bytes.len());
+    info!("Uploading {} attachment to event {}", kind, event_id);
+
+    if let Some(item_id) = budget_item_id {
+        if kind != AttachmentKind::Receipt {
+            return Err(field_error("budget_item_id", "Only receipts belong to a budget item"));
+        }
+        find_item(&state.pool, event.id, item_id).await?;
+    }
+
+    let name = Uuid::new_v4();
+    let storage_key = format!("events/{}/{}.{}", event.id, name, extension(content_type));
+    let thumbnail = if is_image(content_type) {
+        let image = bytes.clone();
+        tokio::task::spawn_blocking(move || make_thumbnail(&image, content_type)).await.ok().flatten()
+    } else {
+        None
+    };
+
+    state
+        .storage
+        .put(&storage_key, content_type, bytes.clone())
+        .await
+        .map_err(|e| storage_error("storing the file", e))?;
+
+    // A missing thumbnail only costs the preview, so the upload goes ahead without one
+    let mut thumbnail_key = None;
+    if let Some(thumbnail) = thumbnail {
+        let thumbnail_type = thumbnail_content_type(content_type);
+        let key = format!("events/{}/{}-thumb.{}", event.id, name, extension(thumbnail_type));
+        match state.storage.put(&key, thumbnail_type, Bytes::from(thumbnail)).await {
+            Ok(()) => thumbnail_key = Some(key),
+            Err(e) => warn!("Storing thumbnail of {} failed: {}", storage_key, e),
+        }
+    }
+
+    let created = AttachmentRepository::create(
+        &state.pool,
+        event.id,
+        budget_item_id,
+        &kind.to_string(),
+        &file_name,
+        content_type,
+        bytes.len() as i64,
+        &storage_key,
+        thumbnail_key.as_deref(),
+        actor.id,
+    )
+    .await;
+
+    let attachment = match created {
+        Ok(attachment) => attachment,
+        Err(e) => {
+            for key in std::iter::once(&storage_key).chain(thumbnail_key.as_ref()) {
+                if let Err(e) = state.storage.delete(key).await {
+                    warn!("Removing orphaned upload {} failed: {}", key, e);
+                }
+            }
+            return Err(database_error("saving attachment", e));
+        }
+    };
+
+    Ok((StatusCode::CREATED, Json(attachment_response(&state.pool, &attachment).await?)))
+}
+
+async fn stored_file(
+    state: &AppState,
+    attachment: &Attachment,
+    key: &str,
+    content_type: &str,
+) -> Result<Response, ApiError> {
+    let bytes = state.storage.get(key).await.map_err(|e| storage_error("loading the file", e))?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (header::CONTENT_DISPOSITION, content_disposition(&attachment.file_name, content_type)),
+            // Browsers must not reinterpret an upload as HTML or script
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+        ],
+        bytes,
+    )
+        .into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}/attachments/{attachment_id}/content",
+    tag = "attachments",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("attachment_id" = i32, Path, description = "Attachment id"),
+    ),
+    responses(
+        (status = 200, description = "The uploaded file", content_type = "application/octet-stream", body = String),
+        (status = 401, description = "A receipt, and not signed in or the session has expired", body = ErrorResponse),
+        (status = 403, description = "A receipt, which only the organizer or a programme admin opens", body = ErrorResponse),
+        (status = 404, description = "Event, attachment or stored file not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+        (status = 503, description = "File storage unavailable", body = ErrorResponse),
+    ),
+    security((), ("session" = []))
+)]
+pub async fn attachment_content(
+    State(state): State<AppState>,
+    Path((event_id, attachment_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let attachment = find_attachment(&state.pool, event_id, attachment_id).await?;
+    ensure_visible(&state, &headers, &attachment).await?;
+    stored_file(&state, &attachment, &attachment.storage_key, &attachment.content_type).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}/attachments/{attachment_id}/thumbnail",
+    tag = "attachments",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("attachment_id" = i32, Path, description = "Attachment id"),
+    ),
+    responses(
+        (status = 200, description = "A JPEG or PNG preview at most 320 pixels on its longest side", content_type = "image/*", body = String),
+        (status = 401, description = "A receipt, and not signed in or the session has expired", body = ErrorResponse),
+        (status = 403, description = "A receipt, which only the organizer or a programme admin opens", body = ErrorResponse),
+        (status = 404, description = "Event or attachment not found, or it has no thumbnail", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+        (status = 503, description = "File storage unavailable", body = ErrorResponse),
+    ),
+    security((), ("session" = []))
+)]
+pub async fn attachment_thumbnail(
+    State(state): State<AppState>,
+    Path((event_id, attachment_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let attachment = find_attachment(&state.pool, event_id, attachment_id).await?;
+    ensure_visible(&state, &headers, &attachment).await?;
+    let key = attachment
+        .thumbnail_key
+        .clone()
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "This attachment has no thumbnail"))?;
+    stored_file(&state, &attachment, &key, thumbnail_content_type(&attachment.content_type)).await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/events/{event_id}/attachments/{attachment_id}",
+    tag = "attachments",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("attachment_id" = i32, Path, description = "Attachment id"),
+    ),
+    responses(
+        (status = 200, description = "Attachment and its files removed; the remaining attachments", body = AttachmentListResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the organizer or a programme admin removes files", body = ErrorResponse),
+        (status = 404, description = "Event or attachment not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn delete_attachment(
+    State(state): State<AppState>,
+    Path((event_id, attachment_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> ApiResult<AttachmentListResponse> {
+    let event = find_event(&state.pool, event_id).await?;
+    let attachment = find_attachment(&state.pool, event.id, attachment_id).await?;
+    find_manager(&state, &headers, &event).await?;
+
+    AttachmentRepository::delete(&state.pool, attachment.id)
+        .await
+        .map_err(|e| database_error("deleting attachment", e))?;
+    // The record is gone, so a file left behind is only wasted space
+    for key in std::iter::once(&attachment.storage_key).chain(attachment.thumbnail_key.as_ref()) {
+        if let Err(e) = state.storage.delete(key).await {
+            warn!("Removing stored file {} failed: {}", key, e);
+        }
+    }
+
+    list_attachments(State(state), Path(event_id), Query(AttachmentListQuery { budget_item_id: None }), headers).await
+}
\ No newline at end of file
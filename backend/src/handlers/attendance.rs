@@ -0,0 +1,457 @@
+use crate::config::StellarConfig;
+use crate::database::connection::DbPool;
+use crate::database::models::{AttendanceIssuance, CheckIn, Event, User};
+use crate::database::repositories::{AttendanceRepository, EventRepository, UserRepository};
+use crate::handlers::auth::{create_user_public, signed_in_user};
+use crate::handlers::budgets::find_event;
+use crate::handlers::chapters::database_error;
+use crate::handlers::error::{api_error, field_error, ApiError, ApiResult};
+use crate::handlers::events::event_status;
+use crate::handlers::plans::{find_actor, find_user};
+use crate::state::AppState;
+use crate::stellar::{
+    attendance_asset_code, build_attendance_tokens, submit_payment, verify_signed, SubmissionOutcome,
+    MAX_TOKENS_PER_TRANSACTION,
+};
+use axum::{
+    extract::{Json, Path, State},
+    http::{HeaderMap, StatusCode},
+};
+use chrono::Utc;
+use shared::dto::{
+    AttendanceIssuanceResponse, AttendanceRecord, AttendanceTokenStatus, CheckInRequest, CheckInResponse, ErrorResponse,
+    EventAttendanceResponse, EventStatus, SubmitAttendanceTokensRequest, UserAttendanceResponse,
+};
+use std::collections::HashMap;
+use tracing::info;
+
+fn issuance_status(issuance: &AttendanceIssuance) -> AttendanceTokenStatus {
+    AttendanceTokenStatus::from_name(&issuance.status).unwrap_or(AttendanceTokenStatus::AwaitingSignature)
+}
+
+fn token_status(issuance: Option<&AttendanceIssuance>) -> AttendanceTokenStatus {
+    issuance.map(issuance_status).unwrap_or(AttendanceTokenStatus::NotIssued)
+}
+
+fn issuance_response(config: &StellarConfig, issuance: &AttendanceIssuance, token_count: u32) -> AttendanceIssuanceResponse {
+    AttendanceIssuanceResponse {
+        id: issuance.id.to_string(),
+        event_id: issuance.event_id.to_string(),
+        asset_code: issuance.asset_code.clone(),
+        issuer: issuance.issuer.clone(),
+        status: issuance_status(issuance),
+        token_count,
+        transaction_xdr: issuance.transaction_xdr.clone(),
+        transaction_hash: issuance.transaction_hash.clone(),
+        network_passphrase: config.network_passphrase.clone(),
+        ledger: issuance.ledger.map(|ledger| ledger as u32),
+        error: issuance.error.clone(),
+        submitted_at: issuance.submitted_at,
+        issued_at: issuance.issued_at,
+        updated_at: issuance.updated_at,
+    }
+}
+
+async fn check_in_response(
+    pool: &DbPool,
+    check_in: &CheckIn,
+    issuance: Option<&AttendanceIssuance>,
+) -> Result<CheckInResponse, ApiError> {
+    let attendee = find_user(pool, Some(check_in.user_id))
+        .await?
+        .ok_or_else(|| api_error(StatusCode::INTERNAL_SERVER_ERROR, "Checked-in attendee not found"))?;
+    Ok(CheckInResponse {
+        id: check_in.id.to_string(),
+        event_id: check_in.event_id.to_string(),
+        attendee,
+        checked_in_by: find_user(pool, check_in.checked_in_by).await?,
+        checked_in_at: check_in.checked_in_at,
+        token_status: token_status(issuance),
+        transaction_hash: issuance.map(|issuance| issuance.transaction_hash.clone()),
+    })
+}
+
+async fn list_issuances(pool: &DbPool, event_id: i32) -> Result<HashMap<i32, AttendanceIssuance>, ApiError> {
+    Ok(AttendanceRepository::list_issuances(pool, event_id)
+        .await
+        .map_err(|e| database_error("loading attendance tokens", e))?
+        .into_iter()
+        .map(|issuance| (issuance.id, issuance))
+        .collect())
+}
+
+async fn attendance_response(state: &AppState, event: &Event) -> Result<EventAttendanceResponse, ApiError> {
+    let check_ins = AttendanceRepository::list_check_ins(&state.pool, event.id)
+        .await
+        .map_err(|e| database_error("listing check-ins", e))?;
+    let issuances = list_issuances(&state.pool, event.id).await?;
+
+    let mut responses = Vec::new();
+    for check_in in &check_ins {
+        let issuance = check_in.issuance_id.and_then(|id| issuances.get(&id));
+        responses.push(check_in_response(&state.pool, check_in, issuance).await?);
+    }
+
+    let open_issuance = issuances
+        .values()
+        .filter(|issuance| {
+            matches!(issuance_status(issuance), AttendanceTokenStatus::AwaitingSignature | AttendanceTokenStatus::Submitted)
+        })
+        .max_by_key(|issuance| issuance.id)
+        .map(|issuance| {
+            let token_count = check_ins.iter().filter(|check_in| check_in.issuance_id == Some(issuance.id)).count();
+            issuance_response(&state.stellar, issuance, token_count as u32)
+        });
+
+    Ok(EventAttendanceResponse {
+        event_title: event.title.clone(),
+        event_status: event_status(event),
+        asset_code: attendance_asset_code(event.id),
+        asset_issuer: state.stellar.attendance_issuer.clone(),
+        check_ins: responses,
+        open_issuance,
+    })
+}
+
+/// Attendees are checked in by the event's organizer and the programme admins.
+async fn find_host(state: &AppState, headers: &HeaderMap, event: &Event) -> Result<User, ApiError> {
+    let actor = signed_in_user(&state.pool, headers).await?;
+    if actor.id != event.organizer_id && !state.stellar.is_admin(&actor.wallet_address) {
+        return Err(api_error(StatusCode::FORBIDDEN, "Only the organizer or a programme admin checks attendees in"));
+    }
+    Ok(actor)
+}
+
+async fn find_admin(state: &AppState, headers: &HeaderMap) -> Result<User, ApiError> {
+    let actor = signed_in_user(&state.pool, headers).await?;
+    if !state.stellar.is_admin(&actor.wallet_address) {
+        return Err(api_error(StatusCode::FORBIDDEN, "Only programme admins issue attendance tokens"));
+    }
+    Ok(actor)
+}
+
+fn check_held(event: &Event) -> Result<(), ApiError> {
+    if !matches!(event_status(event), EventStatus::Published | EventStatus::Completed) || event.date > Utc::now() {
+        return Err(api_error(StatusCode::CONFLICT, "Attendance is only recorded for published events that have started"));
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}/attendance",
+    tag = "attendance",
+    params(("event_id" = i32, Path, description = "Event id")),
+    responses(
+        (status = 200, description = "Checked-in attendees, their token status and the issuance waiting to be signed, if any", body = EventAttendanceResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn get_event_attendance(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+) -> ApiResult<EventAttendanceResponse> {
+    let event = find_event(&state.pool, event_id).await?;
+    Ok((StatusCode::OK, Json(attendance_response(&state, &event).await?)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/attendance",
+    tag = "attendance",
+    params(("event_id" = i32, Path, description = "Event id")),
+    request_body = CheckInRequest,
+    responses(
+        (status = 201, description = "Attendee checked in", body = CheckInResponse),
+        (status = 400, description = "Unknown attendee, or an attendee wallet that is not a Stellar account", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the organizer or a programme admin checks attendees in", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 409, description = "Event not published or not started, or attendee already checked in", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn check_in_attendee(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+    headers: HeaderMap,
+    Json(req): Json<CheckInRequest>,
+) -> ApiResult<CheckInResponse> {
+    let event = find_event(&state.pool, event_id).await?;
+    let host = find_host(&state, &headers, &event).await?;
+    let attendee = find_actor(&state.pool, &req.attendee_wallet_address, "attendee_wallet_address").await?;
+    if attendee.wallet_address.parse::<stellar_xdr::curr::AccountId>().is_err() {
+        return Err(field_error("attendee_wallet_address", "The attendee's wallet is not a Stellar account (G...)"));
+    }
+    check_held(&event)?;
+
+    let check_in = AttendanceRepository::check_in(&state.pool, event.id, attendee.id, host.id)
+        .await
+        .map_err(|e| database_error("checking in", e))?
+        .ok_or_else(|| api_error(StatusCode::CONFLICT, "The attendee is already checked in"))?;
+
+    println!("🎟️ {} checked in at event {} by {}", attendee.username, event.id, host.username);
+    info!("User {} checked in at event {}", attendee.id, event.id);
+
+    Ok((StatusCode::CREATED, Json(check_in_response(&state.pool, &check_in, None).await?)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/events/{event_id}/attendance/{check_in_id}",
+    tag = "attendance",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("check_in_id" = i32, Path, description = "Check-in id"),
+    ),
+    responses(
+        (status = 200, description = "Check-in removed; the event's attendance", body = EventAttendanceResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the organizer or a programme admin removes check-ins", body = ErrorResponse),
+        (status = 404, description = "Event or check-in not found", body = ErrorResponse),
+        (status = 409, description = "The attendee's token is already submitted or issued", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn remove_check_in(
+    State(state): State<AppState>,
+    Path((event_id, check_in_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> ApiResult<EventAttendanceResponse> {
+    let event = find_event(&state.pool, event_id).await?;
+    let check_in = AttendanceRepository::find_check_in(&state.pool, event.id, check_in_id)
+        .await
+        .map_err(|e| database_error("loading check-in", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Check-in not found"))?;
+    find_host(&state, &headers, &event).await?;
+
+    if let Some(issuance_id) = check_in.issuance_id {
+        let issuance = AttendanceRepository::find_issuance(&state.pool, event.id, issuance_id)
+            .await
+            .map_err(|e| database_error("loading attendance token", e))?;
+        let status = token_status(issuance.as_ref());
+        if matches!(status, AttendanceTokenStatus::Submitted | AttendanceTokenStatus::Issued) {
+            return Err(api_error(StatusCode::CONFLICT, format!("The attendee's token is already {}", status)));
+        }
+    }
+
+    AttendanceRepository::delete_check_in(&state.pool, check_in.id)
+        .await
+        .map_err(|e| database_error("removing check-in", e))?;
+
+    Ok((StatusCode::OK, Json(attendance_response(&state, &event).await?)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/attendance/tokens",
+    tag = "attendance",
+    params(("event_id" = i32, Path, description = "Event id")),
+    responses(
+        (status = 201, description = "Unsigned transaction creating a claimable balance of the event's asset for each checked-in attendee without a token (up to 100); replaces an issuance that was never signed", body = AttendanceIssuanceResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only programme admins issue attendance tokens", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 409, description = "Event not published or not started, an issuance already submitted, or nobody waiting for a token", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+        (status = 502, description = "Horizon could not load the issuing account", body = ErrorResponse),
+        (status = 503, description = "No issuing account is configured", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn prepare_attendance_tokens(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+    headers: HeaderMap,
+) -> ApiResult<AttendanceIssuanceResponse> {
+    let admin = find_admin(&state, &headers).await?;
+    println!("🎟️ ATTENDANCE TOKENS for event {} prepared by {}", event_id, admin.wallet_address);
+    info!("Preparing attendance tokens of event {}", event_id);
+
+    let event = find_event(&state.pool, event_id).await?;
+    let issuer = state
+        .stellar
+        .attendance_issuer
+        .as_deref()
+        .ok_or_else(|| api_error(StatusCode::SERVICE_UNAVAILABLE, "Attendance tokens are not configured on this server"))?;
+    check_held(&event)?;
+
+    let issuances = list_issuances(&state.pool, event.id).await?;
+    if issuances.values().any(|issuance| issuance_status(issuance) == AttendanceTokenStatus::Submitted) {
+        return Err(api_error(
+            StatusCode::CONFLICT,
+            "An earlier issuance was submitted without a definite answer; submit it again first",
+        ));
+    }
+
+    let mut recipients = AttendanceRepository::list_token_recipients(&state.pool, event.id)
+        .await
+        .map_err(|e| database_error("loading token recipients", e))?;
+    if recipients.is_empty() {
+        return Err(api_error(StatusCode::CONFLICT, "Every checked-in attendee already has a token"));
+    }
+    recipients.truncate(MAX_TOKENS_PER_TRANSACTION);
+    let wallets: Vec<String> = recipients.iter().map(|recipient| recipient.wallet_address.clone()).collect();
+    let check_in_ids: Vec<i32> = recipients.iter().map(|recipient| recipient.check_in_id).collect();
+
+    let sequence = state
+        .horizon
+        .account_sequence(issuer)
+        .await
+        .map_err(|e| api_error(StatusCode::BAD_GATEWAY, e.to_string()))?;
+    let transaction = build_attendance_tokens(&state.stellar.network_passphrase, issuer, sequence, event.id, &wallets, Utc::now())
+        .map_err(|e| api_error(StatusCode::CONFLICT, e.to_string()))?;
+
+    let issuance = AttendanceRepository::prepare_issuance(
+        &state.pool,
+        event.id,
+        &attendance_asset_code(event.id),
+        issuer,
+        admin.id,
+        &transaction.envelope_xdr,
+        &transaction.hash,
+        &check_in_ids,
+    )
+    .await
+    .map_err(|e| database_error("saving attendance tokens", e))?;
+
+    Ok((StatusCode::CREATED, Json(issuance_response(&state.stellar, &issuance, wallets.len() as u32))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/attendance/tokens/{issuance_id}/submit",
+    tag = "attendance",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("issuance_id" = i32, Path, description = "Issuance id"),
+    ),
+    request_body = SubmitAttendanceTokensRequest,
+    responses(
+        (status = 200, description = "Submitted to the network: Issued, Failed, or still Submitted if Horizon gave no definite answer", body = AttendanceIssuanceResponse),
+        (status = 400, description = "Not the prepared transaction, or not signed", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only programme admins issue attendance tokens", body = ErrorResponse),
+        (status = 404, description = "Event or issuance not found", body = ErrorResponse),
+        (status = 409, description = "The issuance is already issued or failed", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn submit_attendance_tokens(
+    State(state): State<AppState>,
+    Path((event_id, issuance_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+    Json(req): Json<SubmitAttendanceTokensRequest>,
+) -> ApiResult<AttendanceIssuanceResponse> {
+    let admin = find_admin(&state, &headers).await?;
+    println!("🚀 ATTENDANCE TOKEN SUBMISSION for event {} by {}", event_id, admin.wallet_address);
+
+    let event = find_event(&state.pool, event_id).await?;
+    let find_issuance = || async {
+        AttendanceRepository::find_issuance(&state.pool, event.id, issuance_id)
+            .await
+            .map_err(|e| database_error("loading attendance tokens", e))?
+            .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Issuance not found"))
+    };
+    let issuance = find_issuance().await?;
+
+    let status = issuance_status(&issuance);
+    if !matches!(status, AttendanceTokenStatus::AwaitingSignature | AttendanceTokenStatus::Submitted) {
+        return Err(api_error(StatusCode::CONFLICT, format!("The issuance is already {}", status)));
+    }
+    verify_signed(&issuance.transaction_xdr, &req.signed_transaction_xdr)
+        .map_err(|e| field_error("signed_transaction_xdr", e.to_string()))?;
+
+    AttendanceRepository::mark_submitted(&state.pool, issuance.id, req.signed_transaction_xdr.trim())
+        .await
+        .map_err(|e| database_error("recording submission", e))?
+        .ok_or_else(|| api_error(StatusCode::CONFLICT, "The issuance changed in the meantime"))?;
+
+    let outcome = submit_payment(
+        state.horizon.as_ref(),
+        &issuance.transaction_hash,
+        &req.signed_transaction_xdr,
+        status == AttendanceTokenStatus::Submitted,
+    )
+    .await;
+    println!("🎟️ ATTENDANCE ISSUANCE {} outcome: {:?}", issuance.id, outcome);
+    info!("Attendance issuance {} submitted: {:?}", issuance.id, outcome);
+
+    let updated = match &outcome {
+        SubmissionOutcome::Paid { ledger } => AttendanceRepository::mark_issued(&state.pool, issuance.id, *ledger as i32).await,
+        SubmissionOutcome::Failed(error) => AttendanceRepository::mark_failed(&state.pool, issuance.id, error).await,
+        SubmissionOutcome::Pending(error) => AttendanceRepository::record_pending(&state.pool, issuance.id, error).await,
+    }
+    .map_err(|e| database_error("recording submission outcome", e))?;
+
+    // An overlapping submission may have settled the issuance first
+    let issuance = match updated {
+        Some(issuance) => issuance,
+        None => find_issuance().await?,
+    };
+    let token_count = AttendanceRepository::list_check_ins(&state.pool, event.id)
+        .await
+        .map_err(|e| database_error("listing check-ins", e))?
+        .iter()
+        .filter(|check_in| check_in.issuance_id == Some(issuance.id))
+        .count();
+
+    Ok((StatusCode::OK, Json(issuance_response(&state.stellar, &issuance, token_count as u32))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/users/{wallet_address}/attendance",
+    tag = "attendance",
+    params(("wallet_address" = String, Path, description = "Wallet address the user registered with")),
+    responses(
+        (status = 200, description = "Events the user was checked in at, most recent first, with their proof-of-attendance tokens", body = UserAttendanceResponse),
+        (status = 404, description = "No registered user has this wallet address", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn get_user_attendance(
+    State(state): State<AppState>,
+    Path(wallet_address): Path<String>,
+) -> ApiResult<UserAttendanceResponse> {
+    let user = UserRepository::find_by_wallet_address(&state.pool, wallet_address.trim())
+        .await
+        .map_err(|e| database_error("loading user", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "No registered user has this wallet address"))?;
+    let check_ins = AttendanceRepository::list_check_ins_for_user(&state.pool, user.id)
+        .await
+        .map_err(|e| database_error("listing check-ins", e))?;
+
+    let mut attendance = Vec::new();
+    for check_in in &check_ins {
+        let Some(event) = EventRepository::find_by_id(&state.pool, check_in.event_id)
+            .await
+            .map_err(|e| database_error("loading event", e))?
+        else {
+            continue;
+        };
+        let issuance = match check_in.issuance_id {
+            Some(issuance_id) => AttendanceRepository::find_issuance(&state.pool, event.id, issuance_id)
+                .await
+                .map_err(|e| database_error("loading attendance token", e))?,
+            None => None,
+        };
+        attendance.push(AttendanceRecord {
+            event_id: event.id.to_string(),
+            event_title: event.title.clone(),
+            event_date: event.date,
+            checked_in_at: check_in.checked_in_at,
+            asset_code: attendance_asset_code(event.id),
+            asset_issuer: issuance.as_ref().map(|issuance| issuance.issuer.clone()),
+            token_status: token_status(issuance.as_ref()),
+            transaction_hash: issuance.as_ref().map(|issuance| issuance.transaction_hash.clone()),
+            issued_at: issuance.as_ref().and_then(|issuance| issuance.issued_at),
+        });
+    }
+
+    Ok((StatusCode::OK, Json(UserAttendanceResponse { user: create_user_public(&user), attendance })))
+}
\ No newline at end of file
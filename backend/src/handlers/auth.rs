@@ -1,14 +1,29 @@
 use crate::database::connection::DbPool;
-use crate::database::repositories::UserRepository;
+use crate::database::repositories::{SessionRepository, SignupChallengeRepository, UserRepository};
+use crate::handlers::chapters::database_error;
+use crate::handlers::email::send_verification_email;
+use crate::handlers::error::{api_error, field_error, ApiError, ApiResult};
+use crate::state::AppState;
+use crate::stellar::{signin_message, signup_message, verify_signed_message, SignatureError};
 use axum::{
     extract::{Json, State},
-    http::StatusCode,
+    http::{header, HeaderMap, StatusCode},
 };
+use chrono::{DateTime, Duration, Utc};
 use tracing::{info, error};
-use shared::dto::{SignUpRequest, SignUpResponse, UserPublic, UserType};
-use crate::database::models::User;
+use shared::dto::{
+    ErrorResponse, SessionResponse, SignInRequest, SignUpChallengeRequest, SignUpChallengeResponse, SignUpRequest,
+    SignUpResponse, UserPublic, UserType,
+};
+use crate::database::models::{SignupChallenge, User};
+use uuid::Uuid;
+
+/// How long a signup or sign-in nonce can be signed and sent back for.
+pub const SIGNUP_CHALLENGE_TTL: Duration = Duration::minutes(10);
+/// How long a session lasts before the wallet has to sign in again.
+pub const SESSION_TTL: Duration = Duration::days(7);
 
-fn create_user_public(user: &User) -> UserPublic {
+pub(crate) fn create_user_public(user: &User) -> UserPublic {
     let user_type = match user.user_type.as_str() {
         "Ambassador" => UserType::Ambassador,
         "ChapterLead" => UserType::ChapterLead,
@@ -23,7 +38,8 @@ fn create_user_public(user: &User) -> UserPublic {
         user_type,
         organization: user.organization.clone(),
         bio: user.bio.clone(),
-        created_at: user.created_at.map_or("Unknown".to_string(), |dt| dt.to_string()),
+        created_at: user.created_at,
+        email_verified: user.email_verified_at.is_some(),
     }
 }
 
@@ -36,14 +52,132 @@ fn create_error_user_public() -> UserPublic {
         user_type: UserType::Ambassador,
         organization: None,
         bio: None,
-        created_at: "".to_string(),
+        created_at: None,
+        email_verified: false,
     }
 }
 
-pub async fn signup(
+fn signup_failure(status: StatusCode, message: impl Into<String>) -> (StatusCode, Json<SignUpResponse>) {
+    (status, Json(SignUpResponse { user: create_error_user_public(), message: message.into() }))
+}
+
+/// Checks that a signup for `wallet_address` proves control of it: `challenge` was issued
+/// to that wallet, is unused and unexpired at `now`, and the wallet signed its message.
+pub fn check_signup_challenge(
+    challenge: &SignupChallenge,
+    wallet_address: &str,
+    signature: &str,
+    now: DateTime<Utc>,
+) -> Result<(), (StatusCode, String)> {
+    check_challenge(challenge, wallet_address, signature, "signup", signup_message, now)
+}
+
+/// The same check for a sign-in, where the wallet signed `signin_message` instead.
+pub fn check_signin_challenge(
+    challenge: &SignupChallenge,
+    wallet_address: &str,
+    signature: &str,
+    now: DateTime<Utc>,
+) -> Result<(), (StatusCode, String)> {
+    check_challenge(challenge, wallet_address, signature, "sign-in", signin_message, now)
+}
+
+fn check_challenge(
+    challenge: &SignupChallenge,
+    wallet_address: &str,
+    signature: &str,
+    purpose: &str,
+    message: fn(&str, &str) -> String,
+    now: DateTime<Utc>,
+) -> Result<(), (StatusCode, String)> {
+    let wallet_address = wallet_address.trim();
+    if challenge.wallet_address != wallet_address {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("The {} nonce was issued to {}, not {}", purpose, challenge.wallet_address, wallet_address),
+        ));
+    }
+    if challenge.used_at.is_some() {
+        return Err((StatusCode::GONE, format!("The {} nonce was already used; request a new one", purpose)));
+    }
+    if challenge.expires_at <= now {
+        return Err((
+            StatusCode::GONE,
+            format!("The {} nonce expired at {}; request a new one", purpose, challenge.expires_at.format("%H:%M:%S UTC")),
+        ));
+    }
+
+    let message = message(&challenge.wallet_address, &challenge.nonce.to_string());
+    verify_signed_message(wallet_address, &message, signature).map_err(|e| match e {
+        SignatureError::Mismatch(_) => (StatusCode::UNAUTHORIZED, e.to_string()),
+        _ => (StatusCode::BAD_REQUEST, e.to_string()),
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "/signup/challenge",
+    tag = "auth",
+    request_body = SignUpChallengeRequest,
+    responses(
+        (status = 201, description = "Nonce issued; sign its message with the wallet and send both with the signup", body = SignUpChallengeResponse),
+        (status = 400, description = "Not a Stellar account id", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn signup_challenge(
     State(pool): State<DbPool>,
+    Json(req): Json<SignUpChallengeRequest>,
+) -> ApiResult<SignUpChallengeResponse> {
+    issue_challenge(&pool, &req, signup_message).await
+}
+
+/// Issues a nonce to the wallet, with the `message` about it that the wallet signs.
+async fn issue_challenge(
+    pool: &DbPool,
+    req: &SignUpChallengeRequest,
+    message: fn(&str, &str) -> String,
+) -> ApiResult<SignUpChallengeResponse> {
+    let wallet_address = req.wallet_address.trim();
+    if wallet_address.parse::<stellar_xdr::curr::AccountId>().is_err() {
+        return Err(field_error("wallet_address", "Must be a Stellar account id (G...)"));
+    }
+
+    let challenge = SignupChallengeRepository::create(pool, wallet_address, Utc::now() + SIGNUP_CHALLENGE_TTL)
+        .await
+        .map_err(|e| database_error("issuing nonce", e))?;
+    let nonce = challenge.nonce.to_string();
+
+    Ok((
+        StatusCode::CREATED,
+        Json(SignUpChallengeResponse {
+            message: message(&challenge.wallet_address, &nonce),
+            wallet_address: challenge.wallet_address,
+            nonce,
+            expires_at: challenge.expires_at,
+        }),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/signup",
+    tag = "auth",
+    request_body = SignUpRequest,
+    responses(
+        (status = 201, description = "User created with an unverified email address, to which a verification link is mailed", body = SignUpResponse),
+        (status = 400, description = "Unknown nonce, nonce issued to another wallet, or malformed signature", body = SignUpResponse),
+        (status = 401, description = "The signature was not made by the wallet address", body = SignUpResponse),
+        (status = 409, description = "Email or wallet address already registered", body = SignUpResponse),
+        (status = 410, description = "The nonce expired or was already used", body = SignUpResponse),
+        (status = 500, description = "Database error", body = SignUpResponse),
+    )
+)]
+pub async fn signup(
+    State(state): State<AppState>,
     Json(req): Json<SignUpRequest>,
 ) -> (StatusCode, Json<SignUpResponse>) {
+    let pool = &state.pool;
     println!("🚀 NEW SIGNUP REQUEST");
     println!("   Username: {}", req.username);
     println!("   Email: {}", req.email);
@@ -56,8 +190,25 @@ pub async fn signup(
     info!("Received signup request: username={}, email={}, wallet_address={}, user_type={:?}",
           req.username, req.email, req.wallet_address, req.user_type);
 
+    // The wallet must have signed the nonce issued to it, proving the user controls it
+    let Ok(nonce) = Uuid::parse_str(req.nonce.trim()) else {
+        return signup_failure(StatusCode::BAD_REQUEST, "Unknown signup nonce; request a new one");
+    };
+    let challenge = match SignupChallengeRepository::find(pool, nonce).await {
+        Ok(Some(challenge)) => challenge,
+        Ok(None) => return signup_failure(StatusCode::BAD_REQUEST, "Unknown signup nonce; request a new one"),
+        Err(e) => {
+            error!("Database error finding signup nonce: {:?}", e);
+            return signup_failure(StatusCode::INTERNAL_SERVER_ERROR, format!("Database error: {}", e));
+        }
+    };
+    if let Err((status, message)) = check_signup_challenge(&challenge, &req.wallet_address, &req.signature, Utc::now()) {
+        println!("❌ SIGNUP FAILED: {}", message);
+        return signup_failure(status, message);
+    }
+
     // Check if user already exists by email or wallet address
-    match UserRepository::find_by_email(&pool, &req.email).await {
+    match UserRepository::find_by_email(pool, &req.email).await {
         Ok(Some(_)) => {
             println!("❌ SIGNUP FAILED: Email already exists ({})", req.email);
             let resp = SignUpResponse {
@@ -80,7 +231,7 @@ pub async fn signup(
         }
     }
 
-    match UserRepository::find_by_wallet_address(&pool, &req.wallet_address).await {
+    match UserRepository::find_by_wallet_address(pool, &req.wallet_address).await {
         Ok(Some(_)) => {
             println!("❌ SIGNUP FAILED: Wallet address already exists ({})", req.wallet_address);
             let resp = SignUpResponse {
@@ -90,9 +241,21 @@ pub async fn signup(
             (StatusCode::CONFLICT, Json(resp))
         }
         Ok(None) => {
+            // Only used up now, so a signup refused for a taken email can be corrected and sent again
+            match SignupChallengeRepository::mark_used(pool, nonce).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    return signup_failure(StatusCode::GONE, "The signup nonce was already used or has expired; request a new one");
+                }
+                Err(e) => {
+                    error!("Database error using signup nonce: {:?}", e);
+                    return signup_failure(StatusCode::INTERNAL_SERVER_ERROR, format!("Database error: {}", e));
+                }
+            }
+
             let user_type_str = req.user_type.to_string();
             match UserRepository::create_user(
-                &pool,
+                pool,
                 &req.username,
                 &req.email,
                 &req.wallet_address,
@@ -109,11 +272,15 @@ pub async fn signup(
                     println!("   🎉 Welcome to Stellar Europe!");
                     println!("   ════════════════════════════════════");
 
-                    let user_public = create_user_public(&db_user);
+                    // The account is created either way; a new link can be asked for later
+                    let message = match send_verification_email(&state, &db_user).await {
+                        Ok(_) => format!("User created! Check {} for a link to verify your email address.", db_user.email),
+                        Err(_) => "User created, but the verification email could not be sent. Ask for a new link from your profile.".to_string(),
+                    };
 
                     let resp = SignUpResponse {
-                        user: user_public,
-                        message: "User created successfully!".to_string(),
+                        user: create_user_public(&db_user),
+                        message,
                     };
                     (StatusCode::CREATED, Json(resp))
                 }
@@ -138,4 +305,107 @@ pub async fn signup(
             (StatusCode::INTERNAL_SERVER_ERROR, Json(resp))
         }
     }
+}
+
+#[utoipa::path(
+    post,
+    path = "/sessions/challenge",
+    tag = "auth",
+    request_body = SignUpChallengeRequest,
+    responses(
+        (status = 201, description = "Nonce issued; sign its message with the wallet and send both to start a session", body = SignUpChallengeResponse),
+        (status = 400, description = "Not a Stellar account id", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn signin_challenge(
+    State(pool): State<DbPool>,
+    Json(req): Json<SignUpChallengeRequest>,
+) -> ApiResult<SignUpChallengeResponse> {
+    issue_challenge(&pool, &req, signin_message).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/sessions",
+    tag = "auth",
+    request_body = SignInRequest,
+    responses(
+        (status = 201, description = "Session started; send its token as a bearer token", body = SessionResponse),
+        (status = 400, description = "Unknown nonce, nonce issued to another wallet, or malformed signature", body = ErrorResponse),
+        (status = 401, description = "The signature was not made by the wallet address", body = ErrorResponse),
+        (status = 404, description = "No registered user has this wallet address", body = ErrorResponse),
+        (status = 410, description = "The nonce expired or was already used", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn create_session(
+    State(pool): State<DbPool>,
+    Json(req): Json<SignInRequest>,
+) -> ApiResult<SessionResponse> {
+    info!("Sign-in requested for {}", req.wallet_address);
+
+    let unknown_nonce = || field_error("nonce", "Unknown sign-in nonce; request a new one");
+    let nonce = Uuid::parse_str(req.nonce.trim()).map_err(|_| unknown_nonce())?;
+    let challenge = SignupChallengeRepository::find(&pool, nonce)
+        .await
+        .map_err(|e| database_error("finding nonce", e))?
+        .ok_or_else(unknown_nonce)?;
+    check_signin_challenge(&challenge, &req.wallet_address, &req.signature, Utc::now())
+        .map_err(|(status, message)| api_error(status, message))?;
+
+    let user = UserRepository::find_by_wallet_address(&pool, &challenge.wallet_address)
+        .await
+        .map_err(|e| database_error("loading user", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "No registered user has this wallet address; sign up first"))?;
+    let used = SignupChallengeRepository::mark_used(&pool, nonce)
+        .await
+        .map_err(|e| database_error("using nonce", e))?;
+    if !used {
+        return Err(api_error(StatusCode::GONE, "The sign-in nonce was already used or has expired; request a new one"));
+    }
+
+    let expires_at = Utc::now() + SESSION_TTL;
+    let token = SessionRepository::create(&pool, user.id, expires_at)
+        .await
+        .map_err(|e| database_error("starting session", e))?;
+
+    println!("🔑 SIGNED IN: {} ({})", user.username, user.wallet_address);
+    Ok((
+        StatusCode::CREATED,
+        Json(SessionResponse { token: token.to_string(), expires_at, user: create_user_public(&user) }),
+    ))
+}
+
+/// The user signed in with the request's `Authorization: Bearer` session token, if it has
+/// one. A malformed, unknown or expired token is refused rather than treated as no token.
+pub async fn session_user(pool: &DbPool, headers: &HeaderMap) -> Result<Option<User>, ApiError> {
+    let Some(value) = headers.get(header::AUTHORIZATION) else {
+        return Ok(None);
+    };
+    let invalid = || api_error(StatusCode::UNAUTHORIZED, "The session has expired or is not valid; sign in again");
+
+    let token = value
+        .to_str()
+        .ok()
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .and_then(|token| Uuid::parse_str(token.trim()).ok())
+        .ok_or_else(invalid)?;
+    let user_id = SessionRepository::find_user_id(pool, token)
+        .await
+        .map_err(|e| database_error("loading session", e))?
+        .ok_or_else(invalid)?;
+    let user = UserRepository::find_by_id(pool, user_id)
+        .await
+        .map_err(|e| database_error("loading user", e))?
+        .ok_or_else(invalid)?;
+
+    Ok(Some(user))
+}
+
+/// Like `session_user`, for requests that only a signed-in user may make.
+pub async fn signed_in_user(pool: &DbPool, headers: &HeaderMap) -> Result<User, ApiError> {
+    session_user(pool, headers)
+        .await?
+        .ok_or_else(|| api_error(StatusCode::UNAUTHORIZED, "Sign in with your wallet first"))
 }
\ No newline at end of file
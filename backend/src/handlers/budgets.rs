@@ -0,0 +1,431 @@
+use crate::database::connection::DbPool;
+use crate::database::models::{BudgetItem, Event, Reimbursement, User};
+use crate::database::repositories::{BudgetRepository, EventRepository, ReimbursementRepository};
+use crate::handlers::auth::signed_in_user;
+use crate::handlers::chapters::database_error;
+use crate::handlers::error::{api_error, field_error, ApiError, ApiResult};
+use crate::handlers::events::event_status;
+use crate::handlers::reimbursements::reimbursement_status;
+use axum::{
+    extract::{Json, Path, State},
+    http::{HeaderMap, StatusCode},
+};
+use rust_decimal::Decimal;
+use shared::dto::{
+    BudgetAmountRequest, BudgetCategory, BudgetItemRequest, BudgetItemResponse, BudgetRollup, Currency, ErrorResponse,
+    EventBudgetResponse, EventStatus, Money, ReimbursementStatus,
+};
+use std::collections::BTreeMap;
+use tracing::{info, warn};
+
+/// The item's currency. One this build does not know is an error rather than read as
+/// another currency, so no amount is ever reported, checked or paid in the wrong one.
+pub(crate) fn item_currency(item: &BudgetItem) -> Result<Currency, ApiError> {
+    Currency::from_name(&item.currency).ok_or_else(|| {
+        api_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Budget item {} has an unknown currency: {}", item.id, item.currency),
+        )
+    })
+}
+
+/// The item as listed, or `None`, with a warning, if its currency is unknown.
+fn budget_item_response(item: &BudgetItem, reimbursements: &[Reimbursement]) -> Option<BudgetItemResponse> {
+    let Ok(currency) = item_currency(item) else {
+        warn!("Leaving budget item {} out of the budget: unknown currency {}", item.id, item.currency);
+        return None;
+    };
+    let reimbursement = reimbursements.iter().find(|reimbursement| reimbursement.budget_item_id == item.id);
+    Some(BudgetItemResponse {
+        id: item.id.to_string(),
+        event_id: item.event_id.to_string(),
+        category: BudgetCategory::from_name(&item.category).unwrap_or(BudgetCategory::Other),
+        description: item.description.clone(),
+        requested: Money::new(item.requested_amount, currency),
+        approved: item.approved_amount.map(|amount| Money::new(amount, currency)),
+        spent: item.spent_amount.map(|amount| Money::new(amount, currency)),
+        reimbursement_status: reimbursement.map(reimbursement_status),
+        updated_at: item.updated_at,
+    })
+}
+
+/// Totals per currency of `items`, which belong to `events`. Cost per attendee and per SCF
+/// referral divide what was spent by what the spending events recorded achieving. Items in
+/// an unknown currency are left out with a warning.
+pub fn budget_rollups(items: &[BudgetItem], events: &[Event]) -> Vec<BudgetRollup> {
+    #[derive(Default)]
+    struct Totals {
+        requested: Decimal,
+        approved: Decimal,
+        spent: Decimal,
+        spending_events: Vec<i32>,
+    }
+
+    let mut totals: BTreeMap<Currency, Totals> = BTreeMap::new();
+    for item in items {
+        let Ok(currency) = item_currency(item) else {
+            warn!("Leaving budget item {} out of the totals: unknown currency {}", item.id, item.currency);
+            continue;
+        };
+        let currency_totals = totals.entry(currency).or_default();
+        currency_totals.requested += item.requested_amount;
+        currency_totals.approved += item.approved_amount.unwrap_or_default();
+        if let Some(spent) = item.spent_amount {
+            currency_totals.spent += spent;
+            if !currency_totals.spending_events.contains(&item.event_id) {
+                currency_totals.spending_events.push(item.event_id);
+            }
+        }
+    }
+
+    totals
+        .into_iter()
+        .map(|(currency, totals)| {
+            let spending_events = events.iter().filter(|event| totals.spending_events.contains(&event.id));
+            let (attendance, scf_referrals) = spending_events.fold((0u32, 0u32), |(attendance, referrals), event| {
+                (
+                    attendance + event.actual_attendance.unwrap_or(0).max(0) as u32,
+                    referrals + event.actual_scf_referrals.unwrap_or(0).max(0) as u32,
+                )
+            });
+            let cost_per = |count: u32| (count > 0).then(|| Money::new(totals.spent / Decimal::from(count), currency));
+
+            BudgetRollup {
+                currency,
+                requested: Money::new(totals.requested, currency),
+                approved: Money::new(totals.approved, currency),
+                spent: Money::new(totals.spent, currency),
+                attendance,
+                scf_referrals,
+                cost_per_attendee: cost_per(attendance),
+                cost_per_scf_referral: cost_per(scf_referrals),
+            }
+        })
+        .collect()
+}
+
+pub(crate) async fn find_event(pool: &DbPool, event_id: i32) -> Result<Event, ApiError> {
+    EventRepository::find_by_id(pool, event_id)
+        .await
+        .map_err(|e| database_error("loading event", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Event not found"))
+}
+
+pub(crate) async fn find_item(pool: &DbPool, event_id: i32, item_id: i32) -> Result<BudgetItem, ApiError> {
+    BudgetRepository::find(pool, event_id, item_id)
+        .await
+        .map_err(|e| database_error("loading budget item", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Budget item not found"))
+}
+
+async fn find_organizer(pool: &DbPool, headers: &HeaderMap, event: &Event) -> Result<User, ApiError> {
+    let actor = signed_in_user(pool, headers).await?;
+    if actor.id != event.organizer_id {
+        return Err(api_error(StatusCode::FORBIDDEN, "Only the organizer can change this event's budget"));
+    }
+    Ok(actor)
+}
+
+/// Requests are only edited while the event is a draft, so reviewers see what they approve.
+fn ensure_draft(event: &Event) -> Result<(), ApiError> {
+    match event_status(event) {
+        EventStatus::Draft => Ok(()),
+        status => Err(api_error(
+            StatusCode::CONFLICT,
+            format!("The budget request of an event that is {} can no longer be changed", status),
+        )),
+    }
+}
+
+/// Amounts are frozen once their payout is on its way. A payout still waiting for its
+/// signature is dropped, since it was built from the old amounts.
+async fn release_reimbursement(pool: &DbPool, item: &BudgetItem) -> Result<(), ApiError> {
+    let reimbursement = ReimbursementRepository::find_for_item(pool, item.id)
+        .await
+        .map_err(|e| database_error("loading reimbursement", e))?;
+    if let Some(reimbursement) = reimbursement {
+        let status = reimbursement_status(&reimbursement);
+        if matches!(status, ReimbursementStatus::Submitted | ReimbursementStatus::Paid) {
+            return Err(api_error(
+                StatusCode::CONFLICT,
+                format!("The item's reimbursement is {} and its amounts can no longer change", status),
+            ));
+        }
+        ReimbursementRepository::discard_unsubmitted(pool, item.id)
+            .await
+            .map_err(|e| database_error("discarding reimbursement", e))?;
+    }
+    Ok(())
+}
+
+fn validate_amount(field: &str, currency: Currency, amount: Decimal) -> Result<(), ApiError> {
+    currency.check_amount(amount).map_err(|message| field_error(field, message))
+}
+
+async fn event_budget_response(pool: &DbPool, event: &Event) -> Result<EventBudgetResponse, ApiError> {
+    let items = BudgetRepository::list_for_event(pool, event.id)
+        .await
+        .map_err(|e| database_error("listing budget items", e))?;
+    let item_ids: Vec<i32> = items.iter().map(|item| item.id).collect();
+    let reimbursements = ReimbursementRepository::list_for_items(pool, &item_ids)
+        .await
+        .map_err(|e| database_error("listing reimbursements", e))?;
+
+    Ok(EventBudgetResponse {
+        event_id: event.id.to_string(),
+        event_title: event.title.clone(),
+        event_status: event_status(event),
+        items: items.iter().filter_map(|item| budget_item_response(item, &reimbursements)).collect(),
+        totals: budget_rollups(&items, std::slice::from_ref(event)),
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}/budget",
+    tag = "budgets",
+    params(("event_id" = i32, Path, description = "Event id")),
+    responses(
+        (status = 200, description = "The event's budget line items and their totals per currency", body = EventBudgetResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn get_event_budget(
+    State(pool): State<DbPool>,
+    Path(event_id): Path<i32>,
+) -> ApiResult<EventBudgetResponse> {
+    let event = find_event(&pool, event_id).await?;
+    Ok((StatusCode::OK, Json(event_budget_response(&pool, &event).await?)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/budget",
+    tag = "budgets",
+    params(("event_id" = i32, Path, description = "Event id")),
+    request_body = BudgetItemRequest,
+    responses(
+        (status = 201, description = "Line item added", body = EventBudgetResponse),
+        (status = 400, description = "Negative amount or too many decimals for the currency", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the organizer changes the budget", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 409, description = "The event is no longer a draft", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn add_budget_item(
+    State(pool): State<DbPool>,
+    Path(event_id): Path<i32>,
+    headers: HeaderMap,
+    Json(req): Json<BudgetItemRequest>,
+) -> ApiResult<EventBudgetResponse> {
+    println!("💰 BUDGET ITEM for event {}: {} {} {}", event_id, req.category, req.requested_amount, req.currency);
+    info!("Adding budget item to event {}", event_id);
+
+    let event = find_event(&pool, event_id).await?;
+    find_organizer(&pool, &headers, &event).await?;
+    ensure_draft(&event)?;
+    validate_amount("requested_amount", req.currency, req.requested_amount)?;
+
+    BudgetRepository::create(
+        &pool,
+        event.id,
+        &req.category.to_string(),
+        req.description.trim(),
+        &req.currency.to_string(),
+        req.requested_amount,
+    )
+    .await
+    .map_err(|e| database_error("adding budget item", e))?;
+
+    Ok((StatusCode::CREATED, Json(event_budget_response(&pool, &event).await?)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/events/{event_id}/budget/{item_id}",
+    tag = "budgets",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("item_id" = i32, Path, description = "Budget item id"),
+    ),
+    request_body = BudgetItemRequest,
+    responses(
+        (status = 200, description = "Line item changed; any approved amount is cleared", body = EventBudgetResponse),
+        (status = 400, description = "Negative amount or too many decimals for the currency", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the organizer changes the budget", body = ErrorResponse),
+        (status = 404, description = "Event or item not found", body = ErrorResponse),
+        (status = 409, description = "The event is no longer a draft", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn update_budget_item(
+    State(pool): State<DbPool>,
+    Path((event_id, item_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+    Json(req): Json<BudgetItemRequest>,
+) -> ApiResult<EventBudgetResponse> {
+    let event = find_event(&pool, event_id).await?;
+    find_item(&pool, event_id, item_id).await?;
+    find_organizer(&pool, &headers, &event).await?;
+    ensure_draft(&event)?;
+    validate_amount("requested_amount", req.currency, req.requested_amount)?;
+
+    BudgetRepository::update_request(
+        &pool,
+        item_id,
+        &req.category.to_string(),
+        req.description.trim(),
+        &req.currency.to_string(),
+        req.requested_amount,
+    )
+    .await
+    .map_err(|e| database_error("updating budget item", e))?;
+
+    Ok((StatusCode::OK, Json(event_budget_response(&pool, &event).await?)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/events/{event_id}/budget/{item_id}",
+    tag = "budgets",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("item_id" = i32, Path, description = "Budget item id"),
+    ),
+    responses(
+        (status = 200, description = "Line item removed", body = EventBudgetResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the organizer changes the budget", body = ErrorResponse),
+        (status = 404, description = "Event or item not found", body = ErrorResponse),
+        (status = 409, description = "The event is no longer a draft", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn delete_budget_item(
+    State(pool): State<DbPool>,
+    Path((event_id, item_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> ApiResult<EventBudgetResponse> {
+    let event = find_event(&pool, event_id).await?;
+    find_item(&pool, event_id, item_id).await?;
+    find_organizer(&pool, &headers, &event).await?;
+    ensure_draft(&event)?;
+
+    BudgetRepository::delete(&pool, item_id)
+        .await
+        .map_err(|e| database_error("deleting budget item", e))?;
+
+    Ok((StatusCode::OK, Json(event_budget_response(&pool, &event).await?)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/events/{event_id}/budget/{item_id}/approved",
+    tag = "budgets",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("item_id" = i32, Path, description = "Budget item id"),
+    ),
+    request_body = BudgetAmountRequest,
+    responses(
+        (status = 200, description = "Approved amount set", body = EventBudgetResponse),
+        (status = 400, description = "Amount negative, above the request or with too many decimals", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only a Chapter Lead other than the organizer approves", body = ErrorResponse),
+        (status = 404, description = "Event or item not found", body = ErrorResponse),
+        (status = 409, description = "The event is not under review or approved, or the item's reimbursement was submitted", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn approve_budget_item(
+    State(pool): State<DbPool>,
+    Path((event_id, item_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+    Json(req): Json<BudgetAmountRequest>,
+) -> ApiResult<EventBudgetResponse> {
+    let actor = signed_in_user(&pool, &headers).await?;
+    println!("💰 BUDGET APPROVAL for event {} item {}: {:?} by {}", event_id, item_id, req.amount, actor.wallet_address);
+
+    let event = find_event(&pool, event_id).await?;
+    let item = find_item(&pool, event_id, item_id).await?;
+    if actor.user_type != "ChapterLead" || actor.id == event.organizer_id {
+        return Err(api_error(StatusCode::FORBIDDEN, "Budgets are approved by a Chapter Lead other than the organizer"));
+    }
+
+    let status = event_status(&event);
+    if !matches!(status, EventStatus::Submitted | EventStatus::Approved | EventStatus::Published) {
+        return Err(api_error(StatusCode::CONFLICT, format!("The budget of an event that is {} cannot be approved", status)));
+    }
+
+    if let Some(amount) = req.amount {
+        validate_amount("amount", item_currency(&item)?, amount)?;
+        if amount > item.requested_amount {
+            return Err(field_error("amount", "The approved amount cannot exceed the requested amount"));
+        }
+    }
+
+    release_reimbursement(&pool, &item).await?;
+    BudgetRepository::set_approved(&pool, item.id, req.amount)
+        .await
+        .map_err(|e| database_error("approving budget item", e))?;
+
+    Ok((StatusCode::OK, Json(event_budget_response(&pool, &event).await?)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/events/{event_id}/budget/{item_id}/spent",
+    tag = "budgets",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("item_id" = i32, Path, description = "Budget item id"),
+    ),
+    request_body = BudgetAmountRequest,
+    responses(
+        (status = 200, description = "Spent amount recorded", body = EventBudgetResponse),
+        (status = 400, description = "Negative amount or too many decimals for the currency", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the organizer reports spending", body = ErrorResponse),
+        (status = 404, description = "Event or item not found", body = ErrorResponse),
+        (status = 409, description = "The event has not been approved, or the item's reimbursement was submitted", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn record_budget_spending(
+    State(pool): State<DbPool>,
+    Path((event_id, item_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+    Json(req): Json<BudgetAmountRequest>,
+) -> ApiResult<EventBudgetResponse> {
+    println!("💸 BUDGET SPENDING for event {} item {}: {:?}", event_id, item_id, req.amount);
+
+    let event = find_event(&pool, event_id).await?;
+    let item = find_item(&pool, event_id, item_id).await?;
+    find_organizer(&pool, &headers, &event).await?;
+
+    // Events cancelled after approval may still have had costs, e.g. a venue deposit
+    let status = event_status(&event);
+    if event.approved_at.is_none() || !matches!(status, EventStatus::Approved | EventStatus::Published | EventStatus::Completed | EventStatus::Cancelled) {
+        return Err(api_error(StatusCode::CONFLICT, format!("Spending cannot be recorded for an event that is {}", status)));
+    }
+
+    if let Some(amount) = req.amount {
+        validate_amount("amount", item_currency(&item)?, amount)?;
+    }
+
+    release_reimbursement(&pool, &item).await?;
+    BudgetRepository::set_spent(&pool, item.id, req.amount)
+        .await
+        .map_err(|e| database_error("recording budget spending", e))?;
+
+    Ok((StatusCode::OK, Json(event_budget_response(&pool, &event).await?)))
+}
\ No newline at end of file
@@ -0,0 +1,129 @@
+use crate::database::connection::DbPool;
+use crate::database::repositories::{ChapterRepository, EventRepository};
+use crate::handlers::events::is_publicly_listed;
+use crate::ical::render_calendar;
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use shared::dto::{EventType, StrategicFocusArea};
+use tracing::error;
+use utoipa::IntoParams;
+
+const CALENDAR_CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+#[derive(Deserialize, IntoParams)]
+pub struct CalendarFeedQuery {
+    /// Only include events organised for this chapter
+    pub chapter_id: Option<i32>,
+    /// Only include events of this type
+    #[serde(rename = "type")]
+    pub event_type: Option<EventType>,
+    /// Only include events targeting this strategic focus area
+    pub focus_area: Option<StrategicFocusArea>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}/calendar.ics",
+    tag = "calendar",
+    params(("event_id" = i32, Path, description = "Event ID")),
+    responses(
+        (status = 200, description = "iCalendar file for the event", content_type = "text/calendar", body = String),
+        (status = 404, description = "Unknown event, or one that is not publicly listed yet", body = String),
+        (status = 500, description = "Database error", body = String),
+    )
+)]
+pub async fn event_calendar(
+    State(pool): State<DbPool>,
+    Path(event_id): Path<i32>,
+) -> Response {
+    match EventRepository::find_by_id(&pool, event_id).await {
+        Ok(Some(event)) if is_publicly_listed(&event) => {
+            let body = render_calendar(&event.title, std::slice::from_ref(&event));
+            let disposition = format!("attachment; filename=\"stellar-europe-event-{}.ics\"", event.id);
+            (
+                [
+                    (header::CONTENT_TYPE, CALENDAR_CONTENT_TYPE.to_string()),
+                    (header::CONTENT_DISPOSITION, disposition),
+                ],
+                body,
+            )
+                .into_response()
+        }
+        Ok(_) => (StatusCode::NOT_FOUND, "Event not found".to_string()).into_response(),
+        Err(e) => {
+            error!("Database error loading event {} for calendar: {:?}", event_id, e);
+            (StatusCode::INTERNAL_SERVER_ERROR, "Failed to load event".to_string()).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/{event_file}",
+    tag = "calendar",
+    params(("event_file" = String, Path, description = "Event ID followed by `.ics`, e.g. `42.ics`")),
+    responses(
+        (status = 200, description = "iCalendar file for the event, as at `/events/{event_id}/calendar.ics`", content_type = "text/calendar", body = String),
+        (status = 404, description = "Not an `.ics` file name, an unknown event, or one that is not publicly listed yet", body = String),
+        (status = 500, description = "Database error", body = String),
+    )
+)]
+pub async fn event_calendar_file(
+    State(pool): State<DbPool>,
+    Path(event_file): Path<String>,
+) -> Response {
+    match event_file.strip_suffix(".ics").and_then(|id| id.parse::<i32>().ok()) {
+        Some(event_id) => event_calendar(State(pool), Path(event_id)).await,
+        None => (StatusCode::NOT_FOUND, "Not found".to_string()).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/calendar.ics",
+    tag = "calendar",
+    params(CalendarFeedQuery),
+    responses(
+        (status = 200, description = "Subscribable iCalendar feed of recent and upcoming events", content_type = "text/calendar", body = String),
+        (status = 404, description = "Unknown chapter", body = String),
+        (status = 500, description = "Database error", body = String),
+    )
+)]
+pub async fn calendar_feed(
+    State(pool): State<DbPool>,
+    Query(params): Query<CalendarFeedQuery>,
+) -> Response {
+    let event_type = params.event_type.as_ref().map(|t| t.to_string());
+    let focus_area = params.focus_area.as_ref().map(|area| area.to_string());
+
+    let chapter_name = match params.chapter_id {
+        Some(chapter_id) => match ChapterRepository::find_by_id(&pool, chapter_id).await {
+            Ok(Some(chapter)) => Some(chapter.name),
+            Ok(None) => return (StatusCode::NOT_FOUND, "Chapter not found".to_string()).into_response(),
+            Err(e) => {
+                error!("Database error loading chapter {} for calendar: {:?}", chapter_id, e);
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to load chapter".to_string()).into_response();
+            }
+        },
+        None => None,
+    };
+
+    match EventRepository::list_for_calendar(&pool, params.chapter_id, event_type.as_deref(), focus_area.as_deref()).await {
+        Ok(events) => {
+            let mut name = "Stellar Europe Events".to_string();
+            for filter in [&chapter_name, &event_type, &focus_area].into_iter().flatten() {
+                name.push_str(&format!(" · {}", filter));
+            }
+
+            ([(header::CONTENT_TYPE, CALENDAR_CONTENT_TYPE)], render_calendar(&name, &events)).into_response()
+        }
+        Err(e) => {
+            error!("Database error building calendar feed: {:?}", e);
+            (StatusCode::INTERNAL_SERVER_ERROR, "Failed to load events".to_string()).into_response()
+        }
+    }
+}
\ No newline at end of file
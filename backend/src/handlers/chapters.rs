@@ -0,0 +1,395 @@
+use crate::database::connection::DbPool;
+use crate::database::models::{Chapter, User};
+use crate::database::repositories::{ChapterRepository, UserRepository};
+use crate::handlers::auth::{create_user_public, signed_in_user};
+use crate::handlers::error::{api_error, field_error, ApiError, ApiResult};
+use crate::state::AppState;
+use axum::{
+    extract::{Json, Path, State},
+    http::{HeaderMap, StatusCode},
+};
+use shared::dto::{
+    ChapterListResponse, ChapterMembersResponse, ChapterRequest, ChapterResponse, ErrorResponse, MembershipRequest,
+};
+use tracing::{error, info};
+
+pub(crate) fn database_error(context: &str, e: sqlx::Error) -> ApiError {
+    error!("Database error {}: {:?}", context, e);
+    api_error(StatusCode::INTERNAL_SERVER_ERROR, format!("Database error {}", context))
+}
+
+pub(crate) async fn chapter_response(pool: &DbPool, chapter: &Chapter) -> Result<ChapterResponse, ApiError> {
+    let lead = match chapter.lead_id {
+        Some(lead_id) => UserRepository::find_by_id(pool, lead_id)
+            .await
+            .map_err(|e| database_error("loading chapter lead", e))?
+            .map(|user| create_user_public(&user)),
+        None => None,
+    };
+
+    let member_count = ChapterRepository::count_members(pool, chapter.id)
+        .await
+        .map_err(|e| database_error("counting chapter members", e))?;
+
+    Ok(ChapterResponse {
+        id: chapter.id.to_string(),
+        name: chapter.name.clone(),
+        country: chapter.country.clone(),
+        city: chapter.city.clone(),
+        lead,
+        member_count: member_count as u32,
+        created_at: chapter.created_at,
+    })
+}
+
+pub(crate) async fn find_chapter(pool: &DbPool, chapter_id: i32) -> Result<Chapter, ApiError> {
+    ChapterRepository::find_by_id(pool, chapter_id)
+        .await
+        .map_err(|e| database_error("loading chapter", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Chapter not found"))
+}
+
+/// A chapter is managed by its lead and the programme admins.
+pub(crate) async fn find_chapter_manager(state: &AppState, headers: &HeaderMap, chapter: &Chapter) -> Result<User, ApiError> {
+    let actor = signed_in_user(&state.pool, headers).await?;
+    if chapter.lead_id != Some(actor.id) && !state.stellar.is_admin(&actor.wallet_address) {
+        return Err(api_error(StatusCode::FORBIDDEN, "Only the chapter lead or a programme admin manages this chapter"));
+    }
+    Ok(actor)
+}
+
+/// Validates the request and resolves the lead's wallet address to a user id.
+/// `existing` is the chapter being updated, so it may keep its own name.
+async fn validate_chapter(
+    pool: &DbPool,
+    req: &ChapterRequest,
+    existing: Option<i32>,
+) -> Result<Option<i32>, ApiError> {
+    for (field, value) in [("name", &req.name), ("country", &req.country), ("city", &req.city)] {
+        if value.trim().is_empty() {
+            return Err(field_error(field, format!("Chapter {} is required", field)));
+        }
+    }
+
+    let same_name = ChapterRepository::find_by_name(pool, req.name.trim())
+        .await
+        .map_err(|e| database_error("checking chapter name", e))?;
+    if same_name.is_some_and(|chapter| Some(chapter.id) != existing) {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new("A chapter with this name already exists")
+                .with_field_error("name", "A chapter with this name already exists")),
+        ));
+    }
+
+    let Some(wallet_address) = req.lead_wallet_address.as_deref().map(str::trim).filter(|w| !w.is_empty()) else {
+        return Ok(None);
+    };
+
+    let lead = UserRepository::find_by_wallet_address(pool, wallet_address)
+        .await
+        .map_err(|e| database_error("loading chapter lead", e))?
+        .ok_or_else(|| field_error("lead_wallet_address", "No registered user has this wallet address"))?;
+
+    if lead.user_type != "ChapterLead" {
+        return Err(field_error("lead_wallet_address", "The lead must be registered as a Chapter Lead"));
+    }
+
+    Ok(Some(lead.id))
+}
+
+#[utoipa::path(
+    get,
+    path = "/chapters",
+    tag = "chapters",
+    responses(
+        (status = 200, description = "All chapters, by country and city", body = ChapterListResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn list_chapters(State(pool): State<DbPool>) -> ApiResult<ChapterListResponse> {
+    let chapters = ChapterRepository::list_chapters(&pool)
+        .await
+        .map_err(|e| database_error("listing chapters", e))?;
+
+    let mut responses = Vec::new();
+    for chapter in &chapters {
+        responses.push(chapter_response(&pool, chapter).await?);
+    }
+
+    Ok((StatusCode::OK, Json(ChapterListResponse { total: responses.len(), chapters: responses })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/chapters",
+    tag = "chapters",
+    request_body = ChapterRequest,
+    responses(
+        (status = 201, description = "Chapter created", body = ChapterResponse),
+        (status = 400, description = "Missing field or lead is not a registered Chapter Lead", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only a programme admin creates chapters", body = ErrorResponse),
+        (status = 409, description = "Chapter name already taken", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn create_chapter(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<ChapterRequest>,
+) -> ApiResult<ChapterResponse> {
+    let pool = &state.pool;
+    let actor = signed_in_user(pool, &headers).await?;
+    println!("🏛️ NEW CHAPTER REQUEST: {} ({}, {}) by {}", req.name, req.city, req.country, actor.wallet_address);
+    info!("Received chapter creation request: name={}", req.name);
+
+    if !state.stellar.is_admin(&actor.wallet_address) {
+        return Err(api_error(StatusCode::FORBIDDEN, "Only a programme admin can create a chapter"));
+    }
+
+    let lead_id = validate_chapter(pool, &req, None).await?;
+
+    let chapter = ChapterRepository::create_chapter(pool, req.name.trim(), req.country.trim(), req.city.trim(), lead_id)
+        .await
+        .map_err(|e| database_error("creating chapter", e))?;
+
+    if let Some(lead_id) = lead_id {
+        ChapterRepository::add_member(pool, chapter.id, lead_id)
+            .await
+            .map_err(|e| database_error("adding chapter lead as member", e))?;
+    }
+
+    println!("✅ CHAPTER CREATED: {} (ID {})", chapter.name, chapter.id);
+    Ok((StatusCode::CREATED, Json(chapter_response(pool, &chapter).await?)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/chapters/{chapter_id}",
+    tag = "chapters",
+    params(("chapter_id" = i32, Path, description = "Chapter id")),
+    responses(
+        (status = 200, description = "The chapter", body = ChapterResponse),
+        (status = 404, description = "Unknown chapter", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn get_chapter(
+    State(pool): State<DbPool>,
+    Path(chapter_id): Path<i32>,
+) -> ApiResult<ChapterResponse> {
+    let chapter = find_chapter(&pool, chapter_id).await?;
+    Ok((StatusCode::OK, Json(chapter_response(&pool, &chapter).await?)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/chapters/{chapter_id}",
+    tag = "chapters",
+    params(("chapter_id" = i32, Path, description = "Chapter id")),
+    request_body = ChapterRequest,
+    responses(
+        (status = 200, description = "Chapter updated", body = ChapterResponse),
+        (status = 400, description = "Missing field or lead is not a registered Chapter Lead", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the chapter lead or a programme admin edits a chapter", body = ErrorResponse),
+        (status = 404, description = "Unknown chapter", body = ErrorResponse),
+        (status = 409, description = "Chapter name already taken", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn update_chapter(
+    State(state): State<AppState>,
+    Path(chapter_id): Path<i32>,
+    headers: HeaderMap,
+    Json(req): Json<ChapterRequest>,
+) -> ApiResult<ChapterResponse> {
+    let pool = &state.pool;
+    info!("Received chapter update request: id={}", chapter_id);
+
+    let chapter = find_chapter(pool, chapter_id).await?;
+    find_chapter_manager(&state, &headers, &chapter).await?;
+    let lead_id = validate_chapter(pool, &req, Some(chapter_id)).await?;
+
+    let chapter = ChapterRepository::update_chapter(
+        pool,
+        chapter_id,
+        req.name.trim(),
+        req.country.trim(),
+        req.city.trim(),
+        lead_id,
+    )
+    .await
+    .map_err(|e| database_error("updating chapter", e))?
+    .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Chapter not found"))?;
+
+    if let Some(lead_id) = lead_id {
+        ChapterRepository::add_member(pool, chapter.id, lead_id)
+            .await
+            .map_err(|e| database_error("adding chapter lead as member", e))?;
+    }
+
+    Ok((StatusCode::OK, Json(chapter_response(pool, &chapter).await?)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/chapters/{chapter_id}",
+    tag = "chapters",
+    params(("chapter_id" = i32, Path, description = "Chapter id")),
+    responses(
+        (status = 204, description = "Chapter deleted; its events are kept without a chapter"),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the chapter lead or a programme admin deletes a chapter", body = ErrorResponse),
+        (status = 404, description = "Unknown chapter", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn delete_chapter(
+    State(state): State<AppState>,
+    Path(chapter_id): Path<i32>,
+    headers: HeaderMap,
+) -> Result<StatusCode, ApiError> {
+    let pool = &state.pool;
+    info!("Received chapter deletion request: id={}", chapter_id);
+
+    let chapter = find_chapter(pool, chapter_id).await?;
+    find_chapter_manager(&state, &headers, &chapter).await?;
+
+    let deleted = ChapterRepository::delete_chapter(pool, chapter_id)
+        .await
+        .map_err(|e| database_error("deleting chapter", e))?;
+
+    if deleted {
+        println!("🗑️ CHAPTER DELETED: ID {}", chapter_id);
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(api_error(StatusCode::NOT_FOUND, "Chapter not found"))
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/chapters/{chapter_id}/members",
+    tag = "chapters",
+    params(("chapter_id" = i32, Path, description = "Chapter id")),
+    responses(
+        (status = 200, description = "Chapter members, longest-standing first", body = ChapterMembersResponse),
+        (status = 404, description = "Unknown chapter", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn list_chapter_members(
+    State(pool): State<DbPool>,
+    Path(chapter_id): Path<i32>,
+) -> ApiResult<ChapterMembersResponse> {
+    find_chapter(&pool, chapter_id).await?;
+    Ok((StatusCode::OK, Json(members_response(&pool, chapter_id).await?)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/chapters/{chapter_id}/members",
+    tag = "chapters",
+    params(("chapter_id" = i32, Path, description = "Chapter id")),
+    request_body = MembershipRequest,
+    responses(
+        (status = 201, description = "Member added", body = ChapterMembersResponse),
+        (status = 200, description = "Already a member", body = ChapterMembersResponse),
+        (status = 400, description = "No registered user has this wallet address", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the chapter lead or a programme admin adds members", body = ErrorResponse),
+        (status = 404, description = "Unknown chapter", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn add_chapter_member(
+    State(state): State<AppState>,
+    Path(chapter_id): Path<i32>,
+    headers: HeaderMap,
+    Json(req): Json<MembershipRequest>,
+) -> ApiResult<ChapterMembersResponse> {
+    let pool = &state.pool;
+    let chapter = find_chapter(pool, chapter_id).await?;
+    find_chapter_manager(&state, &headers, &chapter).await?;
+
+    let user = UserRepository::find_by_wallet_address(pool, req.wallet_address.trim())
+        .await
+        .map_err(|e| database_error("loading user", e))?
+        .ok_or_else(|| field_error("wallet_address", "No registered user has this wallet address"))?;
+
+    let added = ChapterRepository::add_member(pool, chapter_id, user.id)
+        .await
+        .map_err(|e| database_error("adding chapter member", e))?;
+
+    if added {
+        println!("👥 {} JOINED CHAPTER {}", user.username, chapter_id);
+    }
+
+    let status = if added { StatusCode::CREATED } else { StatusCode::OK };
+    Ok((status, Json(members_response(pool, chapter_id).await?)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/chapters/{chapter_id}/members/{user_id}",
+    tag = "chapters",
+    params(
+        ("chapter_id" = i32, Path, description = "Chapter id"),
+        ("user_id" = i32, Path, description = "Id of the member to remove"),
+    ),
+    responses(
+        (status = 204, description = "Member removed"),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the chapter lead or a programme admin removes members", body = ErrorResponse),
+        (status = 404, description = "Unknown chapter or not a member", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn remove_chapter_member(
+    State(state): State<AppState>,
+    Path((chapter_id, user_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> Result<StatusCode, ApiError> {
+    let pool = &state.pool;
+    let chapter = find_chapter(pool, chapter_id).await?;
+    find_chapter_manager(&state, &headers, &chapter).await?;
+
+    let removed = ChapterRepository::remove_member(pool, chapter_id, user_id)
+        .await
+        .map_err(|e| database_error("removing chapter member", e))?;
+
+    if !removed {
+        return Err(api_error(StatusCode::NOT_FOUND, "User is not a member of this chapter"));
+    }
+
+    // A lead who leaves no longer leads the chapter
+    if chapter.lead_id == Some(user_id) {
+        ChapterRepository::update_chapter(pool, chapter.id, &chapter.name, &chapter.country, &chapter.city, None)
+            .await
+            .map_err(|e| database_error("clearing chapter lead", e))?;
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn members_response(pool: &DbPool, chapter_id: i32) -> Result<ChapterMembersResponse, ApiError> {
+    let members: Vec<_> = ChapterRepository::list_members(pool, chapter_id)
+        .await
+        .map_err(|e| database_error("listing chapter members", e))?
+        .iter()
+        .map(create_user_public)
+        .collect();
+
+    Ok(ChapterMembersResponse {
+        chapter_id: chapter_id.to_string(),
+        total: members.len(),
+        members,
+    })
+}
\ No newline at end of file
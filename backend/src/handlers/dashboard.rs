@@ -0,0 +1,240 @@
+use crate::database::connection::DbPool;
+use crate::database::models::KpiTarget;
+use crate::database::repositories::{BudgetRepository, EventRepository, KpiTargetRepository};
+use crate::handlers::budgets::budget_rollups;
+use crate::handlers::chapters::{chapter_response, database_error, find_chapter, find_chapter_manager};
+use crate::handlers::error::{field_error, ApiError, ApiResult};
+use crate::handlers::events::{create_event_response, organizer_username};
+use crate::state::AppState;
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::{HeaderMap, StatusCode},
+};
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use serde::Deserialize;
+use shared::dto::{ChapterDashboardResponse, ChapterTargetsResponse, ErrorResponse, KpiValues, MonthlyKpis};
+use tracing::info;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+pub struct DashboardQuery {
+    /// Defaults to the current year
+    pub year: Option<i32>,
+    /// 1–4; defaults to the current quarter
+    pub quarter: Option<u32>,
+}
+
+pub(crate) fn validate_quarter(quarter: u32) -> Result<(), ApiError> {
+    if (1..=4).contains(&quarter) {
+        Ok(())
+    } else {
+        Err(field_error("quarter", "Quarter must be between 1 and 4"))
+    }
+}
+
+/// A KPI value as stored; the columns hold at most `i32::MAX`.
+pub(crate) fn kpi_column(field: &str, value: Option<u32>) -> Result<Option<i32>, ApiError> {
+    value
+        .map(i32::try_from)
+        .transpose()
+        .map_err(|_| field_error(field, format!("Must be at most {}", i32::MAX)))
+}
+
+fn month_start(year: i32, month: u32) -> Result<DateTime<Utc>, ApiError> {
+    Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0)
+        .single()
+        .ok_or_else(|| field_error("year", "Year is out of range"))
+}
+
+/// First instant of the quarter and of the quarter after it, in UTC.
+pub fn quarter_bounds(year: i32, quarter: u32) -> Result<(DateTime<Utc>, DateTime<Utc>), ApiError> {
+    let start = month_start(year, quarter * 3 - 2)?;
+    let end = if quarter == 4 { month_start(year + 1, 1)? } else { month_start(year, quarter * 3 + 1)? };
+    Ok((start, end))
+}
+
+fn target_values(target: &KpiTarget) -> KpiValues {
+    KpiValues {
+        monthly_active_ambassadors: target.monthly_active_ambassadors.map(|v| v as u32),
+        monthly_active_accounts: target.monthly_active_accounts.map(|v| v as u32),
+        scf_referrals: target.scf_referrals.map(|v| v as u32),
+        content_produced: target.content_produced.map(|v| v as u32),
+        attendance: target.attendance.map(|v| v as u32),
+        social_growth: target.social_growth.map(|v| v as u32),
+    }
+}
+
+fn targets_response(chapter_id: i32, year: i32, quarter: u32, target: Option<&KpiTarget>) -> ChapterTargetsResponse {
+    ChapterTargetsResponse {
+        chapter_id: chapter_id.to_string(),
+        year,
+        quarter,
+        targets: target.map(target_values).unwrap_or_default(),
+        updated_at: target.map(|target| target.updated_at),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/chapters/{chapter_id}/targets/{year}/{quarter}",
+    tag = "dashboard",
+    params(
+        ("chapter_id" = i32, Path, description = "Chapter id"),
+        ("year" = i32, Path, description = "Calendar year"),
+        ("quarter" = u32, Path, description = "Quarter of the year, 1–4"),
+    ),
+    responses(
+        (status = 200, description = "The quarter's targets; all empty if none were set", body = ChapterTargetsResponse),
+        (status = 400, description = "Quarter out of range", body = ErrorResponse),
+        (status = 404, description = "Chapter not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn get_chapter_targets(
+    State(pool): State<DbPool>,
+    Path((chapter_id, year, quarter)): Path<(i32, i32, u32)>,
+) -> ApiResult<ChapterTargetsResponse> {
+    validate_quarter(quarter)?;
+    find_chapter(&pool, chapter_id).await?;
+
+    let target = KpiTargetRepository::find(&pool, chapter_id, year, quarter as i32)
+        .await
+        .map_err(|e| database_error("loading chapter targets", e))?;
+
+    Ok((StatusCode::OK, Json(targets_response(chapter_id, year, quarter, target.as_ref()))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/chapters/{chapter_id}/targets/{year}/{quarter}",
+    tag = "dashboard",
+    params(
+        ("chapter_id" = i32, Path, description = "Chapter id"),
+        ("year" = i32, Path, description = "Calendar year"),
+        ("quarter" = u32, Path, description = "Quarter of the year, 1–4"),
+    ),
+    request_body = KpiValues,
+    responses(
+        (status = 200, description = "Targets saved; replaces any set before", body = ChapterTargetsResponse),
+        (status = 400, description = "Quarter out of range, or a target too large", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the chapter lead or a programme admin sets targets", body = ErrorResponse),
+        (status = 404, description = "Chapter not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn update_chapter_targets(
+    State(state): State<AppState>,
+    Path((chapter_id, year, quarter)): Path<(i32, i32, u32)>,
+    headers: HeaderMap,
+    Json(targets): Json<KpiValues>,
+) -> ApiResult<ChapterTargetsResponse> {
+    let pool = &state.pool;
+    println!("🎯 CHAPTER TARGETS for chapter {} ({} Q{}): {:?}", chapter_id, year, quarter, targets);
+    info!("Setting KPI targets for chapter {} {} Q{}", chapter_id, year, quarter);
+
+    validate_quarter(quarter)?;
+    quarter_bounds(year, quarter)?;
+    let chapter = find_chapter(pool, chapter_id).await?;
+    find_chapter_manager(&state, &headers, &chapter).await?;
+
+    let target = KpiTargetRepository::upsert(
+        pool,
+        chapter_id,
+        year,
+        quarter as i32,
+        kpi_column("monthly_active_ambassadors", targets.monthly_active_ambassadors)?,
+        kpi_column("monthly_active_accounts", targets.monthly_active_accounts)?,
+        kpi_column("scf_referrals", targets.scf_referrals)?,
+        kpi_column("content_produced", targets.content_produced)?,
+        kpi_column("attendance", targets.attendance)?,
+        kpi_column("social_growth", targets.social_growth)?,
+    )
+    .await
+    .map_err(|e| database_error("saving chapter targets", e))?;
+
+    Ok((StatusCode::OK, Json(targets_response(chapter_id, year, quarter, Some(&target)))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/chapters/{chapter_id}/dashboard",
+    tag = "dashboard",
+    params(("chapter_id" = i32, Path, description = "Chapter id"), DashboardQuery),
+    responses(
+        (status = 200, description = "The chapter's events starting in the quarter (UTC), their summed KPI estimates and actuals, per-month totals, the quarter's targets and its budget per currency", body = ChapterDashboardResponse),
+        (status = 400, description = "Quarter out of range", body = ErrorResponse),
+        (status = 404, description = "Chapter not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn chapter_dashboard(
+    State(pool): State<DbPool>,
+    Path(chapter_id): Path<i32>,
+    Query(params): Query<DashboardQuery>,
+) -> ApiResult<ChapterDashboardResponse> {
+    let today = Utc::now().date_naive();
+    let year = params.year.unwrap_or(today.year());
+    let quarter = params.quarter.unwrap_or(today.month0() / 3 + 1);
+    println!("📊 CHAPTER DASHBOARD REQUEST: chapter {} ({} Q{})", chapter_id, year, quarter);
+
+    validate_quarter(quarter)?;
+    let (start, end) = quarter_bounds(year, quarter)?;
+    let chapter = find_chapter(&pool, chapter_id).await?;
+
+    let events = EventRepository::list_for_chapter_between(&pool, chapter_id, start, end)
+        .await
+        .map_err(|e| database_error("listing chapter events", e))?;
+    let target = KpiTargetRepository::find(&pool, chapter_id, year, quarter as i32)
+        .await
+        .map_err(|e| database_error("loading chapter targets", e))?;
+    let event_ids: Vec<i32> = events.iter().map(|event| event.id).collect();
+    let budget_items = BudgetRepository::list_for_events(&pool, &event_ids)
+        .await
+        .map_err(|e| database_error("listing budget items", e))?;
+
+    let first_month = quarter * 3 - 2;
+    let mut months: Vec<MonthlyKpis> = (first_month..first_month + 3)
+        .map(|month| MonthlyKpis {
+            year,
+            month,
+            event_count: 0,
+            estimated: KpiValues::default(),
+            actual: KpiValues::default(),
+        })
+        .collect();
+    let mut estimated = KpiValues::default();
+    let mut actual = KpiValues::default();
+    let mut event_responses = Vec::new();
+
+    for event in &events {
+        let organizer_username = organizer_username(&pool, event.organizer_id).await;
+        let response = create_event_response(event, &organizer_username);
+        let event_estimates = KpiValues::from(&response.kpi_estimates);
+
+        let month = &mut months[(event.date.month() - first_month) as usize];
+        month.event_count += 1;
+        month.estimated.add(&event_estimates);
+        month.actual.add(&response.kpi_actuals);
+        estimated.add(&event_estimates);
+        actual.add(&response.kpi_actuals);
+
+        event_responses.push(response);
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(ChapterDashboardResponse {
+            chapter: chapter_response(&pool, &chapter).await?,
+            year,
+            quarter,
+            events: event_responses,
+            estimated,
+            actual,
+            targets: target.as_ref().map(target_values).unwrap_or_default(),
+            months,
+            budget: budget_rollups(&budget_items, &events),
+        }),
+    ))
+}
\ No newline at end of file
@@ -0,0 +1,165 @@
+use crate::database::models::{EmailVerification, User};
+use crate::database::repositories::{EmailVerificationRepository, UserRepository};
+use crate::handlers::auth::create_user_public;
+use crate::handlers::chapters::database_error;
+use crate::handlers::error::{api_error, ApiError, ApiResult};
+use crate::handlers::plans::find_actor;
+use crate::jobs::enqueue;
+use crate::mail::{SendEmail, VERIFY_EMAIL};
+use crate::state::AppState;
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+};
+use chrono::{DateTime, Duration, Utc};
+use sha2::{Digest, Sha256};
+use shared::dto::{EmailVerificationResponse, ErrorResponse, ResendVerificationRequest, VerifyEmailRequest};
+use tracing::info;
+use uuid::Uuid;
+
+/// How long a mailed verification link works.
+pub const EMAIL_VERIFICATION_TTL: Duration = Duration::hours(48);
+/// How long to wait before mailing another link to the same user.
+pub const EMAIL_VERIFICATION_RESEND_INTERVAL: Duration = Duration::minutes(1);
+
+/// Tokens are kept hashed, so the table alone cannot verify anyone.
+fn token_hash(token: &str) -> String {
+    Sha256::digest(token.trim().as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Queues a mail to `user` with a new link that verifies their address.
+pub(crate) async fn send_verification_email(state: &AppState, user: &User) -> Result<EmailVerification, ApiError> {
+    let token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+    let verification = EmailVerificationRepository::create(
+        &state.pool,
+        user.id,
+        &user.email,
+        &token_hash(&token),
+        Utc::now() + EMAIL_VERIFICATION_TTL,
+    )
+    .await
+    .map_err(|e| database_error("creating email verification", e))?;
+
+    let link = format!("{}/verify-email?token={}", state.app_url, token);
+    let expires_at = verification.expires_at.format("%d %B %Y, %H:%M UTC").to_string();
+    let email = VERIFY_EMAIL.render(
+        &user.email,
+        &[("username", &user.username), ("link", &link), ("expires_at", &expires_at)],
+    );
+    enqueue(&state.pool, &SendEmail { email })
+        .await
+        .map_err(|e| database_error("queuing verification email", e))?;
+
+    info!("Verification link queued for {} for user {}", user.email, user.id);
+    Ok(verification)
+}
+
+/// Checks that following `verification` verifies `user` at `now`: the link was sent to the
+/// address the user still has, and is unused and unexpired.
+pub fn check_email_verification(verification: &EmailVerification, user: &User, now: DateTime<Utc>) -> Result<(), ApiError> {
+    if verification.email != user.email {
+        return Err(api_error(
+            StatusCode::CONFLICT,
+            "This link was sent to an address the account no longer uses; ask for a new one",
+        ));
+    }
+    if verification.used_at.is_some() {
+        return Err(api_error(StatusCode::GONE, "This link was already used; ask for a new one"));
+    }
+    if verification.expires_at <= now {
+        return Err(api_error(
+            StatusCode::GONE,
+            format!("This link expired at {}; ask for a new one", verification.expires_at.format("%d %B %Y, %H:%M UTC")),
+        ));
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "/email/verify",
+    tag = "auth",
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 200, description = "Email address verified, or already was", body = EmailVerificationResponse),
+        (status = 400, description = "Unknown verification token", body = ErrorResponse),
+        (status = 409, description = "The link was sent to an address the account no longer uses", body = ErrorResponse),
+        (status = 410, description = "The link expired or was already used", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn verify_email(
+    State(state): State<AppState>,
+    Json(req): Json<VerifyEmailRequest>,
+) -> ApiResult<EmailVerificationResponse> {
+    let unknown = || api_error(StatusCode::BAD_REQUEST, "Unknown verification link; ask for a new one");
+    let verification = EmailVerificationRepository::find_by_token_hash(&state.pool, &token_hash(&req.token))
+        .await
+        .map_err(|e| database_error("loading email verification", e))?
+        .ok_or_else(unknown)?;
+    let user = UserRepository::find_by_id(&state.pool, verification.user_id)
+        .await
+        .map_err(|e| database_error("loading user", e))?
+        .ok_or_else(unknown)?;
+
+    // Following the link twice is fine
+    if user.email_verified_at.is_some() && verification.email == user.email {
+        let message = format!("{} is already verified", user.email);
+        return Ok((StatusCode::OK, Json(EmailVerificationResponse { user: create_user_public(&user), message })));
+    }
+    check_email_verification(&verification, &user, Utc::now())?;
+
+    if !EmailVerificationRepository::verify(&state.pool, &verification)
+        .await
+        .map_err(|e| database_error("verifying email", e))?
+    {
+        return Err(api_error(StatusCode::GONE, "This link expired or was already used; ask for a new one"));
+    }
+    let user = UserRepository::find_by_id(&state.pool, user.id)
+        .await
+        .map_err(|e| database_error("loading user", e))?
+        .ok_or_else(unknown)?;
+
+    println!("📧 Email verified for {} ({})", user.username, user.email);
+    info!("Email verified for user {}", user.id);
+
+    let message = format!("{} is verified. You can now create events.", user.email);
+    Ok((StatusCode::OK, Json(EmailVerificationResponse { user: create_user_public(&user), message })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/email/verification",
+    tag = "auth",
+    request_body = ResendVerificationRequest,
+    responses(
+        (status = 202, description = "A new link is on its way to the user's address", body = EmailVerificationResponse),
+        (status = 400, description = "No registered user has this wallet address", body = ErrorResponse),
+        (status = 409, description = "The address is already verified", body = ErrorResponse),
+        (status = 429, description = "A link was mailed less than a minute ago", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn resend_verification_email(
+    State(state): State<AppState>,
+    Json(req): Json<ResendVerificationRequest>,
+) -> ApiResult<EmailVerificationResponse> {
+    let user = find_actor(&state.pool, &req.wallet_address, "wallet_address").await?;
+    if user.email_verified_at.is_some() {
+        return Err(api_error(StatusCode::CONFLICT, format!("{} is already verified", user.email)));
+    }
+
+    let latest = EmailVerificationRepository::latest_for_user(&state.pool, user.id)
+        .await
+        .map_err(|e| database_error("loading email verification", e))?;
+    if latest.is_some_and(|latest| Utc::now() - latest.created_at < EMAIL_VERIFICATION_RESEND_INTERVAL) {
+        return Err(api_error(
+            StatusCode::TOO_MANY_REQUESTS,
+            "A link was mailed less than a minute ago; check your inbox or try again shortly",
+        ));
+    }
+
+    send_verification_email(&state, &user).await?;
+    let message = format!("A new verification link was sent to {}", user.email);
+    Ok((StatusCode::ACCEPTED, Json(EmailVerificationResponse { user: create_user_public(&user), message })))
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+use axum::{http::StatusCode, Json};
+use shared::dto::ErrorResponse;
+
+/// Error half of a handler result, serialised as the shared `ErrorResponse`
+/// so the frontend can show messages and per-field errors.
+pub type ApiError = (StatusCode, Json<ErrorResponse>);
+
+pub type ApiResult<T> = Result<(StatusCode, Json<T>), ApiError>;
+
+pub fn api_error(status: StatusCode, message: impl Into<String>) -> ApiError {
+    (status, Json(ErrorResponse::new(message)))
+}
+
+/// A 400 pointing at the request field that failed validation.
+pub fn field_error(field: &str, message: impl Into<String>) -> ApiError {
+    let message = message.into();
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse::new(message.clone()).with_field_error(field, message)),
+    )
+}
\ No newline at end of file
@@ -1,22 +1,71 @@
 use crate::database::connection::DbPool;
-use crate::database::repositories::{EventRepository, UserRepository};
+use crate::database::repositories::{ChapterRepository, EventRepository, UserRepository};
+use crate::handlers::auth::{session_user, signed_in_user};
+use crate::handlers::chapters::database_error;
+use crate::handlers::error::{api_error, field_error, ApiResult};
+use crate::handlers::dashboard::kpi_column;
+use crate::handlers::registrations::promote_waitlist;
+use crate::notifications::{publish, schedule_reminders, DomainEvent};
+use crate::state::AppState;
 use axum::{
-    extract::{Json, State, Query},
-    http::StatusCode,
+    extract::{Json, Path, State, Query},
+    http::{HeaderMap, StatusCode},
 };
 use tracing::{info, error};
-use shared::dto::{EventRequest, EventResponse, EventListResponse, EventType, StrategicFocusArea, KPIEstimates};
+use shared::dto::{EventRequest, EventResponse, EventListResponse, EventStatus, EventStatusRequest, EventActualsRequest, EventUpdateRequest, EventType, StrategicFocusArea, KPIEstimates, KpiValues, ErrorResponse};
 use crate::database::models::Event;
 use serde::Deserialize;
 use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use utoipa::IntoParams;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
 pub struct ListEventsQuery {
+    /// Only list events organised for this chapter
+    pub chapter_id: Option<i32>,
+    /// Only list events in this status. A signed-in Chapter Lead may select `Submitted`,
+    /// the events awaiting review
+    pub status: Option<String>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
 }
 
-fn create_event_response(event: &Event, organizer_username: &str) -> EventResponse {
+/// Length of events created without an end.
+const LEGACY_EVENT_LENGTH: chrono::Duration = chrono::Duration::hours(2);
+
+fn validate_timezone(timezone: &str) -> Result<(), String> {
+    match timezone.parse::<Tz>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Unknown time zone: {}", timezone)),
+    }
+}
+
+/// Checks the time zone is a known IANA zone and the event ends after it starts.
+fn validate_schedule(timezone: &str, date: DateTime<Utc>, end_date: DateTime<Utc>) -> Result<(), String> {
+    validate_timezone(timezone)?;
+
+    if end_date <= date {
+        return Err("Event must end after it starts".to_string());
+    }
+
+    Ok(())
+}
+
+pub(crate) fn event_status(event: &Event) -> EventStatus {
+    EventStatus::from_name(&event.status).unwrap_or(EventStatus::Draft)
+}
+
+/// Whether the event shows up in public listings, calendars and feeds. Cancellations stay
+/// visible once the event had been approved, so attendees learn about them.
+pub(crate) fn is_publicly_listed(event: &Event) -> bool {
+    match event_status(event) {
+        EventStatus::Approved | EventStatus::Published | EventStatus::Completed => true,
+        EventStatus::Cancelled => event.approved_at.is_some(),
+        EventStatus::Draft | EventStatus::Submitted => false,
+    }
+}
+
+pub(crate) fn create_event_response(event: &Event, organizer_username: &str) -> EventResponse {
     let event_type = match event.event_type.as_str() {
         "Workshop" => EventType::Workshop,
         "Meetup" => EventType::Meetup,
@@ -53,31 +102,76 @@ fn create_event_response(event: &Event, organizer_username: &str) -> EventRespon
         title: event.title.clone(),
         description: event.description.clone(),
         event_type,
-        date: event.date.to_string(),
+        date: event.date,
+        end_date: event.end_date,
+        timezone: event.timezone.clone(),
         location: event.location.clone(),
         max_participants: event.max_participants.map(|p| p as u32),
         registration_required: event.registration_required,
         contact_email: event.contact_email.clone(),
         external_link: event.external_link.clone(),
         organizer: organizer_username.to_string(),
-        created_at: event.created_at.map_or("Unknown".to_string(), |dt| dt.to_string()),
+        chapter_id: event.chapter_id.map(|id| id.to_string()),
+        plan_id: event.plan_id.map(|id| id.to_string()),
+        status: event_status(event),
+        cancellation_reason: event.cancellation_reason.clone(),
+        // Rows from before `created_at` was filled in have only their last change
+        created_at: event.created_at.unwrap_or(event.updated_at),
         strategic_focus_areas,
         kpi_estimates,
         target_audience: event.target_audience.clone(),
         quarterly_goals: event.quarterly_goals.clone(),
         strategic_purpose: event.strategic_purpose.clone(),
         success_metrics: event.success_metrics.clone(),
+        kpi_actuals: KpiValues {
+            monthly_active_ambassadors: event.actual_monthly_active_ambassadors.map(|v| v as u32),
+            monthly_active_accounts: event.actual_monthly_active_accounts.map(|v| v as u32),
+            scf_referrals: event.actual_scf_referrals.map(|v| v as u32),
+            content_produced: event.actual_content_produced.map(|v| v as u32),
+            attendance: event.actual_attendance.map(|v| v as u32),
+            social_growth: event.actual_social_growth.map(|v| v as u32),
+        },
+    }
+}
+
+pub(crate) async fn organizer_username(pool: &DbPool, organizer_id: i32) -> String {
+    match UserRepository::find_by_id(pool, organizer_id).await {
+        Ok(Some(user)) => user.username,
+        Ok(None) => "Unknown".to_string(),
+        Err(_) => "Unknown".to_string(),
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/events",
+    tag = "events",
+    request_body = EventRequest,
+    responses(
+        (status = 201, description = "Event created as a draft", body = String),
+        (status = 400, description = "Unknown time zone or chapter, or end time not after start time", body = String),
+        (status = 401, description = "Not signed in, or the session has expired", body = String),
+        (status = 403, description = "The organizer has not verified their email address", body = String),
+        (status = 422, description = "Malformed request body, e.g. a date that is not RFC 3339"),
+        (status = 500, description = "Database error", body = String),
+    ),
+    security(("session" = []))
+)]
 pub async fn create_event(
-    State(pool): State<DbPool>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
     Json(req): Json<EventRequest>,
 ) -> (StatusCode, Json<String>) {
+    let pool = state.pool;
+    // Clients from before events had an end and a zone leave them out. Such events last
+    // two hours, as the events of that time did when they got an end
+    let end_date = req.end_date.unwrap_or(req.date + LEGACY_EVENT_LENGTH);
+    let timezone = req.timezone.clone().unwrap_or(state.default_timezone);
+
     println!("🎪 NEW EVENT CREATION REQUEST");
     println!("   Title: {}", req.title);
     println!("   Type: {:?}", req.event_type);
-    println!("   Date: {}", req.date);
+    println!("   Date: {} - {} ({})", req.date, end_date, timezone);
     println!("   Location: {}", req.location);
     println!("   Contact: {}", req.contact_email);
     println!("   Strategic Focus Areas: {:?}", req.strategic_focus_areas);
@@ -91,17 +185,51 @@ pub async fn create_event(
 
     info!("Received event creation request: title={}", req.title);
 
-    // For now, we'll use a placeholder organizer_id of 1
-    // In a real app, this would come from authentication
-    let organizer_id = 1;
+    // The signed-in user organises the event, and must have verified their email address
+    let organizer = match signed_in_user(&pool, &headers).await {
+        Ok(user) => user,
+        Err((status, Json(error))) => {
+            error!("Event created without a session: {}", error.message);
+            return (status, Json(error.message));
+        }
+    };
+    if organizer.email_verified_at.is_none() {
+        error!("Organizer {} has not verified their email address", organizer.wallet_address);
+        return (
+            StatusCode::FORBIDDEN,
+            Json(format!("Verify your email address ({}) before creating events", organizer.email)),
+        );
+    }
+    let organizer_id = organizer.id;
+
+    let schedule = match req.end_date {
+        Some(end_date) => validate_schedule(&timezone, req.date, end_date),
+        None => validate_timezone(&timezone),
+    };
+    if let Err(message) = schedule {
+        error!("Invalid event schedule: {}", message);
+        return (StatusCode::BAD_REQUEST, Json(message));
+    }
 
-    // Parse the date string
-    let date = match req.date.parse::<DateTime<Utc>>() {
-        Ok(d) => d,
-        Err(e) => {
-            error!("Invalid date format: {:?}", e);
-            return (StatusCode::BAD_REQUEST, Json("Invalid date format".to_string()));
+    let chapter_id = match req.chapter_id.as_deref().filter(|id| !id.is_empty()) {
+        Some(id) => {
+            let chapter = match id.parse::<i32>() {
+                Ok(id) => ChapterRepository::find_by_id(&pool, id).await,
+                Err(_) => Ok(None),
+            };
+            match chapter {
+                Ok(Some(chapter)) => Some(chapter.id),
+                Ok(None) => {
+                    error!("Unknown chapter for event: {}", id);
+                    return (StatusCode::BAD_REQUEST, Json(format!("Unknown chapter: {}", id)));
+                }
+                Err(e) => {
+                    error!("Database error loading chapter: {:?}", e);
+                    return (StatusCode::INTERNAL_SERVER_ERROR, Json(format!("Failed to create event: {}", e)));
+                }
+            }
         }
+        None => None,
     };
 
     let event_type_str = req.event_type.to_string();
@@ -115,13 +243,16 @@ pub async fn create_event(
         &req.title,
         &req.description,
         &event_type_str,
-        date,
+        req.date,
+        end_date,
+        &timezone,
         &req.location,
         req.max_participants.map(|p| p as i32),
         req.registration_required,
         &req.contact_email,
         req.external_link.as_deref(),
         organizer_id,
+        chapter_id,
         Some(&strategic_focus_areas_strings),
         req.kpi_estimates.monthly_active_ambassadors.map(|v| v as i32),
         req.kpi_estimates.monthly_active_accounts.map(|v| v as i32),
@@ -139,7 +270,7 @@ pub async fn create_event(
             println!("   Event ID: {}", event.id);
             println!("   Title: {}", event.title);
             println!("   Date: {}", event.date);
-            println!("   🎉 Event is ready for the community!");
+            println!("   📝 Saved as a draft; submit it for review to get it listed");
             println!("   ════════════════════════════════════");
             (StatusCode::CREATED, Json("Event created successfully!".to_string()))
         }
@@ -151,24 +282,57 @@ pub async fn create_event(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/events",
+    tag = "events",
+    params(ListEventsQuery),
+    responses(
+        (status = 200, description = "Publicly listed events ordered by date, plus the signed-in user's own and, for Chapter Leads, those awaiting review", body = EventListResponse),
+        (status = 400, description = "Unknown status", body = EventListResponse),
+        (status = 401, description = "The session token has expired or is not valid", body = EventListResponse),
+        (status = 500, description = "Database error", body = EventListResponse),
+    ),
+    security((), ("session" = []))
+)]
 pub async fn list_events(
     State(pool): State<DbPool>,
+    headers: HeaderMap,
     Query(params): Query<ListEventsQuery>,
 ) -> (StatusCode, Json<EventListResponse>) {
-    println!("📋 EVENTS LIST REQUEST (limit: {:?}, offset: {:?})", params.limit, params.offset);
+    println!(
+        "📋 EVENTS LIST REQUEST (chapter: {:?}, status: {:?}, limit: {:?}, offset: {:?})",
+        params.chapter_id, params.status, params.limit, params.offset
+    );
     info!("Received events list request");
 
-    match EventRepository::list_events(&pool, params.limit, params.offset).await {
+    let empty = || EventListResponse { total: 0, events: vec![] };
+
+    let status = match params.status.as_deref().filter(|status| !status.is_empty()) {
+        Some(status) => match EventStatus::from_name(status) {
+            Some(status) => Some(status.to_string()),
+            None => {
+                error!("Unknown event status filter: {}", status);
+                return (StatusCode::BAD_REQUEST, Json(empty()));
+            }
+        },
+        None => None,
+    };
+
+    // Only a signed-in user sees their own unlisted events, and only Chapter Leads the review queue
+    let viewer = match session_user(&pool, &headers).await {
+        Ok(viewer) => viewer,
+        Err((status, _)) => return (status, Json(empty())),
+    };
+    let viewer_id = viewer.as_ref().map(|viewer| viewer.id);
+    let reviewer = viewer.is_some_and(|viewer| viewer.user_type == "ChapterLead");
+
+    match EventRepository::list_events(&pool, params.chapter_id, viewer_id, reviewer, status.as_deref(), params.limit, params.offset).await {
         Ok(events) => {
             let mut event_responses = Vec::new();
 
             for event in events {
-                // Get organizer username
-                let organizer_username = match UserRepository::find_by_id(&pool, event.organizer_id).await {
-                    Ok(Some(user)) => user.username,
-                    Ok(None) => "Unknown".to_string(),
-                    Err(_) => "Unknown".to_string(),
-                };
+                let organizer_username = organizer_username(&pool, event.organizer_id).await;
 
                 event_responses.push(create_event_response(&event, &organizer_username));
             }
@@ -182,11 +346,232 @@ pub async fn list_events(
         }
         Err(e) => {
             error!("Database error listing events: {:?}", e);
-            let response = EventListResponse {
-                total: 0,
-                events: vec![],
-            };
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(response))
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(empty()))
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/events/{event_id}/actuals",
+    tag = "events",
+    params(("event_id" = i32, Path, description = "Event id")),
+    request_body = EventActualsRequest,
+    responses(
+        (status = 200, description = "Actuals recorded; replaces any recorded before", body = EventResponse),
+        (status = 400, description = "An actual value too large", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the organizer records an event's actuals", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn record_event_actuals(
+    State(pool): State<DbPool>,
+    Path(event_id): Path<i32>,
+    headers: HeaderMap,
+    Json(req): Json<EventActualsRequest>,
+) -> ApiResult<EventResponse> {
+    let actor = signed_in_user(&pool, &headers).await?;
+    println!("📈 EVENT ACTUALS for event {} by {}: {:?}", event_id, actor.wallet_address, req.actuals);
+    info!("Recording KPI actuals for event {}", event_id);
+
+    // The actuals feed the chapter dashboard and the budget's cost per attendee
+    let event = EventRepository::find_by_id(&pool, event_id)
+        .await
+        .map_err(|e| database_error("loading event", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Event not found"))?;
+    if actor.id != event.organizer_id {
+        return Err(api_error(StatusCode::FORBIDDEN, "Only the organizer can record this event's actuals"));
+    }
+
+    let actuals = req.actuals;
+    let event = EventRepository::record_actuals(
+        &pool,
+        event.id,
+        kpi_column("monthly_active_ambassadors", actuals.monthly_active_ambassadors)?,
+        kpi_column("monthly_active_accounts", actuals.monthly_active_accounts)?,
+        kpi_column("scf_referrals", actuals.scf_referrals)?,
+        kpi_column("content_produced", actuals.content_produced)?,
+        kpi_column("attendance", actuals.attendance)?,
+        kpi_column("social_growth", actuals.social_growth)?,
+    )
+    .await
+    .map_err(|e| database_error("recording event actuals", e))?
+    .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Event not found"))?;
+
+    let organizer_username = organizer_username(&pool, event.organizer_id).await;
+    Ok((StatusCode::OK, Json(create_event_response(&event, &organizer_username))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/status",
+    tag = "events",
+    params(("event_id" = i32, Path, description = "Event id")),
+    request_body = EventStatusRequest,
+    responses(
+        (status = 200, description = "Event moved to the new status", body = EventResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the organizer changes an event; only another Chapter Lead approves it", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 409, description = "Transition not allowed from the event's current status, or the event has not ended yet", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn update_event_status(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+    headers: HeaderMap,
+    Json(req): Json<EventStatusRequest>,
+) -> ApiResult<EventResponse> {
+    let pool = &state.pool;
+    let actor = signed_in_user(pool, &headers).await?;
+    println!("🚦 EVENT {} STATUS CHANGE to {} by {}", event_id, req.status, actor.wallet_address);
+    info!("Event {} status change requested: {}", event_id, req.status);
+
+    let event = EventRepository::find_by_id(pool, event_id)
+        .await
+        .map_err(|e| database_error("loading event", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Event not found"))?;
+    let status = event_status(&event);
+    if !status.can_move_to(req.status) {
+        return Err(api_error(
+            StatusCode::CONFLICT,
+            format!("An event that is {} cannot become {}", status, req.status),
+        ));
+    }
+
+    if req.status == EventStatus::Approved {
+        if actor.user_type != "ChapterLead" || actor.id == event.organizer_id {
+            return Err(api_error(StatusCode::FORBIDDEN, "Events are approved by a Chapter Lead other than the organizer"));
+        }
+    } else if actor.id != event.organizer_id {
+        return Err(api_error(StatusCode::FORBIDDEN, "Only the organizer can change this event's status"));
+    }
+
+    if req.status == EventStatus::Completed && event.end_date > chrono::Utc::now() {
+        return Err(api_error(StatusCode::CONFLICT, "An event can only be completed after it has ended"));
+    }
+
+    let reason = req.reason.as_deref().map(str::trim).filter(|reason| !reason.is_empty());
+    let changed = EventRepository::update_status(pool, event.id, &status.to_string(), &req.status.to_string(), reason)
+        .await
+        .map_err(|e| database_error("changing event status", e))?;
+    if !changed {
+        return Err(api_error(StatusCode::CONFLICT, "The event was changed by someone else; reload and try again"));
+    }
+
+    println!("✅ EVENT {} is now {}", event.id, req.status);
+
+    let event = EventRepository::find_by_id(pool, event_id)
+        .await
+        .map_err(|e| database_error("loading event", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Event not found"))?;
+    if req.status == EventStatus::Cancelled {
+        publish(&state, DomainEvent::EventCancelled { event: event.clone() }).await;
+    }
+    // Publishing queues the reminders; cancelling or completing the event drops them
+    schedule_reminders(&state, &event).await;
+
+    let organizer_username = organizer_username(pool, event.organizer_id).await;
+    Ok((StatusCode::OK, Json(create_event_response(&event, &organizer_username))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/events/{event_id}/details",
+    tag = "events",
+    params(("event_id" = i32, Path, description = "Event id")),
+    request_body = EventUpdateRequest,
+    responses(
+        (status = 200, description = "Details replaced; registrants are told if the title, description, schedule or location changed", body = EventResponse),
+        (status = 400, description = "Missing title or location, unknown time zone, or end time not after start time", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only the organizer edits an event", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 409, description = "The event is cancelled or completed, or changed in the meantime", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn update_event(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+    headers: HeaderMap,
+    Json(req): Json<EventUpdateRequest>,
+) -> ApiResult<EventResponse> {
+    let pool = &state.pool;
+    let actor = signed_in_user(pool, &headers).await?;
+    println!("✏️ EVENT {} EDITED by {}", event_id, actor.wallet_address);
+    info!("Event {} update requested", event_id);
+
+    let event = EventRepository::find_by_id(pool, event_id)
+        .await
+        .map_err(|e| database_error("loading event", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Event not found"))?;
+    if actor.id != event.organizer_id {
+        return Err(api_error(StatusCode::FORBIDDEN, "Only the organizer can edit this event"));
+    }
+
+    let status = event_status(&event);
+    if matches!(status, EventStatus::Cancelled | EventStatus::Completed) {
+        return Err(api_error(StatusCode::CONFLICT, format!("An event that is {} can no longer be edited", status)));
+    }
+
+    for (field, value) in [("title", &req.title), ("location", &req.location)] {
+        if value.trim().is_empty() {
+            return Err(field_error(field, "This field is required"));
+        }
+    }
+    validate_schedule(&req.timezone, req.date, req.end_date).map_err(|message| field_error("date", message))?;
+
+    let changed = EventRepository::update_details(
+        pool,
+        event.id,
+        &status.to_string(),
+        req.title.trim(),
+        &req.description,
+        req.date,
+        req.end_date,
+        &req.timezone,
+        req.location.trim(),
+        req.max_participants.map(|p| p as i32),
+        req.registration_required,
+        &req.contact_email,
+        req.external_link.as_deref().filter(|link| !link.trim().is_empty()),
+    )
+    .await
+    .map_err(|e| database_error("updating event", e))?;
+    if !changed {
+        return Err(api_error(StatusCode::CONFLICT, "The event was changed by someone else; reload and try again"));
+    }
+
+    let updated = EventRepository::find_by_id(pool, event_id)
+        .await
+        .map_err(|e| database_error("loading event", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Event not found"))?;
+    println!("✅ EVENT {} updated", updated.id);
+
+    // Registrants hear about what affects them, not about contact details or head counts
+    let noticeable = updated.title != event.title
+        || updated.description != event.description
+        || updated.date != event.date
+        || updated.end_date != event.end_date
+        || updated.timezone != event.timezone
+        || updated.location != event.location;
+    if noticeable {
+        publish(&state, DomainEvent::EventUpdated { event: updated.clone() }).await;
+    }
+    if status == EventStatus::Published {
+        promote_waitlist(&state, &updated).await;
+        if updated.date != event.date {
+            schedule_reminders(&state, &updated).await;
         }
     }
+
+    let organizer_username = organizer_username(pool, updated.organizer_id).await;
+    Ok((StatusCode::OK, Json(create_event_response(&updated, &organizer_username))))
 }
\ No newline at end of file
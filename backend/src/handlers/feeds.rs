@@ -0,0 +1,97 @@
+use crate::database::connection::DbPool;
+use crate::database::models::Event;
+use crate::database::repositories::EventRepository;
+use crate::feed::{feed_etag, render_atom, render_rss, FeedLinks};
+use crate::versioning::ApiVersion;
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use tracing::error;
+
+const FEED_LIMIT: i64 = 50;
+
+#[utoipa::path(
+    get,
+    path = "/feeds/events.atom",
+    tag = "feeds",
+    responses(
+        (status = 200, description = "Atom feed of upcoming events", content_type = "application/atom+xml", body = String),
+        (status = 304, description = "The feed still matches the `ETag` given in `If-None-Match`"),
+        (status = 500, description = "Database error", body = String),
+    )
+)]
+pub async fn atom_feed(State(pool): State<DbPool>, headers: HeaderMap) -> Response {
+    serve_feed(&pool, &headers, "application/atom+xml; charset=utf-8", render_atom).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/feeds/events.rss",
+    tag = "feeds",
+    responses(
+        (status = 200, description = "RSS 2.0 feed of upcoming events", content_type = "application/rss+xml", body = String),
+        (status = 304, description = "The feed still matches the `ETag` given in `If-None-Match`"),
+        (status = 500, description = "Database error", body = String),
+    )
+)]
+pub async fn rss_feed(State(pool): State<DbPool>, headers: HeaderMap) -> Response {
+    serve_feed(&pool, &headers, "application/rss+xml; charset=utf-8", render_rss).await
+}
+
+async fn serve_feed(
+    pool: &DbPool,
+    headers: &HeaderMap,
+    content_type: &'static str,
+    render: fn(&FeedLinks, &[Event]) -> String,
+) -> Response {
+    let events = match EventRepository::list_upcoming(pool, FEED_LIMIT).await {
+        Ok(events) => events,
+        Err(e) => {
+            error!("Database error building event feed: {:?}", e);
+            return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to load events".to_string()).into_response();
+        }
+    };
+
+    // The validator covers every event in the feed, so an event leaving it changes it too,
+    // unlike the latest `updated_at`
+    let links = feed_links(headers);
+    let etag = feed_etag(&links, &events);
+    if if_none_match(headers, &etag) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response();
+    }
+
+    (
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (header::ETAG, etag),
+        ],
+        render(&links, &events),
+    )
+        .into_response()
+}
+
+/// Whether `If-None-Match` lists `etag` or is `*`, comparing weakly (RFC 9110 §13.1.2).
+fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim())
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+/// Feed links point at the current API version on the host the client used.
+fn feed_links(headers: &HeaderMap) -> FeedLinks {
+    let header_value = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+    let scheme = header_value("x-forwarded-proto").unwrap_or("http");
+    let host = header_value("x-forwarded-host")
+        .or_else(|| header_value(header::HOST.as_str()))
+        .unwrap_or("127.0.0.1:8081");
+
+    FeedLinks {
+        api_base: format!("{}://{}{}", scheme, host, ApiVersion::CURRENT.prefix()),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses(
+        (status = 200, description = "Server is up", body = String, example = "OK"),
+    )
+)]
+pub async fn health() -> &'static str {
+    "OK"
+}
\ No newline at end of file
@@ -0,0 +1,215 @@
+use crate::database::connection::DbPool;
+use crate::database::models::{Notification, NotificationPreferenceRow, User};
+use crate::database::repositories::NotificationRepository;
+use crate::handlers::auth::signed_in_user;
+use crate::handlers::chapters::database_error;
+use crate::handlers::error::{api_error, field_error, ApiError, ApiResult};
+use crate::notifications::{is_supported_locale, supported_locales};
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::{HeaderMap, StatusCode},
+};
+use serde::Deserialize;
+use shared::dto::{
+    ErrorResponse, MarkNotificationsReadRequest, NotificationKind, NotificationListResponse, NotificationPreference,
+    NotificationPreferencesRequest, NotificationPreferencesResponse, NotificationResponse,
+};
+use utoipa::IntoParams;
+
+/// Most notifications the inbox lists at once.
+const MAX_INBOX_LIMIT: i64 = 200;
+
+#[derive(Deserialize, IntoParams)]
+pub struct ListNotificationsQuery {
+    /// Only list notifications not read yet
+    #[serde(default)]
+    pub unread_only: bool,
+    /// Default 50, at most 200
+    pub limit: Option<i64>,
+}
+
+/// An inbox and its preferences belong to the signed-in user alone.
+async fn find_owner(pool: &DbPool, headers: &HeaderMap, wallet_address: &str) -> Result<User, ApiError> {
+    let user = signed_in_user(pool, headers).await?;
+    if user.wallet_address != wallet_address.trim() {
+        return Err(api_error(StatusCode::FORBIDDEN, "Only the user themselves reads and changes their notifications"));
+    }
+    Ok(user)
+}
+
+fn notification_response(notification: Notification) -> NotificationResponse {
+    NotificationResponse {
+        id: notification.id.to_string(),
+        // Kinds are only ever written from `NotificationKind`
+        kind: NotificationKind::from_name(&notification.kind).unwrap_or(NotificationKind::EventUpdated),
+        subject: notification.subject,
+        body: notification.body,
+        link: notification.link,
+        read_at: notification.read_at,
+        created_at: notification.created_at,
+    }
+}
+
+async fn inbox_response(pool: &DbPool, user: &User, unread_only: bool, limit: i64) -> Result<NotificationListResponse, ApiError> {
+    let notifications = NotificationRepository::list_for_user(pool, user.id, unread_only, limit)
+        .await
+        .map_err(|e| database_error("loading notifications", e))?;
+    let unread = NotificationRepository::count_unread(pool, user.id)
+        .await
+        .map_err(|e| database_error("counting notifications", e))?;
+
+    Ok(NotificationListResponse {
+        notifications: notifications.into_iter().map(notification_response).collect(),
+        unread: unread as u32,
+    })
+}
+
+async fn preferences_response(pool: &DbPool, user: &User) -> Result<NotificationPreferencesResponse, ApiError> {
+    let stored = NotificationRepository::preferences(pool, user.id)
+        .await
+        .map_err(|e| database_error("loading notification preferences", e))?;
+    let locale = NotificationRepository::locale(pool, user.id)
+        .await
+        .map_err(|e| database_error("loading notification preferences", e))?;
+
+    let preferences = NotificationKind::ALL
+        .into_iter()
+        .map(|kind| match stored.iter().find(|row| row.kind == kind.to_string()) {
+            Some(row) => NotificationPreference { kind, email: row.email, in_app: row.in_app },
+            None => NotificationPreference { kind, email: true, in_app: true },
+        })
+        .collect();
+
+    Ok(NotificationPreferencesResponse { locale, preferences })
+}
+
+#[utoipa::path(
+    get,
+    path = "/users/{wallet_address}/notifications",
+    tag = "notifications",
+    params(
+        ("wallet_address" = String, Path, description = "Wallet address the user registered with"),
+        ListNotificationsQuery,
+    ),
+    responses(
+        (status = 200, description = "The user's inbox, newest first, with the number unread", body = NotificationListResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Another user's wallet address", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn list_notifications(
+    State(pool): State<DbPool>,
+    Path(wallet_address): Path<String>,
+    headers: HeaderMap,
+    Query(params): Query<ListNotificationsQuery>,
+) -> ApiResult<NotificationListResponse> {
+    let user = find_owner(&pool, &headers, &wallet_address).await?;
+    let limit = params.limit.unwrap_or(50).clamp(1, MAX_INBOX_LIMIT);
+    Ok((StatusCode::OK, Json(inbox_response(&pool, &user, params.unread_only, limit).await?)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/users/{wallet_address}/notifications/read",
+    tag = "notifications",
+    params(("wallet_address" = String, Path, description = "Wallet address the user registered with")),
+    request_body = MarkNotificationsReadRequest,
+    responses(
+        (status = 200, description = "Notifications marked read; the user's inbox", body = NotificationListResponse),
+        (status = 400, description = "Malformed notification id", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Another user's wallet address", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn mark_notifications_read(
+    State(pool): State<DbPool>,
+    Path(wallet_address): Path<String>,
+    headers: HeaderMap,
+    Json(req): Json<MarkNotificationsReadRequest>,
+) -> ApiResult<NotificationListResponse> {
+    let user = find_owner(&pool, &headers, &wallet_address).await?;
+    let ids = req
+        .notification_ids
+        .iter()
+        .map(|id| id.parse::<i32>().map_err(|_| field_error("notification_ids", format!("Unknown notification: {}", id))))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    NotificationRepository::mark_read(&pool, user.id, &ids)
+        .await
+        .map_err(|e| database_error("marking notifications read", e))?;
+
+    Ok((StatusCode::OK, Json(inbox_response(&pool, &user, false, 50).await?)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/users/{wallet_address}/notification-preferences",
+    tag = "notifications",
+    params(("wallet_address" = String, Path, description = "Wallet address the user registered with")),
+    responses(
+        (status = 200, description = "The user's language and how they get each kind of notification", body = NotificationPreferencesResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Another user's wallet address", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn get_notification_preferences(
+    State(pool): State<DbPool>,
+    Path(wallet_address): Path<String>,
+    headers: HeaderMap,
+) -> ApiResult<NotificationPreferencesResponse> {
+    let user = find_owner(&pool, &headers, &wallet_address).await?;
+    Ok((StatusCode::OK, Json(preferences_response(&pool, &user).await?)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/users/{wallet_address}/notification-preferences",
+    tag = "notifications",
+    params(("wallet_address" = String, Path, description = "Wallet address the user registered with")),
+    request_body = NotificationPreferencesRequest,
+    responses(
+        (status = 200, description = "Preferences saved; kinds left out of the request keep theirs", body = NotificationPreferencesResponse),
+        (status = 400, description = "Unsupported language", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Another user's wallet address", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn update_notification_preferences(
+    State(pool): State<DbPool>,
+    Path(wallet_address): Path<String>,
+    headers: HeaderMap,
+    Json(req): Json<NotificationPreferencesRequest>,
+) -> ApiResult<NotificationPreferencesResponse> {
+    let user = find_owner(&pool, &headers, &wallet_address).await?;
+
+    let locale = req.locale.as_deref().map(str::trim).filter(|locale| !locale.is_empty());
+    if let Some(locale) = locale.filter(|locale| !is_supported_locale(locale)) {
+        return Err(field_error(
+            "locale",
+            format!("Notifications are not written in {}; choose one of {}", locale, supported_locales().join(", ")),
+        ));
+    }
+
+    let preferences: Vec<NotificationPreferenceRow> = req
+        .preferences
+        .iter()
+        .map(|preference| NotificationPreferenceRow {
+            kind: preference.kind.to_string(),
+            email: preference.email,
+            in_app: preference.in_app,
+        })
+        .collect();
+    NotificationRepository::save_preferences(&pool, user.id, locale, &preferences)
+        .await
+        .map_err(|e| database_error("saving notification preferences", e))?;
+
+    Ok((StatusCode::OK, Json(preferences_response(&pool, &user).await?)))
+}
\ No newline at end of file
@@ -1,5 +1,37 @@
+pub mod accounts;
+pub mod attachments;
+pub mod attendance;
 pub mod auth;
+pub mod budgets;
+pub mod calendar;
+pub mod chapters;
+pub mod dashboard;
+pub mod email;
+pub mod error;
 pub mod events;
+pub mod feeds;
+pub mod health;
+pub mod inbox;
+pub mod network;
+pub mod plans;
+pub mod registrations;
+pub mod reimbursements;
 
+pub use accounts::*;
+pub use attachments::*;
+pub use attendance::*;
 pub use auth::*;
-pub use events::*;
\ No newline at end of file
+pub use budgets::*;
+pub use calendar::*;
+pub use chapters::*;
+pub use dashboard::*;
+pub use email::*;
+pub use error::*;
+pub use events::*;
+pub use feeds::*;
+pub use health::*;
+pub use inbox::*;
+pub use network::*;
+pub use plans::*;
+pub use registrations::*;
+pub use reimbursements::*;
\ No newline at end of file
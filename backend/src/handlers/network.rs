@@ -0,0 +1,19 @@
+use crate::state::AppState;
+use axum::extract::{Json, State};
+use shared::dto::StellarNetworkResponse;
+
+#[utoipa::path(
+    get,
+    path = "/stellar/network",
+    tag = "network",
+    responses(
+        (status = 200, description = "Network transactions are built for; the wallet must be on it to sign them", body = StellarNetworkResponse),
+    )
+)]
+pub async fn get_stellar_network(State(state): State<AppState>) -> Json<StellarNetworkResponse> {
+    Json(StellarNetworkResponse {
+        network: state.stellar.network_name().to_string(),
+        network_passphrase: state.stellar.network_passphrase.clone(),
+        horizon_url: state.stellar.horizon_url.clone(),
+    })
+}
\ No newline at end of file
@@ -0,0 +1,475 @@
+use crate::database::connection::DbPool;
+use crate::database::models::{Chapter, QuarterlyPlan, User};
+use crate::database::repositories::{BudgetRepository, ChapterRepository, EventRepository, PlanRepository, UserRepository};
+use crate::handlers::budgets::budget_rollups;
+use crate::handlers::auth::{create_user_public, signed_in_user};
+use crate::handlers::chapters::database_error;
+use crate::handlers::dashboard::{quarter_bounds, validate_quarter};
+use crate::handlers::error::{api_error, field_error, ApiError, ApiResult};
+use crate::handlers::events::{create_event_response, organizer_username};
+use crate::notifications::{publish, DomainEvent};
+use crate::state::AppState;
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::{HeaderMap, StatusCode},
+};
+use serde::Deserialize;
+use shared::dto::{
+    ErrorResponse, FocusAreaSummary, PlanAction, PlanComment, PlanCommentRequest, PlanStatus, PlanTransition,
+    PlanTransitionRequest, QuarterlyPlanListResponse, QuarterlyPlanRequest, QuarterlyPlanResponse,
+    QuarterlyPlanSummary, UserPublic,
+};
+use tracing::info;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+pub struct ListPlansQuery {
+    pub chapter_id: Option<i32>,
+    /// `Draft`, `Submitted`, `Approved` or `ChangesRequested`
+    pub status: Option<String>,
+}
+
+fn plan_status(plan: &QuarterlyPlan) -> PlanStatus {
+    PlanStatus::from_name(&plan.status).unwrap_or(PlanStatus::Draft)
+}
+
+async fn find_plan(pool: &DbPool, plan_id: i32) -> Result<QuarterlyPlan, ApiError> {
+    PlanRepository::find_by_id(pool, plan_id)
+        .await
+        .map_err(|e| database_error("loading plan", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Plan not found"))
+}
+
+async fn load_chapter(pool: &DbPool, chapter_id: i32) -> Result<Option<Chapter>, ApiError> {
+    ChapterRepository::find_by_id(pool, chapter_id)
+        .await
+        .map_err(|e| database_error("loading chapter", e))
+}
+
+pub(crate) async fn find_user(pool: &DbPool, user_id: Option<i32>) -> Result<Option<UserPublic>, ApiError> {
+    match user_id {
+        Some(user_id) => Ok(UserRepository::find_by_id(pool, user_id)
+            .await
+            .map_err(|e| database_error("loading user", e))?
+            .map(|user| create_user_public(&user))),
+        None => Ok(None),
+    }
+}
+
+/// Resolves the wallet address of whoever acts on a plan or event; `field` names it in the request.
+pub(crate) async fn find_actor(pool: &DbPool, wallet_address: &str, field: &str) -> Result<User, ApiError> {
+    UserRepository::find_by_wallet_address(pool, wallet_address.trim())
+        .await
+        .map_err(|e| database_error("loading user", e))?
+        .ok_or_else(|| field_error(field, "No registered user has this wallet address"))
+}
+
+async fn plan_response(pool: &DbPool, plan: &QuarterlyPlan) -> Result<QuarterlyPlanResponse, ApiError> {
+    let chapter_name = load_chapter(pool, plan.chapter_id).await?.map(|chapter| chapter.name).unwrap_or_default();
+
+    let plan_events = EventRepository::list_for_plan(pool, plan.id)
+        .await
+        .map_err(|e| database_error("loading plan events", e))?;
+    let event_ids: Vec<i32> = plan_events.iter().map(|event| event.id).collect();
+    let budget_items = BudgetRepository::list_for_events(pool, &event_ids)
+        .await
+        .map_err(|e| database_error("loading plan budget", e))?;
+
+    let mut events = Vec::new();
+    let mut focus_areas: Vec<FocusAreaSummary> = Vec::new();
+    for event in &plan_events {
+        let organizer_username = organizer_username(pool, event.organizer_id).await;
+        let event = create_event_response(event, &organizer_username);
+        for area in &event.strategic_focus_areas {
+            match focus_areas.iter_mut().find(|summary| &summary.focus_area == area) {
+                Some(summary) => summary.event_count += 1,
+                None => focus_areas.push(FocusAreaSummary { focus_area: area.clone(), event_count: 1 }),
+            }
+        }
+        events.push(event);
+    }
+
+    let mut comments = Vec::new();
+    for comment in PlanRepository::list_comments(pool, plan.id)
+        .await
+        .map_err(|e| database_error("loading plan comments", e))?
+    {
+        comments.push(PlanComment {
+            id: comment.id.to_string(),
+            author: find_user(pool, comment.author_id).await?,
+            body: comment.body,
+            created_at: comment.created_at,
+        });
+    }
+
+    let mut transitions = Vec::new();
+    for transition in PlanRepository::list_transitions(pool, plan.id)
+        .await
+        .map_err(|e| database_error("loading plan history", e))?
+    {
+        transitions.push(PlanTransition {
+            from_status: PlanStatus::from_name(&transition.from_status).unwrap_or(PlanStatus::Draft),
+            to_status: PlanStatus::from_name(&transition.to_status).unwrap_or(PlanStatus::Draft),
+            actor: find_user(pool, transition.actor_id).await?,
+            comment: transition.comment,
+            created_at: transition.created_at,
+        });
+    }
+
+    Ok(QuarterlyPlanResponse {
+        id: plan.id.to_string(),
+        chapter_id: plan.chapter_id.to_string(),
+        chapter_name,
+        year: plan.year,
+        quarter: plan.quarter as u32,
+        status: plan_status(plan),
+        strategic_purpose: plan.strategic_purpose.clone(),
+        quarterly_goals: plan.quarterly_goals.clone(),
+        events,
+        focus_areas,
+        budget: budget_rollups(&budget_items, &plan_events),
+        comments,
+        transitions,
+        created_at: plan.created_at,
+        updated_at: plan.updated_at,
+    })
+}
+
+/// Checks the summary and resolves the request's chapter and events. Events must be the
+/// chapter's, start in the plan's quarter and not already be part of another plan.
+async fn validate_plan(
+    pool: &DbPool,
+    req: &QuarterlyPlanRequest,
+    existing: Option<i32>,
+) -> Result<(i32, Vec<i32>), ApiError> {
+    for (field, value) in [("strategic_purpose", &req.strategic_purpose), ("quarterly_goals", &req.quarterly_goals)] {
+        if value.trim().is_empty() {
+            return Err(field_error(field, "This field is required"));
+        }
+    }
+
+    validate_quarter(req.quarter)?;
+    let (start, end) = quarter_bounds(req.year, req.quarter)?;
+
+    let chapter = match req.chapter_id.parse::<i32>() {
+        Ok(chapter_id) => load_chapter(pool, chapter_id).await?,
+        Err(_) => None,
+    }
+    .ok_or_else(|| field_error("chapter_id", format!("Unknown chapter: {}", req.chapter_id)))?;
+
+    let mut event_ids = Vec::new();
+    for id in &req.event_ids {
+        let event = match id.parse::<i32>() {
+            Ok(event_id) => EventRepository::find_by_id(pool, event_id)
+                .await
+                .map_err(|e| database_error("loading event", e))?,
+            Err(_) => None,
+        }
+        .ok_or_else(|| field_error("event_ids", format!("Unknown event: {}", id)))?;
+
+        if event.chapter_id != Some(chapter.id) {
+            return Err(field_error("event_ids", format!("Event {} is not organised by this chapter", event.id)));
+        }
+        if event.date < start || event.date >= end {
+            return Err(field_error("event_ids", format!("Event {} does not start in Q{} {}", event.id, req.quarter, req.year)));
+        }
+        if event.plan_id.is_some() && event.plan_id != existing {
+            return Err(field_error("event_ids", format!("Event {} is already part of another plan", event.id)));
+        }
+        event_ids.push(event.id);
+    }
+
+    Ok((chapter.id, event_ids))
+}
+
+#[utoipa::path(
+    get,
+    path = "/plans",
+    tag = "plans",
+    params(ListPlansQuery),
+    responses(
+        (status = 200, description = "Plans, newest quarter first", body = QuarterlyPlanListResponse),
+        (status = 400, description = "Unknown status", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn list_plans(
+    State(pool): State<DbPool>,
+    Query(params): Query<ListPlansQuery>,
+) -> ApiResult<QuarterlyPlanListResponse> {
+    let status = match params.status.as_deref().filter(|status| !status.is_empty()) {
+        Some(status) => Some(
+            PlanStatus::from_name(status).ok_or_else(|| field_error("status", format!("Unknown plan status: {}", status)))?,
+        ),
+        None => None,
+    };
+
+    let plans = PlanRepository::list_plans(&pool, params.chapter_id, status.map(|status| status.to_string()).as_deref())
+        .await
+        .map_err(|e| database_error("listing plans", e))?;
+
+    let mut summaries = Vec::new();
+    for plan in &plans {
+        let event_count = PlanRepository::count_events(&pool, plan.id)
+            .await
+            .map_err(|e| database_error("counting plan events", e))?;
+        summaries.push(QuarterlyPlanSummary {
+            id: plan.id.to_string(),
+            chapter_id: plan.chapter_id.to_string(),
+            chapter_name: load_chapter(&pool, plan.chapter_id).await?.map(|chapter| chapter.name).unwrap_or_default(),
+            year: plan.year,
+            quarter: plan.quarter as u32,
+            status: plan_status(plan),
+            event_count: event_count as u32,
+            updated_at: plan.updated_at,
+        });
+    }
+
+    Ok((StatusCode::OK, Json(QuarterlyPlanListResponse { total: summaries.len(), plans: summaries })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/plans",
+    tag = "plans",
+    request_body = QuarterlyPlanRequest,
+    responses(
+        (status = 201, description = "Plan created as a draft", body = QuarterlyPlanResponse),
+        (status = 400, description = "Missing summary, unknown chapter, or an event that cannot be planned", body = ErrorResponse),
+        (status = 409, description = "The chapter already has a plan for this quarter", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn create_plan(
+    State(pool): State<DbPool>,
+    Json(req): Json<QuarterlyPlanRequest>,
+) -> ApiResult<QuarterlyPlanResponse> {
+    println!("🗂️ NEW QUARTERLY PLAN: chapter {} ({} Q{}), {} events", req.chapter_id, req.year, req.quarter, req.event_ids.len());
+    info!("Received plan creation request for chapter {}", req.chapter_id);
+
+    let (chapter_id, event_ids) = validate_plan(&pool, &req, None).await?;
+
+    let existing = PlanRepository::find_for_quarter(&pool, chapter_id, req.year, req.quarter as i32)
+        .await
+        .map_err(|e| database_error("checking existing plans", e))?;
+    if existing.is_some() {
+        return Err(api_error(StatusCode::CONFLICT, "This chapter already has a plan for the quarter"));
+    }
+
+    let mut tx = pool.begin().await.map_err(|e| database_error("starting transaction", e))?;
+    let plan = PlanRepository::create_plan(
+        &mut *tx,
+        chapter_id,
+        req.year,
+        req.quarter as i32,
+        req.strategic_purpose.trim(),
+        req.quarterly_goals.trim(),
+    )
+    .await
+    .map_err(|e| database_error("creating plan", e))?;
+
+    PlanRepository::set_events(&mut tx, plan.id, &event_ids)
+        .await
+        .map_err(|e| database_error("adding plan events", e))?;
+    tx.commit().await.map_err(|e| database_error("creating plan", e))?;
+
+    Ok((StatusCode::CREATED, Json(plan_response(&pool, &plan).await?)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/plans/{plan_id}",
+    tag = "plans",
+    params(("plan_id" = i32, Path, description = "Plan id")),
+    responses(
+        (status = 200, description = "The plan with its events, comments and status history", body = QuarterlyPlanResponse),
+        (status = 404, description = "Plan not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn get_plan(
+    State(pool): State<DbPool>,
+    Path(plan_id): Path<i32>,
+) -> ApiResult<QuarterlyPlanResponse> {
+    let plan = find_plan(&pool, plan_id).await?;
+    Ok((StatusCode::OK, Json(plan_response(&pool, &plan).await?)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/plans/{plan_id}",
+    tag = "plans",
+    params(("plan_id" = i32, Path, description = "Plan id")),
+    request_body = QuarterlyPlanRequest,
+    responses(
+        (status = 200, description = "Summary and events replaced", body = QuarterlyPlanResponse),
+        (status = 400, description = "Missing summary, a different chapter or quarter, or an event that cannot be planned", body = ErrorResponse),
+        (status = 404, description = "Plan not found", body = ErrorResponse),
+        (status = 409, description = "Plan is submitted or approved and can no longer be edited", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn update_plan(
+    State(pool): State<DbPool>,
+    Path(plan_id): Path<i32>,
+    Json(req): Json<QuarterlyPlanRequest>,
+) -> ApiResult<QuarterlyPlanResponse> {
+    println!("🗂️ UPDATE QUARTERLY PLAN {}: {} events", plan_id, req.event_ids.len());
+
+    let plan = find_plan(&pool, plan_id).await?;
+    let status = plan_status(&plan);
+    if !status.is_editable() {
+        return Err(api_error(StatusCode::CONFLICT, format!("A plan that is {} can no longer be edited", status)));
+    }
+
+    if req.chapter_id != plan.chapter_id.to_string() || req.year != plan.year || req.quarter as i32 != plan.quarter {
+        return Err(field_error("chapter_id", "A plan cannot move to another chapter or quarter"));
+    }
+
+    let (_, event_ids) = validate_plan(&pool, &req, Some(plan.id)).await?;
+
+    let mut tx = pool.begin().await.map_err(|e| database_error("starting transaction", e))?;
+    PlanRepository::update_summary(&mut *tx, plan.id, req.strategic_purpose.trim(), req.quarterly_goals.trim())
+        .await
+        .map_err(|e| database_error("updating plan", e))?;
+    PlanRepository::set_events(&mut tx, plan.id, &event_ids)
+        .await
+        .map_err(|e| database_error("updating plan events", e))?;
+    tx.commit().await.map_err(|e| database_error("updating plan", e))?;
+
+    let plan = find_plan(&pool, plan_id).await?;
+    Ok((StatusCode::OK, Json(plan_response(&pool, &plan).await?)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/plans/{plan_id}/transitions",
+    tag = "plans",
+    params(("plan_id" = i32, Path, description = "Plan id")),
+    request_body = PlanTransitionRequest,
+    responses(
+        (status = 200, description = "Status changed and recorded in the plan's history", body = QuarterlyPlanResponse),
+        (status = 400, description = "Missing comment, or submitting a plan without events", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only chapter members submit; only Chapter Leads of other chapters review", body = ErrorResponse),
+        (status = 404, description = "Plan not found", body = ErrorResponse),
+        (status = 409, description = "Action not allowed in the plan's current status", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn transition_plan(
+    State(state): State<AppState>,
+    Path(plan_id): Path<i32>,
+    headers: HeaderMap,
+    Json(req): Json<PlanTransitionRequest>,
+) -> ApiResult<QuarterlyPlanResponse> {
+    let pool = &state.pool;
+    let actor = signed_in_user(pool, &headers).await?;
+    println!("🗂️ PLAN {} TRANSITION: {:?} by {}", plan_id, req.action, actor.wallet_address);
+    info!("Plan {} transition requested: {:?}", plan_id, req.action);
+
+    let plan = find_plan(pool, plan_id).await?;
+    let status = plan_status(&plan);
+    let next = status.apply(req.action).ok_or_else(|| {
+        api_error(StatusCode::CONFLICT, format!("Cannot {:?} a plan that is {}", req.action, status))
+    })?;
+
+    let comment = req.comment.as_deref().map(str::trim).filter(|comment| !comment.is_empty());
+
+    match req.action {
+        PlanAction::Submit => {
+            let is_member = ChapterRepository::is_member(pool, plan.chapter_id, actor.id)
+                .await
+                .map_err(|e| database_error("checking chapter membership", e))?;
+            if !is_member {
+                return Err(api_error(StatusCode::FORBIDDEN, "Only members of the chapter can submit its plan"));
+            }
+
+            let event_count = PlanRepository::count_events(pool, plan.id)
+                .await
+                .map_err(|e| database_error("counting plan events", e))?;
+            if event_count == 0 {
+                return Err(field_error("event_ids", "Add at least one event before submitting the plan"));
+            }
+        }
+        PlanAction::Approve | PlanAction::RequestChanges => {
+            let chapter = load_chapter(pool, plan.chapter_id).await?;
+            let is_member = ChapterRepository::is_member(pool, plan.chapter_id, actor.id)
+                .await
+                .map_err(|e| database_error("checking chapter membership", e))?;
+            if actor.user_type != "ChapterLead"
+                || is_member
+                || chapter.is_some_and(|chapter| chapter.lead_id == Some(actor.id))
+            {
+                return Err(api_error(
+                    StatusCode::FORBIDDEN,
+                    "Plans are reviewed by Chapter Leads of other chapters",
+                ));
+            }
+
+            if req.action == PlanAction::RequestChanges && comment.is_none() {
+                return Err(field_error("comment", "Explain which changes are needed"));
+            }
+        }
+    }
+
+    let changed = PlanRepository::transition(pool, plan.id, &status.to_string(), &next.to_string(), actor.id, comment)
+        .await
+        .map_err(|e| database_error("changing plan status", e))?;
+    if !changed {
+        return Err(api_error(StatusCode::CONFLICT, "The plan was changed by someone else; reload and try again"));
+    }
+
+    println!("✅ PLAN {} is now {}", plan.id, next);
+    let plan = find_plan(pool, plan_id).await?;
+    if next == PlanStatus::Approved {
+        if let Some(chapter) = load_chapter(pool, plan.chapter_id).await? {
+            publish(&state, DomainEvent::PlanApproved { plan: plan.clone(), chapter }).await;
+        }
+    }
+    Ok((StatusCode::OK, Json(plan_response(pool, &plan).await?)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/plans/{plan_id}/comments",
+    tag = "plans",
+    params(("plan_id" = i32, Path, description = "Plan id")),
+    request_body = PlanCommentRequest,
+    responses(
+        (status = 201, description = "Comment added", body = PlanComment),
+        (status = 400, description = "Empty comment", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 404, description = "Plan not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn add_plan_comment(
+    State(pool): State<DbPool>,
+    Path(plan_id): Path<i32>,
+    headers: HeaderMap,
+    Json(req): Json<PlanCommentRequest>,
+) -> ApiResult<PlanComment> {
+    let author = signed_in_user(&pool, &headers).await?;
+    let plan = find_plan(&pool, plan_id).await?;
+
+    if req.body.trim().is_empty() {
+        return Err(field_error("body", "Comment cannot be empty"));
+    }
+
+    let comment = PlanRepository::add_comment(&pool, plan.id, author.id, req.body.trim())
+        .await
+        .map_err(|e| database_error("adding plan comment", e))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(PlanComment {
+            id: comment.id.to_string(),
+            author: Some(create_user_public(&author)),
+            body: comment.body,
+            created_at: comment.created_at,
+        }),
+    ))
+}
\ No newline at end of file
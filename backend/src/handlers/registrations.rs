@@ -0,0 +1,196 @@
+use crate::database::connection::DbPool;
+use crate::database::models::{Event, Registration};
+use crate::database::repositories::{RegistrationRepository, UserRepository};
+use crate::handlers::auth::create_user_public;
+use crate::handlers::budgets::find_event;
+use crate::handlers::chapters::database_error;
+use crate::handlers::error::{api_error, ApiError, ApiResult};
+use crate::handlers::events::{create_event_response, event_status, organizer_username};
+use crate::handlers::plans::find_actor;
+use crate::notifications::{publish, DomainEvent};
+use crate::state::AppState;
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::StatusCode,
+};
+use chrono::Utc;
+use serde::Deserialize;
+use shared::dto::{
+    ErrorResponse, EventRegistrationsResponse, EventStatus, RegistrationRequest, RegistrationResponse, RegistrationStatus,
+};
+use tracing::{info, warn};
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+pub struct RegistrationActorQuery {
+    /// Wallet address of the registrant or the event organizer
+    pub actor_wallet_address: String,
+}
+
+fn registration_status(registration: &Registration) -> RegistrationStatus {
+    RegistrationStatus::from_name(&registration.status).unwrap_or(RegistrationStatus::Cancelled)
+}
+
+async fn registration_response(pool: &DbPool, registration: &Registration) -> Result<RegistrationResponse, ApiError> {
+    let attendee = UserRepository::find_by_id(pool, registration.user_id)
+        .await
+        .map_err(|e| database_error("loading attendee", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "The attendee no longer exists"))?;
+
+    Ok(RegistrationResponse {
+        id: registration.id.to_string(),
+        event_id: registration.event_id.to_string(),
+        attendee: create_user_public(&attendee),
+        status: registration_status(registration),
+        registered_at: registration.registered_at,
+        promoted_at: registration.promoted_at,
+        cancelled_at: registration.cancelled_at,
+    })
+}
+
+async fn registrations_response(pool: &DbPool, event: &Event) -> Result<EventRegistrationsResponse, ApiError> {
+    let mut registrations = Vec::new();
+    for registration in RegistrationRepository::list_active(pool, event.id)
+        .await
+        .map_err(|e| database_error("loading registrations", e))?
+    {
+        registrations.push(registration_response(pool, &registration).await?);
+    }
+    let count = |status| registrations.iter().filter(|registration| registration.status == status).count() as u32;
+
+    Ok(EventRegistrationsResponse {
+        event: create_event_response(event, &organizer_username(pool, event.organizer_id).await),
+        registered: count(RegistrationStatus::Registered),
+        waitlisted: count(RegistrationStatus::Waitlisted),
+        registrations,
+    })
+}
+
+/// Fills the event's free places from its waitlist and tells the people promoted.
+pub(crate) async fn promote_waitlist(state: &AppState, event: &Event) {
+    let promoted = match RegistrationRepository::promote_waitlisted(&state.pool, event.id).await {
+        Ok(promoted) => promoted,
+        Err(e) => {
+            warn!("Could not promote the waitlist of event {}: {}", event.id, e);
+            return;
+        }
+    };
+
+    for registration in promoted {
+        info!("User {} promoted from the waitlist of event {}", registration.user_id, event.id);
+        publish(state, DomainEvent::WaitlistPromoted { event: event.clone(), user_id: registration.user_id }).await;
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}/registrations",
+    tag = "registrations",
+    params(("event_id" = i32, Path, description = "Event id")),
+    responses(
+        (status = 200, description = "The event with its registered and waitlisted users", body = EventRegistrationsResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn list_registrations(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+) -> ApiResult<EventRegistrationsResponse> {
+    let event = find_event(&state.pool, event_id).await?;
+    Ok((StatusCode::OK, Json(registrations_response(&state.pool, &event).await?)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/registrations",
+    tag = "registrations",
+    params(("event_id" = i32, Path, description = "Event id")),
+    request_body = RegistrationRequest,
+    responses(
+        (status = 201, description = "Registered, or waitlisted when the event is full", body = RegistrationResponse),
+        (status = 400, description = "Unknown user", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 409, description = "Event not published or already started, or the user is already registered or waitlisted", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn register_for_event(
+    State(state): State<AppState>,
+    Path(event_id): Path<i32>,
+    Json(req): Json<RegistrationRequest>,
+) -> ApiResult<RegistrationResponse> {
+    let event = find_event(&state.pool, event_id).await?;
+    let user = find_actor(&state.pool, &req.wallet_address, "wallet_address").await?;
+
+    if event_status(&event) != EventStatus::Published {
+        return Err(api_error(StatusCode::CONFLICT, "Registration opens once the event is published"));
+    }
+    if event.date <= Utc::now() {
+        return Err(api_error(StatusCode::CONFLICT, "The event has already started"));
+    }
+
+    let registration = RegistrationRepository::register(&state.pool, event.id, user.id)
+        .await
+        .map_err(|e| database_error("registering", e))?
+        .ok_or_else(|| api_error(StatusCode::CONFLICT, "You are already registered or on the waitlist"))?;
+
+    let status = registration_status(&registration);
+    println!("🎫 {} is {} for event {}", user.username, status, event.id);
+    info!("User {} {} for event {}", user.id, status, event.id);
+
+    if status == RegistrationStatus::Registered {
+        publish(&state, DomainEvent::RegistrationConfirmed { event: event.clone(), user_id: user.id }).await;
+    }
+
+    Ok((StatusCode::CREATED, Json(registration_response(&state.pool, &registration).await?)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/events/{event_id}/registrations/{registration_id}",
+    tag = "registrations",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("registration_id" = i32, Path, description = "Registration id"),
+        RegistrationActorQuery,
+    ),
+    responses(
+        (status = 200, description = "Registration cancelled; the next person on the waitlist takes the place", body = RegistrationResponse),
+        (status = 400, description = "Unknown actor", body = ErrorResponse),
+        (status = 403, description = "Only the registrant or the organizer cancels a registration", body = ErrorResponse),
+        (status = 404, description = "Event or registration not found", body = ErrorResponse),
+        (status = 409, description = "The registration is already cancelled", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn cancel_registration(
+    State(state): State<AppState>,
+    Path((event_id, registration_id)): Path<(i32, i32)>,
+    Query(params): Query<RegistrationActorQuery>,
+) -> ApiResult<RegistrationResponse> {
+    let event = find_event(&state.pool, event_id).await?;
+    let registration = RegistrationRepository::find(&state.pool, event.id, registration_id)
+        .await
+        .map_err(|e| database_error("loading registration", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "Registration not found"))?;
+    let actor = find_actor(&state.pool, &params.actor_wallet_address, "actor_wallet_address").await?;
+
+    if actor.id != registration.user_id && actor.id != event.organizer_id {
+        return Err(api_error(StatusCode::FORBIDDEN, "Only the registrant or the organizer cancels a registration"));
+    }
+
+    let cancelled = RegistrationRepository::cancel(&state.pool, registration.id)
+        .await
+        .map_err(|e| database_error("cancelling registration", e))?
+        .ok_or_else(|| api_error(StatusCode::CONFLICT, "The registration is already cancelled"))?;
+
+    println!("🎫 Registration {} for event {} cancelled by {}", cancelled.id, event.id, actor.username);
+    info!("Registration {} for event {} cancelled", cancelled.id, event.id);
+
+    if registration_status(&registration) == RegistrationStatus::Registered && event_status(&event) == EventStatus::Published {
+        promote_waitlist(&state, &event).await;
+    }
+
+    Ok((StatusCode::OK, Json(registration_response(&state.pool, &cancelled).await?)))
+}
\ No newline at end of file
@@ -0,0 +1,265 @@
+use crate::config::StellarConfig;
+use crate::database::connection::DbPool;
+use crate::database::models::{BudgetItem, Reimbursement, User};
+use crate::database::repositories::{ReimbursementRepository, UserRepository};
+use crate::handlers::auth::signed_in_user;
+use crate::handlers::budgets::{find_event, find_item, item_currency};
+use crate::handlers::chapters::database_error;
+use crate::handlers::error::{api_error, field_error, ApiError, ApiResult};
+use crate::notifications::{publish, DomainEvent};
+use crate::state::AppState;
+use crate::stellar::{build_payment, submit_payment, verify_signed, HorizonError, PayoutAsset, SubmissionOutcome};
+use axum::{
+    extract::{Json, Path, State},
+    http::{HeaderMap, StatusCode},
+};
+use chrono::Utc;
+use shared::dto::{
+    Currency, ErrorResponse, Money, ReimbursementResponse, ReimbursementStatus, SubmitReimbursementRequest,
+};
+use tracing::info;
+
+pub(crate) fn reimbursement_status(reimbursement: &Reimbursement) -> ReimbursementStatus {
+    ReimbursementStatus::from_name(&reimbursement.status).unwrap_or(ReimbursementStatus::AwaitingSignature)
+}
+
+fn reimbursement_response(config: &StellarConfig, reimbursement: &Reimbursement) -> ReimbursementResponse {
+    let currency = Currency::from_name(&reimbursement.asset).unwrap_or(Currency::USDC);
+    ReimbursementResponse {
+        id: reimbursement.id.to_string(),
+        budget_item_id: reimbursement.budget_item_id.to_string(),
+        recipient_wallet_address: reimbursement.recipient_wallet_address.clone(),
+        amount: Money::new(reimbursement.amount, currency),
+        status: reimbursement_status(reimbursement),
+        source_account: config.payout_account.clone().unwrap_or_default(),
+        transaction_xdr: reimbursement.transaction_xdr.clone(),
+        transaction_hash: reimbursement.transaction_hash.clone(),
+        network_passphrase: config.network_passphrase.clone(),
+        ledger: reimbursement.ledger.map(|ledger| ledger as u32),
+        error: reimbursement.error.clone(),
+        submitted_at: reimbursement.submitted_at,
+        paid_at: reimbursement.paid_at,
+        updated_at: reimbursement.updated_at,
+    }
+}
+
+async fn find_reimbursement(pool: &DbPool, item: &BudgetItem) -> Result<Reimbursement, ApiError> {
+    ReimbursementRepository::find_for_item(pool, item.id)
+        .await
+        .map_err(|e| database_error("loading reimbursement", e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, "No reimbursement has been prepared for this item"))
+}
+
+async fn find_admin(state: &AppState, headers: &HeaderMap) -> Result<User, ApiError> {
+    let actor = signed_in_user(&state.pool, headers).await?;
+    if !state.stellar.is_admin(&actor.wallet_address) {
+        return Err(api_error(StatusCode::FORBIDDEN, "Only programme admins pay out reimbursements"));
+    }
+    Ok(actor)
+}
+
+fn horizon_error(error: HorizonError) -> ApiError {
+    api_error(StatusCode::BAD_GATEWAY, error.to_string())
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}/budget/{item_id}/reimbursement",
+    tag = "reimbursements",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("item_id" = i32, Path, description = "Budget item id"),
+    ),
+    responses(
+        (status = 200, description = "The item's payout and where it is", body = ReimbursementResponse),
+        (status = 404, description = "Event, item or reimbursement not found", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    )
+)]
+pub async fn get_reimbursement(
+    State(state): State<AppState>,
+    Path((event_id, item_id)): Path<(i32, i32)>,
+) -> ApiResult<ReimbursementResponse> {
+    find_event(&state.pool, event_id).await?;
+    let item = find_item(&state.pool, event_id, item_id).await?;
+    let reimbursement = find_reimbursement(&state.pool, &item).await?;
+
+    Ok((StatusCode::OK, Json(reimbursement_response(&state.stellar, &reimbursement))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/budget/{item_id}/reimbursement",
+    tag = "reimbursements",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("item_id" = i32, Path, description = "Budget item id"),
+    ),
+    responses(
+        (status = 201, description = "Unsigned payment of the spending, up to the approved amount, to the organizer's wallet; replaces a payout that was never submitted or failed", body = ReimbursementResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only programme admins pay out reimbursements", body = ErrorResponse),
+        (status = 404, description = "Event or item not found", body = ErrorResponse),
+        (status = 409, description = "Not in USDC or XLM, nothing approved and spent, organizer wallet unusable, or already submitted or paid", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+        (status = 502, description = "Horizon could not load the payout account", body = ErrorResponse),
+        (status = 503, description = "No payout account is configured", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn prepare_reimbursement(
+    State(state): State<AppState>,
+    Path((event_id, item_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> ApiResult<ReimbursementResponse> {
+    let admin = find_admin(&state, &headers).await?;
+    println!("💸 REIMBURSEMENT for event {} item {} prepared by {}", event_id, item_id, admin.wallet_address);
+    info!("Preparing reimbursement of budget item {}", item_id);
+
+    let event = find_event(&state.pool, event_id).await?;
+    let item = find_item(&state.pool, event_id, item_id).await?;
+    let payout_account = state
+        .stellar
+        .payout_account
+        .as_deref()
+        .ok_or_else(|| api_error(StatusCode::SERVICE_UNAVAILABLE, "Stellar payouts are not configured on this server"))?;
+
+    let currency = item_currency(&item)?;
+    let asset = match currency {
+        Currency::USDC => PayoutAsset::Credit { code: "USDC".to_string(), issuer: state.stellar.usdc_issuer.clone() },
+        Currency::XLM => PayoutAsset::Native,
+        _ => {
+            return Err(api_error(
+                StatusCode::CONFLICT,
+                format!("Spending in {} is reimbursed off-chain; only USDC and XLM are paid on Stellar", currency),
+            ))
+        }
+    };
+    let (Some(approved), Some(spent)) = (item.approved_amount, item.spent_amount) else {
+        return Err(api_error(StatusCode::CONFLICT, "Only items with an approved amount and recorded spending are reimbursed"));
+    };
+    let amount = spent.min(approved);
+    if amount.is_zero() {
+        return Err(api_error(StatusCode::CONFLICT, "There is nothing to reimburse"));
+    }
+
+    if let Some(existing) = ReimbursementRepository::find_for_item(&state.pool, item.id)
+        .await
+        .map_err(|e| database_error("loading reimbursement", e))?
+    {
+        let status = reimbursement_status(&existing);
+        if matches!(status, ReimbursementStatus::Submitted | ReimbursementStatus::Paid) {
+            return Err(api_error(StatusCode::CONFLICT, format!("The reimbursement is already {}", status)));
+        }
+    }
+
+    let organizer = UserRepository::find_by_id(&state.pool, event.organizer_id)
+        .await
+        .map_err(|e| database_error("loading organizer", e))?
+        .ok_or_else(|| api_error(StatusCode::CONFLICT, "The event's organizer no longer exists"))?;
+
+    let sequence = state.horizon.account_sequence(payout_account).await.map_err(horizon_error)?;
+    let payment = build_payment(
+        &state.stellar.network_passphrase,
+        payout_account,
+        sequence,
+        &organizer.wallet_address,
+        &asset,
+        amount,
+        &format!("Reimbursement {}", item.id),
+        Utc::now(),
+    )
+    .map_err(|e| api_error(StatusCode::CONFLICT, e.to_string()))?;
+
+    let reimbursement = ReimbursementRepository::prepare(
+        &state.pool,
+        item.id,
+        &organizer.wallet_address,
+        &currency.to_string(),
+        amount,
+        admin.id,
+        &payment.envelope_xdr,
+        &payment.hash,
+    )
+    .await
+    .map_err(|e| database_error("saving reimbursement", e))?
+    .ok_or_else(|| api_error(StatusCode::CONFLICT, "The reimbursement was submitted in the meantime"))?;
+
+    Ok((StatusCode::CREATED, Json(reimbursement_response(&state.stellar, &reimbursement))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/budget/{item_id}/reimbursement/submit",
+    tag = "reimbursements",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+        ("item_id" = i32, Path, description = "Budget item id"),
+    ),
+    request_body = SubmitReimbursementRequest,
+    responses(
+        (status = 200, description = "Submitted to the network: Paid, Failed, or still Submitted if Horizon gave no definite answer", body = ReimbursementResponse),
+        (status = 400, description = "Not the prepared transaction, or not signed", body = ErrorResponse),
+        (status = 401, description = "Not signed in, or the session has expired", body = ErrorResponse),
+        (status = 403, description = "Only programme admins pay out reimbursements", body = ErrorResponse),
+        (status = 404, description = "Event, item or reimbursement not found", body = ErrorResponse),
+        (status = 409, description = "The reimbursement is already paid or failed", body = ErrorResponse),
+        (status = 500, description = "Database error", body = ErrorResponse),
+    ),
+    security(("session" = []))
+)]
+pub async fn submit_reimbursement(
+    State(state): State<AppState>,
+    Path((event_id, item_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+    Json(req): Json<SubmitReimbursementRequest>,
+) -> ApiResult<ReimbursementResponse> {
+    let admin = find_admin(&state, &headers).await?;
+    println!("🚀 REIMBURSEMENT SUBMISSION for event {} item {} by {}", event_id, item_id, admin.wallet_address);
+
+    let event = find_event(&state.pool, event_id).await?;
+    let item = find_item(&state.pool, event_id, item_id).await?;
+    let reimbursement = find_reimbursement(&state.pool, &item).await?;
+
+    let status = reimbursement_status(&reimbursement);
+    if !matches!(status, ReimbursementStatus::AwaitingSignature | ReimbursementStatus::Submitted) {
+        return Err(api_error(StatusCode::CONFLICT, format!("The reimbursement is already {}", status)));
+    }
+    verify_signed(&reimbursement.transaction_xdr, &req.signed_transaction_xdr)
+        .map_err(|e| field_error("signed_transaction_xdr", e.to_string()))?;
+
+    ReimbursementRepository::mark_submitted(&state.pool, reimbursement.id, req.signed_transaction_xdr.trim())
+        .await
+        .map_err(|e| database_error("recording submission", e))?
+        .ok_or_else(|| api_error(StatusCode::CONFLICT, "The reimbursement changed in the meantime"))?;
+
+    let outcome = submit_payment(
+        state.horizon.as_ref(),
+        &reimbursement.transaction_hash,
+        &req.signed_transaction_xdr,
+        status == ReimbursementStatus::Submitted,
+    )
+    .await;
+    println!("💸 REIMBURSEMENT {} outcome: {:?}", reimbursement.id, outcome);
+    info!("Reimbursement {} submitted: {:?}", reimbursement.id, outcome);
+
+    let updated = match &outcome {
+        SubmissionOutcome::Paid { ledger } => ReimbursementRepository::mark_paid(&state.pool, reimbursement.id, *ledger as i32).await,
+        SubmissionOutcome::Failed(error) => ReimbursementRepository::mark_failed(&state.pool, reimbursement.id, error).await,
+        SubmissionOutcome::Pending(error) => ReimbursementRepository::record_pending(&state.pool, reimbursement.id, error).await,
+    }
+    .map_err(|e| database_error("recording submission outcome", e))?;
+
+    // An overlapping submission may have settled the payout first, and told the organizer
+    let reimbursement = match updated {
+        Some(reimbursement) => {
+            if reimbursement_status(&reimbursement) == ReimbursementStatus::Paid {
+                publish(&state, DomainEvent::ExpensePaid { event, item: Box::new(item.clone()), reimbursement: Box::new(reimbursement.clone()) }).await;
+            }
+            reimbursement
+        }
+        None => find_reimbursement(&state.pool, &item).await?,
+    };
+
+    Ok((StatusCode::OK, Json(reimbursement_response(&state.stellar, &reimbursement))))
+}
\ No newline at end of file
@@ -0,0 +1,229 @@
+use crate::database::models::Event;
+use chrono::{DateTime, Datelike, Duration, Offset, TimeZone, Utc};
+use chrono_tz::{OffsetComponents, OffsetName, Tz};
+
+const PRODID: &str = "-//Stellar Europe//Events//EN";
+const UID_DOMAIN: &str = "stellareurope.org";
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Renders events as an RFC 5545 calendar, with a VTIMEZONE for every zone the
+/// events are scheduled in.
+pub fn render_calendar(name: &str, events: &[Event]) -> String {
+    let mut writer = ICalWriter::default();
+    writer.line("BEGIN", "VCALENDAR");
+    writer.line("VERSION", "2.0");
+    writer.line("PRODID", PRODID);
+    writer.line("CALSCALE", "GREGORIAN");
+    writer.line("METHOD", "PUBLISH");
+    writer.line("X-WR-CALNAME", &escape_text(name));
+    writer.line("REFRESH-INTERVAL;VALUE=DURATION", "PT1H");
+    writer.line("X-PUBLISHED-TTL", "PT1H");
+
+    let mut zones: Vec<Tz> = events.iter().filter_map(event_zone).collect();
+    zones.sort_by_key(|zone| zone.name());
+    zones.dedup();
+    for zone in zones {
+        let zone_events = events.iter().filter(|event| event_zone(event) == Some(zone));
+        let from = zone_events.clone().map(|event| event.date).min();
+        let to = zone_events.map(|event| event.end_date).max();
+        if let (Some(from), Some(to)) = (from, to) {
+            write_timezone(&mut writer, zone, from, to);
+        }
+    }
+
+    for event in events {
+        write_event(&mut writer, event);
+    }
+
+    writer.line("END", "VCALENDAR");
+    writer.finish()
+}
+
+pub fn event_uid(event_id: i32) -> String {
+    format!("event-{}@{}", event_id, UID_DOMAIN)
+}
+
+/// The event's zone, or `None` for UTC and unknown zones, which are written as UTC times.
+fn event_zone(event: &Event) -> Option<Tz> {
+    match event.timezone.parse::<Tz>() {
+        Ok(Tz::UTC) | Ok(Tz::Etc__UTC) | Err(_) => None,
+        Ok(zone) => Some(zone),
+    }
+}
+
+fn write_event(writer: &mut ICalWriter, event: &Event) {
+    writer.line("BEGIN", "VEVENT");
+    writer.line("UID", &event_uid(event.id));
+    // Calendar clients replace an entry they already have only when SEQUENCE goes up, or
+    // for the same SEQUENCE, when DTSTAMP is later
+    writer.line("DTSTAMP", &format_utc(&event.updated_at));
+    if let Some(created_at) = &event.created_at {
+        writer.line("CREATED", &format_utc(created_at));
+    }
+    writer.line("LAST-MODIFIED", &format_utc(&event.updated_at));
+    writer.line("SEQUENCE", &event.sequence.to_string());
+
+    match event_zone(event) {
+        Some(zone) => {
+            let tzid = format!("TZID={}", zone.name());
+            writer.line(&format!("DTSTART;{}", tzid), &format_local(&event.date.with_timezone(&zone)));
+            writer.line(&format!("DTEND;{}", tzid), &format_local(&event.end_date.with_timezone(&zone)));
+        }
+        None => {
+            writer.line("DTSTART", &format_utc(&event.date));
+            writer.line("DTEND", &format_utc(&event.end_date));
+        }
+    }
+
+    writer.line("SUMMARY", &escape_text(&event.title));
+    writer.line("STATUS", if event.status == "Cancelled" { "CANCELLED" } else { "CONFIRMED" });
+
+    let mut description = event.description.clone();
+    if let Some(reason) = &event.cancellation_reason {
+        description.insert_str(0, &format!("Cancelled: {}\n\n", reason));
+    }
+    if let Some(link) = &event.external_link {
+        description.push_str(&format!("\n\nMore info: {}", link));
+    }
+    writer.line("DESCRIPTION", &escape_text(&description));
+    writer.line("LOCATION", &escape_text(&event.location));
+    if let Some(link) = &event.external_link {
+        writer.line("URL", link);
+    }
+    writer.line("ORGANIZER", &format!("mailto:{}", event.contact_email));
+
+    let mut categories = vec![escape_text(&event.event_type)];
+    categories.extend(
+        event.strategic_focus_areas.iter().flatten().map(|area| escape_text(area)),
+    );
+    writer.line("CATEGORIES", &categories.join(","));
+
+    writer.line("END", "VEVENT");
+}
+
+/// Writes the zone's offsets in effect between `from` and `to` (padded to whole
+/// years) as explicit STANDARD/DAYLIGHT observances, one per transition.
+fn write_timezone(writer: &mut ICalWriter, zone: Tz, from: DateTime<Utc>, to: DateTime<Utc>) {
+    let range_start = Utc.with_ymd_and_hms(from.year(), 1, 1, 0, 0, 0).unwrap();
+    let range_end = Utc.with_ymd_and_hms(to.year() + 1, 1, 1, 0, 0, 0).unwrap();
+
+    writer.line("BEGIN", "VTIMEZONE");
+    writer.line("TZID", zone.name());
+
+    let initial = zone.offset_from_utc_datetime(&range_start.naive_utc());
+    write_observance(writer, &range_start, &initial, &initial);
+
+    for (instant, before, after) in transitions(zone, range_start, range_end) {
+        write_observance(writer, &instant, &before, &after);
+    }
+
+    writer.line("END", "VTIMEZONE");
+}
+
+fn write_observance(
+    writer: &mut ICalWriter,
+    instant: &DateTime<Utc>,
+    before: &<Tz as TimeZone>::Offset,
+    after: &<Tz as TimeZone>::Offset,
+) {
+    let kind = if after.dst_offset().is_zero() { "STANDARD" } else { "DAYLIGHT" };
+    let from_secs = before.fix().local_minus_utc();
+    let to_secs = after.fix().local_minus_utc();
+
+    writer.line("BEGIN", kind);
+    // DTSTART of an observance is local time under the offset being left
+    let onset = instant.naive_utc() + Duration::seconds(from_secs as i64);
+    writer.line("DTSTART", &onset.format("%Y%m%dT%H%M%S").to_string());
+    writer.line("TZOFFSETFROM", &format_offset(from_secs));
+    writer.line("TZOFFSETTO", &format_offset(to_secs));
+    if let Some(abbreviation) = after.abbreviation() {
+        writer.line("TZNAME", &escape_text(abbreviation));
+    }
+    writer.line("END", kind);
+}
+
+/// Instants in `[from, to)` where the zone's UTC offset changes, to the second.
+fn transitions(
+    zone: Tz,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<(DateTime<Utc>, <Tz as TimeZone>::Offset, <Tz as TimeZone>::Offset)> {
+    let offset_at = |instant: DateTime<Utc>| zone.offset_from_utc_datetime(&instant.naive_utc());
+    let mut found = Vec::new();
+    let mut day = from;
+
+    while day < to {
+        let next_day = day + Duration::days(1);
+        let before = offset_at(day);
+        let after = offset_at(next_day);
+
+        if before.fix() != after.fix() {
+            let (mut low, mut high) = (day, next_day);
+            while high - low > Duration::seconds(1) {
+                let mid = low + (high - low) / 2;
+                if offset_at(mid).fix() == before.fix() {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            found.push((high, before, after));
+        }
+
+        day = next_day;
+    }
+
+    found
+}
+
+fn format_utc(instant: &DateTime<Utc>) -> String {
+    instant.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn format_local(instant: &DateTime<Tz>) -> String {
+    instant.format("%Y%m%dT%H%M%S").to_string()
+}
+
+fn format_offset(seconds: i32) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.abs();
+    format!("{}{:02}{:02}", sign, seconds / 3600, (seconds % 3600) / 60)
+}
+
+/// Escapes a TEXT value (RFC 5545 §3.3.11).
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+#[derive(Default)]
+struct ICalWriter {
+    output: String,
+}
+
+impl ICalWriter {
+    /// Appends a content line, folded to 75 octets with CRLF line endings.
+    fn line(&mut self, name: &str, value: &str) {
+        let line = format!("{}:{}", name, value);
+        let mut octets = 0;
+
+        for ch in line.chars() {
+            if octets + ch.len_utf8() > MAX_LINE_OCTETS {
+                self.output.push_str("\r\n ");
+                octets = 1;
+            }
+            self.output.push(ch);
+            octets += ch.len_utf8();
+        }
+
+        self.output.push_str("\r\n");
+    }
+
+    fn finish(self) -> String {
+        self.output
+    }
+}
\ No newline at end of file
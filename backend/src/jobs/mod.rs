@@ -0,0 +1,91 @@
+pub mod worker;
+
+pub use worker::*;
+
+use crate::config::JobsConfig;
+use crate::database::models::QueuedJob;
+use crate::database::repositories::JobRepository;
+use crate::mail::SendEmail;
+use crate::notifications::{schedule_upcoming_reminders, PublishNotification, SendEventReminder};
+use crate::state::AppState;
+use crate::verification::VerifyLinkedAccounts;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{de::DeserializeOwned, Serialize};
+use sqlx::{Error as SqlxError, PgExecutor};
+use thiserror::Error;
+
+/// Wait before the first retry of a failed job; it doubles with each further attempt.
+pub const RETRY_BASE_DELAY: Duration = Duration::seconds(30);
+/// Longest wait between two attempts.
+pub const MAX_RETRY_DELAY: Duration = Duration::hours(1);
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum JobError {
+    /// Worth trying again later, e.g. a service could not be reached
+    #[error("{0}")]
+    Transient(String),
+    /// Would fail the same way every time, so the job is dead-lettered at once
+    #[error("{0}")]
+    Permanent(String),
+}
+
+impl From<SqlxError> for JobError {
+    fn from(error: SqlxError) -> Self {
+        JobError::Transient(format!("Database error: {}", error))
+    }
+}
+
+/// Work done in the background by a [`Worker`]. A job is stored as JSON, so it carries
+/// everything it needs, or the ids to load it by.
+#[async_trait]
+pub trait Job: Serialize + DeserializeOwned + Send + Sync + 'static {
+    /// Names the job in the queue. Jobs already queued are found by it, so it must not change.
+    const KIND: &'static str;
+    /// Runs before the job is dead-lettered.
+    const MAX_ATTEMPTS: i32 = 5;
+
+    /// Jobs with the same key replace each other while queued, e.g. to move a job to
+    /// another time. Without one, every job queued runs.
+    fn unique_key(&self) -> Option<String> {
+        None
+    }
+
+    async fn run(&self, state: &AppState) -> Result<(), JobError>;
+}
+
+/// Queues `job` to run as soon as a worker is free. `executor` is the pool, or a
+/// transaction the job is queued with.
+pub async fn enqueue<J: Job>(executor: impl PgExecutor<'_>, job: &J) -> Result<QueuedJob, SqlxError> {
+    schedule(executor, job, Utc::now()).await
+}
+
+/// Queues `job` to run from `run_at`, replacing a queued job with the same key.
+pub async fn schedule<J: Job>(executor: impl PgExecutor<'_>, job: &J, run_at: DateTime<Utc>) -> Result<QueuedJob, SqlxError> {
+    let payload = serde_json::to_value(job).map_err(|e| SqlxError::Encode(Box::new(e)))?;
+    JobRepository::enqueue(executor, J::KIND, &payload, job.unique_key().as_deref(), J::MAX_ATTEMPTS, run_at).await
+}
+
+/// How long to wait before running a job again after its `attempts`-th run failed.
+pub fn retry_delay(attempts: i32) -> Duration {
+    let doublings = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    (RETRY_BASE_DELAY * 2_i32.pow(doublings)).min(MAX_RETRY_DELAY)
+}
+
+/// A worker for every job the backend defines.
+pub fn worker(state: AppState, config: &JobsConfig) -> Worker {
+    Worker::new(state, config)
+        .register::<SendEmail>()
+        .register::<PublishNotification>()
+        .register::<SendEventReminder>()
+        .register::<VerifyLinkedAccounts>()
+}
+
+/// Queues the first run of the jobs that schedule their own next run, or moves it
+/// forward to now if one is already queued, and the reminders of upcoming events at the
+/// times currently set.
+pub async fn schedule_recurring(state: &AppState) -> Result<(), SqlxError> {
+    enqueue(&state.pool, &VerifyLinkedAccounts).await?;
+    schedule_upcoming_reminders(state).await?;
+    Ok(())
+}
\ No newline at end of file
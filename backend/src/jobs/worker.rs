@@ -0,0 +1,171 @@
+use super::{retry_delay, Job, JobError};
+use crate::config::JobsConfig;
+use crate::database::repositories::JobRepository;
+use crate::state::AppState;
+use chrono::{DateTime, Utc};
+use sqlx::Error as SqlxError;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+type JobFuture = Pin<Box<dyn Future<Output = Result<(), JobError>> + Send>>;
+type Handler = Box<dyn Fn(AppState, serde_json::Value) -> JobFuture + Send + Sync>;
+
+/// What became of a job after one run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobOutcome {
+    /// Ran successfully and left the queue
+    Succeeded,
+    /// Failed and is queued to run again
+    Retrying { run_at: DateTime<Utc>, error: String },
+    /// Failed, but a job with the same key was queued meanwhile and runs instead
+    Superseded { error: String },
+    /// Failed for good and was kept as Dead
+    Dead { error: String },
+}
+
+/// One run of one job.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobRun {
+    pub id: i64,
+    pub kind: String,
+    /// Runs so far, this one included
+    pub attempts: i32,
+    pub outcome: JobOutcome,
+}
+
+/// Runs queued jobs of the kinds registered with it. Any number of workers, in this
+/// process or others, can share one queue.
+pub struct Worker {
+    state: AppState,
+    handlers: HashMap<&'static str, Handler>,
+    concurrency: usize,
+    poll_interval: Duration,
+    job_timeout: Duration,
+}
+
+impl Worker {
+    pub fn new(state: AppState, config: &JobsConfig) -> Self {
+        Self {
+            state,
+            handlers: HashMap::new(),
+            concurrency: config.concurrency.max(1),
+            poll_interval: config.poll_interval,
+            job_timeout: config.job_timeout,
+        }
+    }
+
+    /// Runs jobs of type `J`. Jobs of kinds no worker registered stay queued.
+    pub fn register<J: Job>(mut self) -> Self {
+        let handler: Handler = Box::new(|state, payload| {
+            Box::pin(async move {
+                let job: J = serde_json::from_value(payload)
+                    .map_err(|e| JobError::Permanent(format!("Malformed {} job: {}", J::KIND, e)))?;
+                job.run(&state).await
+            })
+        });
+        self.handlers.insert(J::KIND, handler);
+        self
+    }
+
+    /// Claims and runs the next job due at `now`, if there is one.
+    pub async fn run_once(&self, now: DateTime<Utc>) -> Result<Option<JobRun>, SqlxError> {
+        let kinds: Vec<String> = self.handlers.keys().map(|kind| kind.to_string()).collect();
+        let stale_before = now - chrono::Duration::from_std(self.job_timeout * 2).unwrap_or(chrono::Duration::hours(1));
+        let Some(job) = JobRepository::claim(&self.state.pool, &kinds, now, stale_before).await? else {
+            return Ok(None);
+        };
+
+        let handler = &self.handlers[job.kind.as_str()];
+        // Spawned, so a panicking job fails like any other instead of taking the worker down
+        let mut task = tokio::spawn(handler(self.state.clone(), job.payload));
+        let result = match tokio::time::timeout(self.job_timeout, &mut task).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => Err(JobError::Transient(format!("Job panicked: {}", e))),
+            Err(_) => {
+                task.abort();
+                Err(JobError::Transient(format!("Job did not finish within {}s", self.job_timeout.as_secs())))
+            }
+        };
+
+        let pool = &self.state.pool;
+        let outcome = match result {
+            Ok(()) => {
+                JobRepository::complete(pool, job.id, job.attempts).await?;
+                JobOutcome::Succeeded
+            }
+            Err(JobError::Transient(error)) if job.attempts < job.max_attempts => {
+                let run_at = now + retry_delay(job.attempts);
+                if JobRepository::retry(pool, job.id, job.attempts, &error, run_at).await? {
+                    JobOutcome::Retrying { run_at, error }
+                } else {
+                    JobRepository::complete(pool, job.id, job.attempts).await?;
+                    JobOutcome::Superseded { error }
+                }
+            }
+            Err(JobError::Transient(error)) | Err(JobError::Permanent(error)) => {
+                JobRepository::bury(pool, job.id, job.attempts, &error, now).await?;
+                JobOutcome::Dead { error }
+            }
+        };
+
+        Ok(Some(JobRun { id: job.id, kind: job.kind, attempts: job.attempts, outcome }))
+    }
+
+    /// Keeps running jobs, `concurrency` at a time, for as long as the process runs.
+    pub async fn run(self) {
+        let worker = Arc::new(self);
+        let kinds: Vec<&str> = worker.handlers.keys().copied().collect();
+        info!("Job worker running {} at a time: {}", worker.concurrency, kinds.join(", "));
+
+        let loops: Vec<_> = (0..worker.concurrency)
+            .map(|_| {
+                let worker = worker.clone();
+                tokio::spawn(async move { worker.poll().await })
+            })
+            .collect();
+        for handle in loops {
+            if let Err(e) = handle.await {
+                error!("Job worker stopped: {}", e);
+            }
+        }
+    }
+
+    /// Runs the worker in the background.
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+
+    async fn poll(&self) {
+        loop {
+            match self.run_once(Utc::now()).await {
+                Ok(Some(run)) => log_run(&run),
+                Ok(None) => tokio::time::sleep(self.poll_interval).await,
+                Err(e) => {
+                    warn!("Could not take a job from the queue: {}", e);
+                    tokio::time::sleep(self.poll_interval).await;
+                }
+            }
+        }
+    }
+}
+
+fn log_run(run: &JobRun) {
+    match &run.outcome {
+        JobOutcome::Succeeded => info!("Job {} ({}) done", run.id, run.kind),
+        JobOutcome::Retrying { run_at, error } => warn!(
+            "Job {} ({}) failed on attempt {}, retrying at {}: {}",
+            run.id, run.kind, run.attempts, run_at, error
+        ),
+        JobOutcome::Superseded { error } => {
+            warn!("Job {} ({}) failed and was replaced by a newer one: {}", run.id, run.kind, error)
+        }
+        JobOutcome::Dead { error } => {
+            println!("☠️ Job {} ({}) gave up after {} attempts: {}", run.id, run.kind, run.attempts, error);
+            error!("Job {} ({}) dead after {} attempts: {}", run.id, run.kind, run.attempts, error);
+        }
+    }
+}
\ No newline at end of file
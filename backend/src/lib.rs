@@ -1,5 +1,24 @@
+pub mod app;
+pub mod config;
 pub mod database;
+pub mod feed;
 pub mod handlers;
+pub mod jobs;
+pub mod ical;
+pub mod mail;
+pub mod media;
+pub mod middleware;
+pub mod notifications;
+pub mod openapi;
+pub mod routes;
+pub mod state;
+pub mod stellar;
+pub mod storage;
+pub mod verification;
+pub mod versioning;
 
+pub use app::*;
+pub use config::*;
 pub use database::*;
-pub use handlers::*;
\ No newline at end of file
+pub use handlers::*;
+pub use state::*;
\ No newline at end of file
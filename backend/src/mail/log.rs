@@ -0,0 +1,37 @@
+use super::{build_message, Email, MailError, Mailer};
+use async_trait::async_trait;
+use chrono::Utc;
+use lettre::message::Mailbox;
+use std::path::PathBuf;
+use tracing::info;
+use uuid::Uuid;
+
+/// Mail for development: logs each message instead of sending it, and writes it as an
+/// `.eml` file when given a directory, so links in it can be followed.
+pub struct LogMailer {
+    from: Mailbox,
+    dir: Option<PathBuf>,
+}
+
+impl LogMailer {
+    pub fn new(from: Mailbox, dir: Option<PathBuf>) -> Self {
+        Self { from, dir }
+    }
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, email: &Email) -> Result<(), MailError> {
+        let message = build_message(&self.from, email)?;
+        println!("📧 Mail to {}: {}", email.to, email.subject);
+        info!("Mail to {}: {}\n{}", email.to, email.subject, email.text);
+
+        if let Some(dir) = &self.dir {
+            let unavailable = |e: std::io::Error| MailError::Unavailable(format!("{}: {}", dir.display(), e));
+            tokio::fs::create_dir_all(dir).await.map_err(unavailable)?;
+            let name = format!("{}-{}.eml", Utc::now().format("%Y%m%dT%H%M%S"), Uuid::new_v4().simple());
+            tokio::fs::write(dir.join(name), message.formatted()).await.map_err(unavailable)?;
+        }
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,84 @@
+pub mod log;
+pub mod smtp;
+pub mod templates;
+
+pub use log::*;
+pub use smtp::*;
+pub use templates::*;
+
+use crate::config::{MailConfig, MailTransport};
+use crate::jobs::{Job, JobError};
+use crate::state::AppState;
+use async_trait::async_trait;
+use lettre::message::{Mailbox, MultiPart};
+use lettre::Message;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum MailError {
+    #[error("{0} is not an email address")]
+    InvalidAddress(String),
+    #[error("Mail could not be sent: {0}")]
+    Unavailable(String),
+}
+
+/// A message to one recipient, with a plain-text and an HTML body.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Email {
+    pub to: String,
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+/// Sends mail. Implementations decide how; the sender is theirs to set.
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, email: &Email) -> Result<(), MailError>;
+}
+
+/// Sends one message from the job queue, so it is tried again while the transport is down.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SendEmail {
+    pub email: Email,
+}
+
+#[async_trait]
+impl Job for SendEmail {
+    const KIND: &'static str = "send_email";
+    const MAX_ATTEMPTS: i32 = 8;
+
+    async fn run(&self, state: &AppState) -> Result<(), JobError> {
+        state.mailer.send(&self.email).await.map_err(|e| match e {
+            MailError::InvalidAddress(_) => JobError::Permanent(e.to_string()),
+            MailError::Unavailable(_) => JobError::Transient(e.to_string()),
+        })
+    }
+}
+
+pub fn mailbox(address: &str) -> Result<Mailbox, MailError> {
+    address.trim().parse().map_err(|_| MailError::InvalidAddress(address.to_string()))
+}
+
+/// The MIME message for `email`, sent by `from`.
+pub fn build_message(from: &Mailbox, email: &Email) -> Result<Message, MailError> {
+    Message::builder()
+        .from(from.clone())
+        .to(mailbox(&email.to)?)
+        .subject(&email.subject)
+        .multipart(MultiPart::alternative_plain_html(email.text.clone(), email.html.clone()))
+        .map_err(|e| MailError::Unavailable(e.to_string()))
+}
+
+pub fn open_mailer(config: &MailConfig) -> Result<Arc<dyn Mailer>, MailError> {
+    let from = mailbox(&config.from)?;
+    Ok(match &config.transport {
+        MailTransport::Log { dir } => Arc::new(LogMailer::new(from, dir.clone())),
+        MailTransport::Smtp { host, port, security, username, password } => {
+            let credentials = username.clone().map(|username| (username, password.clone().unwrap_or_default()));
+            Arc::new(SmtpMailer::new(from, host, *port, *security, credentials)?)
+        }
+    })
+}
\ No newline at end of file
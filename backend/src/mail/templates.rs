@@ -0,0 +1,44 @@
+use super::Email;
+
+/// A message with `{{name}}` placeholders in its subject and bodies. Values are escaped
+/// in the HTML body; unknown placeholders are left as they are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MailTemplate {
+    pub subject: &'static str,
+    pub text: &'static str,
+    pub html: &'static str,
+}
+
+/// Sent after signup and on request, with the link that verifies the address.
+/// Placeholders: `username`, `link` and `expires_at`.
+pub const VERIFY_EMAIL: MailTemplate = MailTemplate {
+    subject: "Verify your email address for Stellar Europe",
+    text: include_str!("../../templates/mail/verify_email.txt"),
+    html: include_str!("../../templates/mail/verify_email.html"),
+};
+
+impl MailTemplate {
+    pub fn render(&self, to: &str, values: &[(&str, &str)]) -> Email {
+        Email {
+            to: to.to_string(),
+            subject: fill(self.subject, values, str::to_string),
+            text: fill(self.text, values, str::to_string),
+            html: fill(self.html, values, escape_html),
+        }
+    }
+}
+
+pub(crate) fn fill(template: &str, values: &[(&str, &str)], escape: fn(&str) -> String) -> String {
+    values.iter().fold(template.to_string(), |filled, (name, value)| {
+        filled.replace(&format!("{{{{{}}}}}", name), &escape(value))
+    })
+}
+
+pub(crate) fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
\ No newline at end of file
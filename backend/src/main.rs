@@ -1,10 +1,6 @@
-use axum::{
-    routing::{get, post},
-    Router,
-};
-use backend::{create_pool, handlers};
-use tower_http::cors::CorsLayer;
-use tracing_subscriber;
+use backend::jobs;
+use backend::mail::open_mailer;
+use backend::{create_app, create_pool, AppState, CorsConfig, EventsConfig, JobsConfig, MailConfig, RemindersConfig, StellarConfig, StorageConfig, VersioningConfig};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -21,13 +17,30 @@ async fn main() -> anyhow::Result<()> {
     sqlx::migrate!("./migrations").run(&pool).await?;
 
     // Build application with routes
-    let app = Router::new()
-        .route("/api/signup", post(handlers::signup))
-        .route("/api/events", post(handlers::create_event))
-        .route("/api/events", get(handlers::list_events))
-        .route("/health", get(|| async { "OK" }))
-        .layer(CorsLayer::permissive())
-        .with_state(pool);
+    let cors = CorsConfig::from_env()?;
+    let stellar = StellarConfig::from_env()?;
+    let storage = StorageConfig::from_env()?;
+    let mail = MailConfig::from_env()?;
+    let mailer = open_mailer(&mail)?;
+    let jobs_config = JobsConfig::from_env()?;
+    let reminders = RemindersConfig::from_env()?;
+    let versioning = VersioningConfig::from_env()?;
+    let events = EventsConfig::from_env()?;
+
+    let state = AppState::new(pool, stellar)
+        .with_storage_config(&storage)
+        .with_mailer(mailer, &mail.app_url)
+        .with_reminders(&reminders)
+        .with_events(&events);
+
+    // Mail, notifications, event reminders and linked account checks run as background
+    // jobs, here or in the worker binary
+    jobs::schedule_recurring(&state).await?;
+    if jobs_config.run_worker {
+        jobs::worker(state.clone(), &jobs_config).spawn();
+    }
+
+    let app = create_app(state, &cors, &versioning);
 
     // Start server
     let listener = tokio::net::TcpListener::bind("127.0.0.1:8081").await?;
@@ -35,10 +48,11 @@ async fn main() -> anyhow::Result<()> {
     println!("🚀 STELLAR EUROPE BACKEND SERVER STARTED");
     println!("📡 Server running on http://127.0.0.1:8081");
     println!("🔗 API Endpoints:");
-    println!("   • POST /api/signup - User registration");
-    println!("   • POST /api/events - Create events with KPI planning");
-    println!("   • GET  /api/events - List events");
+    println!("   • POST /api/v1/signup - User registration");
+    println!("   • POST /api/v1/events - Create events with KPI planning");
+    println!("   • GET  /api/v1/events - List events");
     println!("   • GET  /health    - Health check");
+    println!("📖 API docs: http://127.0.0.1:8081/api/docs");
     println!("🎯 Ready to track KPIs and manage Stellar community events!");
     println!("🌟 ═══════════════════════════════════════════════════════════════");
 
@@ -0,0 +1,154 @@
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits};
+use shared::dto::AttachmentKind;
+use std::io::Cursor;
+
+pub const JPEG: &str = "image/jpeg";
+pub const PNG: &str = "image/png";
+pub const WEBP: &str = "image/webp";
+pub const PDF: &str = "application/pdf";
+pub const PPTX: &str = "application/vnd.openxmlformats-officedocument.presentationml.presentation";
+pub const ODP: &str = "application/vnd.oasis.opendocument.presentation";
+
+/// Longest side of a generated thumbnail, in pixels
+pub const THUMBNAIL_SIZE: u32 = 320;
+/// Images larger than this in either dimension are stored without a thumbnail
+const MAX_DECODE_DIMENSION: u32 = 12_000;
+
+/// Works out a file's type from its first bytes. The type the client declared is
+/// never trusted; `None` means the file is not of a type uploads accept.
+pub fn sniff_content_type(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some(JPEG)
+    } else if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some(PNG)
+    } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        Some(WEBP)
+    } else if bytes.starts_with(b"%PDF-") {
+        Some(PDF)
+    } else if bytes.starts_with(b"PK\x03\x04") {
+        sniff_zip(bytes)
+    } else {
+        None
+    }
+}
+
+/// OpenDocument files start with an uncompressed `mimetype` entry naming their type;
+/// PowerPoint files are the zips with a `ppt/presentation.xml` part.
+fn sniff_zip(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.get(30..38) == Some(b"mimetype") && bytes[38..].starts_with(ODP.as_bytes()) {
+        Some(ODP)
+    } else if contains(bytes, b"[Content_Types].xml") && contains(bytes, b"ppt/presentation.xml") {
+        Some(PPTX)
+    } else {
+        None
+    }
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+pub fn is_image(content_type: &str) -> bool {
+    matches!(content_type, JPEG | PNG | WEBP)
+}
+
+/// Whether a file of this type may be uploaded as this kind of attachment.
+pub fn accepts(kind: AttachmentKind, content_type: &str) -> bool {
+    match kind {
+        AttachmentKind::Receipt => is_image(content_type) || content_type == PDF,
+        AttachmentKind::Photo => is_image(content_type),
+        AttachmentKind::Slides => matches!(content_type, PDF | PPTX | ODP),
+        AttachmentKind::Document => true,
+    }
+}
+
+pub fn extension(content_type: &str) -> &'static str {
+    match content_type {
+        JPEG => "jpg",
+        PNG => "png",
+        WEBP => "webp",
+        PDF => "pdf",
+        PPTX => "pptx",
+        ODP => "odp",
+        _ => "bin",
+    }
+}
+
+/// The client's file name without any directories, control characters or quotes,
+/// cut to what the database keeps.
+pub fn clean_file_name(name: Option<&str>) -> String {
+    let name = name.unwrap_or_default();
+    let base = name.rsplit(['/', '\\']).next().unwrap_or_default();
+    let cleaned: String = base
+        .chars()
+        .filter(|c| !c.is_control() && *c != '"')
+        .take(255)
+        .collect();
+
+    match cleaned.trim() {
+        "" | "." | ".." => "upload".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+/// A `Content-Disposition` value for downloading a stored file under its original
+/// name. Images and PDFs are shown in the browser, everything else is saved.
+pub fn content_disposition(file_name: &str, content_type: &str) -> String {
+    let disposition = if is_image(content_type) || content_type == PDF { "inline" } else { "attachment" };
+    let ascii: String = file_name.chars().map(|c| if c.is_ascii() && c != '\\' { c } else { '_' }).collect();
+    let encoded: String = file_name
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect();
+
+    format!("{}; filename=\"{}\"; filename*=UTF-8''{}", disposition, ascii, encoded)
+}
+
+/// Thumbnails of JPEGs are JPEGs; those of PNGs and WebPs are PNGs so transparency survives.
+pub fn thumbnail_content_type(content_type: &str) -> &'static str {
+    if content_type == JPEG {
+        JPEG
+    } else {
+        PNG
+    }
+}
+
+/// A downscaled copy of an image, upright and at most [`THUMBNAIL_SIZE`] pixels on
+/// its longest side, of type [`thumbnail_content_type`]. `None` if the image cannot
+/// be decoded. Decoding is CPU-bound, so call this from `spawn_blocking`.
+pub fn make_thumbnail(bytes: &[u8], content_type: &str) -> Option<Vec<u8>> {
+    let format = match content_type {
+        JPEG => ImageFormat::Jpeg,
+        PNG => ImageFormat::Png,
+        WEBP => ImageFormat::WebP,
+        _ => return None,
+    };
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DECODE_DIMENSION);
+    limits.max_image_height = Some(MAX_DECODE_DIMENSION);
+    let mut reader = ImageReader::with_format(Cursor::new(bytes), format);
+    reader.limits(limits);
+
+    let mut decoder = reader.into_decoder().ok()?;
+    let orientation = decoder.orientation().ok();
+    let mut image = DynamicImage::from_decoder(decoder).ok()?;
+    if let Some(orientation) = orientation {
+        image.apply_orientation(orientation);
+    }
+    let thumbnail = image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE);
+
+    let mut output = Vec::new();
+    if thumbnail_content_type(content_type) == JPEG {
+        JpegEncoder::new_with_quality(&mut output, 80)
+            .encode_image(&thumbnail.to_rgb8())
+            .ok()?;
+    } else {
+        thumbnail.write_to(&mut Cursor::new(&mut output), ImageFormat::Png).ok()?;
+    }
+    Some(output)
+}
\ No newline at end of file
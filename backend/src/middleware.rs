@@ -0,0 +1,30 @@
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+/// Answers CORS preflights from origins outside the allow-list with 403 instead
+/// of letting them through without CORS headers.
+pub async fn reject_disallowed_preflight(
+    State(allowed_origins): State<Vec<HeaderValue>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let is_preflight = request.method() == Method::OPTIONS
+        && request.headers().contains_key(header::ACCESS_CONTROL_REQUEST_METHOD);
+
+    if is_preflight {
+        let origin_allowed = request
+            .headers()
+            .get(header::ORIGIN)
+            .is_some_and(|origin| allowed_origins.contains(origin));
+
+        if !origin_allowed {
+            return (StatusCode::FORBIDDEN, "Origin not allowed").into_response();
+        }
+    }
+
+    next.run(request).await
+}
@@ -0,0 +1,222 @@
+pub mod reminders;
+pub mod templates;
+
+pub use reminders::*;
+pub use templates::*;
+
+use crate::database::connection::DbPool;
+use crate::database::models::{BudgetItem, Chapter, Event, NotificationRecipient, QuarterlyPlan, Reimbursement};
+use crate::database::repositories::{ChapterRepository, NotificationRepository, RegistrationRepository};
+use crate::jobs::{enqueue, Job, JobError};
+use crate::mail::SendEmail;
+use crate::state::AppState;
+use async_trait::async_trait;
+use chrono_tz::Tz;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::dto::{Currency, Money, NotificationKind};
+use sqlx::Error as SqlxError;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Something that happened which people are told about. It carries the records as they
+/// were at the time, so the notification describes that moment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DomainEvent {
+    RegistrationConfirmed { event: Event, user_id: i32 },
+    WaitlistPromoted { event: Event, user_id: i32 },
+    /// The organizer changed the event's title, description, schedule or location
+    EventUpdated { event: Event },
+    EventCancelled { event: Event },
+    /// The event is coming up; sent at the times set by `EVENT_REMINDERS`
+    EventReminder { event: Event },
+    PlanApproved { plan: QuarterlyPlan, chapter: Chapter },
+    ExpensePaid { event: Event, item: Box<BudgetItem>, reimbursement: Box<Reimbursement> },
+}
+
+impl DomainEvent {
+    pub fn kind(&self) -> NotificationKind {
+        match self {
+            DomainEvent::RegistrationConfirmed { .. } => NotificationKind::RegistrationConfirmed,
+            DomainEvent::WaitlistPromoted { .. } => NotificationKind::WaitlistPromoted,
+            DomainEvent::EventUpdated { .. } => NotificationKind::EventUpdated,
+            DomainEvent::EventCancelled { .. } => NotificationKind::EventCancelled,
+            DomainEvent::EventReminder { .. } => NotificationKind::EventReminder,
+            DomainEvent::PlanApproved { .. } => NotificationKind::PlanApproved,
+            DomainEvent::ExpensePaid { .. } => NotificationKind::ExpensePaid,
+        }
+    }
+
+    /// Ids of the users told: the registrant, everyone registered or waitlisted for a
+    /// changed event, those with a place at an event coming up, the chapter's members
+    /// and lead, or the organizer who is paid.
+    async fn recipients(&self, pool: &DbPool) -> Result<Vec<i32>, SqlxError> {
+        Ok(match self {
+            DomainEvent::RegistrationConfirmed { user_id, .. } | DomainEvent::WaitlistPromoted { user_id, .. } => vec![*user_id],
+            DomainEvent::EventUpdated { event } | DomainEvent::EventCancelled { event } => {
+                RegistrationRepository::list_active_user_ids(pool, event.id).await?
+            }
+            DomainEvent::EventReminder { event } => RegistrationRepository::list_registered_user_ids(pool, event.id).await?,
+            DomainEvent::PlanApproved { chapter, .. } => {
+                let mut ids: Vec<i32> = ChapterRepository::list_members(pool, chapter.id).await?.iter().map(|user| user.id).collect();
+                ids.extend(chapter.lead_id.filter(|lead| !ids.contains(lead)));
+                ids
+            }
+            DomainEvent::ExpensePaid { event, .. } => vec![event.organizer_id],
+        })
+    }
+
+    /// Placeholder values every recipient's message shares.
+    pub fn values(&self) -> Vec<(&'static str, String)> {
+        match self {
+            DomainEvent::RegistrationConfirmed { event, .. }
+            | DomainEvent::WaitlistPromoted { event, .. }
+            | DomainEvent::EventUpdated { event }
+            | DomainEvent::EventReminder { event } => vec![
+                ("event_title", event.title.clone()),
+                ("event_date", event_date(event)),
+                ("location", event.location.clone()),
+            ],
+            DomainEvent::EventCancelled { event } => vec![
+                ("event_title", event.title.clone()),
+                ("event_date", event_date(event)),
+                ("reason", event.cancellation_reason.clone().unwrap_or_default()),
+            ],
+            DomainEvent::PlanApproved { plan, chapter } => vec![
+                ("chapter", chapter.name.clone()),
+                ("quarter", format!("Q{} {}", plan.quarter, plan.year)),
+            ],
+            DomainEvent::ExpensePaid { event, item, reimbursement } => vec![
+                ("event_title", event.title.clone()),
+                ("item", item.description.clone()),
+                ("amount", amount(reimbursement.amount, &reimbursement.asset)),
+                ("transaction_hash", reimbursement.transaction_hash.clone()),
+            ],
+        }
+    }
+
+    /// Page of the app the notification links to.
+    pub fn path(&self) -> String {
+        match self {
+            DomainEvent::RegistrationConfirmed { event, .. }
+            | DomainEvent::WaitlistPromoted { event, .. }
+            | DomainEvent::EventUpdated { event }
+            | DomainEvent::EventReminder { event } => format!("/events/{}/registration", event.id),
+            DomainEvent::EventCancelled { .. } => "/events".to_string(),
+            DomainEvent::PlanApproved { plan, .. } => format!("/plans/{}", plan.id),
+            DomainEvent::ExpensePaid { event, .. } => format!("/events/{}/budget", event.id),
+        }
+    }
+}
+
+/// When the event starts, in the zone it was scheduled in. Numeric, so it reads the
+/// same in every language.
+fn event_date(event: &Event) -> String {
+    let zone: Tz = event.timezone.parse().unwrap_or(Tz::UTC);
+    event.date.with_timezone(&zone).format("%Y-%m-%d %H:%M %Z").to_string()
+}
+
+fn amount(amount: Decimal, asset: &str) -> String {
+    match Currency::from_name(asset) {
+        Some(currency) => Money::new(amount, currency).to_string(),
+        None => format!("{} {}", amount, asset),
+    }
+}
+
+/// The channels a recipient gets a notification on. Mail only goes to verified addresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Delivery {
+    pub in_app: bool,
+    pub email: bool,
+}
+
+impl Delivery {
+    pub fn for_recipient(recipient: &NotificationRecipient) -> Self {
+        Delivery { in_app: recipient.in_app, email: recipient.by_email && recipient.email_verified }
+    }
+}
+
+/// Queues the notification of `event`. Failures are logged rather than returned: the
+/// change that caused the notification has already happened.
+pub async fn publish(state: &AppState, event: DomainEvent) {
+    let kind = event.kind();
+    if let Err(e) = enqueue(&state.pool, &PublishNotification::new(event)).await {
+        warn!("Could not queue {} notification: {}", kind, e);
+    }
+}
+
+/// Tells everyone concerned about a domain event, each in their language and on the
+/// channels they chose. Mail is queued as one [`SendEmail`] job per message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublishNotification {
+    pub event: DomainEvent,
+    /// Names this notification in the recipients it was delivered to. Jobs queued before
+    /// it existed get a new one each run.
+    #[serde(default = "Uuid::new_v4")]
+    pub key: Uuid,
+}
+
+impl PublishNotification {
+    pub fn new(event: DomainEvent) -> Self {
+        Self { event, key: Uuid::new_v4() }
+    }
+}
+
+#[async_trait]
+impl Job for PublishNotification {
+    const KIND: &'static str = "publish_notification";
+
+    /// Each recipient is recorded with their inbox entry and mail in one transaction, so a
+    /// run that is retried, or claimed again after timing out, only tells those not yet told.
+    async fn run(&self, state: &AppState) -> Result<(), JobError> {
+        let event = &self.event;
+        let kind = event.kind();
+        let ids = event.recipients(&state.pool).await?;
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let recipients = NotificationRepository::recipients(&state.pool, &ids, &kind.to_string()).await?;
+
+        let path = event.path();
+        let link = format!("{}{}", state.app_url, path);
+        let preferences_link = format!("{}/notifications", state.app_url);
+        let shared_values = event.values();
+
+        for recipient in &recipients {
+            let delivery = Delivery::for_recipient(recipient);
+            if !delivery.in_app && !delivery.email {
+                continue;
+            }
+
+            let mut values: Vec<(&str, &str)> = shared_values.iter().map(|(name, value)| (*name, value.as_str())).collect();
+            values.push(("username", &recipient.username));
+            values.push(("link", &link));
+            let rendered = render_notification(kind, &recipient.locale, &recipient.email, &values, &link, &preferences_link);
+
+            let mut tx = state.pool.begin().await?;
+            if !NotificationRepository::record_delivery(&mut *tx, self.key, recipient.user_id).await? {
+                info!("User {} was already told of {} notification {}", recipient.user_id, kind, self.key);
+                continue;
+            }
+            if delivery.in_app {
+                NotificationRepository::create(
+                    &mut *tx,
+                    recipient.user_id,
+                    &kind.to_string(),
+                    &rendered.subject,
+                    &rendered.body,
+                    Some(&path),
+                )
+                .await?;
+            }
+            if delivery.email {
+                enqueue(&mut *tx, &SendEmail { email: rendered.email }).await?;
+            }
+            tx.commit().await?;
+        }
+
+        println!("📣 {} notification sent to {} user(s)", kind, recipients.len());
+        info!("{} notification published to {} recipient(s)", kind, recipients.len());
+        Ok(())
+    }
+}
\ No newline at end of file
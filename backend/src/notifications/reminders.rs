@@ -0,0 +1,102 @@
+use super::{DomainEvent, PublishNotification};
+use crate::database::models::Event;
+use crate::database::repositories::{EventReminderRepository, EventRepository, JobRepository};
+use crate::handlers::event_status;
+use crate::jobs::{enqueue, schedule, Job, JobError};
+use crate::state::AppState;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use shared::dto::EventStatus;
+use sqlx::Error as SqlxError;
+use tracing::{info, warn};
+
+/// Reminds everyone with a place at an event that it starts in `minutes_before` minutes.
+/// One is queued per reminder time and event, and moved whenever the event is.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SendEventReminder {
+    pub event_id: i32,
+    pub minutes_before: i32,
+    /// Start of the event when the reminder was scheduled
+    pub event_date: DateTime<Utc>,
+}
+
+#[async_trait]
+impl Job for SendEventReminder {
+    const KIND: &'static str = "send_event_reminder";
+
+    fn unique_key(&self) -> Option<String> {
+        Some(reminder_key(self.event_id, self.minutes_before))
+    }
+
+    /// Checks the reminder still applies, then records it and queues the notification in
+    /// one transaction, so a retried or repeated job never reminds anyone twice.
+    async fn run(&self, state: &AppState) -> Result<(), JobError> {
+        let Some(event) = EventRepository::find_by_id(&state.pool, self.event_id).await? else {
+            return Ok(());
+        };
+        let configured = state.reminders_before.iter().any(|before| minutes(*before) == self.minutes_before);
+        if event_status(&event) != EventStatus::Published || event.date != self.event_date || event.date <= Utc::now() || !configured {
+            info!("Reminder {} minutes before event {} no longer applies", self.minutes_before, event.id);
+            return Ok(());
+        }
+
+        let mut tx = state.pool.begin().await?;
+        if !EventReminderRepository::record(&mut *tx, event.id, self.minutes_before, event.date).await? {
+            info!("Reminder {} minutes before event {} was already sent", self.minutes_before, event.id);
+            return Ok(());
+        }
+        enqueue(&mut *tx, &PublishNotification::new(DomainEvent::EventReminder { event })).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+fn reminder_key(event_id: i32, minutes_before: i32) -> String {
+    format!("event_reminder:{}:{}", event_id, minutes_before)
+}
+
+fn minutes(before: std::time::Duration) -> i32 {
+    i32::try_from(before.as_secs() / 60).unwrap_or(i32::MAX)
+}
+
+/// Queues the reminders of a published event for the times still ahead of it, moving any
+/// already queued to the event's current date. Reminders of an event that is not
+/// published, or whose time has passed, are dropped. Failures are logged, as with
+/// [`super::publish`].
+pub async fn schedule_reminders(state: &AppState, event: &Event) {
+    if let Err(e) = reschedule(state, event, Utc::now()).await {
+        warn!("Could not schedule reminders for event {}: {}", event.id, e);
+    }
+}
+
+/// Schedules the reminders of every published event yet to start, e.g. after the
+/// reminder times changed. Returns how many events have reminders ahead.
+pub async fn schedule_upcoming_reminders(state: &AppState) -> Result<usize, SqlxError> {
+    let now = Utc::now();
+    let events = EventRepository::list_published_starting_after(&state.pool, now).await?;
+    let mut scheduled = 0;
+    for event in &events {
+        if reschedule(state, event, now).await? > 0 {
+            scheduled += 1;
+        }
+    }
+    Ok(scheduled)
+}
+
+async fn reschedule(state: &AppState, event: &Event, now: DateTime<Utc>) -> Result<usize, SqlxError> {
+    let published = event_status(event) == EventStatus::Published;
+    let mut scheduled = 0;
+    for before in &state.reminders_before {
+        let minutes_before = minutes(*before);
+        let run_at = event.date - Duration::minutes(minutes_before.into());
+        if published && run_at > now {
+            let reminder = SendEventReminder { event_id: event.id, minutes_before, event_date: event.date };
+            schedule(&state.pool, &reminder, run_at).await?;
+            scheduled += 1;
+        } else {
+            JobRepository::unschedule(&state.pool, &reminder_key(event.id, minutes_before)).await?;
+        }
+    }
+    Ok(scheduled)
+}
\ No newline at end of file
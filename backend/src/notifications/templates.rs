@@ -0,0 +1,139 @@
+use crate::mail::{escape_html, fill, Email};
+use shared::dto::NotificationKind;
+
+/// A notification's subject and plain-text body, with `{{name}}` placeholders. The
+/// source is the subject on the first line, then a blank line, then the body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationTemplate {
+    pub subject: &'static str,
+    pub body: &'static str,
+}
+
+impl NotificationTemplate {
+    fn parse(source: &'static str) -> Self {
+        let (subject, body) = source.split_once('\n').unwrap_or((source, ""));
+        NotificationTemplate { subject: subject.trim(), body: body.trim() }
+    }
+}
+
+/// Every template of one language.
+struct Locale {
+    code: &'static str,
+    footer: &'static str,
+    registration_confirmed: &'static str,
+    waitlist_promoted: &'static str,
+    event_updated: &'static str,
+    event_cancelled: &'static str,
+    event_reminder: &'static str,
+    plan_approved: &'static str,
+    expense_paid: &'static str,
+}
+
+macro_rules! locale {
+    ($code:literal) => {
+        Locale {
+            code: $code,
+            footer: include_str!(concat!("../../templates/notifications/", $code, "/footer.txt")),
+            registration_confirmed: include_str!(concat!("../../templates/notifications/", $code, "/registration_confirmed.txt")),
+            waitlist_promoted: include_str!(concat!("../../templates/notifications/", $code, "/waitlist_promoted.txt")),
+            event_updated: include_str!(concat!("../../templates/notifications/", $code, "/event_updated.txt")),
+            event_cancelled: include_str!(concat!("../../templates/notifications/", $code, "/event_cancelled.txt")),
+            event_reminder: include_str!(concat!("../../templates/notifications/", $code, "/event_reminder.txt")),
+            plan_approved: include_str!(concat!("../../templates/notifications/", $code, "/plan_approved.txt")),
+            expense_paid: include_str!(concat!("../../templates/notifications/", $code, "/expense_paid.txt")),
+        }
+    };
+}
+
+/// The first is the default, and the fallback for unknown languages.
+const LOCALES: [Locale; 3] = [locale!("en"), locale!("es"), locale!("fr")];
+
+/// Wraps every notification mailed, whatever its language. Placeholders: `locale`,
+/// `subject`, `body`, `link` and `footer`.
+const LAYOUT: &str = include_str!("../../templates/notifications/layout.html");
+
+/// Codes of the languages notifications are written in.
+pub fn supported_locales() -> Vec<&'static str> {
+    LOCALES.iter().map(|locale| locale.code).collect()
+}
+
+pub fn is_supported_locale(code: &str) -> bool {
+    LOCALES.iter().any(|locale| locale.code == code)
+}
+
+fn locale(code: &str) -> &'static Locale {
+    LOCALES.iter().find(|locale| locale.code == code).unwrap_or(&LOCALES[0])
+}
+
+/// The template for `kind` in the language `code`, or in English if there is none.
+pub fn notification_template(kind: NotificationKind, code: &str) -> NotificationTemplate {
+    let locale = locale(code);
+    NotificationTemplate::parse(match kind {
+        NotificationKind::RegistrationConfirmed => locale.registration_confirmed,
+        NotificationKind::WaitlistPromoted => locale.waitlist_promoted,
+        NotificationKind::EventUpdated => locale.event_updated,
+        NotificationKind::EventCancelled => locale.event_cancelled,
+        NotificationKind::EventReminder => locale.event_reminder,
+        NotificationKind::PlanApproved => locale.plan_approved,
+        NotificationKind::ExpensePaid => locale.expense_paid,
+    })
+}
+
+/// A notification written out for one recipient: the subject and body kept in the
+/// inbox, and the message mailed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedNotification {
+    pub subject: String,
+    pub body: String,
+    pub email: Email,
+}
+
+/// Fills in the template for `kind` in the language `code`. `link` is the page the
+/// notification is about and `preferences_link` where the recipient changes what they get.
+pub fn render_notification(
+    kind: NotificationKind,
+    code: &str,
+    to: &str,
+    values: &[(&str, &str)],
+    link: &str,
+    preferences_link: &str,
+) -> RenderedNotification {
+    let template = notification_template(kind, code);
+    let subject = fill(template.subject, values, str::to_string);
+    let body = collapse_blank_lines(&fill(template.body, values, str::to_string));
+    let footer = fill(locale(code).footer.trim(), &[("preferences_link", preferences_link)], str::to_string);
+
+    let layout_values = [
+        ("locale", locale(code).code),
+        ("subject", subject.as_str()),
+        ("body", body.as_str()),
+        ("link", link),
+        ("footer", footer.as_str()),
+    ];
+    let email = Email {
+        to: to.to_string(),
+        subject: subject.clone(),
+        text: format!("{}\n\n-- \n{}\n", body, footer),
+        html: fill(LAYOUT, &layout_values, escape_html),
+    };
+
+    RenderedNotification { subject, body, email }
+}
+
+/// Values left empty, like a cancellation without a reason, leave no gap behind.
+fn collapse_blank_lines(text: &str) -> String {
+    let mut collapsed = String::new();
+    let mut blank = false;
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            blank = true;
+            continue;
+        }
+        if !collapsed.is_empty() {
+            collapsed.push_str(if blank { "\n\n" } else { "\n" });
+        }
+        collapsed.push_str(line);
+        blank = false;
+    }
+    collapsed
+}
\ No newline at end of file
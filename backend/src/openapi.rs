@@ -0,0 +1,265 @@
+use crate::handlers;
+use crate::versioning::{ApiVersion, LEGACY_API_PREFIX};
+use shared::dto::{
+    BudgetAmountRequest, BudgetCategory, BudgetItemRequest, BudgetItemResponse, BudgetRollup, Currency,
+    EventBudgetResponse, Money, ReimbursementResponse, ReimbursementStatus,
+    SubmitReimbursementRequest, AttachmentKind, AttachmentListResponse, AttachmentResponse, AttendanceIssuanceResponse,
+    AttendanceRecord, AttendanceTokenStatus, CheckInRequest, CheckInResponse, EventAttendanceResponse,
+    SubmitAttendanceTokensRequest, UserAttendanceResponse,
+    ChapterDashboardResponse, ChapterListResponse, ChapterMembersResponse, ChapterRequest, ChapterResponse,
+    ChapterTargetsResponse, ErrorResponse, EventListResponse, EventRequest, EventResponse, EventStatus, EventStatusRequest, EventType,
+    EventActualsRequest,
+    FieldError,
+    FocusAreaSummary, KPIEstimates, KpiValues, LinkAccountRequest, LinkedAccountListResponse, LinkedAccountResponse,
+    LinkedAccountStatus, MembershipRequest, MonthlyKpis, PlanAction, PlanComment,
+    PlanCommentRequest, PlanStatus, PlanTransition, PlanTransitionRequest, QuarterlyPlanListResponse,
+    QuarterlyPlanRequest, QuarterlyPlanResponse, QuarterlyPlanSummary, SignUpChallengeRequest, SignUpChallengeResponse,
+    SignUpRequest, SignUpResponse, SignInRequest, SessionResponse,
+    StellarNetworkResponse, StrategicFocusArea, UserPublic, UserType, VerifyEmailRequest, ResendVerificationRequest,
+    EmailVerificationResponse, EventUpdateRequest, RegistrationStatus, RegistrationRequest, RegistrationResponse,
+    EventRegistrationsResponse, NotificationKind, NotificationResponse, NotificationListResponse,
+    MarkNotificationsReadRequest, NotificationPreference, NotificationPreferencesResponse, NotificationPreferencesRequest,
+};
+use utoipa::openapi::security::{Http, HttpAuthScheme, SecurityScheme};
+use utoipa::openapi::{path::Operation, Deprecated, OpenApi as OpenApiDocument};
+use utoipa::{Modify, OpenApi};
+
+pub const OPENAPI_JSON_PATH: &str = "/api/openapi.json";
+pub const DOCS_UI_PATH: &str = "/api/docs";
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Stellar Europe API",
+        description = "Community, event and KPI planning API for Stellar Europe chapters."
+    ),
+    nest((path = "/api/v1", api = ApiV1Doc)),
+    paths(handlers::health),
+    components(schemas(
+        SignUpRequest,
+        SignUpResponse,
+        SignUpChallengeRequest,
+        SignUpChallengeResponse,
+        SignInRequest,
+        SessionResponse,
+        VerifyEmailRequest,
+        ResendVerificationRequest,
+        EmailVerificationResponse,
+        UserPublic,
+        UserType,
+        EventRequest,
+        EventResponse,
+        EventListResponse,
+        EventType,
+        EventStatus,
+        EventStatusRequest,
+        EventActualsRequest,
+        EventUpdateRequest,
+        StrategicFocusArea,
+        KPIEstimates,
+        ChapterRequest,
+        ChapterResponse,
+        ChapterListResponse,
+        ChapterMembersResponse,
+        MembershipRequest,
+        KpiValues,
+        ChapterTargetsResponse,
+        MonthlyKpis,
+        ChapterDashboardResponse,
+        PlanStatus,
+        PlanAction,
+        QuarterlyPlanRequest,
+        PlanTransitionRequest,
+        PlanCommentRequest,
+        PlanComment,
+        PlanTransition,
+        FocusAreaSummary,
+        QuarterlyPlanResponse,
+        QuarterlyPlanSummary,
+        QuarterlyPlanListResponse,
+        Currency,
+        Money,
+        BudgetCategory,
+        BudgetItemRequest,
+        BudgetAmountRequest,
+        BudgetItemResponse,
+        BudgetRollup,
+        EventBudgetResponse,
+        ReimbursementStatus,
+        SubmitReimbursementRequest,
+        ReimbursementResponse,
+        AttachmentKind,
+        AttachmentResponse,
+        AttachmentListResponse,
+        handlers::AttachmentUploadForm,
+        LinkedAccountStatus,
+        LinkAccountRequest,
+        LinkedAccountResponse,
+        LinkedAccountListResponse,
+        AttendanceTokenStatus,
+        CheckInRequest,
+        CheckInResponse,
+        SubmitAttendanceTokensRequest,
+        AttendanceIssuanceResponse,
+        EventAttendanceResponse,
+        AttendanceRecord,
+        UserAttendanceResponse,
+        StellarNetworkResponse,
+        RegistrationStatus,
+        RegistrationRequest,
+        RegistrationResponse,
+        EventRegistrationsResponse,
+        NotificationKind,
+        NotificationResponse,
+        NotificationListResponse,
+        MarkNotificationsReadRequest,
+        NotificationPreference,
+        NotificationPreferencesResponse,
+        NotificationPreferencesRequest,
+        ErrorResponse,
+        FieldError,
+    )),
+    modifiers(&LegacyRoutes, &SessionAuth),
+    tags(
+        (name = "auth", description = "Account registration and sessions, proven by signing a nonce with the wallet, and email verification"),
+        (name = "events", description = "Community events and KPI planning"),
+        (name = "calendar", description = "iCalendar exports and subscribable feeds"),
+        (name = "feeds", description = "Atom and RSS feeds of upcoming events"),
+        (name = "chapters", description = "Local chapters and their members"),
+        (name = "dashboard", description = "Chapter KPI targets and quarterly progress"),
+        (name = "plans", description = "Quarterly plan submission and review"),
+        (name = "budgets", description = "Event budget requests, approvals and spending"),
+        (name = "reimbursements", description = "Stellar payouts of event spending to organisers"),
+        (name = "attachments", description = "Receipts, photos and slides uploaded for events"),
+        (name = "accounts", description = "Stellar accounts created at events, verified on Horizon"),
+        (name = "attendance", description = "Check-ins and proof-of-attendance tokens issued as claimable balances"),
+        (name = "registrations", description = "Registration for published events, with a waitlist once they are full"),
+        (name = "notifications", description = "Users' notification inbox, language and delivery preferences"),
+        (name = "network", description = "Stellar network the API builds transactions on"),
+        (name = "health", description = "Service health"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Operations served under `/api/v1`, with paths relative to that prefix.
+#[derive(OpenApi)]
+#[openapi(paths(
+    handlers::signup,
+    handlers::signup_challenge,
+    handlers::signin_challenge,
+    handlers::create_session,
+    handlers::verify_email,
+    handlers::resend_verification_email,
+    handlers::create_event,
+    handlers::list_events,
+    handlers::record_event_actuals,
+    handlers::update_event_status,
+    handlers::update_event,
+    handlers::list_registrations,
+    handlers::register_for_event,
+    handlers::cancel_registration,
+    handlers::get_event_budget,
+    handlers::add_budget_item,
+    handlers::update_budget_item,
+    handlers::delete_budget_item,
+    handlers::approve_budget_item,
+    handlers::record_budget_spending,
+    handlers::get_reimbursement,
+    handlers::prepare_reimbursement,
+    handlers::submit_reimbursement,
+    handlers::list_attachments,
+    handlers::upload_attachment,
+    handlers::attachment_content,
+    handlers::attachment_thumbnail,
+    handlers::delete_attachment,
+    handlers::list_linked_accounts,
+    handlers::link_account,
+    handlers::unlink_account,
+    handlers::get_event_attendance,
+    handlers::check_in_attendee,
+    handlers::remove_check_in,
+    handlers::prepare_attendance_tokens,
+    handlers::submit_attendance_tokens,
+    handlers::get_user_attendance,
+    handlers::list_notifications,
+    handlers::mark_notifications_read,
+    handlers::get_notification_preferences,
+    handlers::update_notification_preferences,
+    handlers::get_stellar_network,
+    handlers::event_calendar,
+    handlers::event_calendar_file,
+    handlers::calendar_feed,
+    handlers::atom_feed,
+    handlers::rss_feed,
+    handlers::list_chapters,
+    handlers::create_chapter,
+    handlers::get_chapter,
+    handlers::update_chapter,
+    handlers::delete_chapter,
+    handlers::list_chapter_members,
+    handlers::add_chapter_member,
+    handlers::remove_chapter_member,
+    handlers::get_chapter_targets,
+    handlers::update_chapter_targets,
+    handlers::chapter_dashboard,
+    handlers::list_plans,
+    handlers::create_plan,
+    handlers::get_plan,
+    handlers::update_plan,
+    handlers::transition_plan,
+    handlers::add_plan_comment,
+))]
+pub struct ApiV1Doc;
+
+/// The bearer token from `POST /sessions`, named `session` in operations' `security`.
+struct SessionAuth;
+
+impl Modify for SessionAuth {
+    fn modify(&self, openapi: &mut OpenApiDocument) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme("session", SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)));
+    }
+}
+
+/// Documents the unversioned `/api/*` aliases of v1 as deprecated operations.
+struct LegacyRoutes;
+
+impl Modify for LegacyRoutes {
+    fn modify(&self, openapi: &mut OpenApiDocument) {
+        let current = ApiVersion::V1.prefix();
+        let legacy: Vec<_> = openapi
+            .paths
+            .paths
+            .iter()
+            .filter_map(|(path, item)| {
+                let relative = path.strip_prefix(current.as_str())?;
+                let mut item = item.clone();
+                for operation in [
+                    &mut item.get,
+                    &mut item.post,
+                    &mut item.put,
+                    &mut item.patch,
+                    &mut item.delete,
+                ]
+                .into_iter()
+                .flatten()
+                {
+                    mark_deprecated(operation);
+                }
+                Some((format!("{}{}", LEGACY_API_PREFIX, relative), item))
+            })
+            .collect();
+
+        openapi.paths.paths.extend(legacy);
+    }
+}
+
+fn mark_deprecated(operation: &mut Operation) {
+    operation.deprecated = Some(Deprecated::True);
+    operation.operation_id = operation.operation_id.as_ref().map(|id| format!("legacy_{}", id));
+    operation.description = Some(format!(
+        "Deprecated unversioned alias of `{}`. {}",
+        ApiVersion::CURRENT.prefix(),
+        operation.description.clone().unwrap_or_default()
+    ));
+}
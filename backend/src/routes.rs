@@ -0,0 +1,66 @@
+use crate::state::AppState;
+use axum::{
+    handler::Handler,
+    http::Method,
+    routing::{delete, get, post, put, MethodRouter},
+    Router,
+};
+
+/// Routes of one part of the API, kept as a list until they are built into a router so
+/// the method and path of each can be read back, e.g. to check them against the
+/// OpenAPI document.
+#[derive(Clone, Default)]
+pub struct ApiRoutes {
+    routes: Vec<(Method, String, MethodRouter<AppState>)>,
+}
+
+impl ApiRoutes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves `method` on `path` with `method_router`, which should route only that
+    /// method. Used over the shorthands when the route needs a layer of its own.
+    pub fn route(mut self, method: Method, path: &str, method_router: MethodRouter<AppState>) -> Self {
+        self.routes.push((method, path.to_string(), method_router));
+        self
+    }
+
+    pub fn get<H: Handler<T, AppState>, T: 'static>(self, path: &str, handler: H) -> Self {
+        self.route(Method::GET, path, get(handler))
+    }
+
+    pub fn post<H: Handler<T, AppState>, T: 'static>(self, path: &str, handler: H) -> Self {
+        self.route(Method::POST, path, post(handler))
+    }
+
+    pub fn put<H: Handler<T, AppState>, T: 'static>(self, path: &str, handler: H) -> Self {
+        self.route(Method::PUT, path, put(handler))
+    }
+
+    pub fn delete<H: Handler<T, AppState>, T: 'static>(self, path: &str, handler: H) -> Self {
+        self.route(Method::DELETE, path, delete(handler))
+    }
+
+    /// Serves `method` on `path` with `method_router` in place of the route already there,
+    /// e.g. to change one handler in a new API version built from the previous one's
+    /// routes. Adds the route if there is none.
+    pub fn replace(mut self, method: Method, path: &str, method_router: MethodRouter<AppState>) -> Self {
+        match self.routes.iter_mut().find(|(m, p, _)| *m == method && p == path) {
+            Some(route) => route.2 = method_router,
+            None => self.routes.push((method, path.to_string(), method_router)),
+        }
+        self
+    }
+
+    /// Method and path of every route, in the order they were added.
+    pub fn paths(&self) -> Vec<(Method, String)> {
+        self.routes.iter().map(|(method, path, _)| (method.clone(), path.clone())).collect()
+    }
+
+    pub fn into_router(self) -> Router<AppState> {
+        self.routes
+            .into_iter()
+            .fold(Router::new(), |router, (_, path, method_router)| router.route(&path, method_router))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,95 @@
+use crate::config::{EventsConfig, MailConfig, RemindersConfig, StellarConfig, StorageConfig};
+use crate::database::connection::DbPool;
+use crate::mail::{LogMailer, Mailer};
+use crate::stellar::{AccountHistory, Horizon, HorizonClient};
+use crate::storage::{open_storage, ObjectStorage};
+use axum::extract::FromRef;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Everything handlers share. Handlers that only need the database extract `State<DbPool>`.
+#[derive(Clone)]
+pub struct AppState {
+    pub pool: DbPool,
+    pub stellar: Arc<StellarConfig>,
+    pub horizon: Arc<dyn Horizon>,
+    /// Reads the history of accounts linked to events
+    pub history: Arc<dyn AccountHistory>,
+    pub storage: Arc<dyn ObjectStorage>,
+    /// Largest file the upload endpoint accepts
+    pub max_upload_bytes: usize,
+    pub mailer: Arc<dyn Mailer>,
+    /// Where the frontend is served, for links in mail
+    pub app_url: String,
+    /// How long before an event registered attendees are reminded of it
+    pub reminders_before: Vec<Duration>,
+    /// Zone of events created without one
+    pub default_timezone: String,
+}
+
+impl AppState {
+    /// Talks to the Horizon server named in `stellar`, keeps uploads in the default
+    /// local directory until [`AppState::with_storage_config`] says otherwise, and logs
+    /// mail until [`AppState::with_mailer`] is given a transport. Reminders go out at
+    /// the default times until [`AppState::with_reminders`] sets others.
+    pub fn new(pool: DbPool, stellar: StellarConfig) -> Self {
+        let horizon = Arc::new(HorizonClient::new(&stellar.horizon_url));
+        let uploads = StorageConfig::default();
+        let storage = open_storage(&uploads.backend);
+        let mail = MailConfig::default();
+        let from = mail.from.parse().expect("default sender is a valid mailbox");
+        Self {
+            pool,
+            stellar: Arc::new(stellar),
+            horizon: horizon.clone(),
+            history: horizon,
+            storage,
+            max_upload_bytes: uploads.max_upload_bytes,
+            mailer: Arc::new(LogMailer::new(from, None)),
+            app_url: mail.app_url,
+            reminders_before: RemindersConfig::default().before,
+            default_timezone: EventsConfig::default().default_timezone,
+        }
+    }
+
+    /// Replaces the Horizon client, e.g. with a local mock.
+    pub fn with_horizon(self, horizon: Arc<dyn Horizon>) -> Self {
+        Self { horizon, ..self }
+    }
+
+    /// Replaces the account history reader, e.g. with recorded Horizon responses.
+    pub fn with_history(self, history: Arc<dyn AccountHistory>) -> Self {
+        Self { history, ..self }
+    }
+
+    /// Keeps uploads where `config` says, with its size limit.
+    pub fn with_storage_config(self, config: &StorageConfig) -> Self {
+        self.with_storage(open_storage(&config.backend), config.max_upload_bytes)
+    }
+
+    /// Sends mail with `mailer`, linking to the frontend at `app_url`.
+    pub fn with_mailer(self, mailer: Arc<dyn Mailer>, app_url: &str) -> Self {
+        Self { mailer, app_url: app_url.trim_end_matches('/').to_string(), ..self }
+    }
+
+    /// Reminds attendees at the times `config` lists.
+    pub fn with_reminders(self, config: &RemindersConfig) -> Self {
+        Self { reminders_before: config.before.clone(), ..self }
+    }
+
+    /// Creates events without a zone in the one `config` names.
+    pub fn with_events(self, config: &EventsConfig) -> Self {
+        Self { default_timezone: config.default_timezone.clone(), ..self }
+    }
+
+    /// Replaces the upload storage, e.g. with a temporary directory.
+    pub fn with_storage(self, storage: Arc<dyn ObjectStorage>, max_upload_bytes: usize) -> Self {
+        Self { storage, max_upload_bytes, ..self }
+    }
+}
+
+impl FromRef<AppState> for DbPool {
+    fn from_ref(state: &AppState) -> Self {
+        state.pool.clone()
+    }
+}
@@ -0,0 +1,82 @@
+use super::payment::{hex, network_id, PaymentError, UnsignedPayment, BASE_FEE, SIGNING_WINDOW};
+use chrono::{DateTime, Utc};
+use stellar_xdr::curr::{
+    AccountId, AlphaNum12, AlphaNum4, Asset, AssetCode12, AssetCode4, ClaimPredicate, Claimant, ClaimantV0,
+    CreateClaimableBalanceOp, Limits, Memo, MuxedAccount, Operation, OperationBody, Preconditions, SequenceNumber,
+    TimeBounds, TimePoint, Transaction, TransactionEnvelope, TransactionExt, TransactionV1Envelope, WriteXdr,
+};
+
+/// Claimable balances one issuance transaction creates at most; the network allows
+/// 100 operations per transaction.
+pub const MAX_TOKENS_PER_TRANSACTION: usize = 100;
+/// Each attendee can claim one whole token, in stroops.
+pub const TOKEN_AMOUNT: i64 = 10_000_000;
+
+/// Code of an event's proof-of-attendance asset, e.g. `POA42`.
+pub fn attendance_asset_code(event_id: i32) -> String {
+    format!("POA{}", event_id)
+}
+
+fn attendance_asset(code: &str, issuer: AccountId) -> Result<Asset, PaymentError> {
+    let invalid = || PaymentError::InvalidAccount(code.to_string());
+    if code.len() <= 4 {
+        Ok(Asset::CreditAlphanum4(AlphaNum4 { asset_code: code.parse::<AssetCode4>().map_err(|_| invalid())?, issuer }))
+    } else {
+        Ok(Asset::CreditAlphanum12(AlphaNum12 { asset_code: code.parse::<AssetCode12>().map_err(|_| invalid())?, issuer }))
+    }
+}
+
+/// Builds one transaction from `issuer` creating a claimable balance of the event's
+/// proof-of-attendance asset for each attendee, who can claim it unconditionally once
+/// they trust the asset. Uses the sequence number after `issuer_sequence` and is valid
+/// until `now` + [`SIGNING_WINDOW`].
+pub fn build_attendance_tokens(
+    network_passphrase: &str,
+    issuer: &str,
+    issuer_sequence: i64,
+    event_id: i32,
+    attendees: &[String],
+    now: DateTime<Utc>,
+) -> Result<UnsignedPayment, PaymentError> {
+    if attendees.is_empty() || attendees.len() > MAX_TOKENS_PER_TRANSACTION {
+        return Err(PaymentError::InvalidEnvelope);
+    }
+    let issuer_id: AccountId = issuer.trim().parse().map_err(|_| PaymentError::InvalidAccount(issuer.to_string()))?;
+    let asset = attendance_asset(&attendance_asset_code(event_id), issuer_id)?;
+
+    let operations = attendees
+        .iter()
+        .map(|attendee| {
+            let destination: AccountId =
+                attendee.trim().parse().map_err(|_| PaymentError::InvalidAccount(attendee.clone()))?;
+            let claimant = Claimant::ClaimantTypeV0(ClaimantV0 { destination, predicate: ClaimPredicate::Unconditional });
+            Ok(Operation {
+                source_account: None,
+                body: OperationBody::CreateClaimableBalance(CreateClaimableBalanceOp {
+                    asset: asset.clone(),
+                    amount: TOKEN_AMOUNT,
+                    claimants: vec![claimant].try_into().map_err(|_| PaymentError::InvalidEnvelope)?,
+                }),
+            })
+        })
+        .collect::<Result<Vec<_>, PaymentError>>()?;
+
+    let tx = Transaction {
+        source_account: issuer.trim().parse::<MuxedAccount>().map_err(|_| PaymentError::InvalidAccount(issuer.to_string()))?,
+        fee: BASE_FEE * operations.len() as u32,
+        seq_num: SequenceNumber(issuer_sequence + 1),
+        cond: Preconditions::Time(TimeBounds {
+            min_time: TimePoint(0),
+            max_time: TimePoint((now + SIGNING_WINDOW).timestamp() as u64),
+        }),
+        memo: Memo::Text(format!("Attendance {}", event_id).try_into().unwrap_or_default()),
+        operations: operations.try_into().map_err(|_| PaymentError::InvalidEnvelope)?,
+        ext: TransactionExt::V0,
+    };
+
+    let envelope = TransactionEnvelope::Tx(TransactionV1Envelope { tx, signatures: Default::default() });
+    let hash = envelope.hash(network_id(network_passphrase)).map_err(|_| PaymentError::InvalidEnvelope)?;
+    let envelope_xdr = envelope.to_xdr_base64(Limits::none()).map_err(|_| PaymentError::InvalidEnvelope)?;
+
+    Ok(UnsignedPayment { envelope_xdr, hash: hex(hash) })
+}
\ No newline at end of file
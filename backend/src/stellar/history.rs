@@ -0,0 +1,195 @@
+use super::horizon::{HorizonClient, HorizonError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Transactions fetched per page when reading an account's history
+pub const TRANSACTIONS_PAGE_SIZE: usize = 100;
+/// Pages read at most per account, so one busy account cannot stall a verification run
+const MAX_TRANSACTION_PAGES: usize = 10;
+
+/// How an account came into existence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountFunding {
+    pub funded_at: DateTime<Utc>,
+    /// Account that sent the starting balance
+    pub funder: String,
+}
+
+/// A successful transaction an account took part in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountTransaction {
+    pub hash: String,
+    pub paging_token: String,
+    /// Account that submitted and paid for the transaction
+    pub source_account: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Read-only Horizon queries about an account's history. Implemented over HTTP by
+/// [`HorizonClient`] and from recorded responses by [`super::RecordedHorizon`].
+#[async_trait]
+pub trait AccountHistory: Send + Sync {
+    /// When and by whom the account was created; `None` if it does not exist.
+    async fn account_funding(&self, account_id: &str) -> Result<Option<AccountFunding>, HorizonError>;
+
+    /// One page of the account's successful transactions, newest first. Pass the last
+    /// record's `paging_token` as `cursor` for the next page; an empty page is the end.
+    async fn account_transactions(
+        &self,
+        account_id: &str,
+        cursor: Option<&str>,
+    ) -> Result<Vec<AccountTransaction>, HorizonError>;
+}
+
+pub(crate) fn funding_path(account_id: &str) -> String {
+    format!("/accounts/{}/operations?order=asc&limit=1", account_id)
+}
+
+pub(crate) fn transactions_path(account_id: &str, cursor: Option<&str>) -> String {
+    match cursor {
+        Some(cursor) => format!(
+            "/accounts/{}/transactions?order=desc&limit={}&cursor={}",
+            account_id, TRANSACTIONS_PAGE_SIZE, cursor
+        ),
+        None => format!("/accounts/{}/transactions?order=desc&limit={}", account_id, TRANSACTIONS_PAGE_SIZE),
+    }
+}
+
+#[derive(Deserialize)]
+struct Page<T> {
+    #[serde(rename = "_embedded")]
+    embedded: Embedded<T>,
+}
+
+#[derive(Deserialize)]
+struct Embedded<T> {
+    records: Vec<T>,
+}
+
+#[derive(Deserialize)]
+struct OperationRecord {
+    #[serde(rename = "type")]
+    kind: String,
+    created_at: DateTime<Utc>,
+    funder: Option<String>,
+    account: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TransactionRecord {
+    hash: String,
+    paging_token: String,
+    source_account: String,
+    created_at: DateTime<Utc>,
+    #[serde(default = "successful_by_default")]
+    successful: bool,
+}
+
+fn successful_by_default() -> bool {
+    true
+}
+
+fn invalid(error: serde_json::Error) -> HorizonError {
+    HorizonError::Unavailable(format!("Unexpected Horizon response: {}", error))
+}
+
+/// The funding in an account's oldest operation, which creates it.
+pub(crate) fn parse_funding(account_id: &str, page: Option<Value>) -> Result<Option<AccountFunding>, HorizonError> {
+    let Some(page) = page else {
+        return Ok(None);
+    };
+    let page: Page<OperationRecord> = serde_json::from_value(page).map_err(invalid)?;
+    Ok(page.embedded.records.into_iter().next().and_then(|operation| {
+        let creates_account = operation.kind == "create_account" && operation.account.as_deref() == Some(account_id);
+        creates_account.then(|| AccountFunding { funded_at: operation.created_at, funder: operation.funder.unwrap_or_default() })
+    }))
+}
+
+pub(crate) fn parse_transactions(page: Option<Value>) -> Result<Vec<AccountTransaction>, HorizonError> {
+    let Some(page) = page else {
+        return Ok(Vec::new());
+    };
+    let page: Page<TransactionRecord> = serde_json::from_value(page).map_err(invalid)?;
+    Ok(page
+        .embedded
+        .records
+        .into_iter()
+        .filter(|record| record.successful)
+        .map(|record| AccountTransaction {
+            hash: record.hash,
+            paging_token: record.paging_token,
+            source_account: record.source_account,
+            created_at: record.created_at,
+        })
+        .collect())
+}
+
+#[async_trait]
+impl AccountHistory for HorizonClient {
+    async fn account_funding(&self, account_id: &str) -> Result<Option<AccountFunding>, HorizonError> {
+        parse_funding(account_id, self.get_json(&funding_path(account_id)).await?)
+    }
+
+    async fn account_transactions(
+        &self,
+        account_id: &str,
+        cursor: Option<&str>,
+    ) -> Result<Vec<AccountTransaction>, HorizonError> {
+        parse_transactions(self.get_json(&transactions_path(account_id, cursor)).await?)
+    }
+}
+
+/// What an account did during a period, as far as counting it as active goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountActivity {
+    pub funding: Option<AccountFunding>,
+    /// Latest transaction the account itself submitted during the period
+    pub last_transaction_at: Option<DateTime<Utc>>,
+}
+
+impl AccountActivity {
+    /// Created during the period and used by its owner during it, not just funded.
+    pub fn is_active_in(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> bool {
+        let funded_in_period = self
+            .funding
+            .as_ref()
+            .is_some_and(|funding| funding.funded_at >= start && funding.funded_at < end);
+        funded_in_period && self.last_transaction_at.is_some()
+    }
+}
+
+/// Reads an account's funding and the transactions it submitted in `[start, end)`.
+pub async fn account_activity(
+    history: &dyn AccountHistory,
+    account_id: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<AccountActivity, HorizonError> {
+    let funding = history.account_funding(account_id).await?;
+    let Some(funded_at) = funding.as_ref().map(|funding| funding.funded_at) else {
+        return Ok(AccountActivity { funding, last_transaction_at: None });
+    };
+
+    // Nothing the account did can predate its funding
+    let earliest = start.max(funded_at);
+    let mut cursor: Option<String> = None;
+    for _ in 0..MAX_TRANSACTION_PAGES {
+        let page = history.account_transactions(account_id, cursor.as_deref()).await?;
+        for transaction in &page {
+            if transaction.created_at < earliest {
+                return Ok(AccountActivity { funding, last_transaction_at: None });
+            }
+            if transaction.created_at < end && transaction.source_account == account_id {
+                return Ok(AccountActivity { funding, last_transaction_at: Some(transaction.created_at) });
+            }
+        }
+        match page.last() {
+            Some(last) if page.len() == TRANSACTIONS_PAGE_SIZE => cursor = Some(last.paging_token.clone()),
+            _ => break,
+        }
+    }
+
+    Ok(AccountActivity { funding, last_transaction_at: None })
+}
\ No newline at end of file
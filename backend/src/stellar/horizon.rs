@@ -0,0 +1,166 @@
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::time::Duration;
+use thiserror::Error;
+
+/// A transaction Horizon found in a ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LedgerTransaction {
+    pub hash: String,
+    pub ledger: u32,
+    /// Failed transactions are in the ledger but none of their operations applied
+    pub successful: bool,
+}
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum HorizonError {
+    #[error("Account {0} does not exist on the network")]
+    AccountNotFound(String),
+    /// The network refused the transaction; it was not and will not be applied
+    #[error("Transaction rejected: {0}")]
+    Rejected(String),
+    /// Horizon could not be reached or timed out; a submitted transaction may still apply
+    #[error("Horizon unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// The parts of the Horizon API the payout flow uses. Implemented over HTTP by
+/// [`HorizonClient`]; tests substitute their own implementation.
+#[async_trait]
+pub trait Horizon: Send + Sync {
+    /// Current sequence number of an account.
+    async fn account_sequence(&self, account_id: &str) -> Result<i64, HorizonError>;
+
+    /// Submits a base64 transaction envelope and waits for it to be included in a ledger.
+    async fn submit_transaction(&self, envelope_xdr: &str) -> Result<LedgerTransaction, HorizonError>;
+
+    /// Looks up a transaction by hash; `None` if no ledger contains it.
+    async fn find_transaction(&self, hash: &str) -> Result<Option<LedgerTransaction>, HorizonError>;
+}
+
+pub struct HorizonClient {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl HorizonClient {
+    pub fn new(base_url: &str) -> Self {
+        let client = reqwest::Client::builder()
+            // Horizon itself gives up on submissions after 30 seconds
+            .timeout(Duration::from_secs(40))
+            .build()
+            .unwrap_or_default();
+
+        Self { client, base_url: base_url.trim_end_matches('/').to_string() }
+    }
+
+    /// A JSON resource of the Horizon API; `None` if Horizon answers 404.
+    pub(crate) async fn get_json(&self, path: &str) -> Result<Option<serde_json::Value>, HorizonError> {
+        let response = self.client.get(format!("{}{}", self.base_url, path)).send().await.map_err(unavailable)?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        response.error_for_status().map_err(unavailable)?.json().await.map(Some).map_err(unavailable)
+    }
+}
+
+#[derive(Deserialize)]
+struct AccountRecord {
+    sequence: String,
+}
+
+#[derive(Deserialize)]
+struct TransactionRecord {
+    hash: String,
+    ledger: u32,
+    #[serde(default = "successful_by_default")]
+    successful: bool,
+}
+
+fn successful_by_default() -> bool {
+    true
+}
+
+impl From<TransactionRecord> for LedgerTransaction {
+    fn from(record: TransactionRecord) -> Self {
+        LedgerTransaction { hash: record.hash, ledger: record.ledger, successful: record.successful }
+    }
+}
+
+/// The `extras.result_codes` Horizon returns with a rejected transaction.
+#[derive(Deserialize)]
+struct SubmissionProblem {
+    title: Option<String>,
+    extras: Option<ProblemExtras>,
+}
+
+#[derive(Deserialize)]
+struct ProblemExtras {
+    result_codes: Option<serde_json::Value>,
+}
+
+fn unavailable(error: reqwest::Error) -> HorizonError {
+    HorizonError::Unavailable(error.to_string())
+}
+
+#[async_trait]
+impl Horizon for HorizonClient {
+    async fn account_sequence(&self, account_id: &str) -> Result<i64, HorizonError> {
+        let response = self
+            .client
+            .get(format!("{}/accounts/{}", self.base_url, account_id))
+            .send()
+            .await
+            .map_err(unavailable)?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(HorizonError::AccountNotFound(account_id.to_string()));
+        }
+        let account: AccountRecord = response.error_for_status().map_err(unavailable)?.json().await.map_err(unavailable)?;
+        account
+            .sequence
+            .parse()
+            .map_err(|_| HorizonError::Unavailable(format!("Invalid sequence number {}", account.sequence)))
+    }
+
+    async fn submit_transaction(&self, envelope_xdr: &str) -> Result<LedgerTransaction, HorizonError> {
+        let response = self
+            .client
+            .post(format!("{}/transactions", self.base_url))
+            .form(&[("tx", envelope_xdr)])
+            .send()
+            .await
+            .map_err(unavailable)?;
+
+        match response.status() {
+            status if status.is_success() => Ok(response.json::<TransactionRecord>().await.map_err(unavailable)?.into()),
+            StatusCode::BAD_REQUEST => {
+                let problem: SubmissionProblem = response.json().await.map_err(unavailable)?;
+                let detail = problem
+                    .extras
+                    .and_then(|extras| extras.result_codes)
+                    .map(|codes| codes.to_string())
+                    .or(problem.title)
+                    .unwrap_or_else(|| "no result codes".to_string());
+                Err(HorizonError::Rejected(detail))
+            }
+            status => Err(HorizonError::Unavailable(format!("Horizon answered {}", status))),
+        }
+    }
+
+    async fn find_transaction(&self, hash: &str) -> Result<Option<LedgerTransaction>, HorizonError> {
+        let response = self
+            .client
+            .get(format!("{}/transactions/{}", self.base_url, hash))
+            .send()
+            .await
+            .map_err(unavailable)?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let record: TransactionRecord = response.error_for_status().map_err(unavailable)?.json().await.map_err(unavailable)?;
+        Ok(Some(record.into()))
+    }
+}
@@ -0,0 +1,13 @@
+pub mod attendance;
+pub mod history;
+pub mod horizon;
+pub mod payment;
+pub mod recorded;
+pub mod signed_message;
+
+pub use attendance::*;
+pub use history::*;
+pub use horizon::*;
+pub use payment::*;
+pub use recorded::*;
+pub use signed_message::*;
\ No newline at end of file
@@ -0,0 +1,187 @@
+use super::horizon::{Horizon, HorizonError};
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use sha2::{Digest, Sha256};
+use stellar_xdr::curr::{
+    AccountId, AlphaNum4, Asset, AssetCode4, Limits, Memo, MuxedAccount, Operation, OperationBody, PaymentOp,
+    Preconditions, ReadXdr, SequenceNumber, TimeBounds, TimePoint, Transaction, TransactionEnvelope, TransactionExt,
+    TransactionV1Envelope, WriteXdr,
+};
+use thiserror::Error;
+
+/// Fee offered per operation, in stroops. Payouts have one operation.
+pub const BASE_FEE: u32 = 100;
+/// How long a built payout may wait for its signature before the network refuses it.
+pub const SIGNING_WINDOW: Duration = Duration::days(7);
+const STROOPS_PER_UNIT: i64 = 10_000_000;
+/// Stellar's limit on the length of a text memo, in bytes.
+pub const MEMO_TEXT_BYTES: usize = 28;
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum PaymentError {
+    #[error("{0} is not a Stellar account")]
+    InvalidAccount(String),
+    #[error("{0} cannot be paid on Stellar")]
+    InvalidAmount(Decimal),
+    #[error("The signed transaction is not valid XDR")]
+    InvalidEnvelope,
+    #[error("The signed transaction differs from the payout that was prepared")]
+    EnvelopeMismatch,
+    #[error("The transaction carries no signature")]
+    Unsigned,
+}
+
+/// What a payout sends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayoutAsset {
+    Native,
+    Credit { code: String, issuer: String },
+}
+
+/// A single-payment transaction waiting for the source account's signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsignedPayment {
+    /// Base64 `TransactionEnvelope` with no signatures
+    pub envelope_xdr: String,
+    /// Hex hash the transaction will have on the network
+    pub hash: String,
+}
+
+/// The outcome of handing a signed payout to the network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Paid { ledger: u32 },
+    /// The payment did not and will not happen; a new transaction can be built
+    Failed(String),
+    /// Nobody knows yet whether the payment happened; submit the same envelope again
+    Pending(String),
+}
+
+/// SHA-256 of the network passphrase, which signatures and hashes commit to.
+pub fn network_id(network_passphrase: &str) -> [u8; 32] {
+    Sha256::digest(network_passphrase.as_bytes()).into()
+}
+
+fn account(address: &str) -> Result<AccountId, PaymentError> {
+    address.trim().parse().map_err(|_| PaymentError::InvalidAccount(address.to_string()))
+}
+
+fn muxed_account(address: &str) -> Result<MuxedAccount, PaymentError> {
+    address.trim().parse().map_err(|_| PaymentError::InvalidAccount(address.to_string()))
+}
+
+fn stroops(amount: Decimal) -> Result<i64, PaymentError> {
+    amount
+        .checked_mul(Decimal::from(STROOPS_PER_UNIT))
+        .filter(|stroops| stroops.fract().is_zero() && stroops.is_sign_positive() && !stroops.is_zero())
+        .and_then(|stroops| stroops.to_i64())
+        .ok_or(PaymentError::InvalidAmount(amount))
+}
+
+/// The longest prefix of `memo` that fits a text memo, which Stellar limits to
+/// [`MEMO_TEXT_BYTES`] bytes. Never cuts a character in half.
+pub fn memo_text(memo: &str) -> &str {
+    let end = memo
+        .char_indices()
+        .map(|(start, c)| start + c.len_utf8())
+        .take_while(|end| *end <= MEMO_TEXT_BYTES)
+        .last()
+        .unwrap_or(0);
+    &memo[..end]
+}
+
+pub(crate) fn hex(bytes: [u8; 32]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Builds a payment of `amount` from `source` to `destination`, using the sequence
+/// number after `source_sequence` and valid until `now` + [`SIGNING_WINDOW`].
+#[allow(clippy::too_many_arguments)]
+pub fn build_payment(
+    network_passphrase: &str,
+    source: &str,
+    source_sequence: i64,
+    destination: &str,
+    asset: &PayoutAsset,
+    amount: Decimal,
+    memo: &str,
+    now: DateTime<Utc>,
+) -> Result<UnsignedPayment, PaymentError> {
+    let asset = match asset {
+        PayoutAsset::Native => Asset::Native,
+        PayoutAsset::Credit { code, issuer } => Asset::CreditAlphanum4(AlphaNum4 {
+            asset_code: code.parse::<AssetCode4>().map_err(|_| PaymentError::InvalidAccount(code.clone()))?,
+            issuer: account(issuer)?,
+        }),
+    };
+
+    let payment = Operation {
+        source_account: None,
+        body: OperationBody::Payment(PaymentOp { destination: muxed_account(destination)?, asset, amount: stroops(amount)? }),
+    };
+
+    let tx = Transaction {
+        source_account: muxed_account(source)?,
+        fee: BASE_FEE,
+        seq_num: SequenceNumber(source_sequence + 1),
+        cond: Preconditions::Time(TimeBounds {
+            min_time: TimePoint(0),
+            max_time: TimePoint((now + SIGNING_WINDOW).timestamp() as u64),
+        }),
+        memo: Memo::Text(memo_text(memo).try_into().map_err(|_| PaymentError::InvalidEnvelope)?),
+        operations: vec![payment].try_into().map_err(|_| PaymentError::InvalidEnvelope)?,
+        ext: TransactionExt::V0,
+    };
+
+    let envelope = TransactionEnvelope::Tx(TransactionV1Envelope { tx, signatures: Default::default() });
+    let hash = envelope.hash(network_id(network_passphrase)).map_err(|_| PaymentError::InvalidEnvelope)?;
+    let envelope_xdr = envelope.to_xdr_base64(Limits::none()).map_err(|_| PaymentError::InvalidEnvelope)?;
+
+    Ok(UnsignedPayment { envelope_xdr, hash: hex(hash) })
+}
+
+/// Checks that `signed_xdr` is the transaction of `unsigned_xdr` with at least one
+/// signature added, so a wallet cannot swap in a different payment.
+pub fn verify_signed(unsigned_xdr: &str, signed_xdr: &str) -> Result<(), PaymentError> {
+    let decode = |xdr: &str| match TransactionEnvelope::from_xdr_base64(xdr.trim(), Limits::none()) {
+        Ok(TransactionEnvelope::Tx(envelope)) => Ok(envelope),
+        _ => Err(PaymentError::InvalidEnvelope),
+    };
+
+    let unsigned = decode(unsigned_xdr)?;
+    let signed = decode(signed_xdr)?;
+    if signed.tx != unsigned.tx {
+        return Err(PaymentError::EnvelopeMismatch);
+    }
+    if signed.signatures.is_empty() {
+        return Err(PaymentError::Unsigned);
+    }
+    Ok(())
+}
+
+/// Submits a signed payout whose hash is `hash`. When an earlier submission's outcome
+/// was unknown (`resubmission`), the ledger is checked first so a payout is never
+/// reported as failed after it went through.
+pub async fn submit_payment(
+    horizon: &dyn Horizon,
+    hash: &str,
+    signed_xdr: &str,
+    resubmission: bool,
+) -> SubmissionOutcome {
+    if resubmission {
+        match horizon.find_transaction(hash).await {
+            Ok(Some(transaction)) if transaction.successful => return SubmissionOutcome::Paid { ledger: transaction.ledger },
+            Ok(Some(_)) => return SubmissionOutcome::Failed("The transaction was included in a ledger but failed".to_string()),
+            Ok(None) => {}
+            Err(error) => return SubmissionOutcome::Pending(error.to_string()),
+        }
+    }
+
+    match horizon.submit_transaction(signed_xdr.trim()).await {
+        Ok(transaction) if transaction.successful => SubmissionOutcome::Paid { ledger: transaction.ledger },
+        Ok(_) => SubmissionOutcome::Failed("The transaction was included in a ledger but failed".to_string()),
+        Err(error @ HorizonError::Unavailable(_)) => SubmissionOutcome::Pending(error.to_string()),
+        Err(error) => SubmissionOutcome::Failed(error.to_string()),
+    }
+}
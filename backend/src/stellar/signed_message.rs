@@ -0,0 +1,57 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use ed25519_dalek::{Signature, VerifyingKey};
+use sha2::{Digest, Sha256};
+use stellar_xdr::curr::{AccountId, PublicKey, Uint256};
+use thiserror::Error;
+
+/// What wallets put in front of a message before hashing and signing it (SEP-53), so a
+/// signed message can never pass for a signed transaction.
+const SIGNED_MESSAGE_PREFIX: &str = "Stellar Signed Message:\n";
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum SignatureError {
+    #[error("{0} is not a Stellar account")]
+    InvalidAccount(String),
+    #[error("The signature is not a base64 ed25519 signature")]
+    InvalidSignature,
+    #[error("The signature was not made by {0}")]
+    Mismatch(String),
+}
+
+/// The text a wallet signs to prove it is the one signing up.
+pub fn signup_message(wallet_address: &str, nonce: &str) -> String {
+    format!("Sign up to Stellar Europe as {}\nNonce: {}", wallet_address, nonce)
+}
+
+/// The text a registered wallet signs to start a session.
+pub fn signin_message(wallet_address: &str, nonce: &str) -> String {
+    format!("Sign in to Stellar Europe as {}\nNonce: {}", wallet_address, nonce)
+}
+
+/// The hash a wallet signs for `message`.
+pub fn signed_message_hash(message: &str) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(SIGNED_MESSAGE_PREFIX.as_bytes())
+        .chain_update(message.as_bytes())
+        .finalize()
+        .into()
+}
+
+/// Checks that `signature`, base64 as wallets return it, is the signature of `message` by the
+/// ed25519 key encoded in `wallet_address`.
+pub fn verify_signed_message(wallet_address: &str, message: &str, signature: &str) -> Result<(), SignatureError> {
+    let invalid_account = || SignatureError::InvalidAccount(wallet_address.to_string());
+    let AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(key))) =
+        wallet_address.trim().parse::<AccountId>().map_err(|_| invalid_account())?;
+    let key = VerifyingKey::from_bytes(&key).map_err(|_| invalid_account())?;
+
+    let signature = BASE64
+        .decode(signature.trim())
+        .ok()
+        .and_then(|bytes| Signature::from_slice(&bytes).ok())
+        .ok_or(SignatureError::InvalidSignature)?;
+
+    key.verify_strict(&signed_message_hash(message), &signature)
+        .map_err(|_| SignatureError::Mismatch(wallet_address.trim().to_string()))
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+pub mod local;
+pub mod s3;
+
+pub use local::*;
+pub use s3::*;
+
+use crate::config::StorageBackend;
+use async_trait::async_trait;
+use axum::body::Bytes;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum StorageError {
+    #[error("Object {0} not found")]
+    NotFound(String),
+    #[error("Invalid object key {0}")]
+    InvalidKey(String),
+    #[error("Storage unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// Where uploaded files are kept. Keys are `/`-separated paths such as
+/// `events/42/3f1c….jpg`; implementations never see user-chosen names.
+#[async_trait]
+pub trait ObjectStorage: Send + Sync {
+    async fn put(&self, key: &str, content_type: &str, bytes: Bytes) -> Result<(), StorageError>;
+
+    async fn get(&self, key: &str) -> Result<Bytes, StorageError>;
+
+    /// Removes an object; removing one that does not exist is not an error.
+    async fn delete(&self, key: &str) -> Result<(), StorageError>;
+}
+
+/// Keys are relative `/`-separated paths without empty, `.` or `..` segments, so
+/// no backend can be steered outside its directory or bucket.
+pub fn check_key(key: &str) -> Result<(), StorageError> {
+    let valid = !key.is_empty()
+        && !key.contains('\\')
+        && key.split('/').all(|segment| !segment.is_empty() && segment != "." && segment != "..");
+    if valid {
+        Ok(())
+    } else {
+        Err(StorageError::InvalidKey(key.to_string()))
+    }
+}
+
+pub fn open_storage(backend: &StorageBackend) -> Arc<dyn ObjectStorage> {
+    match backend {
+        StorageBackend::Local { root } => Arc::new(LocalStorage::new(root.clone())),
+        StorageBackend::S3 { endpoint, bucket, region, access_key_id, secret_access_key } => {
+            let credentials = S3Credentials { access_key_id: access_key_id.clone(), secret_access_key: secret_access_key.clone() };
+            Arc::new(S3Storage::new(endpoint, bucket, region, credentials))
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,175 @@
+use super::{check_key, ObjectStorage, StorageError};
+use async_trait::async_trait;
+use axum::body::Bytes;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::{Method, StatusCode};
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, Clone)]
+pub struct S3Credentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+/// Keeps objects in a bucket of any S3-compatible service (AWS, MinIO, Garage, …).
+/// Requests use path-style addressing, `{endpoint}/{bucket}/{key}`, which every
+/// such service accepts.
+pub struct S3Storage {
+    client: reqwest::Client,
+    endpoint: reqwest::Url,
+    bucket: String,
+    region: String,
+    credentials: S3Credentials,
+}
+
+impl S3Storage {
+    /// `endpoint` is the service's base URL, e.g. `http://localhost:9000` for MinIO.
+    pub fn new(endpoint: &reqwest::Url, bucket: &str, region: &str, credentials: S3Credentials) -> Self {
+        let client = reqwest::Client::builder().timeout(Duration::from_secs(30)).build().unwrap_or_default();
+
+        Self { client, endpoint: endpoint.clone(), bucket: bucket.to_string(), region: region.to_string(), credentials }
+    }
+
+    async fn send(&self, method: Method, key: &str, content_type: Option<&str>, body: Bytes) -> Result<reqwest::Response, StorageError> {
+        check_key(key)?;
+
+        let path = format!("{}/{}/{}", self.endpoint.path().trim_end_matches('/'), uri_encode(&self.bucket), uri_encode_path(key));
+        let host = match self.endpoint.port() {
+            Some(port) => format!("{}:{}", self.endpoint.host_str().unwrap_or_default(), port),
+            None => self.endpoint.host_str().unwrap_or_default().to_string(),
+        };
+        let payload_hash = sha256_hex(&body);
+        let amz_date = amz_date(Utc::now());
+
+        let mut headers = vec![
+            ("host".to_string(), host),
+            ("x-amz-content-sha256".to_string(), payload_hash.clone()),
+            ("x-amz-date".to_string(), amz_date.clone()),
+        ];
+        if let Some(content_type) = content_type {
+            headers.push(("content-type".to_string(), content_type.to_string()));
+        }
+        let authorization = sign_v4(method.as_str(), &path, &headers, &payload_hash, &amz_date, &self.region, &self.credentials);
+
+        let mut url = self.endpoint.clone();
+        url.set_path(&path);
+        let mut request = self.client.request(method, url).header("authorization", authorization).body(body);
+        for (name, value) in headers.into_iter().filter(|(name, _)| name != "host") {
+            request = request.header(name, value);
+        }
+
+        request.send().await.map_err(|error| StorageError::Unavailable(error.to_string()))
+    }
+}
+
+async fn expect_success(response: reqwest::Response, key: &str) -> Result<reqwest::Response, StorageError> {
+    match response.status() {
+        status if status.is_success() => Ok(response),
+        StatusCode::NOT_FOUND => Err(StorageError::NotFound(key.to_string())),
+        status => {
+            let detail = response.text().await.unwrap_or_default();
+            Err(StorageError::Unavailable(format!("S3 answered {}: {}", status, detail.trim())))
+        }
+    }
+}
+
+#[async_trait]
+impl ObjectStorage for S3Storage {
+    async fn put(&self, key: &str, content_type: &str, bytes: Bytes) -> Result<(), StorageError> {
+        let response = self.send(Method::PUT, key, Some(content_type), bytes).await?;
+        expect_success(response, key).await.map(|_| ())
+    }
+
+    async fn get(&self, key: &str) -> Result<Bytes, StorageError> {
+        let response = self.send(Method::GET, key, None, Bytes::new()).await?;
+        expect_success(response, key)
+            .await?
+            .bytes()
+            .await
+            .map_err(|error| StorageError::Unavailable(error.to_string()))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        let response = self.send(Method::DELETE, key, None, Bytes::new()).await?;
+        match expect_success(response, key).await {
+            Err(StorageError::NotFound(_)) | Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// The `Authorization` header for an AWS Signature Version 4 request to S3 without
+/// a query string. `headers` are lower-case names with their values and must
+/// include `host`, `x-amz-content-sha256` and `x-amz-date`; all of them are signed.
+pub fn sign_v4(
+    method: &str,
+    path: &str,
+    headers: &[(String, String)],
+    payload_hash: &str,
+    amz_date: &str,
+    region: &str,
+    credentials: &S3Credentials,
+) -> String {
+    let mut headers: Vec<(String, String)> = headers
+        .iter()
+        .map(|(name, value)| (name.to_lowercase(), value.trim().to_string()))
+        .collect();
+    headers.sort();
+
+    let canonical_headers: String = headers.iter().map(|(name, value)| format!("{}:{}\n", name, value)).collect();
+    let signed_headers = headers.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>().join(";");
+    let canonical_request = format!("{}\n{}\n\n{}\n{}\n{}", method, path, canonical_headers, signed_headers, payload_hash);
+
+    let date = &amz_date[..8];
+    let scope = format!("{}/{}/s3/aws4_request", date, region);
+    let string_to_sign = format!("AWS4-HMAC-SHA256\n{}\n{}\n{}", amz_date, scope, sha256_hex(canonical_request.as_bytes()));
+
+    let mut key = hmac(format!("AWS4{}", credentials.secret_access_key).as_bytes(), date.as_bytes());
+    for part in [region, "s3", "aws4_request"] {
+        key = hmac(&key, part.as_bytes());
+    }
+    let signature = hex(&hmac(&key, string_to_sign.as_bytes()));
+
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{},SignedHeaders={},Signature={}",
+        credentials.access_key_id, scope, signed_headers, signature
+    )
+}
+
+/// The `x-amz-date` form of a timestamp, e.g. `20130524T000000Z`.
+pub fn amz_date(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    hex(&Sha256::digest(bytes))
+}
+
+fn hmac(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Percent-encodes everything but the RFC 3986 unreserved characters, as SigV4 requires.
+fn uri_encode(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn uri_encode_path(key: &str) -> String {
+    key.split('/').map(uri_encode).collect::<Vec<_>>().join("/")
+}
\ No newline at end of file
@@ -0,0 +1,128 @@
+use crate::database::connection::DbPool;
+use crate::database::repositories::{EventRepository, LinkedAccountRepository};
+use crate::jobs::{schedule, Job, JobError};
+use crate::state::AppState;
+use crate::stellar::{account_activity, AccountHistory};
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use tracing::{info, warn};
+
+/// The UTC calendar month an event falls in, `[start, end)`. A linked account counts as
+/// active if it was funded and made a transaction of its own within this month.
+pub fn activity_month(event_date: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+    let (year, month) = (event_date.year(), event_date.month());
+    let start = Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).unwrap();
+    let end = if month == 12 {
+        Utc.with_ymd_and_hms(year + 1, 1, 1, 0, 0, 0).unwrap()
+    } else {
+        Utc.with_ymd_and_hms(year, month + 1, 1, 0, 0, 0).unwrap()
+    };
+    (start, end)
+}
+
+/// What one verification run did.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct VerificationSummary {
+    pub checked: usize,
+    pub verified: usize,
+    pub unverified: usize,
+    /// Links left pending because Horizon could not be reached
+    pub failed: usize,
+}
+
+/// Checks every pending linked account of events that have started by `now`.
+///
+/// An account active in its event's month becomes Verified for good. One that is not yet
+/// active stays Pending until the month is over, then becomes Unverified. Events with
+/// newly verified accounts get their monthly active accounts recounted.
+pub async fn run_account_verification(
+    pool: &DbPool,
+    history: &dyn AccountHistory,
+    now: DateTime<Utc>,
+) -> Result<VerificationSummary, sqlx::Error> {
+    let mut summary = VerificationSummary::default();
+    let mut recount = BTreeSet::new();
+
+    for pending in LinkedAccountRepository::list_pending(pool, now).await? {
+        let link = pending.link;
+        let (start, end) = activity_month(pending.event_date);
+        summary.checked += 1;
+
+        let activity = match account_activity(history, &link.account_id, start, end).await {
+            Ok(activity) => activity,
+            Err(error) => {
+                let reason = error.to_string();
+                warn!("Could not check linked account {}: {}", link.account_id, reason);
+                LinkedAccountRepository::record_check(
+                    pool,
+                    link.id,
+                    "Pending",
+                    link.funded_at,
+                    link.last_transaction_at,
+                    Some(&reason),
+                )
+                .await?;
+                summary.failed += 1;
+                continue;
+            }
+        };
+
+        let funded_at = activity.funding.as_ref().map(|funding| funding.funded_at);
+        let (status, reason) = if activity.is_active_in(start, end) {
+            recount.insert(link.event_id);
+            summary.verified += 1;
+            ("Verified", None)
+        } else if now < end {
+            ("Pending", None)
+        } else {
+            summary.unverified += 1;
+            let reason = match funded_at {
+                None => "Account does not exist on the network",
+                Some(funded_at) if funded_at < start || funded_at >= end => "Account was not created in the event's month",
+                Some(_) => "Account made no transactions of its own in the event's month",
+            };
+            ("Unverified", Some(reason))
+        };
+
+        LinkedAccountRepository::record_check(pool, link.id, status, funded_at, activity.last_transaction_at, reason)
+            .await?;
+    }
+
+    for event_id in recount {
+        EventRepository::set_verified_accounts(pool, event_id).await?;
+    }
+
+    Ok(summary)
+}
+
+/// Runs [`run_account_verification`] once, and schedules the next run
+/// `account_check_interval` later. The next run is queued first, so a failed run is
+/// simply replaced by it instead of retried.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyLinkedAccounts;
+
+#[async_trait]
+impl Job for VerifyLinkedAccounts {
+    const KIND: &'static str = "verify_linked_accounts";
+
+    fn unique_key(&self) -> Option<String> {
+        Some(Self::KIND.to_string())
+    }
+
+    async fn run(&self, state: &AppState) -> Result<(), JobError> {
+        let now = Utc::now();
+        let interval = Duration::from_std(state.stellar.account_check_interval).unwrap_or(Duration::hours(1));
+        schedule(&state.pool, self, now + interval).await?;
+
+        let summary = run_account_verification(&state.pool, state.history.as_ref(), now).await?;
+        if summary.checked > 0 {
+            info!(
+                "🔗 Checked {} linked accounts: {} verified, {} unverified, {} failed",
+                summary.checked, summary.verified, summary.unverified, summary.failed
+            );
+        }
+        Ok(())
+    }
+}
\ No newline at end of file
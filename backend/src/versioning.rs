@@ -0,0 +1,120 @@
+use crate::config::VersioningConfig;
+use crate::routes::ApiRoutes;
+use crate::state::AppState;
+use axum::{
+    extract::{Request, State},
+    http::HeaderValue,
+    middleware::{from_fn_with_state, Next},
+    response::Response,
+    Router,
+};
+use chrono::{DateTime, Utc};
+
+/// Prefix of the unversioned routes that predate `/api/v1`.
+pub const LEGACY_API_PREFIX: &str = "/api";
+
+/// A published version of the HTTP API, served under `/api/v{n}`.
+///
+/// Each version owns the routes relative to its prefix (see `app::api_v1_routes`). To
+/// ship a breaking change, build the new version's routes from the previous one's with
+/// [`ApiRoutes::replace`] for the changed handlers, add both to `app::mounted_apis`, and
+/// mark the old one [`MountedApi::deprecated`] in favour of the new one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApiVersion(pub u32);
+
+impl ApiVersion {
+    pub const V1: ApiVersion = ApiVersion(1);
+    pub const CURRENT: ApiVersion = ApiVersion::V1;
+
+    pub fn prefix(&self) -> String {
+        format!("/api/v{}", self.0)
+    }
+}
+
+/// Deprecation metadata advertised on every response of a superseded version.
+#[derive(Debug, Clone)]
+pub struct Deprecation {
+    /// When the version was deprecated (`Deprecation` header, RFC 9745).
+    pub since: DateTime<Utc>,
+    /// When the version stops being served (`Sunset` header, RFC 8594).
+    pub sunset: Option<DateTime<Utc>>,
+    pub successor: ApiVersion,
+}
+
+impl Deprecation {
+    /// The unversioned `/api/*` routes, superseded by `/api/v1` on the configured dates.
+    pub fn legacy(config: &VersioningConfig) -> Self {
+        Self {
+            since: config.legacy_deprecated_at,
+            sunset: config.legacy_sunset,
+            successor: ApiVersion::V1,
+        }
+    }
+}
+
+/// The routes of one version of the API and the prefix they are served under.
+pub struct MountedApi {
+    pub prefix: String,
+    pub routes: ApiRoutes,
+    pub deprecation: Option<Deprecation>,
+}
+
+impl MountedApi {
+    pub fn new(version: ApiVersion, routes: ApiRoutes) -> Self {
+        Self::at(version.prefix(), routes)
+    }
+
+    /// Routes served under a prefix of their own, like [`LEGACY_API_PREFIX`].
+    pub fn at(prefix: impl Into<String>, routes: ApiRoutes) -> Self {
+        Self { prefix: prefix.into(), routes, deprecation: None }
+    }
+
+    pub fn deprecated(self, deprecation: Deprecation) -> Self {
+        Self { deprecation: Some(deprecation), ..self }
+    }
+}
+
+/// Nests every version under its prefix, wrapping the deprecated ones with [`deprecated`].
+pub fn mount(apis: Vec<MountedApi>) -> Router<AppState> {
+    apis.into_iter().fold(Router::new(), |router, api| {
+        let routes = api.routes.into_router();
+        router.nest(&api.prefix, match api.deprecation {
+            Some(deprecation) => deprecated(routes, deprecation),
+            None => routes,
+        })
+    })
+}
+
+/// Wraps a version's router so its responses carry `Deprecation`, `Sunset` and
+/// `Link: rel="successor-version"` headers.
+pub fn deprecated<S>(router: Router<S>, deprecation: Deprecation) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    router.layer(from_fn_with_state(deprecation, add_deprecation_headers))
+}
+
+async fn add_deprecation_headers(
+    State(deprecation): State<Deprecation>,
+    request: Request,
+    next: Next,
+) -> Response {
+    // Inside a nested router the path is relative to the version prefix
+    let successor = format!("{}{}", deprecation.successor.prefix(), request.uri().path());
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+
+    if let Ok(value) = HeaderValue::from_str(&format!("@{}", deprecation.since.timestamp())) {
+        headers.insert("deprecation", value);
+    }
+    if let Some(sunset) = deprecation.sunset {
+        if let Ok(value) = HeaderValue::from_str(&sunset.format("%a, %d %b %Y %H:%M:%S GMT").to_string()) {
+            headers.insert("sunset", value);
+        }
+    }
+    if let Ok(value) = HeaderValue::from_str(&format!("<{}>; rel=\"successor-version\"", successor)) {
+        headers.insert("link", value);
+    }
+
+    response
+}
@@ -0,0 +1,140 @@
+use backend::stellar::{
+    account_activity, AccountHistory, HorizonError, RecordedHorizon, Recording, TRANSACTIONS_PAGE_SIZE,
+};
+use backend::verification::activity_month;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde_json::json;
+
+const VERIFIED: &str = "GAODJ6EHA62V4YIEYTVSBZY77I6THZAUW4PPNCNBL6WQMQGQVRMMWHRD";
+const FUNDED_EARLIER: &str = "GAVFDU2UPQR2RXSQ6PRDFBNA342WMJ7PMTBQACD5HMTROPYI33JKB5OE";
+const IDLE: &str = "GBH3MI2IQWGC6355NWZH7JGBD3OYKWIRTBU5CA2OFPJTSD6ZFMNAJ3VG";
+const MISSING: &str = "GD72MNMD36THA24H2KCLQ2YNNE5BMHSIICVNFROPNNOSPQ5ZMIPX2ZJS";
+const FUNDER: &str = "GAO4HKYH6T66C5QK4AX7BVCZ4OXQX5UW5MQKNOLYXQ27334QZZ2OTHQQ";
+
+fn recorded() -> RecordedHorizon {
+    RecordedHorizon::from_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/horizon")).unwrap()
+}
+
+/// The month of an event held on 14 March 2026.
+fn march() -> (DateTime<Utc>, DateTime<Utc>) {
+    activity_month(Utc.with_ymd_and_hms(2026, 3, 14, 17, 30, 0).unwrap())
+}
+
+#[test]
+fn activity_month_is_the_utc_calendar_month_of_the_event() {
+    let (start, end) = march();
+    assert_eq!(start, Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap());
+    assert_eq!(end, Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap());
+
+    let (start, end) = activity_month(Utc.with_ymd_and_hms(2026, 12, 31, 23, 59, 59).unwrap());
+    assert_eq!(start, Utc.with_ymd_and_hms(2026, 12, 1, 0, 0, 0).unwrap());
+    assert_eq!(end, Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap());
+}
+
+#[test]
+fn fixture_accounts_are_valid_account_ids() {
+    for account in [VERIFIED, FUNDED_EARLIER, IDLE, MISSING, FUNDER] {
+        assert!(account.parse::<stellar_xdr::curr::AccountId>().is_ok(), "{}", account);
+    }
+}
+
+#[tokio::test]
+async fn account_funded_and_used_in_the_month_is_active() {
+    let (start, end) = march();
+    let activity = account_activity(&recorded(), VERIFIED, start, end).await.unwrap();
+
+    let funding = activity.funding.as_ref().unwrap();
+    assert_eq!(funding.funder, FUNDER);
+    assert_eq!(funding.funded_at, Utc.with_ymd_and_hms(2026, 3, 14, 18, 2, 11).unwrap());
+    // April's transaction is outside the month and the failed one on the 20th does not count
+    assert_eq!(activity.last_transaction_at, Some(Utc.with_ymd_and_hms(2026, 3, 21, 12, 30, 5).unwrap()));
+    assert!(activity.is_active_in(start, end));
+}
+
+#[tokio::test]
+async fn account_created_before_the_month_is_not_active() {
+    let (start, end) = march();
+    let activity = account_activity(&recorded(), FUNDED_EARLIER, start, end).await.unwrap();
+
+    assert!(activity.funding.is_some());
+    assert!(activity.last_transaction_at.is_some());
+    assert!(!activity.is_active_in(start, end));
+}
+
+#[tokio::test]
+async fn account_only_others_transacted_with_is_not_active() {
+    let (start, end) = march();
+    let activity = account_activity(&recorded(), IDLE, start, end).await.unwrap();
+
+    assert!(activity.funding.is_some());
+    assert_eq!(activity.last_transaction_at, None);
+    assert!(!activity.is_active_in(start, end));
+}
+
+#[tokio::test]
+async fn account_missing_from_the_network_has_no_funding() {
+    let (start, end) = march();
+    let activity = account_activity(&recorded(), MISSING, start, end).await.unwrap();
+
+    assert_eq!(activity.funding, None);
+    assert!(!activity.is_active_in(start, end));
+}
+
+#[tokio::test]
+async fn unrecorded_requests_fail_as_unavailable() {
+    let horizon = RecordedHorizon::default();
+    let error = horizon.account_funding(VERIFIED).await.unwrap_err();
+    assert!(matches!(error, HorizonError::Unavailable(_)));
+}
+
+fn transaction(source: &str, created_at: DateTime<Utc>, paging_token: u64) -> serde_json::Value {
+    json!({
+        "hash": format!("{:064x}", paging_token),
+        "paging_token": paging_token.to_string(),
+        "successful": true,
+        "source_account": source,
+        "created_at": created_at.to_rfc3339(),
+    })
+}
+
+fn page(records: Vec<serde_json::Value>) -> serde_json::Value {
+    json!({ "_embedded": { "records": records } })
+}
+
+#[tokio::test]
+async fn own_transaction_on_a_later_page_is_found() {
+    let (start, end) = march();
+    let funded_at = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+    let mut horizon = RecordedHorizon::default();
+    horizon.record(Recording {
+        path: format!("/accounts/{}/operations?order=asc&limit=1", VERIFIED),
+        status: 200,
+        body: page(vec![json!({
+            "type": "create_account",
+            "created_at": funded_at.to_rfc3339(),
+            "funder": FUNDER,
+            "account": VERIFIED,
+        })]),
+    });
+
+    // A full first page of payments received late in the month, then the account's own
+    let busy = Utc.with_ymd_and_hms(2026, 3, 30, 0, 0, 0).unwrap();
+    let first: Vec<_> = (0..TRANSACTIONS_PAGE_SIZE as u64)
+        .map(|i| transaction(FUNDER, busy - Duration::minutes(i as i64), 1000 - i))
+        .collect();
+    horizon.record(Recording {
+        path: format!("/accounts/{}/transactions?order=desc&limit={}", VERIFIED, TRANSACTIONS_PAGE_SIZE),
+        status: 200,
+        body: page(first),
+    });
+    let own = Utc.with_ymd_and_hms(2026, 3, 3, 10, 0, 0).unwrap();
+    horizon.record(Recording {
+        path: format!("/accounts/{}/transactions?order=desc&limit={}&cursor=901", VERIFIED, TRANSACTIONS_PAGE_SIZE),
+        status: 200,
+        body: page(vec![transaction(VERIFIED, own, 900), transaction(FUNDER, funded_at, 899)]),
+    });
+
+    let activity = account_activity(&horizon, VERIFIED, start, end).await.unwrap();
+    assert_eq!(activity.last_transaction_at, Some(own));
+    assert!(activity.is_active_in(start, end));
+}
\ No newline at end of file
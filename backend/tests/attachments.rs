@@ -0,0 +1,280 @@
+//! The upload tests run against the Postgres in `DATABASE_URL`; each gets a fresh, migrated database.
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Method, Request, StatusCode},
+};
+use backend::media::{accepts, clean_file_name, make_thumbnail, sniff_content_type, JPEG, ODP, PDF, PNG, WEBP};
+use backend::database::repositories::SessionRepository;
+use backend::storage::LocalStorage;
+use backend::{create_app, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use chrono::{Duration, Utc};
+use image::{ImageFormat, RgbImage};
+use shared::dto::{AttachmentKind, AttachmentListResponse, AttachmentResponse, ErrorResponse};
+use std::io::Cursor;
+use std::sync::Arc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const BOUNDARY: &str = "stellar-europe-test-boundary";
+const ORGANIZER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+const STRANGER: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+
+fn encoded(width: u32, height: u32, format: ImageFormat) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    RgbImage::from_pixel(width, height, image::Rgb([20, 120, 200]))
+        .write_to(&mut Cursor::new(&mut bytes), format)
+        .unwrap();
+    bytes
+}
+
+fn text_part(name: &str, value: &str) -> Vec<u8> {
+    format!("--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n", BOUNDARY, name, value).into_bytes()
+}
+
+fn upload(token: &str, kind: &str, file_name: &str, file: &[u8]) -> Request<Body> {
+    upload_as(Some(token), text_part("kind", kind), file_name, file)
+}
+
+/// An upload in the session `token` whose form starts with `fields`, then the file.
+fn upload_as(token: Option<&str>, fields: Vec<u8>, file_name: &str, file: &[u8]) -> Request<Body> {
+    let mut body = fields;
+    body.extend_from_slice(
+        format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\nContent-Type: image/jpeg\r\n\r\n",
+            BOUNDARY, file_name
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(file);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", BOUNDARY).as_bytes());
+
+    let mut request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/v1/events/1/attachments")
+        .header(header::CONTENT_TYPE, format!("multipart/form-data; boundary={}", BOUNDARY));
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    request.body(Body::from(body)).unwrap()
+}
+
+fn get(uri: &str, token: Option<&str>) -> Request<Body> {
+    let mut request = Request::builder().method(Method::GET).uri(uri);
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    request.body(Body::empty()).unwrap()
+}
+
+/// The organizer and a stranger, and event 1 organized by the first; returns their sessions.
+async fn seed(pool: &DbPool) -> (String, String) {
+    sqlx::query(
+        "INSERT INTO users (username, email, wallet_address, user_type)
+         VALUES ('ana', 'ana@example.org', $1, 'Ambassador'), ('rui', 'rui@example.org', $2, 'Ambassador')",
+    )
+    .bind(ORGANIZER)
+    .bind(STRANGER)
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO events (id, title, description, event_type, date, end_date, location, contact_email, organizer_id, status)
+         SELECT 1, 'Meetup', '', 'Meetup', NOW(), NOW() + INTERVAL '2 hours', 'Lisbon', 'ana@example.org', id, 'Approved'
+         FROM users WHERE username = 'ana'",
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    let mut sessions = Vec::new();
+    for wallet_address in [ORGANIZER, STRANGER] {
+        let (user_id,): (i32,) = sqlx::query_as("SELECT id FROM users WHERE wallet_address = $1")
+            .bind(wallet_address)
+            .fetch_one(pool)
+            .await
+            .unwrap();
+        let token = SessionRepository::create(pool, user_id, Utc::now() + Duration::hours(1)).await.unwrap();
+        sessions.push(token.to_string());
+    }
+    (sessions.remove(0), sessions.remove(0))
+}
+
+/// The app with uploads of at most `max_upload_bytes` kept in a fresh temporary directory.
+fn app(pool: &DbPool, max_upload_bytes: usize) -> axum::Router {
+    let root = std::env::temp_dir().join(format!("stellar-europe-uploads-{}", Uuid::new_v4()));
+    let state = AppState::new(pool.clone(), StellarConfig::default())
+        .with_storage(Arc::new(LocalStorage::new(root)), max_upload_bytes);
+    create_app(state, &CorsConfig::default(), &VersioningConfig::default())
+}
+
+async fn error_message(response: axum::response::Response) -> String {
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice::<ErrorResponse>(&body).unwrap().message
+}
+
+#[test]
+fn content_type_comes_from_the_file_not_its_name() {
+    assert_eq!(sniff_content_type(&encoded(4, 4, ImageFormat::Jpeg)), Some(JPEG));
+    assert_eq!(sniff_content_type(&encoded(4, 4, ImageFormat::Png)), Some(PNG));
+    assert_eq!(sniff_content_type(b"RIFF\x24\x00\x00\x00WEBPVP8 "), Some(WEBP));
+    assert_eq!(sniff_content_type(b"%PDF-1.7\n%\xE2\xE3"), Some(PDF));
+
+    let mut odp = b"PK\x03\x04".to_vec();
+    odp.extend_from_slice(&[0; 26]);
+    odp.extend_from_slice(b"mimetypeapplication/vnd.oasis.opendocument.presentation");
+    assert_eq!(sniff_content_type(&odp), Some(ODP));
+
+    assert_eq!(sniff_content_type(b"<html><script>alert(1)</script></html>"), None);
+    assert_eq!(sniff_content_type(b"PK\x03\x04 some other archive"), None);
+}
+
+#[test]
+fn each_kind_accepts_its_own_file_types() {
+    assert!(accepts(AttachmentKind::Receipt, PDF));
+    assert!(accepts(AttachmentKind::Receipt, JPEG));
+    assert!(!accepts(AttachmentKind::Receipt, ODP));
+    assert!(accepts(AttachmentKind::Photo, WEBP));
+    assert!(!accepts(AttachmentKind::Photo, PDF));
+    assert!(accepts(AttachmentKind::Slides, ODP));
+    assert!(!accepts(AttachmentKind::Slides, PNG));
+    assert!(accepts(AttachmentKind::Document, ODP));
+}
+
+#[test]
+fn file_names_lose_their_directories() {
+    assert_eq!(clean_file_name(Some("../../etc/passwd")), "passwd");
+    assert_eq!(clean_file_name(Some("C:\\Users\\ana\\Taxi \"airport\".pdf")), "Taxi airport.pdf");
+    assert_eq!(clean_file_name(Some("..")), "upload");
+    assert_eq!(clean_file_name(None), "upload");
+}
+
+#[test]
+fn thumbnails_fit_the_longest_side_and_keep_the_format_family() {
+    let thumbnail = make_thumbnail(&encoded(1000, 500, ImageFormat::Png), PNG).unwrap();
+    let decoded = image::load_from_memory_with_format(&thumbnail, ImageFormat::Png).unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (320, 160));
+
+    let thumbnail = make_thumbnail(&encoded(300, 900, ImageFormat::Jpeg), JPEG).unwrap();
+    assert_eq!(sniff_content_type(&thumbnail), Some(JPEG));
+    let decoded = image::load_from_memory_with_format(&thumbnail, ImageFormat::Jpeg).unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (107, 320));
+
+    assert_eq!(make_thumbnail(b"\xFF\xD8\xFF truncated", JPEG), None);
+}
+
+#[sqlx::test]
+async fn upload_of_a_disallowed_type_is_refused(pool: DbPool) {
+    let (organizer, _) = seed(&pool).await;
+    let response = app(&pool, 10 * 1024 * 1024)
+        .oneshot(upload(&organizer, "Photo", "recap.html", b"<html><script>alert(1)</script></html>"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[sqlx::test]
+async fn upload_over_the_size_limit_is_refused(pool: DbPool) {
+    let (organizer, _) = seed(&pool).await;
+    let mut file = encoded(4, 4, ImageFormat::Jpeg);
+    file.resize(10 * 1024 * 1024 + 1, 0);
+
+    let response = app(&pool, 10 * 1024 * 1024).oneshot(upload(&organizer, "Photo", "huge.jpg", &file)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let message = error_message(response).await;
+    assert!(message.contains("10485760 bytes"), "{}", message);
+}
+
+#[sqlx::test]
+async fn uploads_are_refused_before_their_file_is_read(pool: DbPool) {
+    let (_, stranger) = seed(&pool).await;
+    let mut file = encoded(4, 4, ImageFormat::Jpeg);
+    file.resize(64 * 1024, 0);
+
+    // The file is far over the limit, but who sends it is checked first
+    let response = app(&pool, 1024).oneshot(upload(&stranger, "Photo", "recap.jpg", &file)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert!(error_message(response).await.contains("organizer"));
+
+    let response = app(&pool, 1024).oneshot(upload_as(None, text_part("kind", "Photo"), "recap.jpg", &file)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let expired = Uuid::new_v4().to_string();
+    let response = app(&pool, 1024).oneshot(upload(&expired, "Photo", "recap.jpg", &file)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[sqlx::test]
+async fn a_form_padded_past_the_limit_is_refused(pool: DbPool) {
+    let (organizer, _) = seed(&pool).await;
+    // Every extra field is within the text limit, but together they are not
+    let mut fields = text_part("kind", "Photo");
+    for i in 0..40 {
+        fields.extend(text_part(&format!("padding{}", i), &"x".repeat(1000)));
+    }
+
+    let response = app(&pool, 1024).oneshot(upload_as(Some(&organizer), fields, "recap.png", &encoded(4, 4, ImageFormat::Png))).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[sqlx::test]
+async fn the_organizer_uploads_a_photo_with_a_thumbnail(pool: DbPool) {
+    let (organizer, _) = seed(&pool).await;
+    let file = encoded(400, 200, ImageFormat::Png);
+
+    let response = app(&pool, 10 * 1024 * 1024).oneshot(upload(&organizer, "Photo", "recap.png", &file)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let attachment: AttachmentResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!((attachment.kind, attachment.content_type.as_str()), (AttachmentKind::Photo, PNG));
+    assert_eq!(attachment.size_bytes, file.len() as u64);
+    assert!(attachment.has_thumbnail);
+    assert_eq!(attachment.uploaded_by.unwrap().wallet_address, ORGANIZER);
+}
+
+#[sqlx::test]
+async fn receipts_are_only_shown_to_the_organizer(pool: DbPool) {
+    let (organizer, stranger) = seed(&pool).await;
+    let app = app(&pool, 10 * 1024 * 1024);
+    let file = encoded(40, 20, ImageFormat::Png);
+    for kind in ["Receipt", "Photo"] {
+        let response = app.clone().oneshot(upload(&organizer, kind, "scan.png", &file)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let kinds = |token: Option<&str>| {
+        let app = app.clone();
+        let request = get("/api/v1/events/1/attachments", token);
+        async move {
+            let response = app.oneshot(request).await.unwrap();
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let list: AttachmentListResponse = serde_json::from_slice(&body).unwrap();
+            list.attachments.into_iter().map(|attachment| (attachment.kind, attachment.id)).collect::<Vec<_>>()
+        }
+    };
+    let everything = kinds(Some(&organizer)).await;
+    assert_eq!(everything.len(), 2);
+    assert_eq!(kinds(Some(&stranger)).await.iter().map(|(kind, _)| *kind).collect::<Vec<_>>(), [AttachmentKind::Photo]);
+    assert_eq!(kinds(None).await.len(), 1);
+
+    for (kind, id) in &everything {
+        for file in ["content", "thumbnail"] {
+            let uri = format!("/api/v1/events/1/attachments/{}/{}", id, file);
+            let status = |token| {
+                let (app, request) = (app.clone(), get(&uri, token));
+                async move { app.oneshot(request).await.unwrap().status() }
+            };
+            assert_eq!(status(Some(&organizer)).await, StatusCode::OK);
+            if *kind == AttachmentKind::Receipt {
+                assert_eq!(status(None).await, StatusCode::UNAUTHORIZED);
+                assert_eq!(status(Some(&stranger)).await, StatusCode::FORBIDDEN);
+            } else {
+                assert_eq!(status(None).await, StatusCode::OK);
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,204 @@
+//! The database tests run against the Postgres in `DATABASE_URL`; each gets a fresh, migrated database.
+
+use async_trait::async_trait;
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use backend::database::repositories::SessionRepository;
+use backend::stellar::{
+    attendance_asset_code, build_attendance_tokens, network_id, submit_payment, verify_signed, Horizon, HorizonError,
+    LedgerTransaction, PaymentError, SubmissionOutcome, BASE_FEE, MAX_TOKENS_PER_TRANSACTION, TOKEN_AMOUNT,
+};
+use backend::{create_app, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use chrono::{Duration, TimeZone, Utc};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tower::ServiceExt;
+use stellar_xdr::curr::{
+    Asset, ClaimPredicate, Claimant, DecoratedSignature, Limits, Memo, OperationBody, ReadXdr, SignatureHint,
+    TransactionEnvelope, TransactionV1Envelope, WriteXdr,
+};
+
+const PASSPHRASE: &str = "Test SDF Network ; September 2015";
+const ISSUER: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+const ALICE: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+const BOB: &str = "GDG6JBJXZIWCQCCP6VQIE3IOMOELPRL2KFEXU3FVN44XFCPFF72BWXMH";
+
+/// Stands in for Horizon: accepts every submission into ledger 77.
+struct AcceptingHorizon;
+
+#[async_trait]
+impl Horizon for AcceptingHorizon {
+    async fn account_sequence(&self, _account_id: &str) -> Result<i64, HorizonError> {
+        Ok(9)
+    }
+
+    async fn submit_transaction(&self, _envelope_xdr: &str) -> Result<LedgerTransaction, HorizonError> {
+        Ok(LedgerTransaction { hash: "abc".to_string(), ledger: 77, successful: true })
+    }
+
+    async fn find_transaction(&self, _hash: &str) -> Result<Option<LedgerTransaction>, HorizonError> {
+        Ok(None)
+    }
+}
+
+fn attendees(wallets: &[&str]) -> Vec<String> {
+    wallets.iter().map(|wallet| wallet.to_string()).collect()
+}
+
+fn decode(xdr: &str) -> TransactionV1Envelope {
+    match TransactionEnvelope::from_xdr_base64(xdr, Limits::none()).unwrap() {
+        TransactionEnvelope::Tx(envelope) => envelope,
+        _ => panic!("expected a v1 envelope"),
+    }
+}
+
+#[test]
+fn issuance_creates_one_claimable_balance_per_attendee() {
+    let now = Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap();
+    let unsigned = build_attendance_tokens(PASSPHRASE, ISSUER, 9, 42, &attendees(&[ALICE, BOB]), now).unwrap();
+    let envelope = decode(&unsigned.envelope_xdr);
+
+    assert_eq!(envelope.tx.seq_num.0, 10);
+    assert_eq!(envelope.tx.fee, 2 * BASE_FEE);
+    assert_eq!(envelope.tx.source_account.to_string(), ISSUER);
+    assert!(matches!(&envelope.tx.memo, Memo::Text(text) if text.to_string() == "Attendance 42"));
+
+    let claimants: Vec<String> = envelope
+        .tx
+        .operations
+        .iter()
+        .map(|operation| {
+            let OperationBody::CreateClaimableBalance(op) = &operation.body else {
+                panic!("expected a claimable balance");
+            };
+            let Asset::CreditAlphanum12(asset) = &op.asset else {
+                panic!("POA42 is a 12-character asset code");
+            };
+            assert_eq!(asset.asset_code.to_string(), "POA42");
+            assert_eq!(asset.issuer.to_string(), ISSUER);
+            assert_eq!(op.amount, TOKEN_AMOUNT);
+            let [Claimant::ClaimantTypeV0(claimant)] = op.claimants.as_slice() else {
+                panic!("expected a single claimant");
+            };
+            assert_eq!(claimant.predicate, ClaimPredicate::Unconditional);
+            claimant.destination.to_string()
+        })
+        .collect();
+    assert_eq!(claimants, vec![ALICE.to_string(), BOB.to_string()]);
+
+    let hash = TransactionEnvelope::Tx(envelope).hash(network_id(PASSPHRASE)).unwrap();
+    let hex: String = hash.iter().map(|byte| format!("{:02x}", byte)).collect();
+    assert_eq!(unsigned.hash, hex);
+}
+
+#[test]
+fn short_asset_codes_use_four_characters() {
+    assert_eq!(attendance_asset_code(7), "POA7");
+    let now = Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap();
+    let unsigned = build_attendance_tokens(PASSPHRASE, ISSUER, 9, 7, &attendees(&[ALICE]), now).unwrap();
+    let envelope = decode(&unsigned.envelope_xdr);
+    let OperationBody::CreateClaimableBalance(op) = &envelope.tx.operations[0].body else {
+        panic!("expected a claimable balance");
+    };
+    assert!(matches!(&op.asset, Asset::CreditAlphanum4(asset) if asset.asset_code.to_string() == "POA7"));
+}
+
+#[test]
+fn issuance_refuses_bad_attendees_and_oversized_batches() {
+    let now = Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap();
+    assert_eq!(
+        build_attendance_tokens(PASSPHRASE, ISSUER, 9, 42, &attendees(&["not-an-account"]), now),
+        Err(PaymentError::InvalidAccount("not-an-account".to_string()))
+    );
+    assert!(build_attendance_tokens(PASSPHRASE, ISSUER, 9, 42, &[], now).is_err());
+
+    let crowd = vec![ALICE.to_string(); MAX_TOKENS_PER_TRANSACTION + 1];
+    assert!(build_attendance_tokens(PASSPHRASE, ISSUER, 9, 42, &crowd, now).is_err());
+    assert!(build_attendance_tokens(PASSPHRASE, ISSUER, 9, 42, &crowd[1..], now).is_ok());
+}
+
+#[tokio::test]
+async fn signed_issuance_is_submitted_through_horizon() {
+    let now = Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap();
+    let unsigned = build_attendance_tokens(PASSPHRASE, ISSUER, 9, 42, &attendees(&[ALICE]), now).unwrap();
+
+    let mut envelope = decode(&unsigned.envelope_xdr);
+    envelope.signatures = vec![DecoratedSignature { hint: SignatureHint([1, 2, 3, 4]), signature: vec![7u8; 64].try_into().unwrap() }]
+        .try_into()
+        .unwrap();
+    let signed = TransactionEnvelope::Tx(envelope).to_xdr_base64(Limits::none()).unwrap();
+    verify_signed(&unsigned.envelope_xdr, &signed).unwrap();
+
+    let outcome = submit_payment(&AcceptingHorizon, &unsigned.hash, &signed, false).await;
+    assert_eq!(outcome, SubmissionOutcome::Paid { ledger: 77 });
+}
+
+/// Signs up `name` with `wallet_address` and returns a session for them.
+async fn signed_in(pool: &DbPool, name: &str, wallet_address: &str) -> (i32, String) {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (username, email, wallet_address, user_type) VALUES ($1, $1 || '@example.org', $2, 'Ambassador') RETURNING id",
+    )
+    .bind(name)
+    .bind(wallet_address)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    let token = SessionRepository::create(pool, id, Utc::now() + Duration::hours(1)).await.unwrap();
+    (id, token.to_string())
+}
+
+#[sqlx::test]
+async fn the_signed_in_organizer_checks_in_and_an_admin_issues_tokens(pool: DbPool) {
+    let (organizer, organizer_token) = signed_in(&pool, "ana", ALICE).await;
+    let (_, attendee_token) = signed_in(&pool, "rui", BOB).await;
+    let (_, admin_token) = signed_in(&pool, "admin", "GADMIN").await;
+    let (event_id,): (i32,) = sqlx::query_as(
+        "INSERT INTO events (title, description, event_type, date, end_date, location, contact_email, organizer_id, status)
+         VALUES ('Meetup', '', 'Meetup', NOW() - INTERVAL '1 hour', NOW() + INTERVAL '1 hour', 'Lisbon', 'ana@example.org', $1, 'Published')
+         RETURNING id",
+    )
+    .bind(organizer)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    let stellar = StellarConfig {
+        attendance_issuer: Some(ISSUER.to_string()),
+        admin_wallets: vec!["GADMIN".to_string()],
+        ..StellarConfig::default()
+    };
+    let send = |method: Method, uri: String, token: Option<&str>, body: Option<Value>| {
+        let state = AppState::new(pool.clone(), stellar.clone()).with_horizon(Arc::new(AcceptingHorizon));
+        let app = create_app(state, &CorsConfig::default(), &VersioningConfig::default());
+        let mut request = Request::builder().method(method).uri(uri).header(header::CONTENT_TYPE, "application/json");
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        let body = body.map(|body| Body::from(body.to_string())).unwrap_or_else(Body::empty);
+        async move {
+            let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+            let status = response.status();
+            let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            (status, serde_json::from_slice::<Value>(&bytes).unwrap_or(Value::Null))
+        }
+    };
+    let attendance = format!("/api/v1/events/{}/attendance", event_id);
+    let check_in = || Some(json!({ "attendee_wallet_address": BOB }));
+
+    assert_eq!(send(Method::POST, attendance.clone(), None, check_in()).await.0, StatusCode::UNAUTHORIZED);
+    assert_eq!(send(Method::POST, attendance.clone(), Some(&attendee_token), check_in()).await.0, StatusCode::FORBIDDEN);
+    let (status, checked_in) = send(Method::POST, attendance.clone(), Some(&organizer_token), check_in()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", checked_in);
+    assert_eq!(checked_in["checked_in_by"]["wallet_address"], ALICE);
+
+    let tokens = format!("{}/tokens", attendance);
+    assert_eq!(send(Method::POST, tokens.clone(), None, None).await.0, StatusCode::UNAUTHORIZED);
+    assert_eq!(send(Method::POST, tokens.clone(), Some(&organizer_token), None).await.0, StatusCode::FORBIDDEN);
+    let (status, issuance) = send(Method::POST, tokens, Some(&admin_token), None).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", issuance);
+    assert_eq!(issuance["token_count"], 1);
+
+    let removal = format!("{}/{}", attendance, checked_in["id"].as_str().unwrap());
+    assert_eq!(send(Method::DELETE, removal.clone(), Some(&attendee_token), None).await.0, StatusCode::FORBIDDEN);
+    assert_eq!(send(Method::DELETE, removal, Some(&organizer_token), None).await.0, StatusCode::OK);
+}
\ No newline at end of file
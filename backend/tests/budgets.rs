@@ -0,0 +1,229 @@
+//! The database tests run against the Postgres in `DATABASE_URL`; each gets a fresh, migrated database.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use backend::budget_rollups;
+use backend::database::models::{BudgetItem, Event};
+use backend::database::repositories::SessionRepository;
+use backend::{create_app, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use chrono::{Duration, Utc};
+use rust_decimal::Decimal;
+use serde_json::{json, Value};
+use shared::dto::{BudgetRollup, Currency};
+use tower::ServiceExt;
+
+fn decimal(amount: &str) -> Decimal {
+    amount.parse().unwrap()
+}
+
+fn item(id: i32, event_id: i32, currency: &str, requested: &str, approved: Option<&str>, spent: Option<&str>) -> BudgetItem {
+    BudgetItem {
+        id,
+        event_id,
+        category: "Venue".to_string(),
+        description: String::new(),
+        currency: currency.to_string(),
+        requested_amount: decimal(requested),
+        approved_amount: approved.map(decimal),
+        spent_amount: spent.map(decimal),
+        created_at: None,
+        updated_at: Utc::now(),
+    }
+}
+
+/// An event that recorded `attendance` attendees and `referrals` SCF referrals.
+fn event(id: i32, attendance: Option<i32>, referrals: Option<i32>) -> Event {
+    let date = Utc::now();
+    let mut event = Event::new(
+        "Meetup".to_string(),
+        String::new(),
+        "Meetup".to_string(),
+        date,
+        date + Duration::hours(2),
+        "UTC".to_string(),
+        "Lisbon".to_string(),
+        None,
+        false,
+        "org@example.org".to_string(),
+        None,
+        1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        String::new(),
+        String::new(),
+        String::new(),
+        None,
+    );
+    event.id = id;
+    event.actual_attendance = attendance;
+    event.actual_scf_referrals = referrals;
+    event
+}
+
+/// (requested, approved, spent, cost per attendee, cost per referral) as strings.
+fn amounts(rollup: &BudgetRollup) -> (String, String, String, Option<String>, Option<String>) {
+    (
+        rollup.requested.amount.to_string(),
+        rollup.approved.amount.to_string(),
+        rollup.spent.amount.to_string(),
+        rollup.cost_per_attendee.as_ref().map(|money| money.amount.to_string()),
+        rollup.cost_per_scf_referral.as_ref().map(|money| money.amount.to_string()),
+    )
+}
+
+#[test]
+fn totals_are_kept_per_currency_and_added_exactly() {
+    let items = [
+        item(1, 1, "EUR", "0.10", Some("0.10"), Some("0.10")),
+        item(2, 1, "EUR", "0.20", Some("0.15"), None),
+        item(3, 2, "EUR", "100.00", None, None),
+        item(4, 1, "XLM", "12.3456789", Some("12.3456789"), Some("10.0000001")),
+    ];
+    let rollups = budget_rollups(&items, &[event(1, Some(3), None), event(2, None, None)]);
+
+    let currencies: Vec<_> = rollups.iter().map(|rollup| rollup.currency).collect();
+    assert_eq!(currencies, [Currency::EUR, Currency::XLM]);
+    // 0.10 + 0.20 is exactly 0.30, not a float approximation
+    assert_eq!(amounts(&rollups[0]), ("100.30".into(), "0.25".into(), "0.10".into(), Some("0.03".into()), None));
+    assert_eq!(amounts(&rollups[1]).2, "10.0000001");
+    assert!(rollups.iter().all(|rollup| rollup.spent.currency == rollup.currency));
+}
+
+#[test]
+fn costs_divide_spending_by_what_the_spending_events_achieved() {
+    let items = [
+        item(1, 1, "EUR", "300", Some("300"), Some("250")),
+        item(2, 2, "EUR", "100", Some("100"), Some("50")),
+        // Event 3 spent nothing, so its attendees do not lower the cost
+        item(3, 3, "EUR", "80", None, None),
+        item(4, 3, "USDC", "90", Some("90"), Some("90")),
+    ];
+    let events = [event(1, Some(40), Some(2)), event(2, Some(20), Some(1)), event(3, Some(1000), Some(50))];
+    let rollups = budget_rollups(&items, &events);
+
+    let eur = &rollups[0];
+    assert_eq!((eur.attendance, eur.scf_referrals), (60, 3));
+    // 300 / 60 and 300 / 3
+    assert_eq!(amounts(eur).3.as_deref(), Some("5.00"));
+    assert_eq!(amounts(eur).4.as_deref(), Some("100.00"));
+
+    let usdc = &rollups[1];
+    assert_eq!(usdc.currency, Currency::USDC);
+    assert_eq!((usdc.attendance, usdc.scf_referrals), (1000, 50));
+    assert_eq!(amounts(usdc).3.as_deref(), Some("0.09"));
+}
+
+#[test]
+fn nothing_attended_means_no_cost_per_attendee() {
+    let items = [item(1, 1, "GBP", "120", Some("120"), Some("99.99")), item(2, 2, "GBP", "10", None, Some("0.01"))];
+    // One event recorded nobody, the other recorded nothing at all
+    let rollups = budget_rollups(&items, &[event(1, Some(0), Some(0)), event(2, None, None)]);
+
+    assert_eq!(rollups.len(), 1);
+    assert_eq!((rollups[0].attendance, rollups[0].scf_referrals), (0, 0));
+    assert_eq!(amounts(&rollups[0]), ("130.00".into(), "120.00".into(), "100.00".into(), None, None));
+    assert!(budget_rollups(&[], &[event(1, Some(10), None)]).is_empty());
+}
+
+#[test]
+fn items_in_an_unknown_currency_are_left_out() {
+    let items = [item(1, 1, "EUR", "50", None, Some("50")), item(2, 1, "DOGE", "1000", None, Some("1000"))];
+    let rollups = budget_rollups(&items, &[event(1, Some(10), None)]);
+
+    assert_eq!(rollups.len(), 1);
+    assert_eq!(rollups[0].currency, Currency::EUR);
+    assert_eq!(amounts(&rollups[0]).2, "50.00");
+    assert_eq!(amounts(&rollups[0]).3.as_deref(), Some("5.00"));
+}
+
+async fn send(pool: &DbPool, method: Method, uri: &str, token: Option<&str>, body: Option<Value>) -> (StatusCode, Value) {
+    let app = create_app(AppState::new(pool.clone(), StellarConfig::default()), &CorsConfig::default(), &VersioningConfig::default());
+    let mut request = Request::builder().method(method).uri(uri).header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let body = body.map(|body| Body::from(body.to_string())).unwrap_or_else(Body::empty);
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+/// Signs up `name` as `user_type` and returns a session for them.
+async fn signed_in(pool: &DbPool, name: &str, user_type: &str) -> (i32, String) {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (username, email, wallet_address, user_type)
+         VALUES ($1, $1 || '@example.org', 'G' || UPPER($1), $2) RETURNING id",
+    )
+    .bind(name)
+    .bind(user_type)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    let token = SessionRepository::create(pool, id, Utc::now() + Duration::hours(1)).await.unwrap();
+    (id, token.to_string())
+}
+
+async fn set_status(pool: &DbPool, event_id: i32, status: &str) {
+    sqlx::query("UPDATE events SET status = $2, approved_at = CASE WHEN $2 = 'Approved' THEN NOW() END WHERE id = $1")
+        .bind(event_id)
+        .bind(status)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+#[sqlx::test]
+async fn the_budget_is_changed_by_whoever_is_signed_in(pool: DbPool) {
+    let (organizer, organizer_token) = signed_in(&pool, "org", "Ambassador").await;
+    let (_, stranger) = signed_in(&pool, "eve", "Ambassador").await;
+    let (_, reviewer) = signed_in(&pool, "lead", "ChapterLead").await;
+    let (event_id,): (i32,) = sqlx::query_as(
+        "INSERT INTO events (title, description, event_type, date, end_date, location, contact_email, organizer_id, status)
+         VALUES ('Meetup', '', 'Meetup', NOW() + INTERVAL '7 days', NOW() + INTERVAL '7 days 2 hours', 'Lisbon', 'org@example.org', $1, 'Draft')
+         RETURNING id",
+    )
+    .bind(organizer)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    let budget_uri = format!("/api/v1/events/{}/budget", event_id);
+    let item = json!({ "category": "Venue", "currency": "EUR", "requested_amount": "120.00" });
+
+    let (status, _) = send(&pool, Method::POST, &budget_uri, None, Some(item.clone())).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = send(&pool, Method::POST, &budget_uri, Some(&stranger), Some(item.clone())).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, budget) = send(&pool, Method::POST, &budget_uri, Some(&organizer_token), Some(item)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let item_uri = format!("{}/{}", budget_uri, budget["items"][0]["id"].as_str().unwrap());
+
+    set_status(&pool, event_id, "Submitted").await;
+    let approval = || Some(json!({ "amount": "100.00" }));
+    let (status, _) = send(&pool, Method::PUT, &format!("{}/approved", item_uri), Some(&organizer_token), approval()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, budget) = send(&pool, Method::PUT, &format!("{}/approved", item_uri), Some(&reviewer), approval()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(budget["items"][0]["approved"]["amount"], "100.00");
+
+    set_status(&pool, event_id, "Approved").await;
+    let spending = || Some(json!({ "amount": "95.50" }));
+    let (status, _) = send(&pool, Method::PUT, &format!("{}/spent", item_uri), Some(&reviewer), spending()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, budget) = send(&pool, Method::PUT, &format!("{}/spent", item_uri), Some(&organizer_token), spending()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(budget["items"][0]["spent"]["amount"], "95.50");
+
+    set_status(&pool, event_id, "Draft").await;
+    let (status, _) = send(&pool, Method::DELETE, &item_uri, Some(&stranger), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, budget) = send(&pool, Method::DELETE, &item_uri, Some(&organizer_token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(budget["items"].as_array().unwrap().is_empty());
+}
\ No newline at end of file
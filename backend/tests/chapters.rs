@@ -0,0 +1,113 @@
+//! The database tests run against the Postgres in `DATABASE_URL`; each gets a fresh, migrated database.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use backend::database::repositories::SessionRepository;
+use backend::{create_app, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use chrono::{Duration, Utc};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+const ADMIN: &str = "GADMIN";
+
+async fn send(pool: &DbPool, method: Method, uri: &str, token: Option<&str>, body: Option<Value>) -> (StatusCode, Value) {
+    let stellar = StellarConfig { admin_wallets: vec![ADMIN.to_string()], ..StellarConfig::default() };
+    let app = create_app(AppState::new(pool.clone(), stellar), &CorsConfig::default(), &VersioningConfig::default());
+    let mut request = Request::builder().method(method).uri(uri).header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let body = body.map(|body| Body::from(body.to_string())).unwrap_or_else(Body::empty);
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+async fn add_user(pool: &DbPool, name: &str, user_type: &str) -> i32 {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (username, email, wallet_address, user_type)
+         VALUES ($1, $1 || '@example.org', 'G' || UPPER($1), $2) RETURNING id",
+    )
+    .bind(name)
+    .bind(user_type)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    id
+}
+
+/// A session for the user, as signing in with their wallet would start.
+async fn sign_in(pool: &DbPool, user_id: i32) -> String {
+    SessionRepository::create(pool, user_id, Utc::now() + Duration::hours(1)).await.unwrap().to_string()
+}
+
+fn chapter(name: &str, lead: Option<&str>) -> Value {
+    json!({ "name": name, "country": "Portugal", "city": "Lisbon", "lead_wallet_address": lead })
+}
+
+#[sqlx::test]
+async fn only_programme_admins_create_chapters(pool: DbPool) {
+    let admin = add_user(&pool, "admin", "Ambassador").await;
+    let lead = add_user(&pool, "lead", "ChapterLead").await;
+    let admin = sign_in(&pool, admin).await;
+    let lead = sign_in(&pool, lead).await;
+
+    let (status, _) = send(&pool, Method::POST, "/api/v1/chapters", None, Some(chapter("Lisbon", None))).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, body) = send(&pool, Method::POST, "/api/v1/chapters", Some(&lead), Some(chapter("Lisbon", None))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(body["message"].as_str().unwrap().contains("admin"));
+
+    let (status, body) = send(&pool, Method::POST, "/api/v1/chapters", Some(&admin), Some(chapter("Lisbon", Some("GLEAD")))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["lead"]["wallet_address"], "GLEAD");
+    assert_eq!(body["member_count"], 1);
+}
+
+#[sqlx::test]
+async fn a_chapter_is_managed_by_its_lead_and_the_admins(pool: DbPool) {
+    let admin = add_user(&pool, "admin", "Ambassador").await;
+    let lead = add_user(&pool, "lead", "ChapterLead").await;
+    let other_lead = add_user(&pool, "other", "ChapterLead").await;
+    let member = add_user(&pool, "ana", "Ambassador").await;
+    let (admin, lead, other_lead) = (sign_in(&pool, admin).await, sign_in(&pool, lead).await, sign_in(&pool, other_lead).await);
+
+    let (_, created) = send(&pool, Method::POST, "/api/v1/chapters", Some(&admin), Some(chapter("Lisbon", Some("GLEAD")))).await;
+    let chapter_id = created["id"].as_str().unwrap().to_string();
+    let chapter_uri = format!("/api/v1/chapters/{}", chapter_id);
+    let members_uri = format!("{}/members", chapter_uri);
+    let member_uri = format!("{}/{}", members_uri, member);
+    let join = || Some(json!({ "wallet_address": "GANA" }));
+
+    // Nobody else, not even another Chapter Lead, changes the chapter or its members
+    for token in [None, Some(other_lead.as_str())] {
+        let expected = if token.is_some() { StatusCode::FORBIDDEN } else { StatusCode::UNAUTHORIZED };
+        let (status, _) = send(&pool, Method::PUT, &chapter_uri, token, Some(chapter("Lisboa", Some("GLEAD")))).await;
+        assert_eq!(status, expected);
+        let (status, _) = send(&pool, Method::POST, &members_uri, token, join()).await;
+        assert_eq!(status, expected);
+        let (status, _) = send(&pool, Method::DELETE, &chapter_uri, token, None).await;
+        assert_eq!(status, expected);
+    }
+    let (_, body) = send(&pool, Method::GET, &chapter_uri, None, None).await;
+    assert_eq!(body["name"], "Lisbon");
+    assert_eq!(body["member_count"], 1);
+
+    let (status, body) = send(&pool, Method::PUT, &chapter_uri, Some(&lead), Some(chapter("Lisboa", Some("GLEAD")))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "Lisboa");
+    let (status, _) = send(&pool, Method::POST, &members_uri, Some(&lead), join()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = send(&pool, Method::DELETE, &member_uri, Some(&other_lead), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(&pool, Method::DELETE, &member_uri, Some(&lead), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(&pool, Method::POST, &members_uri, Some(&admin), join()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = send(&pool, Method::DELETE, &chapter_uri, Some(&admin), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = send(&pool, Method::GET, &chapter_uri, None, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use axum::Router;
+use backend::{create_app, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use sqlx::postgres::PgPoolOptions;
+
+/// A pool that never connects, for tests that only exercise routing and middleware.
+pub fn lazy_pool() -> DbPool {
+    PgPoolOptions::new()
+        .connect_lazy("postgresql://postgres@localhost/unused")
+        .expect("lazy pool")
+}
+
+pub fn test_app() -> Router {
+    test_app_with(StellarConfig::default())
+}
+
+pub fn test_app_with(stellar: StellarConfig) -> Router {
+    create_app(AppState::new(lazy_pool(), stellar), &CorsConfig::default(), &VersioningConfig::default())
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+};
+use tower::ServiceExt;
+
+fn preflight(origin: &str, method: &str) -> Request<Body> {
+    Request::builder()
+        .method(Method::OPTIONS)
+        .uri("/api/events")
+        .header(header::ORIGIN, origin)
+        .header(header::ACCESS_CONTROL_REQUEST_METHOD, method)
+        .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "content-type")
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn preflight_from_allowed_origin_is_accepted() {
+    let response = common::test_app()
+        .oneshot(preflight("http://127.0.0.1:8000", "POST"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let headers = response.headers();
+    assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_ORIGIN], "http://127.0.0.1:8000");
+    assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+    assert!(headers[header::ACCESS_CONTROL_ALLOW_METHODS]
+        .to_str()
+        .unwrap()
+        .contains("POST"));
+}
+
+#[tokio::test]
+async fn preflight_from_unknown_origin_is_rejected() {
+    let response = common::test_app()
+        .oneshot(preflight("https://evil.example.com", "POST"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert!(response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+    assert!(response.headers().get(header::ACCESS_CONTROL_ALLOW_CREDENTIALS).is_none());
+}
+
+#[tokio::test]
+async fn simple_request_from_unknown_origin_gets_no_cors_headers() {
+    let request = Request::builder()
+        .uri("/health")
+        .header(header::ORIGIN, "https://evil.example.com")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = common::test_app().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+}
+
+#[tokio::test]
+async fn preflight_without_origin_is_rejected() {
+    let request = Request::builder()
+        .method(Method::OPTIONS)
+        .uri("/api/events")
+        .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = common::test_app().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn disallowed_method_is_not_advertised() {
+    let response = common::test_app()
+        .oneshot(preflight("http://localhost:8000", "PATCH"))
+        .await
+        .unwrap();
+
+    let allowed = response.headers()[header::ACCESS_CONTROL_ALLOW_METHODS].to_str().unwrap();
+    assert!(!allowed.contains("PATCH"));
+}
+
+/// The only test that touches the environment, so it cannot race the others.
+#[test]
+fn wildcards_are_refused_in_every_list() {
+    for key in ["CORS_ALLOWED_ORIGINS", "CORS_ALLOWED_METHODS", "CORS_ALLOWED_HEADERS"] {
+        std::env::set_var(key, "*");
+        let error = backend::CorsConfig::from_env().expect_err(key).to_string();
+        std::env::remove_var(key);
+        assert!(error.contains(key) && error.contains("'*' is not allowed"), "{}", error);
+    }
+
+    std::env::set_var("CORS_ALLOWED_HEADERS", "content-type, x-request-id");
+    let config = backend::CorsConfig::from_env().unwrap();
+    std::env::remove_var("CORS_ALLOWED_HEADERS");
+    assert_eq!(config.allowed_headers, vec![header::CONTENT_TYPE, header::HeaderName::from_static("x-request-id")]);
+}
\ No newline at end of file
@@ -0,0 +1,204 @@
+//! The database tests run against the Postgres in `DATABASE_URL`; each gets a fresh, migrated database.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use axum::Router;
+use backend::database::repositories::SessionRepository;
+use backend::{create_app, quarter_bounds, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use chrono::{Duration, TimeZone, Utc};
+use serde_json::{json, Value};
+use shared::dto::{ChapterDashboardResponse, KpiValues};
+use tower::ServiceExt;
+
+#[test]
+fn quarters_run_from_the_first_of_their_month_to_the_first_of_the_next_quarter() {
+    let at = |year, month| Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).unwrap();
+    assert_eq!(quarter_bounds(2026, 1).unwrap(), (at(2026, 1), at(2026, 4)));
+    assert_eq!(quarter_bounds(2026, 2).unwrap(), (at(2026, 4), at(2026, 7)));
+    assert_eq!(quarter_bounds(2026, 3).unwrap(), (at(2026, 7), at(2026, 10)));
+    assert_eq!(quarter_bounds(2026, 4).unwrap(), (at(2026, 10), at(2027, 1)));
+    assert!(quarter_bounds(i32::MAX, 4).is_err());
+}
+
+const ADMIN: &str = "GADMIN";
+
+fn app(pool: &DbPool) -> Router {
+    let stellar = StellarConfig { admin_wallets: vec![ADMIN.to_string()], ..StellarConfig::default() };
+    create_app(AppState::new(pool.clone(), stellar), &CorsConfig::default(), &VersioningConfig::default())
+}
+
+async fn send(pool: &DbPool, method: Method, uri: &str, token: Option<&str>, body: Option<Value>) -> (StatusCode, Value) {
+    let mut request = Request::builder().method(method).uri(uri).header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let body = body.map(|body| Body::from(body.to_string())).unwrap_or_else(Body::empty);
+    let response = app(pool).oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+async fn add_user(pool: &DbPool, name: &str) -> i32 {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (username, email, wallet_address, user_type)
+         VALUES ($1, $1 || '@example.org', 'G' || UPPER($1), 'Ambassador') RETURNING id",
+    )
+    .bind(name)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    id
+}
+
+/// A session for the user, as signing in with their wallet would start.
+async fn sign_in(pool: &DbPool, user_id: i32) -> String {
+    SessionRepository::create(pool, user_id, Utc::now() + Duration::hours(1)).await.unwrap().to_string()
+}
+
+async fn add_chapter(pool: &DbPool, name: &str, lead: Option<i32>) -> i32 {
+    let (id,): (i32,) =
+        sqlx::query_as("INSERT INTO chapters (name, country, city, lead_id) VALUES ($1, 'Portugal', 'Lisbon', $2) RETURNING id")
+            .bind(name)
+            .bind(lead)
+            .fetch_one(pool)
+            .await
+            .unwrap();
+    id
+}
+
+/// An event of `chapter_id` on `date` expecting `attendance` people and `referrals` SCF referrals.
+async fn add_event(pool: &DbPool, organizer: i32, chapter_id: i32, date: &str, status: &str, attendance: i32, referrals: Option<i32>) -> i32 {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO events (title, description, event_type, date, end_date, location, contact_email, organizer_id, chapter_id,
+                             status, expected_attendance, scf_referrals)
+         VALUES ('Meetup', '', 'Meetup', $1::TIMESTAMPTZ, $1::TIMESTAMPTZ + INTERVAL '2 hours', 'Lisbon', 'org@example.org', $2, $3,
+                 $4, $5, $6)
+         RETURNING id",
+    )
+    .bind(date)
+    .bind(organizer)
+    .bind(chapter_id)
+    .bind(status)
+    .bind(attendance)
+    .bind(referrals)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    id
+}
+
+async fn record_actuals(pool: &DbPool, event_id: i32, token: Option<&str>, actuals: Value) -> (StatusCode, Value) {
+    let body = json!({ "actuals": actuals });
+    send(pool, Method::PUT, &format!("/api/v1/events/{}/actuals", event_id), token, Some(body)).await
+}
+
+#[sqlx::test]
+async fn only_the_organizer_records_actuals(pool: DbPool) {
+    let organizer = add_user(&pool, "org").await;
+    let eve = add_user(&pool, "eve").await;
+    let chapter = add_chapter(&pool, "Lisbon", None).await;
+    let event = add_event(&pool, organizer, chapter, "2026-02-10T18:00:00Z", "Completed", 30, None).await;
+    let (organizer, eve) = (sign_in(&pool, organizer).await, sign_in(&pool, eve).await);
+
+    let (status, body) = record_actuals(&pool, event, Some(&eve), json!({ "attendance": 900 })).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(body["message"].as_str().unwrap().contains("organizer"));
+    let (status, _) = record_actuals(&pool, event, None, json!({ "attendance": 900 })).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = record_actuals(&pool, event + 1, Some(&organizer), json!({ "attendance": 900 })).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    // The columns hold an i32; larger values are refused rather than wrapped
+    let (status, body) = record_actuals(&pool, event, Some(&organizer), json!({ "attendance": 2_147_483_648u32 })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["field_errors"][0]["field"], "attendance");
+    let (actual_attendance,): (Option<i32>,) = sqlx::query_as("SELECT actual_attendance FROM events WHERE id = $1")
+        .bind(event)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(actual_attendance, None);
+
+    let (status, body) = record_actuals(&pool, event, Some(&organizer), json!({ "attendance": 28, "scf_referrals": 2 })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["kpi_actuals"]["attendance"], 28);
+    assert_eq!(body["kpi_actuals"]["scf_referrals"], 2);
+}
+
+#[sqlx::test]
+async fn dashboard_adds_up_the_quarter_by_month(pool: DbPool) {
+    let organizer = add_user(&pool, "org").await;
+    let lead = add_user(&pool, "lead").await;
+    let chapter = add_chapter(&pool, "Lisbon", Some(lead)).await;
+    let other_chapter = add_chapter(&pool, "Porto", None).await;
+    let (organizer_token, lead) = (sign_in(&pool, organizer).await, sign_in(&pool, lead).await);
+
+    let january = add_event(&pool, organizer, chapter, "2026-01-15T18:00:00Z", "Completed", 30, Some(3)).await;
+    let february = add_event(&pool, organizer, chapter, "2026-02-10T18:00:00Z", "Completed", 20, None).await;
+    // Planned, with nothing recorded yet
+    add_event(&pool, organizer, chapter, "2026-02-20T18:00:00Z", "Approved", 50, None).await;
+    // Outside the quarter, cancelled or another chapter's: not counted
+    add_event(&pool, organizer, chapter, "2026-04-01T00:00:00Z", "Approved", 500, None).await;
+    add_event(&pool, organizer, chapter, "2026-03-05T18:00:00Z", "Cancelled", 500, None).await;
+    add_event(&pool, organizer, other_chapter, "2026-03-05T18:00:00Z", "Approved", 500, None).await;
+
+    record_actuals(&pool, january, Some(&organizer_token), json!({ "attendance": 25, "scf_referrals": 4 })).await;
+    record_actuals(&pool, february, Some(&organizer_token), json!({ "attendance": 22 })).await;
+    let targets_uri = format!("/api/v1/chapters/{}/targets/2026/1", chapter);
+    let (status, _) = send(&pool, Method::PUT, &targets_uri, Some(&lead), Some(json!({ "attendance": 120 }))).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(&pool, Method::GET, &format!("/api/v1/chapters/{}/dashboard?year=2026&quarter=1", chapter), None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let dashboard: ChapterDashboardResponse = serde_json::from_value(body).unwrap();
+
+    assert_eq!(dashboard.events.len(), 3);
+    assert_eq!(dashboard.estimated.attendance, Some(100));
+    assert_eq!(dashboard.estimated.scf_referrals, Some(3));
+    assert_eq!(dashboard.actual.attendance, Some(47));
+    assert_eq!(dashboard.actual.scf_referrals, Some(4));
+    // KPIs nobody planned or recorded stay unset rather than zero
+    assert_eq!(dashboard.actual.content_produced, None);
+    assert_eq!(dashboard.targets, KpiValues { attendance: Some(120), ..KpiValues::default() });
+
+    let months: Vec<_> = dashboard
+        .months
+        .iter()
+        .map(|month| (month.month, month.event_count, month.estimated.attendance, month.actual.attendance))
+        .collect();
+    assert_eq!(months, vec![(1, 1, Some(30), Some(25)), (2, 2, Some(70), Some(22)), (3, 0, None, None)]);
+    assert!(dashboard.months.iter().all(|month| month.year == 2026));
+}
+
+#[sqlx::test]
+async fn dashboard_rejects_unknown_quarters(pool: DbPool) {
+    let chapter = add_chapter(&pool, "Lisbon", None).await;
+    let (status, _) = send(&pool, Method::GET, &format!("/api/v1/chapters/{}/dashboard?year=2026&quarter=5", chapter), None, None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[sqlx::test]
+async fn targets_are_set_by_the_chapter_lead_or_an_admin(pool: DbPool) {
+    let lead = add_user(&pool, "lead").await;
+    let member = add_user(&pool, "ana").await;
+    let admin = add_user(&pool, "admin").await;
+    let chapter = add_chapter(&pool, "Lisbon", Some(lead)).await;
+    let (lead, member, admin) = (sign_in(&pool, lead).await, sign_in(&pool, member).await, sign_in(&pool, admin).await);
+    let uri = format!("/api/v1/chapters/{}/targets/2026/1", chapter);
+
+    let (status, _) = send(&pool, Method::PUT, &uri, None, Some(json!({ "attendance": 900 }))).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = send(&pool, Method::PUT, &uri, Some(&member), Some(json!({ "attendance": 900 }))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, body) = send(&pool, Method::PUT, &uri, Some(&lead), Some(json!({ "scf_referrals": 3_000_000_000u32 }))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["field_errors"][0]["field"], "scf_referrals");
+    let (_, body) = send(&pool, Method::GET, &uri, None, None).await;
+    assert_eq!(body["targets"], json!(KpiValues::default()));
+
+    let (status, _) = send(&pool, Method::PUT, &uri, Some(&lead), Some(json!({ "attendance": 100 }))).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, body) = send(&pool, Method::PUT, &uri, Some(&admin), Some(json!({ "attendance": 120 }))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["targets"]["attendance"], 120);
+}
\ No newline at end of file
@@ -0,0 +1,273 @@
+//! The database tests run against the Postgres in `DATABASE_URL`; each gets a fresh, migrated database.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use backend::stellar::{signed_message_hash, signin_message};
+use backend::{create_app, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use ed25519_dalek::{Signer, SigningKey};
+use serde_json::{json, Value};
+use shared::dto::EventStatus;
+use stellar_xdr::curr::{AccountId, PublicKey, Uint256};
+use tower::ServiceExt;
+
+#[test]
+fn events_only_move_along_their_lifecycle() {
+    use EventStatus::*;
+
+    let allowed = [
+        (Draft, Submitted),
+        (Draft, Cancelled),
+        (Submitted, Approved),
+        (Submitted, Draft),
+        (Submitted, Cancelled),
+        (Approved, Published),
+        (Approved, Cancelled),
+        (Published, Completed),
+        (Published, Cancelled),
+    ];
+    for from in EventStatus::ALL {
+        for to in EventStatus::ALL {
+            assert_eq!(from.can_move_to(to), allowed.contains(&(from, to)), "{} -> {}", from, to);
+        }
+    }
+    // Cancelled and completed events are final
+    assert!(Cancelled.next_statuses().is_empty());
+    assert!(Completed.next_statuses().is_empty());
+}
+
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn address(key: &SigningKey) -> String {
+    AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(key.verifying_key().to_bytes()))).to_string()
+}
+
+async fn send(pool: &DbPool, method: Method, uri: &str, token: Option<&str>, body: Option<Value>) -> (StatusCode, Value) {
+    let app = create_app(AppState::new(pool.clone(), StellarConfig::default()), &CorsConfig::default(), &VersioningConfig::default());
+    let mut request = Request::builder().method(method).uri(uri).header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let body = body.map(|body| Body::from(body.to_string())).unwrap_or_else(Body::empty);
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body = serde_json::from_slice(&bytes).unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    (status, body)
+}
+
+async fn add_user(pool: &DbPool, name: &str, key: &SigningKey, user_type: &str) -> i32 {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (username, email, wallet_address, user_type, email_verified_at)
+         VALUES ($1, $1 || '@example.org', $2, $3, NOW()) RETURNING id",
+    )
+    .bind(name)
+    .bind(address(key))
+    .bind(user_type)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    id
+}
+
+async fn add_event(pool: &DbPool, organizer: i32, title: &str, status: &str, approved: bool) -> i32 {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO events (title, description, event_type, date, end_date, location, contact_email, organizer_id, status, approved_at)
+         VALUES ($1, '', 'Meetup', NOW() + INTERVAL '7 days', NOW() + INTERVAL '7 days 2 hours', 'Lisbon', 'org@example.org', $2, $3,
+                 CASE WHEN $4 THEN NOW() END)
+         RETURNING id",
+    )
+    .bind(title)
+    .bind(organizer)
+    .bind(status)
+    .bind(approved)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    id
+}
+
+/// Signs in as `key`'s wallet and returns the session token.
+async fn sign_in(pool: &DbPool, key: &SigningKey) -> String {
+    let wallet_address = address(key);
+    let (status, challenge) =
+        send(pool, Method::POST, "/api/v1/sessions/challenge", None, Some(json!({ "wallet_address": wallet_address }))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let nonce = challenge["nonce"].as_str().unwrap();
+    assert_eq!(challenge["message"], signin_message(&wallet_address, nonce));
+
+    let signature = BASE64.encode(key.sign(&signed_message_hash(&signin_message(&wallet_address, nonce))).to_bytes());
+    let body = json!({ "wallet_address": wallet_address, "nonce": nonce, "signature": signature });
+    let (status, session) = send(pool, Method::POST, "/api/v1/sessions", None, Some(body)).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", session);
+    session["token"].as_str().unwrap().to_string()
+}
+
+async fn listed_titles(pool: &DbPool, query: &str, token: Option<&str>) -> Vec<String> {
+    let (status, body) = send(pool, Method::GET, &format!("/api/v1/events{}", query), token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let mut titles: Vec<String> = body["events"].as_array().unwrap().iter().map(|event| event["title"].as_str().unwrap().to_string()).collect();
+    titles.sort();
+    titles
+}
+
+/// One event in every status, organised by `organizer`. Only the first cancellation had been approved.
+async fn add_event_in_every_status(pool: &DbPool, organizer: i32) {
+    add_event(pool, organizer, "Draft", "Draft", false).await;
+    add_event(pool, organizer, "Submitted", "Submitted", false).await;
+    add_event(pool, organizer, "Approved", "Approved", true).await;
+    add_event(pool, organizer, "Published", "Published", true).await;
+    add_event(pool, organizer, "Completed", "Completed", true).await;
+    add_event(pool, organizer, "Cancelled after approval", "Cancelled", true).await;
+    add_event(pool, organizer, "Cancelled draft", "Cancelled", false).await;
+}
+
+const PUBLIC: [&str; 4] = ["Approved", "Cancelled after approval", "Completed", "Published"];
+
+#[sqlx::test]
+async fn only_approved_events_and_their_cancellations_are_public(pool: DbPool) {
+    let organizer_key = signing_key(1);
+    let organizer = add_user(&pool, "org", &organizer_key, "Ambassador").await;
+    add_event_in_every_status(&pool, organizer).await;
+
+    assert_eq!(listed_titles(&pool, "", None).await, PUBLIC);
+    // Naming the organiser or the review queue in the query string shows nothing more
+    assert_eq!(listed_titles(&pool, &format!("?organizer={}", address(&organizer_key)), None).await, PUBLIC);
+    assert!(listed_titles(&pool, "?status=Submitted", None).await.is_empty());
+    assert!(listed_titles(&pool, "?status=Draft", None).await.is_empty());
+
+    let (_, feed) = send(&pool, Method::GET, "/api/v1/feeds/events.atom", None, None).await;
+    let (_, calendar) = send(&pool, Method::GET, "/api/v1/calendar.ics", None, None).await;
+    for document in [feed.as_str().unwrap(), calendar.as_str().unwrap()] {
+        for title in PUBLIC {
+            assert!(document.contains(title), "{} is missing", title);
+        }
+        assert!(!document.contains("Draft"));
+        assert!(!document.contains("Submitted"));
+    }
+}
+
+#[sqlx::test]
+async fn signed_in_organizers_see_their_own_events_and_leads_the_review_queue(pool: DbPool) {
+    let (organizer_key, other_key, lead_key) = (signing_key(1), signing_key(2), signing_key(3));
+    let organizer = add_user(&pool, "org", &organizer_key, "Ambassador").await;
+    add_user(&pool, "other", &other_key, "Ambassador").await;
+    add_user(&pool, "lead", &lead_key, "ChapterLead").await;
+    add_event_in_every_status(&pool, organizer).await;
+
+    let organizer_token = sign_in(&pool, &organizer_key).await;
+    assert_eq!(listed_titles(&pool, "", Some(&organizer_token)).await.len(), 7);
+    assert_eq!(listed_titles(&pool, "?status=Draft", Some(&organizer_token)).await, ["Draft"]);
+
+    let other_token = sign_in(&pool, &other_key).await;
+    assert_eq!(listed_titles(&pool, "", Some(&other_token)).await, PUBLIC);
+    assert!(listed_titles(&pool, "?status=Submitted", Some(&other_token)).await.is_empty());
+
+    let lead_token = sign_in(&pool, &lead_key).await;
+    assert_eq!(listed_titles(&pool, "?status=Submitted", Some(&lead_token)).await, ["Submitted"]);
+    assert!(listed_titles(&pool, "?status=Draft", Some(&lead_token)).await.is_empty());
+
+    // A token that is not a session is refused rather than ignored
+    let (status, _) = send(&pool, Method::GET, "/api/v1/events", Some("00000000-0000-0000-0000-000000000000"), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[sqlx::test]
+async fn status_changes_come_from_the_signed_in_user(pool: DbPool) {
+    let (organizer_key, lead_key) = (signing_key(1), signing_key(3));
+    let organizer = add_user(&pool, "org", &organizer_key, "Ambassador").await;
+    add_user(&pool, "lead", &lead_key, "ChapterLead").await;
+    let event = add_event(&pool, organizer, "Meetup", "Draft", false).await;
+    let uri = format!("/api/v1/events/{}/status", event);
+
+    // Naming the organiser's wallet is not enough without their session
+    let body = json!({ "status": "Submitted", "actor_wallet_address": address(&organizer_key) });
+    let (status, _) = send(&pool, Method::POST, &uri, None, Some(body)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (organizer_token, lead_token) = (sign_in(&pool, &organizer_key).await, sign_in(&pool, &lead_key).await);
+    let (status, _) = send(&pool, Method::POST, &uri, Some(&lead_token), Some(json!({ "status": "Submitted" }))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, body) = send(&pool, Method::POST, &uri, Some(&organizer_token), Some(json!({ "status": "Submitted" }))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "Submitted");
+
+    let (status, _) = send(&pool, Method::POST, &uri, Some(&organizer_token), Some(json!({ "status": "Approved" }))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(&pool, Method::POST, &uri, Some(&lead_token), Some(json!({ "status": "Published" }))).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, body) = send(&pool, Method::POST, &uri, Some(&lead_token), Some(json!({ "status": "Approved" }))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "Approved");
+}
+
+#[sqlx::test]
+async fn a_sign_in_nonce_is_accepted_once_and_only_for_sign_in(pool: DbPool) {
+    let key = signing_key(1);
+    add_user(&pool, "org", &key, "Ambassador").await;
+    let wallet_address = address(&key);
+
+    let (_, challenge) =
+        send(&pool, Method::POST, "/api/v1/sessions/challenge", None, Some(json!({ "wallet_address": wallet_address }))).await;
+    let nonce = challenge["nonce"].as_str().unwrap();
+    let sign = |message: String| BASE64.encode(key.sign(&signed_message_hash(&message)).to_bytes());
+
+    // A signature over the signup message for the same nonce does not start a session
+    let signup_signature = sign(backend::stellar::signup_message(&wallet_address, nonce));
+    let body = json!({ "wallet_address": wallet_address, "nonce": nonce, "signature": signup_signature });
+    assert_eq!(send(&pool, Method::POST, "/api/v1/sessions", None, Some(body)).await.0, StatusCode::UNAUTHORIZED);
+
+    let body = json!({ "wallet_address": wallet_address, "nonce": nonce, "signature": sign(signin_message(&wallet_address, nonce)) });
+    assert_eq!(send(&pool, Method::POST, "/api/v1/sessions", None, Some(body.clone())).await.0, StatusCode::CREATED);
+    assert_eq!(send(&pool, Method::POST, "/api/v1/sessions", None, Some(body)).await.0, StatusCode::GONE);
+
+    // Only registered wallets sign in
+    let stranger = signing_key(9);
+    let stranger_address = address(&stranger);
+    let (_, challenge) =
+        send(&pool, Method::POST, "/api/v1/sessions/challenge", None, Some(json!({ "wallet_address": stranger_address }))).await;
+    let nonce = challenge["nonce"].as_str().unwrap();
+    let signature = BASE64.encode(stranger.sign(&signed_message_hash(&signin_message(&stranger_address, nonce))).to_bytes());
+    let body = json!({ "wallet_address": stranger_address, "nonce": nonce, "signature": signature });
+    assert_eq!(send(&pool, Method::POST, "/api/v1/sessions", None, Some(body)).await.0, StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test]
+async fn clients_from_before_events_had_an_end_and_a_zone_still_create_them(pool: DbPool) {
+    let key = signing_key(1);
+    add_user(&pool, "org", &key, "Ambassador").await;
+    let token = sign_in(&pool, &key).await;
+    // The shape of a request from before `end_date` and `timezone`, sent to the legacy alias;
+    // the organizer it names is ignored in favour of the session
+    let body = json!({
+        "title": "Meetup",
+        "description": "",
+        "event_type": "Meetup",
+        "date": "2026-11-05T18:00:00Z",
+        "location": "Lisbon",
+        "max_participants": null,
+        "registration_required": false,
+        "contact_email": "org@example.org",
+        "external_link": null,
+        "organizer_wallet_address": address(&key),
+        "strategic_focus_areas": [],
+        "kpi_estimates": {},
+        "target_audience": "",
+        "quarterly_goals": "",
+        "strategic_purpose": "",
+        "success_metrics": null,
+    });
+    assert_eq!(send(&pool, Method::POST, "/api/events", None, Some(body.clone())).await.0, StatusCode::UNAUTHORIZED);
+    let (status, message) = send(&pool, Method::POST, "/api/events", Some(&token), Some(body)).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", message);
+
+    let (_, listed) = send(&pool, Method::GET, "/api/v1/events", Some(&token), None).await;
+    let event = &listed["events"][0];
+    assert_eq!(event["date"], "2026-11-05T18:00:00Z");
+    assert_eq!(event["end_date"], "2026-11-05T20:00:00Z");
+    assert_eq!(event["timezone"], "UTC");
+    assert!(event["created_at"].is_string());
+}
@@ -0,0 +1,92 @@
+//! These run against the Postgres in `DATABASE_URL`; each test gets a fresh, migrated database.
+
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::response::Response;
+use backend::{create_app, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use tower::ServiceExt;
+
+async fn add_event(pool: &DbPool, title: &str, starts_in_days: i32) -> i32 {
+    let (organizer,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (username, email, wallet_address, user_type)
+         VALUES ($1, $1 || '@example.org', 'G' || UPPER($1), 'Ambassador')
+         ON CONFLICT (email) DO UPDATE SET username = EXCLUDED.username RETURNING id",
+    )
+    .bind("org")
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO events (title, description, event_type, date, end_date, location, contact_email, organizer_id, status, approved_at)
+         VALUES ($1, '', 'Meetup', NOW() + make_interval(days => $2), NOW() + make_interval(days => $2, hours => 2),
+                 'Lisbon', 'org@example.org', $3, 'Published', NOW())
+         RETURNING id",
+    )
+    .bind(title)
+    .bind(starts_in_days)
+    .bind(organizer)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    id
+}
+
+async fn get_feed(pool: &DbPool, path: &str, if_none_match: Option<&str>) -> Response {
+    let app = create_app(AppState::new(pool.clone(), StellarConfig::default()), &CorsConfig::default(), &VersioningConfig::default());
+    let mut request = Request::builder().uri(format!("/api/v1/feeds/{}", path)).header(header::HOST, "api.example.org");
+    if let Some(etag) = if_none_match {
+        request = request.header(header::IF_NONE_MATCH, etag);
+    }
+    app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap()
+}
+
+fn etag(response: &Response) -> String {
+    response.headers()[header::ETAG].to_str().unwrap().to_string()
+}
+
+#[sqlx::test]
+async fn unchanged_feeds_are_not_sent_again(pool: DbPool) {
+    add_event(&pool, "Soroban Workshop", 3).await;
+
+    for path in ["events.atom", "events.rss"] {
+        let response = get_feed(&pool, path, None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let tag = etag(&response);
+        assert!(tag.starts_with('"') && tag.ends_with('"'));
+
+        let response = get_feed(&pool, path, Some(&tag)).await;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(etag(&response), tag);
+
+        // Weak tags and lists of tags match too
+        let response = get_feed(&pool, path, Some(&format!("\"stale\", W/{}", tag))).await;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(get_feed(&pool, path, Some("*")).await.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(get_feed(&pool, path, Some("\"stale\"")).await.status(), StatusCode::OK);
+    }
+}
+
+#[sqlx::test]
+async fn editing_an_event_changes_the_feed_etag(pool: DbPool) {
+    let event_id = add_event(&pool, "Soroban Workshop", 3).await;
+    let before = etag(&get_feed(&pool, "events.atom", None).await);
+
+    sqlx::query("UPDATE events SET title = 'Soroban Deep Dive' WHERE id = $1").bind(event_id).execute(&pool).await.unwrap();
+    let response = get_feed(&pool, "events.atom", Some(&before)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(etag(&response), before);
+}
+
+#[sqlx::test]
+async fn an_event_leaving_the_feed_changes_the_etag(pool: DbPool) {
+    add_event(&pool, "Meetup", 2).await;
+    let latest = add_event(&pool, "Hackathon", 5).await;
+    let before = etag(&get_feed(&pool, "events.rss", None).await);
+
+    // The most recently edited event drops out, as when it ends, without any other event
+    // changing: the newest `updated_at` in the feed goes back, but the feed did change
+    sqlx::query("DELETE FROM events WHERE id = $1").bind(latest).execute(&pool).await.unwrap();
+    let response = get_feed(&pool, "events.rss", Some(&before)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(etag(&response), before);
+}
\ No newline at end of file
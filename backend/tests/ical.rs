@@ -0,0 +1,201 @@
+//! The database tests run against the Postgres in `DATABASE_URL`; each gets a fresh, migrated database.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use backend::database::models::Event;
+use backend::database::repositories::EventRepository;
+use backend::ical::render_calendar;
+use backend::{create_app, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use tower::ServiceExt;
+
+fn event(id: i32, title: &str, timezone: &str, date: DateTime<Utc>) -> Event {
+    let mut event = Event::new(
+        title.to_string(),
+        "Hands-on session".to_string(),
+        "Workshop".to_string(),
+        date,
+        date + Duration::hours(2),
+        timezone.to_string(),
+        "Lisbon".to_string(),
+        None,
+        false,
+        "org@example.org".to_string(),
+        None,
+        1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        String::new(),
+        String::new(),
+        String::new(),
+        None,
+    );
+    event.id = id;
+    event
+}
+
+/// Content lines with folding undone.
+fn unfolded(calendar: &str) -> Vec<String> {
+    calendar.replace("\r\n ", "").split("\r\n").map(str::to_string).collect()
+}
+
+fn value<'a>(lines: &'a [String], name: &str) -> Option<&'a str> {
+    lines.iter().find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+}
+
+#[test]
+fn text_values_are_escaped() {
+    let mut event = event(1, "Soroban; Rust, and \\ more", "UTC", Utc::now());
+    event.description = "Line one\nLine two\r\nLine three".to_string();
+    event.location = "Rua Augusta, 1; Lisbon".to_string();
+    let lines = unfolded(&render_calendar("Chapter, Lisbon", &[event]));
+
+    assert_eq!(value(&lines, "X-WR-CALNAME"), Some("Chapter\\, Lisbon"));
+    assert_eq!(value(&lines, "SUMMARY"), Some("Soroban\\; Rust\\, and \\\\ more"));
+    assert_eq!(value(&lines, "DESCRIPTION"), Some("Line one\\nLine two\\nLine three"));
+    assert_eq!(value(&lines, "LOCATION"), Some("Rua Augusta\\, 1\\; Lisbon"));
+}
+
+#[test]
+fn long_lines_are_folded_at_75_octets_without_splitting_characters() {
+    let mut event = event(1, "Workshop", "UTC", Utc::now());
+    // Multibyte characters land on every fold boundary
+    event.description = "€uro ✨ Soroban ".repeat(20);
+    let calendar = render_calendar("Stellar Europe Events", &[event.clone()]);
+
+    assert!(calendar.ends_with("\r\n"));
+    let physical: Vec<&str> = calendar.trim_end_matches("\r\n").split("\r\n").collect();
+    assert!(physical.iter().all(|line| line.len() <= 75), "a line is longer than 75 octets");
+    let continuations = physical.iter().filter(|line| line.starts_with(' ')).count();
+    assert!(continuations > 3);
+
+    let lines = unfolded(&calendar);
+    assert_eq!(value(&lines, "DESCRIPTION"), Some(event.description.as_str()));
+}
+
+#[test]
+fn vtimezone_lists_each_transition_of_the_zone() {
+    let date = Utc.with_ymd_and_hms(2026, 6, 12, 16, 0, 0).unwrap();
+    let lines = unfolded(&render_calendar("Events", &[event(1, "Meetup", "Europe/Berlin", date)]));
+
+    let start = lines.iter().position(|line| line == "BEGIN:VTIMEZONE").unwrap();
+    let end = lines.iter().position(|line| line == "END:VTIMEZONE").unwrap();
+    assert_eq!(
+        lines[start..=end],
+        [
+            "BEGIN:VTIMEZONE",
+            "TZID:Europe/Berlin",
+            "BEGIN:STANDARD",
+            "DTSTART:20260101T010000",
+            "TZOFFSETFROM:+0100",
+            "TZOFFSETTO:+0100",
+            "TZNAME:CET",
+            "END:STANDARD",
+            "BEGIN:DAYLIGHT",
+            "DTSTART:20260329T020000",
+            "TZOFFSETFROM:+0100",
+            "TZOFFSETTO:+0200",
+            "TZNAME:CEST",
+            "END:DAYLIGHT",
+            "BEGIN:STANDARD",
+            "DTSTART:20261025T030000",
+            "TZOFFSETFROM:+0200",
+            "TZOFFSETTO:+0100",
+            "TZNAME:CET",
+            "END:STANDARD",
+            "END:VTIMEZONE",
+        ]
+    );
+    assert_eq!(value(&lines, "DTSTART;TZID=Europe/Berlin"), Some("20260612T180000"));
+    assert_eq!(value(&lines, "DTEND;TZID=Europe/Berlin"), Some("20260612T200000"));
+}
+
+#[test]
+fn utc_and_unknown_zones_get_no_vtimezone() {
+    let date = Utc.with_ymd_and_hms(2026, 6, 12, 16, 0, 0).unwrap();
+    let calendar = render_calendar("Events", &[event(1, "Meetup", "UTC", date), event(2, "Meetup", "Mars/Olympus", date)]);
+    let lines = unfolded(&calendar);
+
+    assert!(!calendar.contains("BEGIN:VTIMEZONE"));
+    assert_eq!(lines.iter().filter(|line| *line == "DTSTART:20260612T160000Z").count(), 2);
+}
+
+#[test]
+fn revisions_are_stamped_with_the_last_change() {
+    let mut event = event(7, "Meetup", "UTC", Utc::now());
+    event.created_at = Some(Utc.with_ymd_and_hms(2026, 9, 1, 8, 0, 0).unwrap());
+    event.updated_at = Utc.with_ymd_and_hms(2026, 9, 14, 12, 30, 0).unwrap();
+    event.sequence = 3;
+    let lines = unfolded(&render_calendar("Events", &[event]));
+
+    assert_eq!(value(&lines, "UID"), Some("event-7@stellareurope.org"));
+    assert_eq!(value(&lines, "DTSTAMP"), Some("20260914T123000Z"));
+    assert_eq!(value(&lines, "CREATED"), Some("20260901T080000Z"));
+    assert_eq!(value(&lines, "LAST-MODIFIED"), Some("20260914T123000Z"));
+    assert_eq!(value(&lines, "SEQUENCE"), Some("3"));
+}
+
+async fn get(pool: &DbPool, uri: &str) -> (StatusCode, String) {
+    let app = create_app(AppState::new(pool.clone(), StellarConfig::default()), &CorsConfig::default(), &VersioningConfig::default());
+    let response = app.oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap()).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+async fn served_calendar(pool: &DbPool, event_id: i32) -> Vec<String> {
+    let (status, body) = get(pool, &format!("/api/v1/events/{}/calendar.ics", event_id)).await;
+    assert_eq!(status, StatusCode::OK);
+    // The same file is served under the event's own `.ics` name
+    assert_eq!(get(pool, &format!("/api/v1/events/{}.ics", event_id)).await, (status, body.clone()));
+    unfolded(&body)
+}
+
+#[sqlx::test]
+async fn sequence_goes_up_with_every_change_a_calendar_shows(pool: DbPool) {
+    let (organizer,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (username, email, wallet_address, user_type) VALUES ('org', 'org@example.org', 'GORG', 'Ambassador') RETURNING id",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO events (title, description, event_type, date, end_date, location, contact_email, organizer_id, status, approved_at)
+         VALUES ('Meetup', '', 'Meetup', NOW() + INTERVAL '7 days', NOW() + INTERVAL '7 days 2 hours', 'Lisbon', 'org@example.org', $1, 'Published', NOW())
+         RETURNING id",
+    )
+    .bind(organizer)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(value(&served_calendar(&pool, id).await, "SEQUENCE"), Some("0"));
+
+    let update = |sql: &'static str| {
+        let pool = pool.clone();
+        async move { sqlx::query(sql).bind(id).execute(&pool).await.unwrap() }
+    };
+    update("UPDATE events SET date = date + INTERVAL '1 hour', end_date = end_date + INTERVAL '1 hour' WHERE id = $1").await;
+    let lines = served_calendar(&pool, id).await;
+    assert_eq!(value(&lines, "SEQUENCE"), Some("1"));
+    let event = EventRepository::find_by_id(&pool, id).await.unwrap().unwrap();
+    assert_eq!(value(&lines, "LAST-MODIFIED"), Some(event.updated_at.format("%Y%m%dT%H%M%SZ").to_string().as_str()));
+
+    // KPI actuals are not part of the calendar entry
+    update("UPDATE events SET actual_attendance = 40 WHERE id = $1").await;
+    assert_eq!(value(&served_calendar(&pool, id).await, "SEQUENCE"), Some("1"));
+
+    update("UPDATE events SET status = 'Cancelled', cancellation_reason = 'Venue closed' WHERE id = $1").await;
+    let lines = served_calendar(&pool, id).await;
+    assert_eq!(value(&lines, "SEQUENCE"), Some("2"));
+    assert_eq!(value(&lines, "STATUS"), Some("CANCELLED"));
+
+    for uri in [format!("/api/v1/events/{}", id), format!("/api/v1/events/{}.txt", id), "/api/v1/events/x.ics".to_string()] {
+        assert_eq!(get(&pool, &uri).await.0, StatusCode::NOT_FOUND, "{}", uri);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,303 @@
+//! These run against the Postgres in `DATABASE_URL`; each test gets a fresh, migrated database.
+
+use async_trait::async_trait;
+use backend::database::repositories::JobRepository;
+use backend::jobs::{enqueue, retry_delay, schedule, Job, JobError, JobOutcome, Worker};
+use backend::mail::{Email, SendEmail};
+use backend::{AppState, DbPool, JobsConfig, StellarConfig};
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Runs of each test job, by name, so jobs running in the worker can be counted.
+static RUNS: Mutex<Option<HashMap<String, u32>>> = Mutex::new(None);
+
+fn record_run(name: &str) -> u32 {
+    let mut runs = RUNS.lock().unwrap();
+    let count = runs.get_or_insert_with(HashMap::new).entry(name.to_string()).or_default();
+    *count += 1;
+    *count
+}
+
+fn runs(name: &str) -> u32 {
+    RUNS.lock().unwrap().as_ref().and_then(|runs| runs.get(name).copied()).unwrap_or(0)
+}
+
+/// Fails its first `failures` runs, then succeeds.
+#[derive(Serialize, Deserialize)]
+struct Flaky {
+    name: String,
+    failures: u32,
+}
+
+#[async_trait]
+impl Job for Flaky {
+    const KIND: &'static str = "test_flaky";
+    const MAX_ATTEMPTS: i32 = 3;
+
+    async fn run(&self, _state: &AppState) -> Result<(), JobError> {
+        if record_run(&self.name) <= self.failures {
+            return Err(JobError::Transient(format!("{} is not ready", self.name)));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Broken {
+    name: String,
+}
+
+#[async_trait]
+impl Job for Broken {
+    const KIND: &'static str = "test_broken";
+
+    async fn run(&self, _state: &AppState) -> Result<(), JobError> {
+        record_run(&self.name);
+        Err(JobError::Permanent("Never going to work".to_string()))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Panicking;
+
+#[async_trait]
+impl Job for Panicking {
+    const KIND: &'static str = "test_panicking";
+
+    async fn run(&self, _state: &AppState) -> Result<(), JobError> {
+        panic!("job blew up")
+    }
+}
+
+/// Queued under one key per `slot`.
+#[derive(Serialize, Deserialize)]
+struct Keyed {
+    slot: String,
+    version: u32,
+}
+
+#[async_trait]
+impl Job for Keyed {
+    const KIND: &'static str = "test_keyed";
+
+    fn unique_key(&self) -> Option<String> {
+        Some(format!("keyed:{}", self.slot))
+    }
+
+    async fn run(&self, _state: &AppState) -> Result<(), JobError> {
+        record_run(&format!("{}:{}", self.slot, self.version));
+        Ok(())
+    }
+}
+
+/// The time to microseconds, as Postgres keeps it.
+fn now() -> DateTime<Utc> {
+    Utc::now().trunc_subsecs(6)
+}
+
+fn worker(pool: DbPool) -> Worker {
+    Worker::new(AppState::new(pool, StellarConfig::default()), &JobsConfig::default())
+        .register::<Flaky>()
+        .register::<Broken>()
+        .register::<Panicking>()
+        .register::<Keyed>()
+}
+
+#[test]
+fn retry_delay_doubles_up_to_an_hour() {
+    assert_eq!(retry_delay(1), Duration::seconds(30));
+    assert_eq!(retry_delay(2), Duration::minutes(1));
+    assert_eq!(retry_delay(4), Duration::minutes(4));
+    assert_eq!(retry_delay(8), Duration::hours(1));
+    assert_eq!(retry_delay(1000), Duration::hours(1));
+}
+
+#[sqlx::test]
+async fn a_job_runs_once_and_leaves_the_queue(pool: DbPool) {
+    let job = enqueue(&pool, &Flaky { name: "once".to_string(), failures: 0 }).await.unwrap();
+    let worker = worker(pool.clone());
+
+    let run = worker.run_once(now()).await.unwrap().expect("a job is due");
+    assert_eq!((run.id, run.kind.as_str(), run.attempts, run.outcome), (job.id, "test_flaky", 1, JobOutcome::Succeeded));
+    assert_eq!(runs("once"), 1);
+    assert!(worker.run_once(now()).await.unwrap().is_none());
+    assert!(JobRepository::find(&pool, job.id).await.unwrap().is_none());
+}
+
+#[sqlx::test]
+async fn jobs_wait_for_their_time(pool: DbPool) {
+    let now = now();
+    schedule(&pool, &Flaky { name: "later".to_string(), failures: 0 }, now + Duration::hours(1)).await.unwrap();
+    let worker = worker(pool);
+
+    assert!(worker.run_once(now).await.unwrap().is_none());
+    assert!(worker.run_once(now + Duration::hours(1)).await.unwrap().is_some());
+    assert_eq!(runs("later"), 1);
+}
+
+#[sqlx::test]
+async fn failed_jobs_back_off_then_succeed(pool: DbPool) {
+    let job = enqueue(&pool, &Flaky { name: "flaky".to_string(), failures: 2 }).await.unwrap();
+    let worker = worker(pool.clone());
+    let now = now();
+
+    let first = worker.run_once(now).await.unwrap().unwrap();
+    let JobOutcome::Retrying { run_at, error } = first.outcome else { panic!("expected a retry, got {:?}", first.outcome) };
+    assert_eq!(run_at, now + Duration::seconds(30));
+    assert_eq!(error, "flaky is not ready");
+    let queued = JobRepository::find(&pool, job.id).await.unwrap().unwrap();
+    assert_eq!((queued.status.as_str(), queued.attempts), ("Queued", 1));
+    assert_eq!(queued.last_error.as_deref(), Some("flaky is not ready"));
+
+    // Not due again until the backoff has passed
+    assert!(worker.run_once(now + Duration::seconds(29)).await.unwrap().is_none());
+    let second = worker.run_once(run_at).await.unwrap().unwrap();
+    assert!(matches!(second.outcome, JobOutcome::Retrying { run_at: next, .. } if next == run_at + Duration::minutes(1)));
+
+    let third = worker.run_once(run_at + Duration::minutes(1)).await.unwrap().unwrap();
+    assert_eq!((third.attempts, third.outcome), (3, JobOutcome::Succeeded));
+    assert_eq!(runs("flaky"), 3);
+}
+
+#[sqlx::test]
+async fn jobs_out_of_attempts_are_dead_lettered_and_can_be_queued_again(pool: DbPool) {
+    let job = enqueue(&pool, &Flaky { name: "hopeless".to_string(), failures: 10 }).await.unwrap();
+    let worker = worker(pool.clone());
+    let mut now = self::now();
+
+    for _ in 0..2 {
+        let run = worker.run_once(now).await.unwrap().unwrap();
+        let JobOutcome::Retrying { run_at, .. } = run.outcome else { panic!("expected a retry, got {:?}", run.outcome) };
+        now = run_at;
+    }
+    let last = worker.run_once(now).await.unwrap().unwrap();
+    assert_eq!((last.attempts, last.outcome), (3, JobOutcome::Dead { error: "hopeless is not ready".to_string() }));
+    assert!(worker.run_once(now + Duration::days(1)).await.unwrap().is_none());
+
+    let dead = JobRepository::list_dead(&pool).await.unwrap();
+    assert_eq!(dead.iter().map(|job| job.id).collect::<Vec<_>>(), vec![job.id]);
+    assert_eq!(dead[0].failed_at, Some(now));
+
+    let requeued = JobRepository::requeue(&pool, job.id, now).await.unwrap().expect("dead jobs can be queued again");
+    assert_eq!((requeued.status.as_str(), requeued.attempts), ("Queued", 0));
+    assert!(JobRepository::requeue(&pool, job.id, now).await.unwrap().is_none());
+    assert!(worker.run_once(now).await.unwrap().is_some());
+}
+
+#[sqlx::test]
+async fn permanent_failures_and_malformed_jobs_are_dead_lettered_at_once(pool: DbPool) {
+    let broken = enqueue(&pool, &Broken { name: "broken".to_string() }).await.unwrap();
+    let malformed = JobRepository::enqueue(&pool, Flaky::KIND, &serde_json::json!({ "name": 7 }), None, 3, now())
+        .await
+        .unwrap();
+    let worker = worker(pool.clone());
+
+    let first = worker.run_once(now()).await.unwrap().unwrap();
+    assert_eq!((first.id, first.outcome), (broken.id, JobOutcome::Dead { error: "Never going to work".to_string() }));
+    let second = worker.run_once(now()).await.unwrap().unwrap();
+    assert_eq!(second.id, malformed.id);
+    assert!(matches!(second.outcome, JobOutcome::Dead { ref error } if error.starts_with("Malformed test_flaky job")));
+    assert_eq!(runs("broken"), 1);
+}
+
+#[sqlx::test]
+async fn a_panicking_job_is_retried_without_stopping_the_worker(pool: DbPool) {
+    enqueue(&pool, &Panicking).await.unwrap();
+    enqueue(&pool, &Flaky { name: "after-panic".to_string(), failures: 0 }).await.unwrap();
+    let worker = worker(pool);
+
+    let panicked = worker.run_once(now()).await.unwrap().unwrap();
+    assert!(matches!(panicked.outcome, JobOutcome::Retrying { ref error, .. } if error.contains("panicked")));
+    let next = worker.run_once(now()).await.unwrap().unwrap();
+    assert_eq!(next.outcome, JobOutcome::Succeeded);
+    assert_eq!(runs("after-panic"), 1);
+}
+
+#[sqlx::test]
+async fn concurrent_workers_never_run_a_job_twice(pool: DbPool) {
+    for i in 0..40 {
+        enqueue(&pool, &Flaky { name: format!("shared-{}", i), failures: 0 }).await.unwrap();
+    }
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let worker = worker(pool.clone());
+            tokio::spawn(async move {
+                let mut ran = 0;
+                while worker.run_once(now()).await.unwrap().is_some() {
+                    ran += 1;
+                }
+                ran
+            })
+        })
+        .collect();
+    let mut total = 0;
+    for handle in workers {
+        total += handle.await.unwrap();
+    }
+
+    assert_eq!(total, 40);
+    assert!((0..40).all(|i| runs(&format!("shared-{}", i)) == 1));
+}
+
+#[sqlx::test]
+async fn a_job_whose_worker_died_is_claimed_again(pool: DbPool) {
+    let job = enqueue(&pool, &Flaky { name: "orphaned".to_string(), failures: 0 }).await.unwrap();
+    let now = now();
+    // A worker claims the job and is never heard from again
+    let kinds = vec![Flaky::KIND.to_string()];
+    JobRepository::claim(&pool, &kinds, now, now - Duration::hours(1)).await.unwrap().expect("the job is due");
+
+    let worker = worker(pool.clone());
+    assert!(worker.run_once(now + Duration::minutes(5)).await.unwrap().is_none());
+    let run = worker.run_once(now + Duration::minutes(11)).await.unwrap().expect("a stale claim is taken over");
+    assert_eq!((run.id, run.attempts, run.outcome), (job.id, 2, JobOutcome::Succeeded));
+}
+
+#[sqlx::test]
+async fn unregistered_kinds_are_left_for_other_workers(pool: DbPool) {
+    let job = enqueue(&pool, &Broken { name: "elsewhere".to_string() }).await.unwrap();
+    let worker = Worker::new(AppState::new(pool.clone(), StellarConfig::default()), &JobsConfig::default()).register::<Flaky>();
+
+    assert!(worker.run_once(now()).await.unwrap().is_none());
+    assert_eq!(JobRepository::find(&pool, job.id).await.unwrap().unwrap().status, "Queued");
+}
+
+#[sqlx::test]
+async fn a_queued_job_with_the_same_key_is_replaced(pool: DbPool) {
+    let now = now();
+    let first = schedule(&pool, &Keyed { slot: "a".to_string(), version: 1 }, now + Duration::days(7)).await.unwrap();
+    let second = schedule(&pool, &Keyed { slot: "a".to_string(), version: 2 }, now + Duration::days(3)).await.unwrap();
+    schedule(&pool, &Keyed { slot: "b".to_string(), version: 1 }, now + Duration::days(7)).await.unwrap();
+
+    assert_eq!(first.id, second.id);
+    let queued = JobRepository::list_queued(&pool, Keyed::KIND).await.unwrap();
+    assert_eq!(queued.len(), 2);
+    assert_eq!((queued[0].run_at, queued[0].payload["version"].as_u64()), (now + Duration::days(3), Some(2)));
+
+    assert!(JobRepository::unschedule(&pool, "keyed:b").await.unwrap());
+    assert!(!JobRepository::unschedule(&pool, "keyed:b").await.unwrap());
+
+    let worker = worker(pool);
+    assert!(worker.run_once(now + Duration::days(10)).await.unwrap().is_some());
+    assert!(worker.run_once(now + Duration::days(10)).await.unwrap().is_none());
+    assert_eq!((runs("a:1"), runs("a:2"), runs("b:1")), (0, 1, 0));
+}
+
+#[sqlx::test]
+async fn mail_to_an_invalid_address_is_dead_lettered(pool: DbPool) {
+    let email = Email {
+        to: "not an address".to_string(),
+        subject: "Hello".to_string(),
+        text: "Hello".to_string(),
+        html: "<p>Hello</p>".to_string(),
+    };
+    enqueue(&pool, &SendEmail { email }).await.unwrap();
+    let worker = Worker::new(AppState::new(pool, StellarConfig::default()), &JobsConfig::default()).register::<SendEmail>();
+
+    let run = worker.run_once(now()).await.unwrap().unwrap();
+    assert_eq!(run.kind, "send_email");
+    assert!(matches!(run.outcome, JobOutcome::Dead { ref error } if error.contains("not an email address")));
+}
\ No newline at end of file
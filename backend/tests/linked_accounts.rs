@@ -0,0 +1,73 @@
+//! The database tests run against the Postgres in `DATABASE_URL`; each gets a fresh, migrated database.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use backend::database::repositories::SessionRepository;
+use backend::{create_app, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use chrono::{Duration, Utc};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+const ACCOUNT: &str = "GAODJ6EHA62V4YIEYTVSBZY77I6THZAUW4PPNCNBL6WQMQGQVRMMWHRD";
+
+async fn send(pool: &DbPool, method: Method, uri: &str, token: Option<&str>, body: Option<Value>) -> (StatusCode, Value) {
+    let app = create_app(AppState::new(pool.clone(), StellarConfig::default()), &CorsConfig::default(), &VersioningConfig::default());
+    let mut request = Request::builder().method(method).uri(uri).header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let body = body.map(|body| Body::from(body.to_string())).unwrap_or_else(Body::empty);
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+async fn add_user(pool: &DbPool, name: &str) -> i32 {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (username, email, wallet_address, user_type)
+         VALUES ($1, $1 || '@example.org', 'G' || UPPER($1), 'Ambassador') RETURNING id",
+    )
+    .bind(name)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    id
+}
+
+/// A session for the user, as signing in with their wallet would start.
+async fn sign_in(pool: &DbPool, user_id: i32) -> String {
+    SessionRepository::create(pool, user_id, Utc::now() + Duration::hours(1)).await.unwrap().to_string()
+}
+
+#[sqlx::test]
+async fn accounts_are_linked_and_unlinked_as_the_signed_in_attendee(pool: DbPool) {
+    let organizer = add_user(&pool, "ana").await;
+    let attendee = add_user(&pool, "rui").await;
+    let stranger = add_user(&pool, "eva").await;
+    sqlx::query(
+        "INSERT INTO events (id, title, description, event_type, date, end_date, location, contact_email, organizer_id, status)
+         VALUES (1, 'Meetup', '', 'Meetup', NOW(), NOW() + INTERVAL '2 hours', 'Lisbon', 'ana@example.org', $1, 'Published')",
+    )
+    .bind(organizer)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let (attendee, stranger) = (sign_in(&pool, attendee).await, sign_in(&pool, stranger).await);
+    let link = || Some(json!({ "account_id": ACCOUNT }));
+
+    let (status, _) = send(&pool, Method::POST, "/api/v1/events/1/accounts", None, link()).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, body) = send(&pool, Method::POST, "/api/v1/events/1/accounts", Some(&attendee), link()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["linked_by"]["wallet_address"], "GRUI");
+
+    let link_uri = format!("/api/v1/events/1/accounts/{}", body["id"].as_str().unwrap());
+    let (status, _) = send(&pool, Method::DELETE, &link_uri, None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = send(&pool, Method::DELETE, &link_uri, Some(&stranger), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, body) = send(&pool, Method::DELETE, &link_uri, Some(&attendee), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["accounts"], json!([]));
+}
\ No newline at end of file
@@ -0,0 +1,172 @@
+use backend::config::SmtpSecurity;
+use backend::database::models::{EmailVerification, User};
+use backend::handlers::check_email_verification;
+use backend::mail::{mailbox, Email, LogMailer, MailError, Mailer, SmtpMailer, VERIFY_EMAIL};
+use chrono::{Duration, TimeZone, Utc};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use uuid::Uuid;
+
+const FROM: &str = "Stellar Europe <noreply@stellar.example>";
+
+fn verification_email() -> Email {
+    VERIFY_EMAIL.render(
+        "ada@example.org",
+        &[
+            ("username", "Ada <script>"),
+            ("link", "http://127.0.0.1:8000/verify-email?token=abc&x=1"),
+            ("expires_at", "03 October 2026, 12:00 UTC"),
+        ],
+    )
+}
+
+/// A mail catcher speaking just enough SMTP for one message. Returns its port and the
+/// message data once received.
+async fn smtp_catcher() -> (u16, Arc<Mutex<Option<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let caught = Arc::new(Mutex::new(None));
+
+    let received = caught.clone();
+    tokio::spawn(async move {
+        let (socket, _) = listener.accept().await.unwrap();
+        let (reader, mut writer) = socket.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        writer.write_all(b"220 catcher ESMTP\r\n").await.unwrap();
+
+        while let Some(line) = lines.next_line().await.unwrap() {
+            let command = line.to_ascii_uppercase();
+            let reply: &[u8] = if command.starts_with("EHLO") || command.starts_with("HELO") {
+                b"250 catcher\r\n"
+            } else if command.starts_with("DATA") {
+                writer.write_all(b"354 go ahead\r\n").await.unwrap();
+                let mut data = Vec::new();
+                while let Some(line) = lines.next_line().await.unwrap() {
+                    if line == "." {
+                        break;
+                    }
+                    data.push(line);
+                }
+                *received.lock().unwrap() = Some(data.join("\n"));
+                b"250 queued\r\n"
+            } else if command.starts_with("QUIT") {
+                writer.write_all(b"221 bye\r\n").await.unwrap();
+                break;
+            } else {
+                b"250 ok\r\n"
+            };
+            writer.write_all(reply).await.unwrap();
+        }
+    });
+
+    (port, caught)
+}
+
+#[test]
+fn templates_fill_in_values_and_escape_them_in_html() {
+    let email = verification_email();
+
+    assert_eq!(email.to, "ada@example.org");
+    assert_eq!(email.subject, "Verify your email address for Stellar Europe");
+    assert!(email.text.starts_with("Hi Ada <script>,"), "{}", email.text);
+    assert!(email.text.contains("http://127.0.0.1:8000/verify-email?token=abc&x=1"));
+    assert!(email.text.contains("until 03 October 2026, 12:00 UTC"));
+    assert!(email.html.contains("Hi Ada &lt;script&gt;,"), "{}", email.html);
+    assert!(email.html.contains(r#"href="http://127.0.0.1:8000/verify-email?token=abc&amp;x=1""#));
+    assert!(!email.text.contains("{{") && !email.html.contains("{{"));
+}
+
+#[tokio::test]
+async fn log_mailer_writes_messages_to_its_directory() {
+    let dir = std::env::temp_dir().join(format!("stellar-europe-mail-{}", Uuid::new_v4()));
+    let mailer = LogMailer::new(mailbox(FROM).unwrap(), Some(dir.clone()));
+
+    mailer.send(&verification_email()).await.unwrap();
+
+    let files: Vec<_> = std::fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].extension().unwrap(), "eml");
+    let message = std::fs::read_to_string(&files[0]).unwrap();
+    assert!(message.contains("To: ada@example.org"), "{}", message);
+    assert!(message.contains("Subject: Verify your email address for Stellar Europe"), "{}", message);
+    std::fs::remove_dir_all(dir).ok();
+}
+
+#[tokio::test]
+async fn mailers_refuse_invalid_addresses() {
+    let mailer = LogMailer::new(mailbox(FROM).unwrap(), None);
+    let email = Email { to: "not an address".to_string(), ..verification_email() };
+
+    assert_eq!(mailer.send(&email).await, Err(MailError::InvalidAddress("not an address".to_string())));
+    assert_eq!(mailbox("nope").unwrap_err(), MailError::InvalidAddress("nope".to_string()));
+}
+
+#[tokio::test]
+async fn smtp_mailer_delivers_to_the_server() {
+    let (port, caught) = smtp_catcher().await;
+    let mailer = SmtpMailer::new(mailbox(FROM).unwrap(), "127.0.0.1", port, SmtpSecurity::None, None).unwrap();
+
+    mailer.send(&verification_email()).await.unwrap();
+
+    let message = caught.lock().unwrap().clone().expect("the catcher received a message");
+    assert!(message.contains("From: \"Stellar Europe\" <noreply@stellar.example>"), "{}", message);
+    assert!(message.contains("To: ada@example.org"), "{}", message);
+    assert!(message.contains("multipart/alternative"), "{}", message);
+}
+
+#[tokio::test]
+async fn smtp_mailer_reports_an_unreachable_server() {
+    let port = TcpListener::bind("127.0.0.1:0").await.unwrap().local_addr().unwrap().port();
+    let mailer = SmtpMailer::new(mailbox(FROM).unwrap(), "127.0.0.1", port, SmtpSecurity::None, None).unwrap();
+
+    assert!(matches!(mailer.send(&verification_email()).await, Err(MailError::Unavailable(_))));
+}
+
+fn user(email: &str) -> User {
+    User {
+        id: 7,
+        username: "ada".to_string(),
+        email: email.to_string(),
+        wallet_address: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
+        user_type: "Ambassador".to_string(),
+        organization: None,
+        bio: None,
+        created_at: None,
+        email_verified_at: None,
+    }
+}
+
+fn sent_to(email: &str) -> EmailVerification {
+    let sent = Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap();
+    EmailVerification {
+        id: 1,
+        user_id: 7,
+        email: email.to_string(),
+        token_hash: "hash".to_string(),
+        expires_at: sent + Duration::hours(48),
+        used_at: None,
+        created_at: sent,
+    }
+}
+
+#[test]
+fn verification_links_work_once_until_they_expire() {
+    let verification = sent_to("ada@example.org");
+    let sent = verification.created_at;
+
+    assert!(check_email_verification(&verification, &user("ada@example.org"), sent + Duration::hours(1)).is_ok());
+
+    let (status, error) =
+        check_email_verification(&verification, &user("ada@example.org"), verification.expires_at).unwrap_err();
+    assert_eq!(status, axum::http::StatusCode::GONE);
+    assert_eq!(error.message, "This link expired at 03 October 2026, 12:00 UTC; ask for a new one");
+
+    let used = EmailVerification { used_at: Some(sent), ..verification.clone() };
+    let (status, _) = check_email_verification(&used, &user("ada@example.org"), sent).unwrap_err();
+    assert_eq!(status, axum::http::StatusCode::GONE);
+
+    // A link does not verify an address the user changed to afterwards
+    let (status, _) = check_email_verification(&verification, &user("ada@new.example"), sent).unwrap_err();
+    assert_eq!(status, axum::http::StatusCode::CONFLICT);
+}
\ No newline at end of file
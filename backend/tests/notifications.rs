@@ -0,0 +1,172 @@
+//! The database tests run against the Postgres in `DATABASE_URL`; each gets a fresh, migrated database.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use backend::database::models::NotificationRecipient;
+use backend::database::repositories::SessionRepository;
+use backend::notifications::{notification_template, render_notification, supported_locales, Delivery};
+use backend::{create_app, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use chrono::{Duration, Utc};
+use serde_json::{json, Value};
+use shared::dto::{NotificationKind, NOTIFICATION_LOCALES};
+use tower::ServiceExt;
+
+const LINK: &str = "http://127.0.0.1:8000/events/7/registration";
+const PREFERENCES: &str = "http://127.0.0.1:8000/notifications";
+
+/// A value for every placeholder any template uses.
+const VALUES: [(&str, &str); 11] = [
+    ("username", "Ada"),
+    ("event_title", "Soroban <Workshop>"),
+    ("event_date", "2026-11-05 18:00 CET"),
+    ("location", "Lisbon"),
+    ("reason", "The venue flooded"),
+    ("chapter", "Lisbon"),
+    ("quarter", "Q4 2026"),
+    ("item", "Venue"),
+    ("amount", "120.00 USDC"),
+    ("transaction_hash", "abc123"),
+    ("link", LINK),
+];
+
+fn recipient(email_verified: bool, by_email: bool, in_app: bool) -> NotificationRecipient {
+    NotificationRecipient {
+        user_id: 1,
+        username: "ada".to_string(),
+        email: "ada@example.org".to_string(),
+        email_verified,
+        locale: "en".to_string(),
+        by_email,
+        in_app,
+    }
+}
+
+#[test]
+fn every_offered_language_has_templates() {
+    let offered: Vec<&str> = NOTIFICATION_LOCALES.iter().map(|(code, _)| *code).collect();
+    assert_eq!(supported_locales(), offered);
+}
+
+#[test]
+fn every_template_is_filled_in_completely() {
+    for code in supported_locales() {
+        for kind in NotificationKind::ALL {
+            let template = notification_template(kind, code);
+            assert!(!template.subject.is_empty(), "{} {} has no subject", code, kind);
+            assert!(!template.body.is_empty(), "{} {} has no body", code, kind);
+
+            let rendered = render_notification(kind, code, "ada@example.org", &VALUES, LINK, PREFERENCES);
+            for text in [&rendered.subject, &rendered.body, &rendered.email.text, &rendered.email.html] {
+                assert!(!text.contains("{{"), "{} {} leaves a placeholder: {}", code, kind, text);
+            }
+            assert!(rendered.email.text.contains(PREFERENCES));
+        }
+    }
+}
+
+#[test]
+fn languages_differ_and_unknown_ones_fall_back_to_english() {
+    let english = notification_template(NotificationKind::EventCancelled, "en");
+    assert_ne!(notification_template(NotificationKind::EventCancelled, "fr"), english);
+    assert_ne!(notification_template(NotificationKind::EventCancelled, "es"), english);
+    assert_eq!(notification_template(NotificationKind::EventCancelled, "tlh"), english);
+}
+
+#[test]
+fn values_are_escaped_in_html_only() {
+    let rendered = render_notification(
+        NotificationKind::RegistrationConfirmed,
+        "en",
+        "ada@example.org",
+        &VALUES,
+        LINK,
+        PREFERENCES,
+    );
+
+    assert_eq!(rendered.subject, "You're registered for Soroban <Workshop>");
+    assert!(rendered.body.contains("Soroban <Workshop>"));
+    assert!(rendered.email.html.contains("Soroban &lt;Workshop&gt;"));
+    assert!(!rendered.email.html.contains("<Workshop>"));
+    assert!(rendered.email.html.contains("lang=\"en\""));
+}
+
+#[test]
+fn a_missing_cancellation_reason_leaves_no_gap() {
+    let values: Vec<(&str, &str)> = VALUES
+        .iter()
+        .map(|(name, value)| if *name == "reason" { (*name, "") } else { (*name, *value) })
+        .collect();
+    let rendered = render_notification(NotificationKind::EventCancelled, "en", "ada@example.org", &values, LINK, PREFERENCES);
+
+    assert!(!rendered.body.contains("\n\n\n"));
+    assert!(rendered.body.contains("no longer holds.\n\nSee what else is on:"));
+}
+
+#[test]
+fn mail_only_goes_to_verified_addresses_that_want_it() {
+    assert_eq!(Delivery::for_recipient(&recipient(true, true, true)), Delivery { in_app: true, email: true });
+    assert_eq!(Delivery::for_recipient(&recipient(false, true, true)), Delivery { in_app: true, email: false });
+    assert_eq!(Delivery::for_recipient(&recipient(true, false, true)), Delivery { in_app: true, email: false });
+    assert_eq!(Delivery::for_recipient(&recipient(true, true, false)), Delivery { in_app: false, email: true });
+}
+
+async fn send(pool: &DbPool, method: Method, uri: &str, token: Option<&str>, body: Option<Value>) -> (StatusCode, Value) {
+    let app = create_app(AppState::new(pool.clone(), StellarConfig::default()), &CorsConfig::default(), &VersioningConfig::default());
+    let mut request = Request::builder().method(method).uri(uri).header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let body = body.map(|body| Body::from(body.to_string())).unwrap_or_else(Body::empty);
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+/// Signs up `name`, whose wallet is `G` and their name in capitals, with one unread
+/// notification, and returns a session for them.
+async fn signed_in_with_a_notification(pool: &DbPool, name: &str) -> String {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (username, email, wallet_address, user_type)
+         VALUES ($1, $1 || '@example.org', 'G' || UPPER($1), 'Ambassador') RETURNING id",
+    )
+    .bind(name)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO notifications (user_id, kind, subject, body) VALUES ($1, 'EventReminder', 'Soon', 'Tomorrow')")
+        .bind(id)
+        .execute(pool)
+        .await
+        .unwrap();
+    SessionRepository::create(pool, id, Utc::now() + Duration::hours(1)).await.unwrap().to_string()
+}
+
+#[sqlx::test]
+async fn only_the_signed_in_user_sees_and_changes_their_notifications(pool: DbPool) {
+    let ana = signed_in_with_a_notification(&pool, "ana").await;
+    let rui = signed_in_with_a_notification(&pool, "rui").await;
+    let inbox = "/api/v1/users/GANA/notifications";
+    let read = "/api/v1/users/GANA/notifications/read";
+    let preferences = "/api/v1/users/GANA/notification-preferences";
+    let locale = || Some(json!({ "locale": "es", "preferences": [] }));
+
+    for (token, expected) in [(None, StatusCode::UNAUTHORIZED), (Some(rui.as_str()), StatusCode::FORBIDDEN)] {
+        assert_eq!(send(&pool, Method::GET, inbox, token, None).await.0, expected);
+        assert_eq!(send(&pool, Method::POST, read, token, Some(json!({ "notification_ids": [] }))).await.0, expected);
+        assert_eq!(send(&pool, Method::GET, preferences, token, None).await.0, expected);
+        assert_eq!(send(&pool, Method::PUT, preferences, token, locale()).await.0, expected);
+    }
+
+    let (status, body) = send(&pool, Method::GET, inbox, Some(&ana), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["unread"], 1);
+    let (_, body) = send(&pool, Method::POST, read, Some(&ana), Some(json!({ "notification_ids": [] }))).await;
+    assert_eq!(body["unread"], 0);
+    let (_, body) = send(&pool, Method::PUT, preferences, Some(&ana), locale()).await;
+    assert_eq!(body["locale"], "es");
+
+    // The other inbox was left alone
+    let (_, body) = send(&pool, Method::GET, "/api/v1/users/GRUI/notifications", Some(&rui), None).await;
+    assert_eq!(body["unread"], 1);
+}
\ No newline at end of file
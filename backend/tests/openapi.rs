@@ -0,0 +1,115 @@
+mod common;
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{Request, StatusCode},
+};
+use backend::openapi::{ApiDoc, ApiV1Doc, DOCS_UI_PATH, OPENAPI_JSON_PATH};
+use backend::{api_v1_routes, route_table};
+use tower::ServiceExt;
+use utoipa::openapi::{path::Operation, Deprecated, OpenApi as OpenApiDocument};
+use utoipa::OpenApi;
+
+/// Method and path of every route the app serves, as the router was built.
+fn served_routes() -> Vec<(String, String)> {
+    route_table().into_iter().map(|(method, path)| (method.as_str().to_lowercase(), path)).collect()
+}
+
+/// Paths of `/api/v1`, relative to it.
+fn v1_routes() -> Vec<(String, String)> {
+    api_v1_routes().paths().into_iter().map(|(method, path)| (method.as_str().to_lowercase(), path)).collect()
+}
+
+fn operation<'a>(doc: &'a OpenApiDocument, method: &str, path: &str) -> Option<&'a Operation> {
+    let item = doc.paths.paths.get(path)?;
+    match method {
+        "get" => item.get.as_ref(),
+        "post" => item.post.as_ref(),
+        "put" => item.put.as_ref(),
+        "patch" => item.patch.as_ref(),
+        "delete" => item.delete.as_ref(),
+        _ => None,
+    }
+}
+
+#[test]
+fn every_route_is_documented() {
+    let routes = served_routes();
+    assert!(routes.iter().any(|(method, path)| method == "get" && path == "/health"));
+    let doc = ApiDoc::openapi();
+
+    let undocumented: Vec<_> = routes
+        .iter()
+        .filter(|(method, path)| operation(&doc, method, path).is_none())
+        .map(|(method, path)| format!("{} {}", method.to_uppercase(), path))
+        .collect();
+    assert!(
+        undocumented.is_empty(),
+        "routes missing from the OpenAPI docs (add #[utoipa::path] and list them in openapi.rs): {:?}",
+        undocumented
+    );
+}
+
+#[test]
+fn every_documented_route_is_served() {
+    let routes = served_routes();
+    let doc = ApiDoc::openapi();
+
+    for (path, item) in &doc.paths.paths {
+        let operations = [("get", &item.get), ("post", &item.post), ("put", &item.put), ("patch", &item.patch), ("delete", &item.delete)];
+        for (method, documented) in operations {
+            if documented.is_some() {
+                assert!(
+                    routes.iter().any(|(routed_method, routed)| routed_method == method && routed == path),
+                    "{} {} is documented but not served",
+                    method.to_uppercase(),
+                    path
+                );
+            }
+        }
+    }
+
+    // The v1 document is the one nested under /api/v1
+    for path in ApiV1Doc::openapi().paths.paths.keys() {
+        assert!(v1_routes().iter().any(|(_, routed)| routed == path), "{} is documented but not routed in api_v1", path);
+    }
+}
+
+#[test]
+fn legacy_aliases_are_documented_as_deprecated() {
+    let doc = ApiDoc::openapi();
+
+    for (method, path) in &v1_routes() {
+        let current = operation(&doc, method, &format!("/api/v1{}", path))
+            .unwrap_or_else(|| panic!("{} /api/v1{} missing", method, path));
+        assert!(current.deprecated.is_none());
+
+        let legacy = operation(&doc, method, &format!("/api{}", path))
+            .unwrap_or_else(|| panic!("{} /api{} missing", method, path));
+        assert!(matches!(legacy.deprecated, Some(Deprecated::True)));
+    }
+}
+
+#[tokio::test]
+async fn openapi_document_is_served() {
+    let request = Request::builder().uri(OPENAPI_JSON_PATH).body(Body::empty()).unwrap();
+    let response = common::test_app().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let doc: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(doc["openapi"].as_str().unwrap().starts_with("3."));
+    assert!(doc["paths"]["/api/v1/events"]["get"].is_object());
+    assert!(doc["components"]["schemas"]["EventRequest"].is_object());
+}
+
+#[tokio::test]
+async fn docs_ui_is_served() {
+    let request = Request::builder()
+        .uri(format!("{}/", DOCS_UI_PATH))
+        .body(Body::empty())
+        .unwrap();
+    let response = common::test_app().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
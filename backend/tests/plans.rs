@@ -0,0 +1,219 @@
+//! The database tests run against the Postgres in `DATABASE_URL`; each gets a fresh, migrated database.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use backend::database::repositories::{PlanRepository, SessionRepository};
+use backend::{create_app, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use chrono::{Duration, Utc};
+use serde_json::{json, Value};
+use shared::dto::{PlanAction, PlanStatus};
+use tower::ServiceExt;
+
+#[test]
+fn plans_move_through_review_only_by_allowed_actions() {
+    use PlanAction::*;
+    use PlanStatus::*;
+
+    let expected = [
+        (Draft, Submit, Some(Submitted)),
+        (Draft, Approve, None),
+        (Draft, RequestChanges, None),
+        (Submitted, Submit, None),
+        (Submitted, Approve, Some(Approved)),
+        (Submitted, RequestChanges, Some(ChangesRequested)),
+        (Approved, Submit, None),
+        (Approved, Approve, None),
+        (Approved, RequestChanges, None),
+        (ChangesRequested, Submit, Some(Submitted)),
+        (ChangesRequested, Approve, None),
+        (ChangesRequested, RequestChanges, None),
+    ];
+    for (status, action, next) in expected {
+        assert_eq!(status.apply(action), next, "{} + {:?}", status, action);
+    }
+
+    assert_eq!(Draft.allowed_actions(), vec![Submit]);
+    assert_eq!(Submitted.allowed_actions(), vec![Approve, RequestChanges]);
+    assert_eq!(Approved.allowed_actions(), vec![]);
+    assert_eq!(ChangesRequested.allowed_actions(), vec![Submit]);
+}
+
+#[test]
+fn only_drafts_and_plans_sent_back_are_editable() {
+    let editable: Vec<_> = PlanStatus::ALL.into_iter().filter(|status| status.is_editable()).collect();
+    assert_eq!(editable, vec![PlanStatus::Draft, PlanStatus::ChangesRequested]);
+    for status in PlanStatus::ALL {
+        assert_eq!(PlanStatus::from_name(&status.to_string()), Some(status));
+    }
+    assert_eq!(PlanStatus::from_name("Rejected"), None);
+}
+
+async fn send(pool: &DbPool, method: Method, uri: &str, body: Value) -> (StatusCode, Value) {
+    send_as(pool, None, method, uri, body).await
+}
+
+async fn send_as(pool: &DbPool, token: Option<&str>, method: Method, uri: &str, body: Value) -> (StatusCode, Value) {
+    let app = create_app(AppState::new(pool.clone(), StellarConfig::default()), &CorsConfig::default(), &VersioningConfig::default());
+    let mut request = Request::builder().method(method).uri(uri).header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let response = app.oneshot(request.body(Body::from(body.to_string())).unwrap()).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+async fn add_user(pool: &DbPool, name: &str, user_type: &str) -> i32 {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (username, email, wallet_address, user_type)
+         VALUES ($1, $1 || '@example.org', 'G' || UPPER($1), $2) RETURNING id",
+    )
+    .bind(name)
+    .bind(user_type)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    id
+}
+
+/// A session for the user, as signing in with their wallet would start.
+async fn sign_in(pool: &DbPool, user_id: i32) -> String {
+    SessionRepository::create(pool, user_id, Utc::now() + Duration::hours(1)).await.unwrap().to_string()
+}
+
+async fn add_chapter(pool: &DbPool, name: &str, lead_id: Option<i32>, members: &[i32]) -> i32 {
+    let (id,): (i32,) = sqlx::query_as("INSERT INTO chapters (name, country, city, lead_id) VALUES ($1, 'Portugal', $1, $2) RETURNING id")
+        .bind(name)
+        .bind(lead_id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+    for member in members {
+        sqlx::query("INSERT INTO chapter_members (chapter_id, user_id) VALUES ($1, $2)")
+            .bind(id)
+            .bind(member)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+    id
+}
+
+async fn add_event(pool: &DbPool, organizer: i32, chapter_id: i32, date: &str) -> i32 {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO events (title, description, event_type, date, end_date, location, contact_email, organizer_id, chapter_id, status)
+         VALUES ('Meetup', '', 'Meetup', $1::TIMESTAMPTZ, $1::TIMESTAMPTZ + INTERVAL '2 hours', 'Lisbon', 'org@example.org', $2, $3, 'Approved')
+         RETURNING id",
+    )
+    .bind(date)
+    .bind(organizer)
+    .bind(chapter_id)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    id
+}
+
+async fn planned_events(pool: &DbPool) -> Vec<(i32, Option<i32>)> {
+    sqlx::query_as("SELECT id, plan_id FROM events ORDER BY id").fetch_all(pool).await.unwrap()
+}
+
+fn plan_request(chapter_id: i32, event_ids: &[i32]) -> Value {
+    json!({
+        "chapter_id": chapter_id.to_string(),
+        "year": 2026,
+        "quarter": 1,
+        "strategic_purpose": "Grow the Lisbon developer community",
+        "quarterly_goals": "Two meetups",
+        "event_ids": event_ids.iter().map(i32::to_string).collect::<Vec<_>>(),
+    })
+}
+
+async fn transition(pool: &DbPool, plan_id: &str, action: &str, actor: Option<&str>) -> (StatusCode, Value) {
+    let body = json!({ "action": action, "comment": "Looks thin on content" });
+    send_as(pool, actor, Method::POST, &format!("/api/v1/plans/{}/transitions", plan_id), body).await
+}
+
+#[sqlx::test]
+async fn chapter_leads_cannot_review_plans_of_chapters_they_belong_to(pool: DbPool) {
+    let member = add_user(&pool, "ana", "Ambassador").await;
+    let lead = add_user(&pool, "lead", "ChapterLead").await;
+    let member_lead = add_user(&pool, "rui", "ChapterLead").await;
+    let outside_lead = add_user(&pool, "eva", "ChapterLead").await;
+    let chapter = add_chapter(&pool, "Lisbon", Some(lead), &[member, lead, member_lead]).await;
+    add_chapter(&pool, "Berlin", Some(outside_lead), &[outside_lead]).await;
+    let event = add_event(&pool, member, chapter, "2026-02-10T18:00:00Z").await;
+    let member = sign_in(&pool, member).await;
+
+    let (status, plan) = send(&pool, Method::POST, "/api/v1/plans", plan_request(chapter, &[event])).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let plan_id = plan["id"].as_str().unwrap();
+    assert_eq!(transition(&pool, plan_id, "Submit", None).await.0, StatusCode::UNAUTHORIZED);
+    assert_eq!(transition(&pool, plan_id, "Submit", Some(&member)).await.0, StatusCode::OK);
+
+    for (reviewer, token) in [("lead", sign_in(&pool, lead).await), ("rui", sign_in(&pool, member_lead).await), ("ana", member.clone())] {
+        for action in ["Approve", "RequestChanges"] {
+            let (status, body) = transition(&pool, plan_id, action, Some(&token)).await;
+            assert_eq!(status, StatusCode::FORBIDDEN, "{} could {}", reviewer, action);
+            assert!(body["message"].as_str().unwrap().contains("other chapters"));
+        }
+    }
+
+    let (status, body) = transition(&pool, plan_id, "Approve", Some(&sign_in(&pool, outside_lead).await)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "Approved");
+    let approval = body["transitions"].as_array().unwrap().iter().find(|transition| transition["to_status"] == "Approved").unwrap();
+    assert_eq!(approval["actor"]["username"], "eva");
+}
+
+#[sqlx::test]
+async fn comments_are_signed_by_the_session(pool: DbPool) {
+    let member = add_user(&pool, "ana", "Ambassador").await;
+    let chapter = add_chapter(&pool, "Lisbon", None, &[member]).await;
+    let (_, plan) = send(&pool, Method::POST, "/api/v1/plans", plan_request(chapter, &[])).await;
+    let uri = format!("/api/v1/plans/{}/comments", plan["id"].as_str().unwrap());
+
+    let (status, _) = send(&pool, Method::POST, &uri, json!({ "body": "Who is speaking?" })).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let token = sign_in(&pool, member).await;
+    let (status, _) = send_as(&pool, Some(&token), Method::POST, &uri, json!({ "body": "  " })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, comment) = send_as(&pool, Some(&token), Method::POST, &uri, json!({ "body": "Add a workshop" })).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(comment["author"]["username"], "ana");
+    assert_eq!(comment["body"], "Add a workshop");
+}
+
+#[sqlx::test]
+async fn updating_a_plan_replaces_its_events(pool: DbPool) {
+    let member = add_user(&pool, "ana", "Ambassador").await;
+    let chapter = add_chapter(&pool, "Lisbon", None, &[member]).await;
+    let january = add_event(&pool, member, chapter, "2026-01-20T18:00:00Z").await;
+    let february = add_event(&pool, member, chapter, "2026-02-10T18:00:00Z").await;
+
+    let (status, plan) = send(&pool, Method::POST, "/api/v1/plans", plan_request(chapter, &[january])).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let plan_id: i32 = plan["id"].as_str().unwrap().parse().unwrap();
+    assert_eq!(planned_events(&pool).await, vec![(january, Some(plan_id)), (february, None)]);
+
+    let (status, _) = send(&pool, Method::PUT, &format!("/api/v1/plans/{}", plan_id), plan_request(chapter, &[february])).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(planned_events(&pool).await, vec![(january, None), (february, Some(plan_id))]);
+}
+
+#[sqlx::test]
+async fn a_plan_and_its_events_are_saved_together(pool: DbPool) {
+    let member = add_user(&pool, "ana", "Ambassador").await;
+    let chapter = add_chapter(&pool, "Lisbon", None, &[member]).await;
+    let event = add_event(&pool, member, chapter, "2026-02-10T18:00:00Z").await;
+
+    // A transaction that fails after both steps leaves neither the plan nor its events behind
+    let mut tx = pool.begin().await.unwrap();
+    let plan = PlanRepository::create_plan(&mut *tx, chapter, 2026, 1, "Purpose", "Goals").await.unwrap();
+    PlanRepository::set_events(&mut tx, plan.id, &[event]).await.unwrap();
+    tx.rollback().await.unwrap();
+
+    assert!(PlanRepository::find_for_quarter(&pool, chapter, 2026, 1).await.unwrap().is_none());
+    assert_eq!(planned_events(&pool).await, vec![(event, None)]);
+}
\ No newline at end of file
@@ -0,0 +1,284 @@
+//! The database tests run against the Postgres in `DATABASE_URL`; each gets a fresh, migrated database.
+
+use async_trait::async_trait;
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use backend::database::repositories::SessionRepository;
+use backend::stellar::{
+    build_payment, memo_text, network_id, submit_payment, verify_signed, Horizon, HorizonError, LedgerTransaction, PaymentError,
+    PayoutAsset, SubmissionOutcome, UnsignedPayment,
+};
+use backend::{create_app, AppState, CorsConfig, DbPool, StellarConfig, VersioningConfig};
+use chrono::{Duration, TimeZone, Utc};
+use rust_decimal::Decimal;
+use serde_json::Value;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use tower::ServiceExt;
+use stellar_xdr::curr::{
+    Asset, DecoratedSignature, Limits, Memo, OperationBody, Preconditions, ReadXdr, SignatureHint, TransactionEnvelope,
+    WriteXdr,
+};
+
+const PASSPHRASE: &str = "Test SDF Network ; September 2015";
+const PROGRAMME: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+const AMBASSADOR: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+/// Stands in for Horizon: answers submissions with a canned result and
+/// remembers what was sent.
+struct MockHorizon {
+    submission: Result<LedgerTransaction, HorizonError>,
+    in_ledger: Option<LedgerTransaction>,
+    submitted: Mutex<Vec<String>>,
+}
+
+impl MockHorizon {
+    fn answering(submission: Result<LedgerTransaction, HorizonError>) -> Self {
+        Self { submission, in_ledger: None, submitted: Mutex::new(Vec::new()) }
+    }
+
+    fn submissions(&self) -> usize {
+        self.submitted.lock().unwrap().len()
+    }
+}
+
+#[async_trait]
+impl Horizon for MockHorizon {
+    async fn account_sequence(&self, _account_id: &str) -> Result<i64, HorizonError> {
+        Ok(41)
+    }
+
+    async fn submit_transaction(&self, envelope_xdr: &str) -> Result<LedgerTransaction, HorizonError> {
+        self.submitted.lock().unwrap().push(envelope_xdr.to_string());
+        self.submission.clone()
+    }
+
+    async fn find_transaction(&self, _hash: &str) -> Result<Option<LedgerTransaction>, HorizonError> {
+        Ok(self.in_ledger.clone())
+    }
+}
+
+fn in_ledger(ledger: u32, successful: bool) -> LedgerTransaction {
+    LedgerTransaction { hash: "abc".to_string(), ledger, successful }
+}
+
+fn usdc() -> PayoutAsset {
+    PayoutAsset::Credit { code: "USDC".to_string(), issuer: AMBASSADOR.to_string() }
+}
+
+fn payment(amount: &str) -> UnsignedPayment {
+    build_payment(
+        PASSPHRASE,
+        PROGRAMME,
+        41,
+        AMBASSADOR,
+        &usdc(),
+        Decimal::from_str(amount).unwrap(),
+        "Reimbursement 7",
+        Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap(),
+    )
+    .unwrap()
+}
+
+/// What a wallet returns: the same transaction with a signature added.
+fn sign(unsigned_xdr: &str) -> String {
+    let TransactionEnvelope::Tx(mut envelope) = TransactionEnvelope::from_xdr_base64(unsigned_xdr, Limits::none()).unwrap() else {
+        panic!("expected a v1 envelope");
+    };
+    envelope.signatures = vec![DecoratedSignature { hint: SignatureHint([1, 2, 3, 4]), signature: vec![7u8; 64].try_into().unwrap() }]
+        .try_into()
+        .unwrap();
+    TransactionEnvelope::Tx(envelope).to_xdr_base64(Limits::none()).unwrap()
+}
+
+#[test]
+fn builds_an_unsigned_payment_to_the_ambassador() {
+    let built = payment("120.50");
+    let envelope = TransactionEnvelope::from_xdr_base64(&built.envelope_xdr, Limits::none()).unwrap();
+
+    let hash: String = envelope.hash(network_id(PASSPHRASE)).unwrap().iter().map(|b| format!("{:02x}", b)).collect();
+    assert_eq!(built.hash, hash);
+
+    let TransactionEnvelope::Tx(envelope) = envelope else { panic!("expected a v1 envelope") };
+    assert!(envelope.signatures.is_empty());
+    assert_eq!(envelope.tx.source_account.to_string(), PROGRAMME);
+    assert_eq!(envelope.tx.seq_num.0, 42);
+    assert!(matches!(&envelope.tx.memo, Memo::Text(text) if text.to_string() == "Reimbursement 7"));
+    let Preconditions::Time(bounds) = &envelope.tx.cond else { panic!("expected time bounds") };
+    assert_eq!(bounds.max_time.0, Utc.with_ymd_and_hms(2026, 10, 8, 12, 0, 0).unwrap().timestamp() as u64);
+
+    let [operation] = envelope.tx.operations.as_slice() else { panic!("expected one operation") };
+    let OperationBody::Payment(payment) = &operation.body else { panic!("expected a payment") };
+    assert_eq!(payment.destination.to_string(), AMBASSADOR);
+    assert_eq!(payment.amount, 1_205_000_000);
+    assert!(matches!(&payment.asset, Asset::CreditAlphanum4(asset) if asset.asset_code.to_string() == "USDC"));
+}
+
+#[test]
+fn rejects_unpayable_amounts_and_accounts() {
+    let build = |destination: &str, amount: &str| {
+        build_payment(PASSPHRASE, PROGRAMME, 41, destination, &PayoutAsset::Native, Decimal::from_str(amount).unwrap(), "", Utc::now())
+    };
+
+    assert!(matches!(build(AMBASSADOR, "0"), Err(PaymentError::InvalidAmount(_))));
+    assert!(matches!(build(AMBASSADOR, "0.00000001"), Err(PaymentError::InvalidAmount(_))));
+    assert!(matches!(build("not-a-wallet", "10"), Err(PaymentError::InvalidAccount(_))));
+    assert!(build(AMBASSADOR, "0.0000001").is_ok());
+}
+
+#[test]
+fn memos_are_cut_to_28_bytes_between_characters() {
+    assert_eq!(memo_text("Reimbursement 7"), "Reimbursement 7");
+    // 27 ASCII bytes, then a two-byte character that would make 29
+    let memo = format!("{}é and more", "a".repeat(27));
+    assert_eq!(memo_text(&memo), "a".repeat(27));
+    // 14 two-byte characters fill the memo exactly
+    assert_eq!(memo_text(&"é".repeat(20)), "é".repeat(14));
+    assert_eq!(memo_text("€€€€€€€€€€"), "€€€€€€€€€");
+
+    let built = build_payment(
+        PASSPHRASE,
+        PROGRAMME,
+        41,
+        AMBASSADOR,
+        &usdc(),
+        Decimal::from_str("10").unwrap(),
+        "Reembolso: café e transporte em Lisboa",
+        Utc::now(),
+    )
+    .unwrap();
+    let envelope = TransactionEnvelope::from_xdr_base64(&built.envelope_xdr, Limits::none()).unwrap();
+    let TransactionEnvelope::Tx(envelope) = envelope else { panic!("expected a v1 envelope") };
+    let Memo::Text(text) = &envelope.tx.memo else { panic!("expected a text memo") };
+    assert_eq!(text.as_slice(), "Reembolso: café e transport".as_bytes());
+}
+
+#[test]
+fn only_the_prepared_transaction_with_a_signature_is_accepted() {
+    let built = payment("120.50");
+
+    assert_eq!(verify_signed(&built.envelope_xdr, &sign(&built.envelope_xdr)), Ok(()));
+    assert_eq!(verify_signed(&built.envelope_xdr, &built.envelope_xdr), Err(PaymentError::Unsigned));
+    assert_eq!(
+        verify_signed(&built.envelope_xdr, &sign(&payment("999.00").envelope_xdr)),
+        Err(PaymentError::EnvelopeMismatch)
+    );
+    assert_eq!(verify_signed(&built.envelope_xdr, "garbage"), Err(PaymentError::InvalidEnvelope));
+}
+
+#[tokio::test]
+async fn a_payment_included_in_a_ledger_is_paid() {
+    let horizon = MockHorizon::answering(Ok(in_ledger(1234, true)));
+    let signed = sign(&payment("10").envelope_xdr);
+
+    let outcome = submit_payment(&horizon, "abc", &signed, false).await;
+
+    assert_eq!(outcome, SubmissionOutcome::Paid { ledger: 1234 });
+    assert_eq!(horizon.submitted.lock().unwrap().as_slice(), [signed]);
+}
+
+#[tokio::test]
+async fn a_rejected_payment_fails() {
+    let horizon = MockHorizon::answering(Err(HorizonError::Rejected("tx_bad_auth".to_string())));
+
+    let outcome = submit_payment(&horizon, "abc", &sign(&payment("10").envelope_xdr), false).await;
+
+    assert!(matches!(outcome, SubmissionOutcome::Failed(error) if error.contains("tx_bad_auth")));
+}
+
+#[tokio::test]
+async fn an_unanswered_submission_stays_pending() {
+    let horizon = MockHorizon::answering(Err(HorizonError::Unavailable("timeout".to_string())));
+
+    let outcome = submit_payment(&horizon, "abc", &sign(&payment("10").envelope_xdr), false).await;
+
+    assert!(matches!(outcome, SubmissionOutcome::Pending(_)));
+}
+
+#[tokio::test]
+async fn a_resubmission_finds_a_payment_that_already_went_through() {
+    let horizon = MockHorizon {
+        in_ledger: Some(in_ledger(99, true)),
+        ..MockHorizon::answering(Err(HorizonError::Rejected("tx_bad_seq".to_string())))
+    };
+
+    let outcome = submit_payment(&horizon, "abc", &sign(&payment("10").envelope_xdr), true).await;
+
+    assert_eq!(outcome, SubmissionOutcome::Paid { ledger: 99 });
+    assert_eq!(horizon.submissions(), 0);
+}
+
+/// Signs up `name` with `wallet_address` and returns a session for them.
+async fn signed_in(pool: &DbPool, name: &str, wallet_address: &str) -> (i32, String) {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (username, email, wallet_address, user_type) VALUES ($1, $1 || '@example.org', $2, 'Ambassador') RETURNING id",
+    )
+    .bind(name)
+    .bind(wallet_address)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    let token = SessionRepository::create(pool, id, Utc::now() + Duration::hours(1)).await.unwrap();
+    (id, token.to_string())
+}
+
+#[sqlx::test]
+async fn only_a_signed_in_programme_admin_prepares_a_payout(pool: DbPool) {
+    let (organizer, organizer_token) = signed_in(&pool, "org", AMBASSADOR).await;
+    let (_, admin_token) = signed_in(&pool, "admin", "GADMIN").await;
+    let (event_id,): (i32,) = sqlx::query_as(
+        "INSERT INTO events (title, description, event_type, date, end_date, location, contact_email, organizer_id, status, approved_at)
+         VALUES ('Meetup', '', 'Meetup', NOW() - INTERVAL '2 days', NOW() - INTERVAL '1 day', 'Lisbon', 'org@example.org', $1, 'Completed', NOW())
+         RETURNING id",
+    )
+    .bind(organizer)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    let (item_id,): (i32,) = sqlx::query_as(
+        "INSERT INTO event_budget_items (event_id, category, currency, requested_amount, approved_amount, spent_amount)
+         VALUES ($1, 'Venue', 'USDC', 50, 40, 45) RETURNING id",
+    )
+    .bind(event_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    let stellar = StellarConfig {
+        payout_account: Some(PROGRAMME.to_string()),
+        admin_wallets: vec!["GADMIN".to_string()],
+        ..StellarConfig::default()
+    };
+    let prepare = |token: Option<&str>| {
+        let state = AppState::new(pool.clone(), stellar.clone()).with_horizon(Arc::new(MockHorizon::answering(Ok(in_ledger(1, true)))));
+        let app = create_app(state, &CorsConfig::default(), &VersioningConfig::default());
+        let mut request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("/api/v1/events/{}/budget/{}/reimbursement", event_id, item_id));
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        async move {
+            let response = app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+            let status = response.status();
+            let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            (status, serde_json::from_slice::<Value>(&bytes).unwrap_or(Value::Null))
+        }
+    };
+
+    assert_eq!(prepare(None).await.0, StatusCode::UNAUTHORIZED);
+    assert_eq!(prepare(Some(&organizer_token)).await.0, StatusCode::FORBIDDEN);
+
+    let (status, reimbursement) = prepare(Some(&admin_token)).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", reimbursement);
+    assert_eq!(reimbursement["recipient_wallet_address"], AMBASSADOR);
+    assert_eq!(reimbursement["status"], "AwaitingSignature");
+    let (prepared_by,): (String,) = sqlx::query_as(
+        "SELECT u.username FROM expense_reimbursements r JOIN users u ON u.id = r.prepared_by WHERE r.budget_item_id = $1",
+    )
+    .bind(item_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(prepared_by, "admin");
+}
\ No newline at end of file
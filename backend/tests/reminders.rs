@@ -0,0 +1,230 @@
+//! These run against the Postgres in `DATABASE_URL`; each test gets a fresh, migrated database.
+
+use backend::database::models::Event;
+use backend::database::repositories::{EventRepository, JobRepository, NotificationRepository};
+use backend::jobs::{self, enqueue, schedule, Job, Worker};
+use backend::mail::SendEmail;
+use backend::notifications::{
+    schedule_reminders, schedule_upcoming_reminders, DomainEvent, PublishNotification, SendEventReminder,
+};
+use backend::{AppState, DbPool, JobsConfig, RemindersConfig, StellarConfig};
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+
+/// The time to microseconds, as Postgres keeps it.
+fn now() -> DateTime<Utc> {
+    Utc::now().trunc_subsecs(6)
+}
+
+fn state(pool: &DbPool) -> AppState {
+    AppState::new(pool.clone(), StellarConfig::default())
+}
+
+async fn add_user(pool: &DbPool, name: &str) -> i32 {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO users (username, email, wallet_address, user_type, email_verified_at)
+         VALUES ($1, $1 || '@example.org', 'G' || UPPER($1), 'Ambassador', NOW()) RETURNING id",
+    )
+    .bind(name)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    id
+}
+
+/// A published event starting `starts_in` from now, with Ada registered, Bea on the
+/// waitlist and Cy cancelled.
+async fn published_event(pool: &DbPool, starts_in: Duration) -> Event {
+    let organizer = add_user(pool, "org").await;
+    let date = now() + starts_in;
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO events (title, description, event_type, date, end_date, location, contact_email, organizer_id, status)
+         VALUES ('Soroban Workshop', '', 'Workshop', $1, $2, 'Lisbon', 'org@example.org', $3, 'Published') RETURNING id",
+    )
+    .bind(date)
+    .bind(date + Duration::hours(2))
+    .bind(organizer)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+
+    for (name, status) in [("ada", "Registered"), ("bea", "Waitlisted"), ("cy", "Cancelled")] {
+        let user = add_user(pool, name).await;
+        sqlx::query("INSERT INTO event_registrations (event_id, user_id, status) VALUES ($1, $2, $3)")
+            .bind(id)
+            .bind(user)
+            .bind(status)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+    EventRepository::find_by_id(pool, id).await.unwrap().unwrap()
+}
+
+async fn queued_reminders(pool: &DbPool) -> Vec<(DateTime<Utc>, SendEventReminder)> {
+    JobRepository::list_queued(pool, SendEventReminder::KIND)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|job| (job.run_at, serde_json::from_value(job.payload).unwrap()))
+        .collect()
+}
+
+/// Runs every job due at `at`, returning their kinds in the order they ran.
+async fn run_due(worker: &Worker, at: DateTime<Utc>) -> Vec<String> {
+    let mut kinds = Vec::new();
+    while let Some(run) = worker.run_once(at).await.unwrap() {
+        kinds.push(run.kind);
+    }
+    kinds
+}
+
+async fn reminders_received(pool: &DbPool, name: &str) -> usize {
+    let (user_id,): (i32,) = sqlx::query_as("SELECT id FROM users WHERE username = $1")
+        .bind(name)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+    let inbox = NotificationRepository::list_for_user(pool, user_id, false, 50).await.unwrap();
+    inbox.iter().filter(|notification| notification.kind == "EventReminder").count()
+}
+
+async fn set_event(pool: &DbPool, event_id: i32, date: DateTime<Utc>, status: &str) -> Event {
+    sqlx::query("UPDATE events SET date = $2, end_date = $2 + INTERVAL '2 hours', status = $3 WHERE id = $1")
+        .bind(event_id)
+        .bind(date)
+        .bind(status)
+        .execute(pool)
+        .await
+        .unwrap();
+    EventRepository::find_by_id(pool, event_id).await.unwrap().unwrap()
+}
+
+#[sqlx::test]
+async fn published_events_get_the_reminders_still_ahead_of_them(pool: DbPool) {
+    let state = state(&pool);
+    let soon = published_event(&pool, Duration::days(10)).await;
+    schedule_reminders(&state, &soon).await;
+
+    let queued = queued_reminders(&pool).await;
+    let times: Vec<_> = queued.iter().map(|(run_at, reminder)| (*run_at, reminder.minutes_before)).collect();
+    assert_eq!(times, vec![(soon.date - Duration::days(7), 7 * 24 * 60), (soon.date - Duration::hours(24), 24 * 60)]);
+
+    // Three days out, only the day-before reminder is still ahead
+    let date = now() + Duration::days(3);
+    let sooner = set_event(&pool, soon.id, date, "Published").await;
+    schedule_reminders(&state, &sooner).await;
+    let times: Vec<_> = queued_reminders(&pool).await.into_iter().map(|(run_at, _)| run_at).collect();
+    assert_eq!(times, vec![date - Duration::hours(24)]);
+}
+
+#[sqlx::test]
+async fn registered_attendees_are_reminded_once(pool: DbPool) {
+    let state = state(&pool);
+    let event = published_event(&pool, Duration::days(10)).await;
+    schedule_reminders(&state, &event).await;
+    let worker = jobs::worker(state, &JobsConfig::default());
+
+    let week_before = event.date - Duration::days(7);
+    assert!(run_due(&worker, week_before - Duration::minutes(1)).await.is_empty());
+    assert_eq!(run_due(&worker, week_before).await, vec!["send_event_reminder", "publish_notification", "send_email"]);
+    assert_eq!(reminders_received(&pool, "ada").await, 1);
+    assert_eq!(reminders_received(&pool, "bea").await, 0);
+    assert_eq!(reminders_received(&pool, "cy").await, 0);
+
+    // The same reminder queued again, e.g. by a restart, is not sent twice
+    let again = SendEventReminder { event_id: event.id, minutes_before: 7 * 24 * 60, event_date: event.date };
+    schedule(&pool, &again, week_before).await.unwrap();
+    assert_eq!(run_due(&worker, week_before).await, vec!["send_event_reminder"]);
+    assert_eq!(reminders_received(&pool, "ada").await, 1);
+
+    let day_before = event.date - Duration::hours(24);
+    assert_eq!(run_due(&worker, day_before).await, vec!["send_event_reminder", "publish_notification", "send_email"]);
+    assert_eq!(reminders_received(&pool, "ada").await, 2);
+}
+
+#[sqlx::test]
+async fn a_notification_claimed_again_after_timing_out_tells_nobody_twice(pool: DbPool) {
+    let state = state(&pool);
+    let event = published_event(&pool, Duration::days(10)).await;
+    let notification = PublishNotification::new(DomainEvent::EventReminder { event: event.clone() });
+    enqueue(&pool, &notification).await.unwrap();
+
+    // A worker claims the job and stalls past the timeout, then finishes after all
+    let kinds = vec![PublishNotification::KIND.to_string()];
+    let stalled = JobRepository::claim(&pool, &kinds, now(), now() - Duration::hours(1)).await.unwrap().unwrap();
+    let config = JobsConfig::default();
+    let worker = jobs::worker(state.clone(), &config);
+    let later = now() + Duration::from_std(config.job_timeout * 2).unwrap() + Duration::seconds(1);
+    assert_eq!(worker.run_once(later).await.unwrap().map(|run| run.id), Some(stalled.id));
+    serde_json::from_value::<PublishNotification>(stalled.payload).unwrap().run(&state).await.unwrap();
+
+    assert_eq!(reminders_received(&pool, "ada").await, 1);
+    assert_eq!(JobRepository::list_queued(&pool, SendEmail::KIND).await.unwrap().len(), 1);
+
+    // Another notification about the same event is one more
+    PublishNotification::new(DomainEvent::EventReminder { event }).run(&state).await.unwrap();
+    assert_eq!(reminders_received(&pool, "ada").await, 2);
+    assert_eq!(JobRepository::list_queued(&pool, SendEmail::KIND).await.unwrap().len(), 2);
+}
+
+#[sqlx::test]
+async fn rescheduling_moves_the_reminders(pool: DbPool) {
+    let state = state(&pool);
+    let event = published_event(&pool, Duration::days(10)).await;
+    schedule_reminders(&state, &event).await;
+
+    let later = set_event(&pool, event.id, event.date + Duration::days(2), "Published").await;
+    schedule_reminders(&state, &later).await;
+    let queued = queued_reminders(&pool).await;
+    let times: Vec<_> = queued.iter().map(|(run_at, _)| *run_at).collect();
+    assert_eq!(times, vec![later.date - Duration::days(7), later.date - Duration::hours(24)]);
+    assert!(queued.iter().all(|(_, reminder)| reminder.event_date == later.date));
+
+    // A reminder for the old date that was already running when the event moved does nothing
+    let worker = jobs::worker(state, &JobsConfig::default());
+    let stale = SendEventReminder { event_id: event.id, minutes_before: 7 * 24 * 60, event_date: event.date };
+    JobRepository::unschedule(&pool, &stale.unique_key().unwrap()).await.unwrap();
+    schedule(&pool, &stale, event.date - Duration::days(7)).await.unwrap();
+    assert_eq!(run_due(&worker, event.date - Duration::days(7)).await, vec!["send_event_reminder"]);
+    assert_eq!(reminders_received(&pool, "ada").await, 0);
+}
+
+#[sqlx::test]
+async fn cancelling_removes_the_reminders(pool: DbPool) {
+    let state = state(&pool);
+    let event = published_event(&pool, Duration::days(10)).await;
+    schedule_reminders(&state, &event).await;
+    assert_eq!(queued_reminders(&pool).await.len(), 2);
+
+    let cancelled = set_event(&pool, event.id, event.date, "Cancelled").await;
+    schedule_reminders(&state, &cancelled).await;
+    assert!(queued_reminders(&pool).await.is_empty());
+
+    // Nor does a reminder that slipped through remind anyone of a cancelled event
+    let worker = jobs::worker(state, &JobsConfig::default());
+    let reminder = SendEventReminder { event_id: event.id, minutes_before: 24 * 60, event_date: event.date };
+    schedule(&pool, &reminder, now()).await.unwrap();
+    assert_eq!(run_due(&worker, now()).await, vec!["send_event_reminder"]);
+    assert_eq!(reminders_received(&pool, "ada").await, 0);
+}
+
+#[sqlx::test]
+async fn reminders_follow_the_configured_times(pool: DbPool) {
+    let event = published_event(&pool, Duration::days(10)).await;
+    schedule_reminders(&state(&pool), &event).await;
+
+    let state = state(&pool).with_reminders(&RemindersConfig { before: vec![std::time::Duration::from_secs(2 * 60 * 60)] });
+    assert_eq!(schedule_upcoming_reminders(&state).await.unwrap(), 1);
+    let times: Vec<_> = queued_reminders(&pool).await.iter().map(|(run_at, reminder)| (*run_at, reminder.minutes_before)).collect();
+    assert_eq!(
+        times,
+        vec![(event.date - Duration::days(7), 7 * 24 * 60), (event.date - Duration::hours(24), 24 * 60), (event.date - Duration::hours(2), 120)]
+    );
+
+    // Reminders queued for times no longer configured are skipped
+    let worker = jobs::worker(state, &JobsConfig::default());
+    assert_eq!(run_due(&worker, event.date - Duration::hours(24)).await, vec!["send_event_reminder", "send_event_reminder"]);
+    assert_eq!(reminders_received(&pool, "ada").await, 0);
+    assert_eq!(run_due(&worker, event.date - Duration::hours(2)).await, vec!["send_event_reminder", "publish_notification", "send_email"]);
+    assert_eq!(reminders_received(&pool, "ada").await, 1);
+}
\ No newline at end of file
@@ -0,0 +1,125 @@
+mod common;
+
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+};
+use backend::database::models::SignupChallenge;
+use backend::handlers::check_signup_challenge;
+use backend::stellar::{signed_message_hash, signup_message, verify_signed_message, SignatureError};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::{Duration, TimeZone, Utc};
+use ed25519_dalek::{Signer, SigningKey};
+use stellar_xdr::curr::{AccountId, PublicKey, Uint256};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn address(key: &SigningKey) -> String {
+    AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(key.verifying_key().to_bytes()))).to_string()
+}
+
+/// Signs the way SEP-53 wallets sign messages.
+fn sign(key: &SigningKey, message: &str) -> String {
+    BASE64.encode(key.sign(&signed_message_hash(message)).to_bytes())
+}
+
+fn challenge_for(wallet_address: &str) -> SignupChallenge {
+    let issued = Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap();
+    SignupChallenge {
+        nonce: Uuid::new_v4(),
+        wallet_address: wallet_address.to_string(),
+        expires_at: issued + Duration::minutes(10),
+        used_at: None,
+        created_at: issued,
+    }
+}
+
+fn signed(key: &SigningKey, challenge: &SignupChallenge) -> String {
+    sign(key, &signup_message(&challenge.wallet_address, &challenge.nonce.to_string()))
+}
+
+#[test]
+fn signatures_are_checked_against_the_key_in_the_address() {
+    let (alice, mallory) = (signing_key(1), signing_key(2));
+    let message = signup_message(&address(&alice), "nonce");
+
+    assert_eq!(verify_signed_message(&address(&alice), &message, &sign(&alice, &message)), Ok(()));
+    assert_eq!(
+        verify_signed_message(&address(&alice), &message, &sign(&mallory, &message)),
+        Err(SignatureError::Mismatch(address(&alice)))
+    );
+    assert_eq!(
+        verify_signed_message(&address(&alice), "another message", &sign(&alice, &message)),
+        Err(SignatureError::Mismatch(address(&alice)))
+    );
+    assert_eq!(
+        verify_signed_message(&address(&alice), &message, "not base64!"),
+        Err(SignatureError::InvalidSignature)
+    );
+    assert_eq!(
+        verify_signed_message("GNOTANADDRESS", &message, &sign(&alice, &message)),
+        Err(SignatureError::InvalidAccount("GNOTANADDRESS".to_string()))
+    );
+}
+
+#[test]
+fn a_signed_unexpired_nonce_proves_the_wallet() {
+    let alice = signing_key(1);
+    let challenge = challenge_for(&address(&alice));
+    let now = challenge.created_at + Duration::minutes(5);
+
+    assert_eq!(check_signup_challenge(&challenge, &address(&alice), &signed(&alice, &challenge), now), Ok(()));
+}
+
+#[test]
+fn a_signature_by_another_wallet_is_refused() {
+    let (alice, mallory) = (signing_key(1), signing_key(2));
+    let challenge = challenge_for(&address(&alice));
+    let now = challenge.created_at;
+
+    let (status, message) =
+        check_signup_challenge(&challenge, &address(&alice), &signed(&mallory, &challenge), now).unwrap_err();
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(message, format!("The signature was not made by {}", address(&alice)));
+
+    // Mallory's own signature does not get her Alice's nonce either
+    let (status, message) =
+        check_signup_challenge(&challenge, &address(&mallory), &signed(&mallory, &challenge), now).unwrap_err();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(message.contains("was issued to"), "{}", message);
+}
+
+#[test]
+fn expired_and_used_nonces_are_refused() {
+    let alice = signing_key(1);
+    let challenge = challenge_for(&address(&alice));
+    let signature = signed(&alice, &challenge);
+
+    let (status, message) =
+        check_signup_challenge(&challenge, &address(&alice), &signature, challenge.expires_at).unwrap_err();
+    assert_eq!(status, StatusCode::GONE);
+    assert_eq!(message, "The signup nonce expired at 12:10:00 UTC; request a new one");
+
+    let used = SignupChallenge { used_at: Some(challenge.created_at), ..challenge.clone() };
+    let (status, message) = check_signup_challenge(&used, &address(&alice), &signature, challenge.created_at).unwrap_err();
+    assert_eq!(status, StatusCode::GONE);
+    assert!(message.contains("already used"), "{}", message);
+}
+
+#[tokio::test]
+async fn challenges_are_only_issued_to_stellar_accounts() {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/v1/signup/challenge")
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(r#"{"wallet_address":"not-a-wallet"}"#))
+        .unwrap();
+    let response = common::test_app().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+mod common;
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{Request, StatusCode},
+    Router,
+};
+use backend::StellarConfig;
+use shared::dto::StellarNetworkResponse;
+use tower::ServiceExt;
+
+async fn network_of(app: Router) -> StellarNetworkResponse {
+    let request = Request::builder().uri("/api/v1/stellar/network").body(Body::empty()).unwrap();
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn network_defaults_to_testnet() {
+    let network = network_of(common::test_app()).await;
+
+    assert_eq!(network.network, "TESTNET");
+    assert_eq!(network.network_passphrase, "Test SDF Network ; September 2015");
+    assert_eq!(network.horizon_url, "https://horizon-testnet.stellar.org");
+}
+
+#[tokio::test]
+async fn network_is_named_after_its_passphrase() {
+    let public = StellarConfig {
+        network_passphrase: "Public Global Stellar Network ; September 2015".to_string(),
+        ..StellarConfig::default()
+    };
+    assert_eq!(network_of(common::test_app_with(public)).await.network, "PUBLIC");
+
+    let standalone = StellarConfig {
+        network_passphrase: "Standalone Network ; February 2017".to_string(),
+        horizon_url: "http://localhost:8000".to_string(),
+        ..StellarConfig::default()
+    };
+    let network = network_of(common::test_app_with(standalone)).await;
+    assert_eq!(network.network, "CUSTOM");
+    assert_eq!(network.horizon_url, "http://localhost:8000");
+}
\ No newline at end of file
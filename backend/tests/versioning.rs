@@ -0,0 +1,147 @@
+mod common;
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Method, Request, StatusCode},
+    routing::get,
+    Router,
+};
+use backend::versioning::{mount, ApiVersion, Deprecation, MountedApi};
+use backend::{api_v1_routes, create_app, AppState, CorsConfig, StellarConfig, VersioningConfig};
+use chrono::{TimeZone, Utc};
+use tower::ServiceExt;
+
+// A body-less POST is rejected by the JSON extractor before any database access
+fn empty_post(uri: &str) -> Request<Body> {
+    Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn v1_routes_are_not_deprecated() {
+    let response = common::test_app().oneshot(empty_post("/api/v1/events")).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert!(response.headers().get("deprecation").is_none());
+    assert!(response.headers().get(header::LINK).is_none());
+}
+
+#[tokio::test]
+async fn legacy_routes_carry_deprecation_headers() {
+    let response = common::test_app().oneshot(empty_post("/api/events")).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    let deprecation = response.headers()["deprecation"].to_str().unwrap();
+    assert!(deprecation.starts_with('@'));
+    assert!(deprecation[1..].parse::<i64>().is_ok());
+    assert_eq!(
+        response.headers()[header::LINK],
+        "</api/v1/events>; rel=\"successor-version\""
+    );
+}
+
+#[tokio::test]
+async fn legacy_signup_points_at_v1_successor() {
+    let response = common::test_app().oneshot(empty_post("/api/signup")).await.unwrap();
+
+    assert_eq!(
+        response.headers()[header::LINK],
+        "</api/v1/signup>; rel=\"successor-version\""
+    );
+}
+
+#[tokio::test]
+async fn health_is_unversioned() {
+    let request = Request::builder().uri("/health").body(Body::empty()).unwrap();
+    let response = common::test_app().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("deprecation").is_none());
+}
+
+#[tokio::test]
+async fn deprecation_headers_are_exposed_to_allowed_origins() {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/events")
+        .header(header::ORIGIN, "http://localhost:8000")
+        .body(Body::empty())
+        .unwrap();
+    let response = common::test_app().oneshot(request).await.unwrap();
+
+    let exposed = response.headers()[header::ACCESS_CONTROL_EXPOSE_HEADERS].to_str().unwrap();
+    assert!(exposed.contains("deprecation"));
+    assert!(exposed.contains("link"));
+}
+
+fn get_request(uri: &str) -> Request<Body> {
+    Request::builder().uri(uri).body(Body::empty()).unwrap()
+}
+
+async fn body_text(app: &Router, uri: &str) -> String {
+    let response = app.clone().oneshot(get_request(uri)).await.unwrap();
+    String::from_utf8(to_bytes(response.into_body(), usize::MAX).await.unwrap().to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn a_new_version_overrides_handlers_next_to_the_old_one() {
+    let v2 = ApiVersion(2);
+    let since = Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap();
+    let v2_routes = api_v1_routes().replace(Method::GET, "/stellar/network", get(|| async { "v2 network" }));
+    let app = mount(vec![
+        MountedApi::new(v2, v2_routes),
+        MountedApi::new(ApiVersion::V1, api_v1_routes()).deprecated(Deprecation { since, sunset: None, successor: v2 }),
+    ])
+    .with_state(AppState::new(common::lazy_pool(), StellarConfig::default()));
+
+    assert_eq!(body_text(&app, "/api/v2/stellar/network").await, "v2 network");
+    assert!(body_text(&app, "/api/v1/stellar/network").await.contains("\"network\":\"TESTNET\""));
+
+    // Routes v2 did not change are served by the v1 handlers
+    let response = app.clone().oneshot(empty_post("/api/v2/events")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert!(response.headers().get("deprecation").is_none());
+
+    let response = app.oneshot(get_request("/api/v1/stellar/network")).await.unwrap();
+    assert_eq!(response.headers()["deprecation"], format!("@{}", since.timestamp()));
+    assert_eq!(response.headers()[header::LINK], "</api/v2/stellar/network>; rel=\"successor-version\"");
+}
+
+#[tokio::test]
+async fn legacy_deprecation_dates_come_from_config() {
+    let versioning = VersioningConfig {
+        legacy_deprecated_at: Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap(),
+        legacy_sunset: Some(Utc.with_ymd_and_hms(2027, 5, 1, 0, 0, 0).unwrap()),
+    };
+    let app = create_app(AppState::new(common::lazy_pool(), StellarConfig::default()), &CorsConfig::default(), &versioning);
+    let response = app.oneshot(empty_post("/api/events")).await.unwrap();
+
+    assert_eq!(response.headers()["deprecation"], format!("@{}", versioning.legacy_deprecated_at.timestamp()));
+    assert_eq!(response.headers()["sunset"], "Sat, 01 May 2027 00:00:00 GMT");
+}
+
+#[test]
+fn legacy_deprecation_dates_are_read_from_the_environment() {
+    std::env::set_var("LEGACY_API_DEPRECATED_AT", "2026-11-01T00:00:00Z");
+    std::env::set_var("LEGACY_API_SUNSET", "2027-05-01T12:00:00+02:00");
+    let config = VersioningConfig::from_env().unwrap();
+    assert_eq!(config.legacy_deprecated_at, Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap());
+    assert_eq!(config.legacy_sunset, Some(Utc.with_ymd_and_hms(2027, 5, 1, 10, 0, 0).unwrap()));
+
+    std::env::set_var("LEGACY_API_SUNSET", "2026-10-01T00:00:00Z");
+    let error = VersioningConfig::from_env().unwrap_err().to_string();
+    assert!(error.contains("LEGACY_API_SUNSET must be after"));
+
+    std::env::set_var("LEGACY_API_SUNSET", "next spring");
+    let error = VersioningConfig::from_env().unwrap_err().to_string();
+    assert!(error.contains("RFC 3339"));
+
+    std::env::remove_var("LEGACY_API_DEPRECATED_AT");
+    std::env::remove_var("LEGACY_API_SUNSET");
+    let config = VersioningConfig::from_env().unwrap();
+    assert_eq!(config.legacy_deprecated_at, VersioningConfig::default().legacy_deprecated_at);
+    assert_eq!(config.legacy_sunset, None);
+}
\ No newline at end of file
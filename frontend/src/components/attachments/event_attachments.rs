@@ -0,0 +1,275 @@
+use yew::prelude::*;
+use gloo::file::ObjectUrl;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use shared::dto::{AttachmentKind, AttachmentListResponse, AttachmentResponse, BudgetItemResponse};
+use crate::services::ApiClient;
+
+#[derive(Properties, PartialEq)]
+pub struct EventAttachmentsProps {
+    pub event_id: String,
+    /// Budget items a receipt can be attached to
+    pub items: Vec<BudgetItemResponse>,
+}
+
+fn kind_icon(kind: AttachmentKind) -> &'static str {
+    match kind {
+        AttachmentKind::Receipt => "🧾",
+        AttachmentKind::Photo => "📷",
+        AttachmentKind::Slides => "📊",
+        AttachmentKind::Document => "📄",
+    }
+}
+
+fn file_size(bytes: u64) -> String {
+    match bytes {
+        bytes if bytes >= 1024 * 1024 => format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0)),
+        bytes if bytes >= 1024 => format!("{} KB", bytes / 1024),
+        bytes => format!("{} B", bytes),
+    }
+}
+
+/// Reads the file chosen in `input` and uploads it.
+async fn upload(
+    event_id: &str,
+    input: &HtmlInputElement,
+    kind: AttachmentKind,
+    budget_item_id: Option<&str>,
+    max_upload_bytes: u64,
+) -> Result<AttachmentListResponse, String> {
+    let file = input
+        .files()
+        .and_then(|files| files.get(0))
+        .map(gloo::file::File::from)
+        .ok_or_else(|| "Choose a file to upload".to_string())?;
+    if file.size() > max_upload_bytes {
+        return Err(format!("{} is larger than the {} allowed", file.name(), file_size(max_upload_bytes)));
+    }
+
+    let bytes = gloo::file::futures::read_as_bytes(&file)
+        .await
+        .map_err(|e| format!("Could not read {}: {}", file.name(), e))?;
+    ApiClient::default()
+        .upload_attachment(event_id, kind, budget_item_id, file.name(), bytes)
+        .await
+        .map_err(|e| e.to_string())?;
+    ApiClient::default().list_attachments(event_id).await.map_err(|e| e.to_string())
+}
+
+#[derive(Properties, PartialEq)]
+struct AttachmentCardProps {
+    event_id: String,
+    attachment: AttachmentResponse,
+    /// The budget item a receipt is for
+    item_label: Option<String>,
+    on_remove: Callback<MouseEvent>,
+}
+
+/// One uploaded file. Receipts are only served with the session, so they are fetched
+/// and opened from memory rather than linked to.
+#[function_component(AttachmentCard)]
+fn attachment_card(props: &AttachmentCardProps) -> Html {
+    let attachment = &props.attachment;
+    let is_receipt = attachment.kind == AttachmentKind::Receipt;
+    let receipt = use_state(|| None::<ObjectUrl>);
+
+    {
+        let receipt = receipt.clone();
+        use_effect_with((props.event_id.clone(), attachment.id.clone(), is_receipt), move |(event_id, attachment_id, is_receipt)| {
+            if *is_receipt {
+                let (event_id, attachment_id) = (event_id.clone(), attachment_id.clone());
+                wasm_bindgen_futures::spawn_local(async move {
+                    match ApiClient::default().attachment_content(&event_id, &attachment_id).await {
+                        Ok(blob) => receipt.set(Some(ObjectUrl::from(blob))),
+                        Err(e) => gloo::console::warn!(format!("Could not load receipt {}: {}", attachment_id, e)),
+                    }
+                });
+            }
+            || ()
+        });
+    }
+
+    let client = ApiClient::default();
+    let (content_url, thumbnail_url) = if is_receipt {
+        let url = receipt.as_ref().map(|url| url.to_string());
+        (url.clone(), url)
+    } else {
+        (
+            Some(client.attachment_content_url(&props.event_id, &attachment.id)),
+            Some(client.attachment_thumbnail_url(&props.event_id, &attachment.id)),
+        )
+    };
+
+    html! {
+        <li class="attachment-card">
+            <a href={content_url.clone()} target="_blank" rel="noopener" class="attachment-preview">
+                if let (true, Some(thumbnail_url)) = (attachment.has_thumbnail, thumbnail_url) {
+                    <img src={thumbnail_url} alt={attachment.file_name.clone()} loading="lazy" />
+                } else {
+                    <span class="attachment-icon">{kind_icon(attachment.kind)}</span>
+                }
+            </a>
+            <a href={content_url} target="_blank" rel="noopener" class="attachment-name">{&attachment.file_name}</a>
+            <small class="member-wallet">
+                {format!("{} · {}", attachment.kind, file_size(attachment.size_bytes))}
+                if let Some(label) = &props.item_label {
+                    {format!(" · {}", label)}
+                }
+            </small>
+            <button class="link-button" onclick={props.on_remove.clone()}>{"Remove"}</button>
+        </li>
+    }
+}
+
+/// Receipts, photos and slides of an event, with a form to upload more.
+#[function_component(EventAttachments)]
+pub fn event_attachments(props: &EventAttachmentsProps) -> Html {
+    let attachments = use_state(|| None::<AttachmentListResponse>);
+    let error = use_state(|| None::<String>);
+    let busy = use_state(|| false);
+    let kind = use_state(|| AttachmentKind::Receipt);
+    let budget_item_id = use_state(String::new);
+    let file_input = use_node_ref();
+
+    {
+        let (attachments, error) = (attachments.clone(), error.clone());
+        use_effect_with(props.event_id.clone(), move |event_id| {
+            let event_id = event_id.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().list_attachments(&event_id).await {
+                    Ok(list) => attachments.set(Some(list)),
+                    Err(e) => error.set(Some(format!("Failed to load files: {}", e))),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_upload = {
+        let event_id = props.event_id.clone();
+        let (attachments, error, busy, kind, budget_item_id, file_input) =
+            (attachments.clone(), error.clone(), busy.clone(), kind.clone(), budget_item_id.clone(), file_input.clone());
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let Some(input) = file_input.cast::<HtmlInputElement>() else {
+                return;
+            };
+            let max_upload_bytes = attachments.as_ref().map(|list| list.max_upload_bytes).unwrap_or(u64::MAX);
+            let item = (*kind == AttachmentKind::Receipt && !budget_item_id.is_empty()).then(|| (*budget_item_id).clone());
+            let (event_id, kind) = (event_id.clone(), *kind);
+            let (attachments, error, busy) = (attachments.clone(), error.clone(), busy.clone());
+            busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match upload(&event_id, &input, kind, item.as_deref(), max_upload_bytes).await {
+                    Ok(list) => {
+                        input.set_value("");
+                        error.set(None);
+                        attachments.set(Some(list));
+                    }
+                    Err(message) => error.set(Some(message)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_remove = {
+        let event_id = props.event_id.clone();
+        let (attachments, error) = (attachments.clone(), error.clone());
+        Callback::from(move |attachment_id: String| {
+            let event_id = event_id.clone();
+            let (attachments, error) = (attachments.clone(), error.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = ApiClient::default();
+                let result = match client.delete_attachment(&event_id, &attachment_id).await {
+                    Ok(()) => client.list_attachments(&event_id).await,
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(list) => {
+                        error.set(None);
+                        attachments.set(Some(list));
+                    }
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+            });
+        })
+    };
+
+    let item_label = |id: &str| {
+        props
+            .items
+            .iter()
+            .find(|item| item.id == id)
+            .map(|item| format!("{} {}", item.category, item.description).trim().to_string())
+    };
+
+    let render_attachment = |attachment: &AttachmentResponse| {
+        let on_remove = {
+            let (on_remove, id) = (on_remove.clone(), attachment.id.clone());
+            Callback::from(move |_: MouseEvent| on_remove.emit(id.clone()))
+        };
+        html! {
+            <AttachmentCard
+                key={attachment.id.clone()}
+                event_id={props.event_id.clone()}
+                attachment={attachment.clone()}
+                item_label={attachment.budget_item_id.as_deref().and_then(item_label)}
+                {on_remove}
+            />
+        }
+    };
+
+    html! {
+        <div class="attachments">
+            if let Some(error) = &*error {
+                <div class="form-error">{error}</div>
+            }
+            {match &*attachments {
+                None => html! { <p class="form-help">{"Loading files..."}</p> },
+                Some(list) if list.attachments.is_empty() => html! { <p class="form-help">{"No files uploaded yet."}</p> },
+                Some(list) => html! {
+                    <ul class="attachment-grid">
+                        {list.attachments.iter().map(render_attachment).collect::<Html>()}
+                    </ul>
+                },
+            }}
+
+            <form class="budget-item-form" onsubmit={on_upload}>
+                <select onchange={{
+                    let kind = kind.clone();
+                    Callback::from(move |e: Event| {
+                        let select: HtmlSelectElement = e.target_unchecked_into();
+                        kind.set(AttachmentKind::from_name(&select.value()).unwrap_or(AttachmentKind::Document));
+                    })
+                }}>
+                    {AttachmentKind::ALL.iter().map(|option| html! {
+                        <option value={option.to_string()} selected={*kind == *option}>{option.to_string()}</option>
+                    }).collect::<Html>()}
+                </select>
+                if *kind == AttachmentKind::Receipt && !props.items.is_empty() {
+                    <select onchange={{
+                        let budget_item_id = budget_item_id.clone();
+                        Callback::from(move |e: Event| {
+                            let select: HtmlSelectElement = e.target_unchecked_into();
+                            budget_item_id.set(select.value());
+                        })
+                    }}>
+                        <option value="" selected={budget_item_id.is_empty()}>{"Whole event"}</option>
+                        {props.items.iter().map(|item| html! {
+                            <option value={item.id.clone()} selected={*budget_item_id == item.id}>
+                                {item_label(&item.id).unwrap_or_default()}
+                            </option>
+                        }).collect::<Html>()}
+                    </select>
+                }
+                <input type="file" ref={file_input} accept="image/jpeg,image/png,image/webp,application/pdf,.pptx,.odp" />
+                <button type="submit" class="primary-button" disabled={*busy}>
+                    {if *busy { "Uploading..." } else { "Upload" }}
+                </button>
+            </form>
+            <small class="form-help">
+                {"Receipts are images or PDFs, photos are JPEG, PNG or WebP, and slides are PDF, PowerPoint or OpenDocument files."}
+            </small>
+        </div>
+    }
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod event_attachments;
+
+pub use event_attachments::*;
\ No newline at end of file
@@ -0,0 +1,53 @@
+use yew::prelude::*;
+use shared::dto::{BudgetRollup, Money};
+
+#[derive(Properties, PartialEq)]
+pub struct BudgetTotalsProps {
+    pub totals: Vec<BudgetRollup>,
+    /// Whether to show what the spending achieved per attendee and SCF referral
+    #[prop_or(true)]
+    pub show_costs: bool,
+}
+
+fn money_or_dash(money: &Option<Money>) -> String {
+    money.map(|money| money.to_string()).unwrap_or_else(|| "—".to_string())
+}
+
+/// Requested, approved and spent totals with one row per currency.
+#[function_component(BudgetTotals)]
+pub fn budget_totals(props: &BudgetTotalsProps) -> Html {
+    if props.totals.is_empty() {
+        return html! { <p class="form-help">{"No budget requested yet."}</p> };
+    }
+
+    html! {
+        <table class="budget-table">
+            <thead>
+                <tr>
+                    <th>{"Currency"}</th>
+                    <th>{"Requested"}</th>
+                    <th>{"Approved"}</th>
+                    <th>{"Spent"}</th>
+                    if props.show_costs {
+                        <th>{"Per attendee"}</th>
+                        <th>{"Per SCF referral"}</th>
+                    }
+                </tr>
+            </thead>
+            <tbody>
+                {props.totals.iter().map(|rollup| html! {
+                    <tr key={rollup.currency.to_string()}>
+                        <td>{rollup.currency.to_string()}</td>
+                        <td>{rollup.requested.to_string()}</td>
+                        <td>{rollup.approved.to_string()}</td>
+                        <td>{rollup.spent.to_string()}</td>
+                        if props.show_costs {
+                            <td title={format!("{} attendees recorded", rollup.attendance)}>{money_or_dash(&rollup.cost_per_attendee)}</td>
+                            <td title={format!("{} referrals recorded", rollup.scf_referrals)}>{money_or_dash(&rollup.cost_per_scf_referral)}</td>
+                        }
+                    </tr>
+                }).collect::<Html>()}
+            </tbody>
+        </table>
+    }
+}
\ No newline at end of file
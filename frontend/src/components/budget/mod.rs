@@ -0,0 +1,3 @@
+pub mod budget_totals;
+
+pub use budget_totals::*;
\ No newline at end of file
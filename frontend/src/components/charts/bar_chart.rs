@@ -0,0 +1,106 @@
+use yew::prelude::*;
+
+/// One coloured set of bars, with a value per chart category.
+#[derive(Clone, PartialEq)]
+pub struct ChartSeries {
+    pub label: String,
+    pub color: String,
+    pub values: Vec<Option<u32>>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct BarChartProps {
+    pub title: String,
+    pub categories: Vec<String>,
+    pub series: Vec<ChartSeries>,
+}
+
+const WIDTH: f64 = 640.0;
+const HEIGHT: f64 = 280.0;
+const LEFT: f64 = 40.0;
+const BOTTOM: f64 = 40.0;
+const TOP: f64 = 10.0;
+const GRID_LINES: u32 = 4;
+
+/// Smallest "round" number (1, 2 or 5 × 10ⁿ per grid step) at or above `max`.
+fn axis_max(max: u32) -> u32 {
+    let step = max.div_ceil(GRID_LINES).max(1);
+    let magnitude = 10u32.pow(step.ilog10());
+    let nice = [1, 2, 5, 10].into_iter().map(|m| m * magnitude).find(|&m| m >= step).unwrap_or(step);
+    nice * GRID_LINES
+}
+
+/// Grouped vertical bar chart drawn as inline SVG, one group per category.
+#[function_component(BarChart)]
+pub fn bar_chart(props: &BarChartProps) -> Html {
+    let max = props.series.iter().flat_map(|series| series.values.iter().flatten()).copied().max().unwrap_or(0);
+    let axis_max = axis_max(max) as f64;
+    let plot_height = HEIGHT - TOP - BOTTOM;
+    let group_width = (WIDTH - LEFT) / props.categories.len().max(1) as f64;
+    let bar_width = group_width * 0.8 / props.series.len().max(1) as f64;
+    let y_of = |value: f64| TOP + plot_height - value / axis_max * plot_height;
+
+    let grid = (0..=GRID_LINES).map(|line| {
+        let value = axis_max * line as f64 / GRID_LINES as f64;
+        let y = y_of(value);
+        html! {
+            <g>
+                <line x1={LEFT.to_string()} x2={WIDTH.to_string()} y1={y.to_string()} y2={y.to_string()} stroke="#333" />
+                <text x={(LEFT - 6.0).to_string()} y={(y + 4.0).to_string()} text-anchor="end" class="chart-axis">
+                    {value.to_string()}
+                </text>
+            </g>
+        }
+    });
+
+    let groups = props.categories.iter().enumerate().map(|(index, category)| {
+        let group_x = LEFT + group_width * index as f64 + group_width * 0.1;
+        let bars = props.series.iter().enumerate().filter_map(|(position, series)| {
+            let value = series.values.get(index).copied().flatten()?;
+            let y = y_of(value as f64);
+            Some(html! {
+                <rect
+                    x={(group_x + bar_width * position as f64).to_string()}
+                    y={y.to_string()}
+                    width={(bar_width - 2.0).max(1.0).to_string()}
+                    height={(TOP + plot_height - y).to_string()}
+                    fill={series.color.clone()}
+                >
+                    <title>{format!("{} – {}: {}", category, series.label, value)}</title>
+                </rect>
+            })
+        });
+
+        html! {
+            <g>
+                {for bars}
+                <text
+                    x={(LEFT + group_width * (index as f64 + 0.5)).to_string()}
+                    y={(HEIGHT - BOTTOM + 18.0).to_string()}
+                    text-anchor="middle"
+                    class="chart-label"
+                >
+                    {category}
+                </text>
+            </g>
+        }
+    });
+
+    html! {
+        <figure class="chart">
+            <figcaption class="chart-title">{&props.title}</figcaption>
+            <svg viewBox={format!("0 0 {} {}", WIDTH, HEIGHT)} role="img" aria-label={props.title.clone()}>
+                {for grid}
+                {for groups}
+            </svg>
+            <div class="chart-legend">
+                {props.series.iter().map(|series| html! {
+                    <span class="chart-legend-item">
+                        <span class="chart-swatch" style={format!("background: {};", series.color)}></span>
+                        {&series.label}
+                    </span>
+                }).collect::<Html>()}
+            </div>
+        </figure>
+    }
+}
\ No newline at end of file
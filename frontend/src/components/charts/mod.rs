@@ -0,0 +1,5 @@
+pub mod bar_chart;
+pub mod progress_bar;
+
+pub use bar_chart::*;
+pub use progress_bar::*;
\ No newline at end of file
@@ -0,0 +1,61 @@
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ProgressBarProps {
+    pub label: String,
+    pub actual: Option<u32>,
+    #[prop_or_default]
+    pub estimated: Option<u32>,
+    pub target: Option<u32>,
+}
+
+/// Inline SVG bar of `actual` against `target`, with the planned estimate
+/// drawn behind it. Bars past the target are capped at full width.
+#[function_component(ProgressBar)]
+pub fn progress_bar(props: &ProgressBarProps) -> Html {
+    const WIDTH: f64 = 400.0;
+    const HEIGHT: f64 = 16.0;
+
+    let actual = props.actual.unwrap_or(0);
+    let width_of = |value: u32| -> f64 {
+        match props.target {
+            Some(target) if target > 0 => (value as f64 / target as f64).min(1.0) * WIDTH,
+            _ => 0.0,
+        }
+    };
+
+    let summary = match props.target {
+        Some(target) if target > 0 => format!("{} / {} ({}%)", actual, target, actual as u64 * 100 / target as u64),
+        _ => format!("{} (no target)", actual),
+    };
+    let reached = props.target.is_some_and(|target| actual >= target);
+
+    html! {
+        <div class="progress">
+            <div class="progress-label">
+                <span>{&props.label}</span>
+                <span class="progress-summary">{summary}</span>
+            </div>
+            <svg
+                class="progress-bar"
+                viewBox={format!("0 0 {} {}", WIDTH, HEIGHT)}
+                preserveAspectRatio="none"
+                role="img"
+                aria-label={format!("{}: {}", props.label, actual)}
+            >
+                <rect width={WIDTH.to_string()} height={HEIGHT.to_string()} rx="4" fill="#2a2a2a" />
+                if let Some(estimated) = props.estimated {
+                    <rect width={width_of(estimated).to_string()} height={HEIGHT.to_string()} rx="4" fill="#00d4ff" fill-opacity="0.25">
+                        <title>{format!("Estimated: {}", estimated)}</title>
+                    </rect>
+                }
+                <rect
+                    width={width_of(actual).to_string()}
+                    height={HEIGHT.to_string()}
+                    rx="4"
+                    fill={if reached { "#10b981" } else { "#00d4ff" }}
+                />
+            </svg>
+        </div>
+    }
+}
\ No newline at end of file
@@ -0,0 +1,138 @@
+use yew::prelude::*;
+use web_sys::HtmlInputElement;
+use shared::dto::ChapterRequest;
+use crate::services::ApiError;
+
+#[derive(Properties, PartialEq)]
+pub struct ChapterFormProps {
+    /// Values to start from when editing an existing chapter.
+    #[prop_or_default]
+    pub initial: Option<ChapterRequest>,
+    pub submit_label: String,
+    pub on_submit: Callback<ChapterRequest>,
+    #[prop_or_default]
+    pub on_cancel: Option<Callback<()>>,
+    /// Error from the last submission; validation errors are shown next to their field.
+    #[prop_or_default]
+    pub error: Option<ApiError>,
+    #[prop_or_default]
+    pub busy: bool,
+}
+
+#[function_component(ChapterForm)]
+pub fn chapter_form(props: &ChapterFormProps) -> Html {
+    let initial = props.initial.clone();
+    let name = use_state(|| initial.as_ref().map(|c| c.name.clone()).unwrap_or_default());
+    let country = use_state(|| initial.as_ref().map(|c| c.country.clone()).unwrap_or_default());
+    let city = use_state(|| initial.as_ref().map(|c| c.city.clone()).unwrap_or_default());
+    let lead_wallet_address = use_state(|| {
+        initial.as_ref().and_then(|c| c.lead_wallet_address.clone()).unwrap_or_default()
+    });
+
+    let on_input = |field: UseStateHandle<String>| {
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            field.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let (name, country, city, lead_wallet_address) =
+            (name.clone(), country.clone(), city.clone(), lead_wallet_address.clone());
+        let on_submit = props.on_submit.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let lead = lead_wallet_address.trim().to_string();
+            on_submit.emit(ChapterRequest {
+                name: name.trim().to_string(),
+                country: country.trim().to_string(),
+                city: city.trim().to_string(),
+                lead_wallet_address: if lead.is_empty() { None } else { Some(lead) },
+            });
+        })
+    };
+
+    let field_error = |field: &str| -> Html {
+        match props.error.as_ref().and_then(|error| error.field_error(field)) {
+            Some(message) => html! { <small class="field-error">{message}</small> },
+            None => html! {},
+        }
+    };
+
+    // Validation errors are shown next to their fields instead
+    let general_error = match &props.error {
+        Some(ApiError::Validation { .. }) | None => html! {},
+        Some(error) => html! { <div class="form-error">{error.to_string()}</div> },
+    };
+
+    html! {
+        <form class="chapter-form" onsubmit={on_submit}>
+            {general_error}
+
+            <div class="form-group">
+                <label for="chapter-name">{"Chapter Name *"}</label>
+                <input
+                    type="text"
+                    id="chapter-name"
+                    value={(*name).clone()}
+                    onchange={on_input(name.clone())}
+                    placeholder="e.g. Stellar Berlin"
+                    required=true
+                />
+                {field_error("name")}
+            </div>
+
+            <div class="form-row">
+                <div class="form-group">
+                    <label for="chapter-city">{"City *"}</label>
+                    <input
+                        type="text"
+                        id="chapter-city"
+                        value={(*city).clone()}
+                        onchange={on_input(city.clone())}
+                        placeholder="Berlin"
+                        required=true
+                    />
+                    {field_error("city")}
+                </div>
+
+                <div class="form-group">
+                    <label for="chapter-country">{"Country *"}</label>
+                    <input
+                        type="text"
+                        id="chapter-country"
+                        value={(*country).clone()}
+                        onchange={on_input(country.clone())}
+                        placeholder="Germany"
+                        required=true
+                    />
+                    {field_error("country")}
+                </div>
+            </div>
+
+            <div class="form-group">
+                <label for="chapter-lead">{"Chapter Lead Wallet Address"}</label>
+                <input
+                    type="text"
+                    id="chapter-lead"
+                    value={(*lead_wallet_address).clone()}
+                    onchange={on_input(lead_wallet_address.clone())}
+                    placeholder="G..."
+                />
+                <small class="form-help">{"The Stellar address the lead signed up with. They must be registered as a Chapter Lead."}</small>
+                {field_error("lead_wallet_address")}
+            </div>
+
+            <div class="form-actions">
+                if let Some(on_cancel) = props.on_cancel.clone() {
+                    <button type="button" class="secondary-button" onclick={move |_| on_cancel.emit(())}>
+                        {"Cancel"}
+                    </button>
+                }
+                <button type="submit" class="primary-button" disabled={props.busy}>
+                    {if props.busy { "Saving..." } else { props.submit_label.as_str() }}
+                </button>
+            </div>
+        </form>
+    }
+}
\ No newline at end of file
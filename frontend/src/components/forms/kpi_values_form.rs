@@ -0,0 +1,111 @@
+use yew::prelude::*;
+use web_sys::HtmlInputElement;
+use shared::dto::KpiValues;
+use crate::services::ApiError;
+
+/// Display names of the tracked KPIs, in the order of [`kpi_array`].
+pub const KPI_LABELS: [&str; 6] = [
+    "Monthly Active Ambassadors",
+    "Monthly Active Accounts",
+    "SCF Referrals",
+    "Content Produced",
+    "Attendance",
+    "Social Growth",
+];
+
+pub fn kpi_array(values: &KpiValues) -> [Option<u32>; 6] {
+    [
+        values.monthly_active_ambassadors,
+        values.monthly_active_accounts,
+        values.scf_referrals,
+        values.content_produced,
+        values.attendance,
+        values.social_growth,
+    ]
+}
+
+fn kpi_values(array: [Option<u32>; 6]) -> KpiValues {
+    let [monthly_active_ambassadors, monthly_active_accounts, scf_referrals, content_produced, attendance, social_growth] = array;
+    KpiValues {
+        monthly_active_ambassadors,
+        monthly_active_accounts,
+        scf_referrals,
+        content_produced,
+        attendance,
+        social_growth,
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct KpiValuesFormProps {
+    #[prop_or_default]
+    pub initial: KpiValues,
+    pub submit_label: String,
+    pub on_submit: Callback<KpiValues>,
+    #[prop_or_default]
+    pub on_cancel: Option<Callback<()>>,
+    #[prop_or_default]
+    pub error: Option<ApiError>,
+    #[prop_or_default]
+    pub busy: bool,
+}
+
+/// Number inputs for each KPI; left-empty fields are submitted as `None`.
+#[function_component(KpiValuesForm)]
+pub fn kpi_values_form(props: &KpiValuesFormProps) -> Html {
+    let initial = props.initial.clone();
+    let values = use_state(|| kpi_array(&initial).map(|value| value.map(|v| v.to_string()).unwrap_or_default()));
+
+    let on_input = |index: usize| {
+        let values = values.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut updated = (*values).clone();
+            updated[index] = input.value();
+            values.set(updated);
+        })
+    };
+
+    let on_submit = {
+        let values = values.clone();
+        let on_submit = props.on_submit.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            on_submit.emit(kpi_values((*values).clone().map(|value| value.trim().parse::<u32>().ok())));
+        })
+    };
+
+    html! {
+        <form class="kpi-form" onsubmit={on_submit}>
+            if let Some(error) = &props.error {
+                <div class="form-error">{error.to_string()}</div>
+            }
+
+            <div class="kpi-form-grid">
+                {KPI_LABELS.iter().enumerate().map(|(index, label)| html! {
+                    <div class="form-group">
+                        <label>{*label}</label>
+                        <input
+                            type="number"
+                            min="0"
+                            value={values[index].clone()}
+                            onchange={on_input(index)}
+                            placeholder="—"
+                        />
+                    </div>
+                }).collect::<Html>()}
+            </div>
+
+            <div class="form-actions">
+                if let Some(on_cancel) = props.on_cancel.clone() {
+                    <button type="button" class="secondary-button" onclick={move |_| on_cancel.emit(())}>
+                        {"Cancel"}
+                    </button>
+                }
+                <button type="submit" class="primary-button" disabled={props.busy}>
+                    {if props.busy { "Saving..." } else { props.submit_label.as_str() }}
+                </button>
+            </div>
+        </form>
+    }
+}
\ No newline at end of file
@@ -1,5 +1,9 @@
 pub mod wallet_input;
 pub mod form_section;
+pub mod chapter_form;
+pub mod kpi_values_form;
 
 pub use wallet_input::*;
-pub use form_section::*;
\ No newline at end of file
+pub use form_section::*;
+pub use chapter_form::*;
+pub use kpi_values_form::*;
\ No newline at end of file
@@ -1,6 +1,7 @@
 use yew::prelude::*;
-use crate::components::ui::{Input, Button, ButtonVariant};
-use crate::hooks::{use_freighter, FreighterStatus};
+use web_sys::HtmlInputElement;
+use crate::components::ui::{Input, Button, ButtonVariant, Select, SelectOption};
+use crate::hooks::{use_wallet, WalletList, WalletStatus};
 
 #[derive(Properties, PartialEq)]
 pub struct WalletInputProps {
@@ -14,76 +15,143 @@ pub struct WalletInputProps {
     pub required: bool,
     #[prop_or_default]
     pub help_text: Option<String>,
+    /// Wallets offered in the picker; every supported wallet by default
+    #[prop_or_default]
+    pub wallets: WalletList,
 }
 
 #[function_component(WalletInput)]
 pub fn wallet_input(props: &WalletInputProps) -> Html {
-    let freighter = use_freighter();
+    let wallet = use_wallet(props.wallets.clone());
+    // Before one is installed, offer to install the picked wallet or else the first one
+    let shown_wallet = wallet.selected_wallet().or_else(|| wallet.wallets.0.first().cloned());
+    let wallet_name = shown_wallet.as_ref().map(|shown| shown.name()).unwrap_or("Wallet");
 
-    // Auto-fill wallet address when Freighter connects
+    // Auto-fill wallet address when the wallet connects
     {
         let on_change = props.on_change.clone();
-        let public_key = freighter.get_public_key();
-        use_effect_with_deps(move |public_key| {
+        let public_key = wallet.get_public_key();
+        use_effect_with(public_key, move |public_key| {
             if let Some(key) = public_key {
                 on_change.emit(key.clone());
             }
             || {}
-        }, public_key);
+        });
     }
 
-    let connect_freighter = {
-        let connect = freighter.connect.clone();
-        Callback::from(move |_| {
-            connect.emit(());
+    let on_button_click = {
+        let connect = wallet.connect.clone();
+        let install_url = shown_wallet.as_ref().map(|shown| shown.install_url());
+        let not_installed = matches!(*wallet.status, WalletStatus::NotInstalled);
+        Callback::from(move |_| match install_url.filter(|_| not_installed) {
+            Some(url) => {
+                let _ = gloo::utils::window().open_with_url_and_target(url, "_blank");
+            }
+            None => connect.emit(()),
+        })
+    };
+
+    let on_disconnect = {
+        let disconnect = wallet.disconnect.clone();
+        Callback::from(move |_| disconnect.emit(()))
+    };
+
+    let on_remember_change = {
+        let set_remember = wallet.set_remember.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            set_remember.emit(input.checked());
         })
     };
 
-    let button_text = match &*freighter.status {
-        FreighterStatus::NotInstalled => "❌ Install Freighter",
-        FreighterStatus::Disconnected => "🚀 Connect Freighter",
-        FreighterStatus::Connecting => "⏳ Connecting...",
-        FreighterStatus::Connected(_) => "✅ Connected",
-        FreighterStatus::Error(_) => "❌ Connection Failed",
+    let button_text = match &*wallet.status {
+        WalletStatus::Detecting => "⏳ Looking for wallets...".to_string(),
+        WalletStatus::NotInstalled => format!("⬇️ Install {}", wallet_name),
+        WalletStatus::Disconnected => format!("🚀 Connect {}", wallet_name),
+        WalletStatus::Connecting => "⏳ Connecting...".to_string(),
+        WalletStatus::Connected(_) if wallet.network_warning().is_some() => "⚠️ Wrong Network".to_string(),
+        WalletStatus::Connected(_) => "✅ Connected".to_string(),
+        WalletStatus::Error(_) => format!("🔄 Retry {}", wallet_name),
     };
 
-    let help_text = match &*freighter.status {
-        FreighterStatus::Connected(_) => Some("✅ Wallet connected via Freighter extension".to_string()),
-        FreighterStatus::Error(error) => Some(format!("❌ {}", error)),
+    let help_text = match &*wallet.status {
+        WalletStatus::Connected(_) => match wallet.network_warning() {
+            Some(warning) => Some(format!("⚠️ {}", warning)),
+            None => Some(format!("✅ Wallet connected via {}", wallet_name)),
+        },
+        WalletStatus::Error(error) => Some(format!("❌ {}", error)),
+        WalletStatus::NotInstalled => Some(format!("No Stellar wallet found - install {} or enter your key manually", wallet_name)),
         _ => props.help_text.clone().or_else(|| {
-            Some("Your Stellar public key - use Freighter extension or enter manually".to_string())
+            Some("Your Stellar public key - connect a wallet or enter manually".to_string())
         }),
     };
 
-    let button_variant = match &*freighter.status {
-        FreighterStatus::Connected(_) => ButtonVariant::Success,
-        FreighterStatus::Error(_) => ButtonVariant::Error,
+    let button_variant = match &*wallet.status {
+        WalletStatus::Connected(_) if wallet.network_warning().is_some() => ButtonVariant::Error,
+        WalletStatus::Connected(_) => ButtonVariant::Success,
+        WalletStatus::Error(_) => ButtonVariant::Error,
         _ => ButtonVariant::Secondary,
     };
 
+    let wallet_options: Vec<SelectOption> = wallet.wallets.0.iter().map(|offered| {
+        if offered.is_available() {
+            SelectOption::new(offered.id(), offered.name())
+        } else {
+            SelectOption::new(offered.id(), format!("{} (not installed)", offered.name())).disabled()
+        }
+    }).collect();
+
     html! {
         <div class="wallet-input-container">
             <Input
                 label={props.label.clone()}
                 value={props.value.clone()}
                 on_change={props.on_change.clone()}
-                placeholder="GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+                placeholder="GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
                 required={props.required}
                 error={props.error.clone()}
                 help_text={help_text}
-                class={freighter.is_connected().then(|| "wallet-input--connected".to_string())}
+                class={wallet.is_connected().then(|| "wallet-input--connected".to_string())}
             />
 
             <div class="wallet-input-actions">
+                if wallet_options.len() > 1 {
+                    <Select
+                        id="wallet-picker"
+                        value={(*wallet.selected).clone().unwrap_or_default()}
+                        on_change={wallet.select.clone()}
+                        options={wallet_options}
+                        placeholder="Choose a wallet"
+                        disabled={wallet.is_connecting()}
+                        class="wallet-picker"
+                    />
+                }
                 <Button
                     variant={button_variant}
-                    onclick={connect_freighter}
-                    disabled={freighter.is_connected() || freighter.is_connecting()}
-                    loading={freighter.is_connecting()}
+                    onclick={on_button_click}
+                    disabled={wallet.is_connected() || wallet.is_connecting() || wallet.is_detecting()}
+                    loading={wallet.is_connecting()}
                 >
                     {button_text}
                 </Button>
+                if wallet.is_connected() {
+                    <Button variant={ButtonVariant::Ghost} onclick={on_disconnect} class="wallet-disconnect">
+                        {"Disconnect"}
+                    </Button>
+                }
             </div>
+
+            if !matches!(*wallet.status, WalletStatus::Detecting | WalletStatus::NotInstalled) {
+                <label class="checkbox-label wallet-remember">
+                    <input
+                        type="checkbox"
+                        checked={*wallet.remember}
+                        onchange={on_remember_change}
+                    />
+                    <span class="checkmark"></span>
+                    {"Remember connection"}
+                </label>
+            }
         </div>
     }
 }
\ No newline at end of file
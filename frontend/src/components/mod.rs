@@ -1,7 +1,13 @@
 pub mod navbar;
 pub mod ui;
 pub mod forms;
+pub mod charts;
+pub mod budget;
+pub mod attachments;
 
 pub use navbar::*;
 pub use ui::*;
-pub use forms::*;
\ No newline at end of file
+pub use forms::*;
+pub use charts::*;
+pub use budget::*;
+pub use attachments::*;
\ No newline at end of file
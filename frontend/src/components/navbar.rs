@@ -24,6 +24,18 @@ pub fn navbar() -> Html {
                         <Link<Route> to={Route::EventOutput} classes="nav-link">
                             {"Events"}
                         </Link<Route>>
+                        <Link<Route> to={Route::Chapters} classes="nav-link">
+                            {"Chapters"}
+                        </Link<Route>>
+                        <Link<Route> to={Route::Plans} classes="nav-link">
+                            {"Plans"}
+                        </Link<Route>>
+                        <Link<Route> to={Route::Profile} classes="nav-link">
+                            {"Profile"}
+                        </Link<Route>>
+                        <Link<Route> to={Route::Notifications} classes="nav-link">
+                            {"Notifications"}
+                        </Link<Route>>
                     </div>
                 </div>
 
@@ -1,5 +1,5 @@
 pub mod use_form;
-pub mod use_freighter;
+pub mod use_wallet;
 
 pub use use_form::*;
-pub use use_freighter::*;
\ No newline at end of file
+pub use use_wallet::*;
\ No newline at end of file
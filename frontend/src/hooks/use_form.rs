@@ -116,8 +116,9 @@ impl FormHandle {
     }
 }
 
+#[hook]
 pub fn use_form() -> FormHandle {
-    let fields = use_state(|| HashMap::new());
+    let fields = use_state(HashMap::new);
     let validators = Rc::new(HashMap::new());
 
     FormHandle {
@@ -126,6 +127,7 @@ pub fn use_form() -> FormHandle {
     }
 }
 
+#[hook]
 pub fn use_form_with_validators(
     validators: HashMap<String, Box<dyn Fn(&str) -> Option<String>>>
 ) -> FormHandle {
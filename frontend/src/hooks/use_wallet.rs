@@ -0,0 +1,277 @@
+use std::rc::Rc;
+use yew::prelude::*;
+use gloo::timers::callback::Interval;
+use gloo::timers::future::TimeoutFuture;
+use shared::dto::StellarNetworkResponse;
+use crate::services::ApiClient;
+use crate::wallet::{
+    connect_wallet, network_mismatch, pick_wallet, remember_wallet, remembered_wallet, remembers_connection,
+    restore_connection, set_remember_connection, supported_wallets, WalletNetwork, WalletRef,
+};
+
+/// How often a connected wallet is asked whether its account or network changed.
+/// Wallets do not emit events for either, so Freighter's own watcher polls too.
+const WATCH_INTERVAL_MS: u32 = 3_000;
+/// When to look for wallets after mounting. Extensions may put their API on the page
+/// after the app has started.
+const DETECTION_DELAYS_MS: [u32; 3] = [0, 500, 1_500];
+
+#[derive(Clone, PartialEq)]
+pub enum WalletStatus {
+    /// Looking for installed wallets
+    Detecting,
+    NotInstalled,
+    Disconnected,
+    Connecting,
+    Connected(String), // public key
+    Error(String),
+}
+
+/// The wallets to offer. Compared by identity, so a list can be passed down as a prop.
+#[derive(Clone)]
+pub struct WalletList(pub Rc<Vec<WalletRef>>);
+
+impl WalletList {
+    pub fn new(wallets: Vec<WalletRef>) -> Self {
+        Self(Rc::new(wallets))
+    }
+
+    pub fn find(&self, id: &str) -> Option<WalletRef> {
+        self.0.iter().find(|wallet| wallet.id() == id).cloned()
+    }
+}
+
+impl Default for WalletList {
+    fn default() -> Self {
+        Self::new(supported_wallets())
+    }
+}
+
+impl PartialEq for WalletList {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[derive(Clone)]
+pub struct WalletHandle {
+    pub wallets: WalletList,
+    /// Id of the wallet picked to connect with
+    pub selected: UseStateHandle<Option<String>>,
+    pub status: UseStateHandle<WalletStatus>,
+    /// Network the connected wallet is on, if it says
+    pub network: UseStateHandle<Option<WalletNetwork>>,
+    /// Network the backend builds transactions for, once it has answered
+    pub expected_network: UseStateHandle<Option<StellarNetworkResponse>>,
+    /// Whether to reconnect on the next visit, kept in local storage
+    pub remember: UseStateHandle<bool>,
+    pub select: Callback<String>,
+    pub connect: Callback<()>,
+    pub disconnect: Callback<()>,
+    pub set_remember: Callback<bool>,
+}
+
+impl WalletHandle {
+    pub fn is_connected(&self) -> bool {
+        matches!(*self.status, WalletStatus::Connected(_))
+    }
+
+    pub fn is_connecting(&self) -> bool {
+        matches!(*self.status, WalletStatus::Connecting)
+    }
+
+    pub fn is_detecting(&self) -> bool {
+        matches!(*self.status, WalletStatus::Detecting)
+    }
+
+    pub fn get_public_key(&self) -> Option<String> {
+        match &*self.status {
+            WalletStatus::Connected(key) => Some(key.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_error(&self) -> Option<String> {
+        match &*self.status {
+            WalletStatus::Error(error) => Some(error.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_network(&self) -> Option<WalletNetwork> {
+        (*self.network).clone()
+    }
+
+    pub fn selected_wallet(&self) -> Option<WalletRef> {
+        self.wallets.find(self.selected.as_deref()?)
+    }
+
+    /// What to tell the user when the connected wallet is on another network than the backend.
+    pub fn network_warning(&self) -> Option<String> {
+        network_mismatch(self.network.as_ref()?, self.expected_network.as_ref()?)
+    }
+}
+
+/// The wallet to start with once one is installed, looking again while extensions load.
+async fn detect_wallet(wallets: &WalletList) -> Option<WalletRef> {
+    for delay in DETECTION_DELAYS_MS {
+        TimeoutFuture::new(delay).await;
+        if let Some(wallet) = pick_wallet(&wallets.0, remembered_wallet().as_deref()) {
+            if wallet.is_installed().await {
+                return Some(wallet);
+            }
+        }
+    }
+    None
+}
+
+/// Connects to one of `wallets`, starting with the one the user picked last time. Detects
+/// installed wallets on mount and restores a remembered connection without prompting.
+#[hook]
+pub fn use_wallet(wallets: WalletList) -> WalletHandle {
+    let wallets = (*use_state(|| wallets)).clone();
+    let selected = {
+        let wallets = wallets.clone();
+        use_state_eq(move || pick_wallet(&wallets.0, remembered_wallet().as_deref()).map(|wallet| wallet.id().to_string()))
+    };
+    let status = use_state_eq(|| WalletStatus::Detecting);
+    let network = use_state_eq(|| None::<WalletNetwork>);
+    let expected_network = use_state_eq(|| None::<StellarNetworkResponse>);
+    let remember = use_state_eq(remembers_connection);
+
+    {
+        let (wallets, selected, status, network) = (wallets.clone(), selected.clone(), status.clone(), network.clone());
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let Some(wallet) = detect_wallet(&wallets).await else {
+                    status.set(WalletStatus::NotInstalled);
+                    return;
+                };
+                selected.set(Some(wallet.id().to_string()));
+
+                let restored = match remembers_connection() {
+                    true => restore_connection(wallet.as_ref()).await,
+                    false => None,
+                };
+                match restored {
+                    Some((public_key, wallet_network)) => {
+                        status.set(WalletStatus::Connected(public_key));
+                        network.set(wallet_network);
+                    }
+                    None => status.set(WalletStatus::Disconnected),
+                }
+            });
+            || ()
+        });
+    }
+
+    {
+        let expected_network = expected_network.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Ok(backend_network) = ApiClient::default().get_stellar_network().await {
+                    expected_network.set(Some(backend_network));
+                }
+            });
+            || ()
+        });
+    }
+
+    let select = {
+        let (selected, status, network) = (selected.clone(), status.clone(), network.clone());
+        use_callback((), move |id: String, _| {
+            remember_wallet(&id);
+            selected.set(Some(id));
+            status.set(WalletStatus::Disconnected);
+            network.set(None);
+        })
+    };
+
+    let connect = {
+        let (wallets, status, network) = (wallets.clone(), status.clone(), network.clone());
+        use_callback(selected.clone(), move |_: (), selected| {
+            let Some(wallet) = selected.as_deref().and_then(|id| wallets.find(id)) else {
+                status.set(WalletStatus::NotInstalled);
+                return;
+            };
+            let (status, network) = (status.clone(), network.clone());
+            status.set(WalletStatus::Connecting);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match connect_wallet(wallet.as_ref()).await {
+                    Ok((public_key, wallet_network)) => {
+                        remember_wallet(wallet.id());
+                        status.set(WalletStatus::Connected(public_key));
+                        network.set(wallet_network);
+                    }
+                    Err(error) => {
+                        status.set(WalletStatus::Error(error));
+                    }
+                }
+            });
+        })
+    };
+
+    // Disconnecting on purpose also stops reconnecting on the next visit
+    let disconnect = {
+        let (status, network, remember) = (status.clone(), network.clone(), remember.clone());
+        use_callback((), move |_: (), _| {
+            set_remember_connection(false);
+            remember.set(false);
+            status.set(WalletStatus::Disconnected);
+            network.set(None);
+        })
+    };
+
+    let set_remember = {
+        let remember = remember.clone();
+        use_callback((), move |value: bool, _| {
+            set_remember_connection(value);
+            remember.set(value);
+        })
+    };
+
+    // Follow account and network switches in the wallet while connected
+    {
+        let (wallets, status, network) = (wallets.clone(), status.clone(), network.clone());
+        let watched = matches!(*status, WalletStatus::Connected(_)).then(|| (*selected).clone()).flatten();
+        use_effect_with(watched, move |watched| {
+            let watcher = watched.as_deref().and_then(|id| wallets.find(id)).map(|wallet| {
+                Interval::new(WATCH_INTERVAL_MS, move || {
+                    let (wallet, status, network) = (wallet.clone(), status.clone(), network.clone());
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let current = match wallet.public_key().await {
+                            Ok(public_key) => wallet.network().await.map(|wallet_network| (public_key, wallet_network)),
+                            Err(error) => Err(error),
+                        };
+                        match current {
+                            Ok((public_key, wallet_network)) => {
+                                status.set(WalletStatus::Connected(public_key));
+                                network.set(wallet_network);
+                            }
+                            // Locked, or access for this site was revoked
+                            Err(_) => {
+                                status.set(WalletStatus::Disconnected);
+                                network.set(None);
+                            }
+                        }
+                    });
+                })
+            });
+            move || drop(watcher)
+        });
+    }
+
+    WalletHandle {
+        wallets,
+        selected,
+        status,
+        network,
+        expected_network,
+        remember,
+        select,
+        connect,
+        disconnect,
+        set_remember,
+    }
+}
\ No newline at end of file
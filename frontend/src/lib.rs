@@ -4,6 +4,7 @@ pub mod routing;
 pub mod services;
 pub mod utils;
 pub mod hooks;
+pub mod wallet;
 
 use wasm_bindgen::prelude::*;
 use yew::prelude::*;
@@ -0,0 +1,312 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use shared::dto::{ChapterDashboardResponse, EventActualsRequest, EventResponse, KpiValues};
+use crate::components::{kpi_array, BarChart, BudgetTotals, ChartSeries, KpiValuesForm, ProgressBar, KPI_LABELS};
+use crate::routing::Route;
+use crate::services::{ApiClient, ApiError};
+use crate::utils::format_in_zone;
+
+const MONTH_NAMES: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+#[derive(PartialEq, Clone)]
+pub enum ChapterDashboardState {
+    Loading,
+    Loaded(Box<ChapterDashboardResponse>),
+    Error(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ChapterDashboardPageProps {
+    pub id: String,
+}
+
+/// The quarter `offset` quarters away from `year`/`quarter`.
+fn shift_quarter(year: i32, quarter: u32, offset: i32) -> (i32, u32) {
+    let index = year * 4 + quarter as i32 - 1 + offset;
+    (index.div_euclid(4), index.rem_euclid(4) as u32 + 1)
+}
+
+#[function_component(ChapterDashboardPage)]
+pub fn chapter_dashboard_page(props: &ChapterDashboardPageProps) -> Html {
+    let state = use_state(|| ChapterDashboardState::Loading);
+    // `None` until the user picks a quarter, so the server's current quarter is shown first
+    let period = use_state(|| None::<(i32, u32)>);
+    let editing_targets = use_state(|| false);
+    let editing_event = use_state(|| None::<String>);
+    let saving = use_state(|| false);
+    let form_error = use_state(|| None::<ApiError>);
+
+    let reload = {
+        let state = state.clone();
+        let id = props.id.clone();
+        let period = *period;
+        Callback::from(move |_: ()| {
+            let state = state.clone();
+            let id = id.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().chapter_dashboard(&id, period).await {
+                    Ok(dashboard) => state.set(ChapterDashboardState::Loaded(Box::new(dashboard))),
+                    Err(e) => state.set(ChapterDashboardState::Error(format!("Failed to load dashboard: {}", e))),
+                }
+            });
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with((props.id.clone(), *period), move |_| {
+            reload.emit(());
+            || ()
+        });
+    }
+
+    let on_toggle_targets = {
+        let (editing_targets, form_error) = (editing_targets.clone(), form_error.clone());
+        Callback::from(move |_: ()| {
+            form_error.set(None);
+            editing_targets.set(!*editing_targets);
+        })
+    };
+
+    let on_edit_event = {
+        let (editing_event, form_error) = (editing_event.clone(), form_error.clone());
+        Callback::from(move |event_id: Option<String>| {
+            form_error.set(None);
+            editing_event.set(event_id);
+        })
+    };
+
+    let content = match &*state {
+        ChapterDashboardState::Loading => html! {
+            <div class="loading-container">
+                <div class="spinner"></div>
+                <h2>{"Loading dashboard..."}</h2>
+            </div>
+        },
+        ChapterDashboardState::Error(error) => html! {
+            <div class="error-container">
+                <div class="error-icon">{"⚠️"}</div>
+                <h2>{"Dashboard Unavailable"}</h2>
+                <p>{error}</p>
+                <Link<Route> to={Route::ChapterDetail { id: props.id.clone() }} classes="primary-button">
+                    {"Back to Chapter"}
+                </Link<Route>>
+            </div>
+        },
+        ChapterDashboardState::Loaded(dashboard) => {
+            let (year, quarter) = (dashboard.year, dashboard.quarter);
+            let go_to = |offset: i32| {
+                let period = period.clone();
+                Callback::from(move |_: MouseEvent| period.set(Some(shift_quarter(year, quarter, offset))))
+            };
+
+            let on_save_targets = {
+                let id = props.id.clone();
+                let (editing_targets, saving, form_error, reload) =
+                    (editing_targets.clone(), saving.clone(), form_error.clone(), reload.clone());
+                Callback::from(move |targets: KpiValues| {
+                    let id = id.clone();
+                    let (editing_targets, saving, form_error, reload) =
+                        (editing_targets.clone(), saving.clone(), form_error.clone(), reload.clone());
+                    saving.set(true);
+                    wasm_bindgen_futures::spawn_local(async move {
+                        match ApiClient::default().update_chapter_targets(&id, year, quarter, &targets).await {
+                            Ok(_) => {
+                                editing_targets.set(false);
+                                reload.emit(());
+                            }
+                            Err(e) => form_error.set(Some(e)),
+                        }
+                        saving.set(false);
+                    });
+                })
+            };
+
+            let estimated = kpi_array(&dashboard.estimated);
+            let actual = kpi_array(&dashboard.actual);
+            let targets = kpi_array(&dashboard.targets);
+            let month_names: Vec<String> = dashboard.months.iter()
+                .map(|month| format!("{} {}", MONTH_NAMES[(month.month - 1) as usize], month.year))
+                .collect();
+
+            let totals_series = vec![
+                ChartSeries { label: "Estimated".to_string(), color: "#0099cc".to_string(), values: estimated.to_vec() },
+                ChartSeries { label: "Actual".to_string(), color: "#10b981".to_string(), values: actual.to_vec() },
+                ChartSeries { label: "Target".to_string(), color: "#ff6b35".to_string(), values: targets.to_vec() },
+            ];
+            let events_series = vec![ChartSeries {
+                label: "Events".to_string(),
+                color: "#00d4ff".to_string(),
+                values: dashboard.months.iter().map(|month| Some(month.event_count)).collect(),
+            }];
+            let attendance_series = vec![
+                ChartSeries {
+                    label: "Estimated attendance".to_string(),
+                    color: "#0099cc".to_string(),
+                    values: dashboard.months.iter().map(|month| month.estimated.attendance).collect(),
+                },
+                ChartSeries {
+                    label: "Actual attendance".to_string(),
+                    color: "#10b981".to_string(),
+                    values: dashboard.months.iter().map(|month| month.actual.attendance).collect(),
+                },
+            ];
+
+            html! {
+                <>
+                    <div class="chapters-header">
+                        <Link<Route> to={Route::ChapterDetail { id: dashboard.chapter.id.clone() }} classes="back-link">
+                            {format!("← {}", dashboard.chapter.name)}
+                        </Link<Route>>
+                        <h1 class="page-title">{"Chapter Dashboard"}</h1>
+                        <div class="quarter-picker">
+                            <button class="secondary-button" onclick={go_to(-1)}>{"‹"}</button>
+                            <span class="quarter-label">{format!("Q{} {}", quarter, year)}</span>
+                            <button class="secondary-button" onclick={go_to(1)}>{"›"}</button>
+                        </div>
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <div class="dashboard-section-header">
+                            <h2>{"Progress Against Targets"}</h2>
+                            if !*editing_targets {
+                                <button class="secondary-button" onclick={on_toggle_targets.reform(|_| ())}>{"Set Targets"}</button>
+                            }
+                        </div>
+
+                        if *editing_targets {
+                            <KpiValuesForm
+                                initial={dashboard.targets.clone()}
+                                submit_label="Save Targets"
+                                on_submit={on_save_targets}
+                                on_cancel={on_toggle_targets.clone()}
+                                error={(*form_error).clone()}
+                                busy={*saving}
+                            />
+                        } else {
+                            {KPI_LABELS.iter().enumerate().map(|(index, label)| html! {
+                                <ProgressBar
+                                    label={label.to_string()}
+                                    actual={actual[index]}
+                                    estimated={estimated[index]}
+                                    target={targets[index]}
+                                />
+                            }).collect::<Html>()}
+                            <p class="form-help">{"Filled bars show recorded actuals; the faint bar behind shows what events planned."}</p>
+                        }
+                    </div>
+
+                    <div class="dashboard-charts">
+                        <BarChart title="Quarter Totals" categories={KPI_LABELS.iter().map(|label| label.to_string()).collect::<Vec<_>>()} series={totals_series} />
+                        <BarChart title="Events per Month" categories={month_names.clone()} series={events_series} />
+                        <BarChart title="Attendance per Month" categories={month_names} series={attendance_series} />
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <h2>{"Budget"}</h2>
+                        <BudgetTotals totals={dashboard.budget.clone()} />
+                        <small class="form-help">{"Costs per attendee and per SCF referral divide spending by the actuals of the events that spent in that currency."}</small>
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <h2>{format!("Events This Quarter ({})", dashboard.events.len())}</h2>
+                        if dashboard.events.is_empty() {
+                            <p class="form-help">{"No chapter events start in this quarter."}</p>
+                        } else {
+                            <small class="form-help">{"The organizer of an event, signed in, records its actuals."}</small>
+                        }
+                        <ul class="member-list">
+                            {dashboard.events.iter().map(|event| dashboard_event(
+                                event,
+                                editing_event.as_deref() == Some(event.id.as_str()),
+                                &on_edit_event,
+                                &reload,
+                                &form_error,
+                                &saving,
+                            )).collect::<Html>()}
+                        </ul>
+                    </div>
+                </>
+            }
+        }
+    };
+
+    html! {
+        <div class="chapters-container">
+            {content}
+
+            <style>
+                {include_str!("chapterspage.css")}
+                {include_str!("chapterdashboardpage.css")}
+                {include_str!("eventbudgetpage.css")}
+            </style>
+        </div>
+    }
+}
+
+/// One event row with its planned and recorded attendance, and a form to record actuals.
+fn dashboard_event(
+    event: &EventResponse,
+    editing: bool,
+    on_edit: &Callback<Option<String>>,
+    reload: &Callback<()>,
+    form_error: &UseStateHandle<Option<ApiError>>,
+    saving: &UseStateHandle<bool>,
+) -> Html {
+    let event_id = event.id.clone();
+    let on_open = on_edit.reform(move |_: MouseEvent| Some(event_id.clone()));
+
+    let on_save = {
+        let event_id = event.id.clone();
+        let (on_edit, reload, form_error, saving) = (on_edit.clone(), reload.clone(), form_error.clone(), saving.clone());
+        Callback::from(move |actuals: KpiValues| {
+            let event_id = event_id.clone();
+            let request = EventActualsRequest { actuals };
+            let (on_edit, reload, form_error, saving) = (on_edit.clone(), reload.clone(), form_error.clone(), saving.clone());
+            saving.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().record_event_actuals(&event_id, &request).await {
+                    Ok(_) => {
+                        on_edit.emit(None);
+                        reload.emit(());
+                    }
+                    Err(e) => form_error.set(Some(e)),
+                }
+                saving.set(false);
+            });
+        })
+    };
+
+    let attendance = |value: Option<u32>| value.map(|v| v.to_string()).unwrap_or_else(|| "—".to_string());
+
+    html! {
+        <li class="dashboard-event" key={event.id.clone()}>
+            <div class="member-item">
+                <div>
+                    <strong>{&event.title}</strong>
+                    <small class="member-wallet">{format_in_zone(&event.date, &event.timezone)}</small>
+                    <small class="member-wallet">
+                        {format!(
+                            "Attendance: {} planned, {} actual",
+                            attendance(event.kpi_estimates.expected_attendance),
+                            attendance(event.kpi_actuals.attendance),
+                        )}
+                    </small>
+                </div>
+                if !editing {
+                    <button class="secondary-button" onclick={on_open}>{"Record Actuals"}</button>
+                }
+            </div>
+            if editing {
+                <KpiValuesForm
+                    initial={event.kpi_actuals.clone()}
+                    submit_label="Save Actuals"
+                    on_submit={on_save}
+                    on_cancel={on_edit.reform(|_| None)}
+                    error={(**form_error).clone()}
+                    busy={**saving}
+                />
+            }
+        </li>
+    }
+}
\ No newline at end of file
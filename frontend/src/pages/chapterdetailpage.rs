@@ -0,0 +1,279 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use web_sys::HtmlInputElement;
+use shared::dto::{ChapterMembersResponse, ChapterRequest, ChapterResponse, MembershipRequest};
+use crate::components::ChapterForm;
+use crate::pages::EventsQuery;
+use crate::routing::Route;
+use crate::services::{ApiClient, ApiError};
+
+#[derive(PartialEq, Clone)]
+pub enum ChapterDetailState {
+    Loading,
+    Loaded(Box<ChapterResponse>, ChapterMembersResponse),
+    Error(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ChapterDetailPageProps {
+    pub id: String,
+}
+
+#[function_component(ChapterDetailPage)]
+pub fn chapter_detail_page(props: &ChapterDetailPageProps) -> Html {
+    let state = use_state(|| ChapterDetailState::Loading);
+    let editing = use_state(|| false);
+    let saving = use_state(|| false);
+    let form_error = use_state(|| None::<ApiError>);
+    let new_member = use_state(String::new);
+    let member_error = use_state(|| None::<String>);
+    let navigator = use_navigator();
+
+    // Reloads the chapter and its members; used after every change
+    let reload = {
+        let state = state.clone();
+        let id = props.id.clone();
+        Callback::from(move |_: ()| {
+            let state = state.clone();
+            let id = id.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = ApiClient::default();
+                let result = match client.get_chapter(&id).await {
+                    Ok(chapter) => client.list_chapter_members(&id).await.map(|members| (chapter, members)),
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok((chapter, members)) => state.set(ChapterDetailState::Loaded(Box::new(chapter), members)),
+                    Err(e) => state.set(ChapterDetailState::Error(format!("Failed to load chapter: {}", e))),
+                }
+            });
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with(props.id.clone(), move |_| {
+            reload.emit(());
+            || ()
+        });
+    }
+
+    let on_toggle_edit = {
+        let editing = editing.clone();
+        let form_error = form_error.clone();
+        Callback::from(move |_: ()| {
+            form_error.set(None);
+            editing.set(!*editing);
+        })
+    };
+
+    let on_update = {
+        let id = props.id.clone();
+        let (editing, saving, form_error, reload) =
+            (editing.clone(), saving.clone(), form_error.clone(), reload.clone());
+        Callback::from(move |request: ChapterRequest| {
+            let id = id.clone();
+            let (editing, saving, form_error, reload) =
+                (editing.clone(), saving.clone(), form_error.clone(), reload.clone());
+            saving.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().update_chapter(&id, &request).await {
+                    Ok(_) => {
+                        editing.set(false);
+                        reload.emit(());
+                    }
+                    Err(e) => form_error.set(Some(e)),
+                }
+                saving.set(false);
+            });
+        })
+    };
+
+    let on_delete = {
+        let id = props.id.clone();
+        let state = state.clone();
+        Callback::from(move |_: MouseEvent| {
+            if !gloo::dialogs::confirm("Delete this chapter? Its events are kept but no longer linked to it.") {
+                return;
+            }
+            let id = id.clone();
+            let state = state.clone();
+            let navigator = navigator.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().delete_chapter(&id).await {
+                    Ok(()) => {
+                        if let Some(navigator) = navigator {
+                            navigator.push(&Route::Chapters);
+                        }
+                    }
+                    Err(e) => state.set(ChapterDetailState::Error(format!("Failed to delete chapter: {}", e))),
+                }
+            });
+        })
+    };
+
+    let on_new_member_change = {
+        let new_member = new_member.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            new_member.set(input.value());
+        })
+    };
+
+    let on_add_member = {
+        let id = props.id.clone();
+        let (new_member, member_error, reload) = (new_member.clone(), member_error.clone(), reload.clone());
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let request = MembershipRequest { wallet_address: new_member.trim().to_string() };
+            if request.wallet_address.is_empty() {
+                return;
+            }
+            let id = id.clone();
+            let (new_member, member_error, reload) = (new_member.clone(), member_error.clone(), reload.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().add_chapter_member(&id, &request).await {
+                    Ok(_) => {
+                        new_member.set(String::new());
+                        member_error.set(None);
+                        reload.emit(());
+                    }
+                    Err(e) => {
+                        let message = e.field_error("wallet_address").map(str::to_string).unwrap_or_else(|| e.to_string());
+                        member_error.set(Some(message));
+                    }
+                }
+            });
+        })
+    };
+
+    let on_remove_member = {
+        let id = props.id.clone();
+        let (member_error, reload) = (member_error.clone(), reload.clone());
+        Callback::from(move |user_id: String| {
+            let id = id.clone();
+            let (member_error, reload) = (member_error.clone(), reload.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().remove_chapter_member(&id, &user_id).await {
+                    Ok(()) => reload.emit(()),
+                    Err(e) => member_error.set(Some(e.to_string())),
+                }
+            });
+        })
+    };
+
+    let content = match &*state {
+        ChapterDetailState::Loading => html! {
+            <div class="loading-container">
+                <div class="spinner"></div>
+                <h2>{"Loading chapter..."}</h2>
+            </div>
+        },
+        ChapterDetailState::Error(error) => html! {
+            <div class="error-container">
+                <div class="error-icon">{"⚠️"}</div>
+                <h2>{"Chapter Unavailable"}</h2>
+                <p>{error}</p>
+                <Link<Route> to={Route::Chapters} classes="primary-button">{"Back to Chapters"}</Link<Route>>
+            </div>
+        },
+        ChapterDetailState::Loaded(chapter, members) => {
+            let events_query = EventsQuery { chapter_id: Some(chapter.id.clone()), ..Default::default() };
+            let initial = ChapterRequest {
+                name: chapter.name.clone(),
+                country: chapter.country.clone(),
+                city: chapter.city.clone(),
+                lead_wallet_address: chapter.lead.as_ref().map(|lead| lead.wallet_address.clone()),
+            };
+
+            html! {
+                <>
+                    <div class="chapters-header">
+                        <Link<Route> to={Route::Chapters} classes="back-link">{"← All chapters"}</Link<Route>>
+                        <h1 class="page-title">{&chapter.name}</h1>
+                        <p class="page-subtitle">{format!("📍 {}, {}", chapter.city, chapter.country)}</p>
+                        <div class="chapter-actions">
+                            <Link<Route, EventsQuery> to={Route::EventOutput} query={Some(events_query)} classes="primary-button">
+                                {"View Chapter Events"}
+                            </Link<Route, EventsQuery>>
+                            <Link<Route> to={Route::ChapterDashboard { id: chapter.id.clone() }} classes="secondary-button">
+                                {"📊 Dashboard"}
+                            </Link<Route>>
+                            <a href={ApiClient::default().calendar_subscription_url(Some(&chapter.id))} class="secondary-button">
+                                {"📅 Subscribe to Calendar"}
+                            </a>
+                            if !*editing {
+                                <button class="secondary-button" onclick={on_toggle_edit.reform(|_| ())}>{"Edit"}</button>
+                                <button class="danger-button" onclick={on_delete}>{"Delete"}</button>
+                            }
+                        </div>
+                    </div>
+
+                    if *editing {
+                        <div class="chapter-form-card">
+                            <h2>{"Edit Chapter"}</h2>
+                            <ChapterForm
+                                initial={Some(initial)}
+                                submit_label="Save Changes"
+                                on_submit={on_update}
+                                on_cancel={on_toggle_edit.clone()}
+                                error={(*form_error).clone()}
+                                busy={*saving}
+                            />
+                        </div>
+                    }
+
+                    <div class="chapter-form-card">
+                        <h2>{format!("Members ({})", members.total)}</h2>
+                        <p class="chapter-lead">
+                            {match &chapter.lead {
+                                Some(lead) => format!("👤 Chapter Lead: {}", lead.username),
+                                None => "👤 This chapter has no lead yet".to_string(),
+                            }}
+                        </p>
+
+                        <ul class="member-list">
+                            {members.members.iter().map(|member| {
+                                let user_id = member.id.clone();
+                                let on_remove = on_remove_member.reform(move |_: MouseEvent| user_id.clone());
+                                html! {
+                                    <li class="member-item" key={member.id.clone()}>
+                                        <div>
+                                            <strong>{&member.username}</strong>
+                                            <span class="member-type">{format!("{:?}", member.user_type)}</span>
+                                            <small class="member-wallet">{&member.wallet_address}</small>
+                                        </div>
+                                        <button class="link-button" onclick={on_remove}>{"Remove"}</button>
+                                    </li>
+                                }
+                            }).collect::<Html>()}
+                        </ul>
+
+                        <form class="add-member-form" onsubmit={on_add_member}>
+                            <input
+                                type="text"
+                                value={(*new_member).clone()}
+                                onchange={on_new_member_change}
+                                placeholder="Member's Stellar wallet address (G...)"
+                            />
+                            <button type="submit" class="primary-button">{"Add Member"}</button>
+                        </form>
+                        if let Some(error) = &*member_error {
+                            <small class="field-error">{error}</small>
+                        }
+                    </div>
+                </>
+            }
+        }
+    };
+
+    html! {
+        <div class="chapters-container">
+            {content}
+
+            <style>
+                {include_str!("chapterspage.css")}
+            </style>
+        </div>
+    }
+}
\ No newline at end of file
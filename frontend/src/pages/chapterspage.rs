@@ -0,0 +1,137 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use shared::dto::{ChapterListResponse, ChapterRequest};
+use crate::components::ChapterForm;
+use crate::routing::Route;
+use crate::services::{ApiClient, ApiError};
+
+#[derive(PartialEq, Clone)]
+pub enum ChapterListState {
+    Loading,
+    Loaded(ChapterListResponse),
+    Error(String),
+}
+
+#[function_component(ChaptersPage)]
+pub fn chapters_page() -> Html {
+    let state = use_state(|| ChapterListState::Loading);
+    let show_form = use_state(|| false);
+    let saving = use_state(|| false);
+    let form_error = use_state(|| None::<ApiError>);
+    let navigator = use_navigator();
+
+    {
+        let state = state.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().list_chapters().await {
+                    Ok(chapters) => state.set(ChapterListState::Loaded(chapters)),
+                    Err(e) => state.set(ChapterListState::Error(format!("Failed to load chapters: {}", e))),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_toggle_form = {
+        let show_form = show_form.clone();
+        let form_error = form_error.clone();
+        Callback::from(move |_: ()| {
+            form_error.set(None);
+            show_form.set(!*show_form);
+        })
+    };
+
+    let on_create = {
+        let saving = saving.clone();
+        let form_error = form_error.clone();
+        Callback::from(move |request: ChapterRequest| {
+            let saving = saving.clone();
+            let form_error = form_error.clone();
+            let navigator = navigator.clone();
+            saving.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().create_chapter(&request).await {
+                    Ok(chapter) => {
+                        if let Some(navigator) = navigator {
+                            navigator.push(&Route::ChapterDetail { id: chapter.id });
+                        }
+                    }
+                    Err(e) => form_error.set(Some(e)),
+                }
+                saving.set(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="chapters-container">
+            <div class="chapters-header">
+                <h1 class="page-title">{"Stellar Europe Chapters"}</h1>
+                <p class="page-subtitle">{"Local communities of Ambassadors led by Chapter Leads across Europe"}</p>
+                if !*show_form {
+                    <button class="primary-button" onclick={on_toggle_form.reform(|_| ())}>
+                        {"+ New Chapter"}
+                    </button>
+                }
+            </div>
+
+            if *show_form {
+                <div class="chapter-form-card">
+                    <h2>{"New Chapter"}</h2>
+                    <ChapterForm
+                        submit_label="Create Chapter"
+                        on_submit={on_create}
+                        on_cancel={on_toggle_form.clone()}
+                        error={(*form_error).clone()}
+                        busy={*saving}
+                    />
+                </div>
+            }
+
+            {match &*state {
+                ChapterListState::Loading => html! {
+                    <div class="loading-container">
+                        <div class="spinner"></div>
+                        <h2>{"Loading chapters..."}</h2>
+                    </div>
+                },
+                ChapterListState::Loaded(response) if response.chapters.is_empty() => html! {
+                    <div class="empty-state">
+                        <div class="empty-icon">{"🏛️"}</div>
+                        <h2>{"No Chapters Yet"}</h2>
+                        <p>{"Start the first Stellar Europe chapter in your city."}</p>
+                    </div>
+                },
+                ChapterListState::Loaded(response) => html! {
+                    <div class="chapters-grid">
+                        {response.chapters.iter().map(|chapter| html! {
+                            <Link<Route> to={Route::ChapterDetail { id: chapter.id.clone() }} classes="chapter-card">
+                                <h3 class="chapter-name">{&chapter.name}</h3>
+                                <p class="chapter-location">{format!("📍 {}, {}", chapter.city, chapter.country)}</p>
+                                <p class="chapter-lead">
+                                    {match &chapter.lead {
+                                        Some(lead) => format!("👤 Led by {}", lead.username),
+                                        None => "👤 Looking for a Chapter Lead".to_string(),
+                                    }}
+                                </p>
+                                <p class="chapter-members">{format!("👥 {} members", chapter.member_count)}</p>
+                            </Link<Route>>
+                        }).collect::<Html>()}
+                    </div>
+                },
+                ChapterListState::Error(error) => html! {
+                    <div class="error-container">
+                        <div class="error-icon">{"⚠️"}</div>
+                        <h2>{"Failed to Load Chapters"}</h2>
+                        <p>{error}</p>
+                    </div>
+                },
+            }}
+
+            <style>
+                {include_str!("chapterspage.css")}
+            </style>
+        </div>
+    }
+}
\ No newline at end of file
@@ -0,0 +1,230 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use web_sys::HtmlInputElement;
+use shared::dto::{EventStatus, LinkAccountRequest, LinkedAccountListResponse, LinkedAccountResponse, LinkedAccountStatus};
+use crate::pages::event_status_label;
+use crate::routing::Route;
+use crate::services::ApiClient;
+
+#[derive(PartialEq, Clone)]
+pub enum EventAccountsState {
+    Loading,
+    Loaded(Box<LinkedAccountListResponse>),
+    Error(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct EventAccountsPageProps {
+    pub id: String,
+}
+
+fn status_label(status: LinkedAccountStatus) -> &'static str {
+    match status {
+        LinkedAccountStatus::Pending => "Awaiting verification",
+        LinkedAccountStatus::Verified => "Verified on Stellar",
+        LinkedAccountStatus::Unverified => "Not verified",
+    }
+}
+
+/// Attendees link the Stellar accounts they created at the event; verified ones
+/// become the event's monthly active accounts.
+#[function_component(EventAccountsPage)]
+pub fn event_accounts_page(props: &EventAccountsPageProps) -> Html {
+    let state = use_state(|| EventAccountsState::Loading);
+    let account_id = use_state(String::new);
+    let action_error = use_state(|| None::<String>);
+    let busy = use_state(|| false);
+
+    let reload = {
+        let (id, state) = (props.id.clone(), state.clone());
+        move || {
+            let (id, state) = (id.clone(), state.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().list_linked_accounts(&id).await {
+                    Ok(accounts) => state.set(EventAccountsState::Loaded(Box::new(accounts))),
+                    Err(e) => state.set(EventAccountsState::Error(format!("Failed to load accounts: {}", e))),
+                }
+            });
+        }
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with(props.id.clone(), move |_| {
+            reload();
+            || ()
+        });
+    }
+
+    let on_input = |field: UseStateHandle<String>| {
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            field.set(input.value().trim().to_string());
+        })
+    };
+
+    let on_link = {
+        let id = props.id.clone();
+        let (account_id, action_error, busy) = (account_id.clone(), action_error.clone(), busy.clone());
+        let reload = reload.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let request = LinkAccountRequest { account_id: (*account_id).clone() };
+            let id = id.clone();
+            let (account_id, action_error, busy, reload) =
+                (account_id.clone(), action_error.clone(), busy.clone(), reload.clone());
+            busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().link_account(&id, &request).await {
+                    Ok(_) => {
+                        account_id.set(String::new());
+                        action_error.set(None);
+                        reload();
+                    }
+                    Err(e) => action_error.set(Some(e.to_string())),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_unlink = {
+        let id = props.id.clone();
+        let (action_error, reload) = (action_error.clone(), reload.clone());
+        Callback::from(move |link_id: String| {
+            let id = id.clone();
+            let (action_error, reload) = (action_error.clone(), reload.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().unlink_account(&id, &link_id).await {
+                    Ok(()) => {
+                        action_error.set(None);
+                        reload();
+                    }
+                    Err(e) => action_error.set(Some(e.to_string())),
+                }
+            });
+        })
+    };
+
+    let content = match &*state {
+        EventAccountsState::Loading => html! {
+            <div class="loading-container">
+                <div class="spinner"></div>
+                <h2>{"Loading accounts..."}</h2>
+            </div>
+        },
+        EventAccountsState::Error(error) => html! {
+            <div class="error-container">
+                <div class="error-icon">{"⚠️"}</div>
+                <h2>{"Accounts Unavailable"}</h2>
+                <p>{error}</p>
+                <Link<Route> to={Route::EventOutput} classes="primary-button">{"Back to Events"}</Link<Route>>
+            </div>
+        },
+        EventAccountsState::Loaded(list) => {
+            let can_link = matches!(list.event_status, EventStatus::Published | EventStatus::Completed);
+            html! {
+                <>
+                    <div class="chapters-header">
+                        <Link<Route> to={Route::EventOutput} classes="back-link">{"← Events"}</Link<Route>>
+                        <h1 class="page-title">{"Stellar Accounts"}</h1>
+                        <p class="page-subtitle">
+                            {format!("{} · {}", list.event_title, event_status_label(list.event_status))}
+                        </p>
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <p class="form-help">
+                            {format!(
+                                "{} verified. An account is verified once it was created and made a transaction of its own between {} and {} (UTC).",
+                                list.verified_count,
+                                list.window_start.format("%-d %B %Y"),
+                                (list.window_end - chrono::Duration::days(1)).format("%-d %B %Y"),
+                            )}
+                        </p>
+
+                        if let Some(error) = &*action_error {
+                            <div class="form-error">{error}</div>
+                        }
+
+                        if can_link {
+                            <form class="budget-item-form" onsubmit={on_link}>
+                                <input
+                                    type="text"
+                                    placeholder="G... account you created at the event"
+                                    value={(*account_id).clone()}
+                                    onchange={on_input(account_id.clone())}
+                                />
+                                <button type="submit" class="primary-button" disabled={*busy}>{"Link Account"}</button>
+                            </form>
+                        }
+
+                        <h2>{"Linked Accounts"}</h2>
+                        if list.accounts.is_empty() {
+                            <p class="form-help">{"No accounts linked yet."}</p>
+                        }
+                        <ul class="member-list">
+                            {list.accounts.iter().map(|account| html! {
+                                <LinkedAccountRow key={account.id.clone()} account={account.clone()} on_unlink={on_unlink.clone()} />
+                            }).collect::<Html>()}
+                        </ul>
+                    </div>
+                </>
+            }
+        }
+    };
+
+    html! {
+        <div class="chapters-container">
+            {content}
+
+            <style>
+                {include_str!("chapterspage.css")}
+                {include_str!("eventbudgetpage.css")}
+            </style>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct LinkedAccountRowProps {
+    account: LinkedAccountResponse,
+    on_unlink: Callback<String>,
+}
+
+#[function_component(LinkedAccountRow)]
+fn linked_account_row(props: &LinkedAccountRowProps) -> Html {
+    let account = &props.account;
+    let on_remove = {
+        let (id, on_unlink) = (account.id.clone(), props.on_unlink.clone());
+        Callback::from(move |_: MouseEvent| on_unlink.emit(id.clone()))
+    };
+
+    html! {
+        <li class="member-item budget-item">
+            <div>
+                <strong class="member-wallet">{&account.account_id}</strong>
+                <small class="member-wallet">
+                    {match &account.linked_by {
+                        Some(user) => format!("Linked by {}", user.username),
+                        None => "Linked by a former member".to_string(),
+                    }}
+                    if let Some(checked_at) = account.checked_at {
+                        {format!(" · checked {}", checked_at.format("%-d %b %Y %H:%M"))}
+                    }
+                </small>
+                <span class={classes!("reimbursement-badge", format!("account-badge--{}", account.status.to_string().to_lowercase()))}>
+                    {status_label(account.status)}
+                </span>
+                if let Some(error) = &account.check_error {
+                    <small class="form-help">{error}</small>
+                }
+            </div>
+            <div class="budget-item-actions">
+                if account.status != LinkedAccountStatus::Verified {
+                    <button class="link-button" onclick={on_remove}>{"Remove"}</button>
+                }
+            </div>
+        </li>
+    }
+}
\ No newline at end of file
@@ -0,0 +1,290 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use web_sys::HtmlInputElement;
+use shared::dto::{
+    AttendanceTokenStatus, CheckInRequest, CheckInResponse, EventAttendanceResponse, EventStatus,
+    SubmitAttendanceTokensRequest,
+};
+use crate::wallet::sign_transaction;
+use crate::pages::event_status_label;
+use crate::routing::Route;
+use crate::services::ApiClient;
+
+#[derive(PartialEq, Clone)]
+pub enum EventAttendanceState {
+    Loading,
+    Loaded(Box<EventAttendanceResponse>),
+    Error(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct EventAttendancePageProps {
+    pub id: String,
+}
+
+pub fn token_status_label(status: AttendanceTokenStatus) -> &'static str {
+    match status {
+        AttendanceTokenStatus::NotIssued => "No token yet",
+        AttendanceTokenStatus::AwaitingSignature => "Token awaiting signature",
+        AttendanceTokenStatus::Submitted => "Token submitted",
+        AttendanceTokenStatus::Issued => "Token ready to claim",
+        AttendanceTokenStatus::Failed => "Token failed",
+    }
+}
+
+/// Prepares an issuance for everyone still without a token (or reuses the one whose
+/// outcome is unknown), has the wallet sign it and submits it. Returns why the tokens
+/// were not issued, if they were not.
+async fn issue_tokens(event_id: &str, attendance: &EventAttendanceResponse) -> Result<Option<String>, String> {
+    let prepared = match &attendance.open_issuance {
+        Some(issuance) if issuance.status == AttendanceTokenStatus::Submitted => issuance.clone(),
+        _ => ApiClient::default().prepare_attendance_tokens(event_id).await.map_err(|e| e.to_string())?,
+    };
+
+    let signed_transaction_xdr = sign_transaction(&prepared.transaction_xdr, &prepared.network_passphrase).await?;
+    let request = SubmitAttendanceTokensRequest { signed_transaction_xdr };
+    let submitted = ApiClient::default()
+        .submit_attendance_tokens(event_id, &prepared.id, &request)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok((submitted.status != AttendanceTokenStatus::Issued)
+        .then(|| format!("{}: {}", token_status_label(submitted.status), submitted.error.unwrap_or_default())))
+}
+
+/// The organizer checks attendees in; a programme admin then issues everyone's
+/// proof-of-attendance token in one wallet-signed transaction.
+#[function_component(EventAttendancePage)]
+pub fn event_attendance_page(props: &EventAttendancePageProps) -> Html {
+    let state = use_state(|| EventAttendanceState::Loading);
+    let attendee_wallet = use_state(String::new);
+    let action_error = use_state(|| None::<String>);
+    let busy = use_state(|| false);
+
+    let reload = {
+        let (id, state) = (props.id.clone(), state.clone());
+        move || {
+            let (id, state) = (id.clone(), state.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().get_event_attendance(&id).await {
+                    Ok(attendance) => state.set(EventAttendanceState::Loaded(Box::new(attendance))),
+                    Err(e) => state.set(EventAttendanceState::Error(format!("Failed to load attendance: {}", e))),
+                }
+            });
+        }
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with(props.id.clone(), move |_| {
+            reload();
+            || ()
+        });
+    }
+
+    let on_input = |field: UseStateHandle<String>| {
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            field.set(input.value().trim().to_string());
+        })
+    };
+
+    let on_check_in = {
+        let id = props.id.clone();
+        let (attendee_wallet, action_error, busy, reload) =
+            (attendee_wallet.clone(), action_error.clone(), busy.clone(), reload.clone());
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let request = CheckInRequest { attendee_wallet_address: (*attendee_wallet).clone() };
+            let id = id.clone();
+            let (attendee_wallet, action_error, busy, reload) =
+                (attendee_wallet.clone(), action_error.clone(), busy.clone(), reload.clone());
+            busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().check_in_attendee(&id, &request).await {
+                    Ok(_) => {
+                        attendee_wallet.set(String::new());
+                        action_error.set(None);
+                        reload();
+                    }
+                    Err(e) => action_error.set(Some(e.to_string())),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_remove = {
+        let id = props.id.clone();
+        let (action_error, reload) = (action_error.clone(), reload.clone());
+        Callback::from(move |check_in_id: String| {
+            let id = id.clone();
+            let (action_error, reload) = (action_error.clone(), reload.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().remove_check_in(&id, &check_in_id).await {
+                    Ok(()) => {
+                        action_error.set(None);
+                        reload();
+                    }
+                    Err(e) => action_error.set(Some(e.to_string())),
+                }
+            });
+        })
+    };
+
+    let content = match &*state {
+        EventAttendanceState::Loading => html! {
+            <div class="loading-container">
+                <div class="spinner"></div>
+                <h2>{"Loading attendance..."}</h2>
+            </div>
+        },
+        EventAttendanceState::Error(error) => html! {
+            <div class="error-container">
+                <div class="error-icon">{"⚠️"}</div>
+                <h2>{"Attendance Unavailable"}</h2>
+                <p>{error}</p>
+                <Link<Route> to={Route::EventOutput} classes="primary-button">{"Back to Events"}</Link<Route>>
+            </div>
+        },
+        EventAttendanceState::Loaded(attendance) => {
+            let can_check_in = matches!(attendance.event_status, EventStatus::Published | EventStatus::Completed);
+            let waiting = attendance
+                .check_ins
+                .iter()
+                .filter(|check_in| {
+                    !matches!(check_in.token_status, AttendanceTokenStatus::Submitted | AttendanceTokenStatus::Issued)
+                })
+                .count();
+            let resubmit = attendance
+                .open_issuance
+                .as_ref()
+                .is_some_and(|issuance| issuance.status == AttendanceTokenStatus::Submitted);
+            let on_issue = {
+                let (id, attendance) = (props.id.clone(), (**attendance).clone());
+                let (action_error, busy, reload) = (action_error.clone(), busy.clone(), reload.clone());
+                Callback::from(move |_: MouseEvent| {
+                    let (id, attendance) = (id.clone(), attendance.clone());
+                    let (action_error, busy, reload) = (action_error.clone(), busy.clone(), reload.clone());
+                    busy.set(true);
+                    wasm_bindgen_futures::spawn_local(async move {
+                        match issue_tokens(&id, &attendance).await {
+                            Ok(problem) => action_error.set(problem),
+                            Err(error) => action_error.set(Some(error)),
+                        }
+                        reload();
+                        busy.set(false);
+                    });
+                })
+            };
+
+            html! {
+                <>
+                    <div class="chapters-header">
+                        <Link<Route> to={Route::EventOutput} classes="back-link">{"← Events"}</Link<Route>>
+                        <h1 class="page-title">{"Attendance"}</h1>
+                        <p class="page-subtitle">
+                            {format!("{} · {}", attendance.event_title, event_status_label(attendance.event_status))}
+                        </p>
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <p class="form-help">
+                            {match &attendance.asset_issuer {
+                                Some(issuer) => format!(
+                                    "Checked-in attendees receive one {} token, issued by {}, as a claimable balance they can claim from their wallet.",
+                                    attendance.asset_code, issuer
+                                ),
+                                None => "Attendance tokens are not configured on this server; check-ins are still recorded.".to_string(),
+                            }}
+                        </p>
+
+                        if let Some(error) = &*action_error {
+                            <div class="form-error">{error}</div>
+                        }
+
+                        if can_check_in {
+                            <form class="budget-item-form" onsubmit={on_check_in}>
+                                <input
+                                    type="text"
+                                    placeholder="Attendee's wallet address"
+                                    value={(*attendee_wallet).clone()}
+                                    onchange={on_input(attendee_wallet.clone())}
+                                />
+                                <button type="submit" class="primary-button" disabled={*busy}>{"Check In"}</button>
+                            </form>
+                        }
+
+                        <h2>{format!("Checked In ({})", attendance.check_ins.len())}</h2>
+                        if attendance.check_ins.is_empty() {
+                            <p class="form-help">{"Nobody has been checked in yet."}</p>
+                        }
+                        <ul class="member-list">
+                            {attendance.check_ins.iter().map(|check_in| html! {
+                                <CheckInRow key={check_in.id.clone()} check_in={check_in.clone()} on_remove={on_remove.clone()} />
+                            }).collect::<Html>()}
+                        </ul>
+
+                        if attendance.asset_issuer.is_some() && (waiting > 0 || resubmit) {
+                            <button class="primary-button" onclick={on_issue} disabled={*busy}>
+                                {if *busy {
+                                    "Issuing...".to_string()
+                                } else if resubmit {
+                                    "Submit Pending Tokens Again".to_string()
+                                } else {
+                                    format!("Issue {} Tokens with Wallet", waiting.min(100))
+                                }}
+                            </button>
+                        }
+                    </div>
+                </>
+            }
+        }
+    };
+
+    html! {
+        <div class="chapters-container">
+            {content}
+
+            <style>
+                {include_str!("chapterspage.css")}
+                {include_str!("eventbudgetpage.css")}
+            </style>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct CheckInRowProps {
+    check_in: CheckInResponse,
+    on_remove: Callback<String>,
+}
+
+#[function_component(CheckInRow)]
+fn check_in_row(props: &CheckInRowProps) -> Html {
+    let check_in = &props.check_in;
+    let on_remove = {
+        let (id, on_remove) = (check_in.id.clone(), props.on_remove.clone());
+        Callback::from(move |_: MouseEvent| on_remove.emit(id.clone()))
+    };
+    let status = check_in.token_status;
+
+    html! {
+        <li class="member-item budget-item">
+            <div>
+                <strong>{&check_in.attendee.username}</strong>
+                <small class="member-wallet">
+                    {format!("{} · checked in {}", check_in.attendee.wallet_address, check_in.checked_in_at.format("%-d %b %Y %H:%M"))}
+                </small>
+                <span class={classes!("reimbursement-badge", format!("token-badge--{}", status.to_string().to_lowercase()))}>
+                    {token_status_label(status)}
+                </span>
+            </div>
+            <div class="budget-item-actions">
+                if !matches!(status, AttendanceTokenStatus::Submitted | AttendanceTokenStatus::Issued) {
+                    <button class="link-button" onclick={on_remove}>{"Remove"}</button>
+                }
+            </div>
+        </li>
+    }
+}
\ No newline at end of file
@@ -0,0 +1,431 @@
+use std::str::FromStr;
+use yew::prelude::*;
+use yew_router::prelude::*;
+use web_sys::HtmlInputElement;
+use shared::dto::{
+    BudgetAmountRequest, BudgetCategory, BudgetItemRequest, BudgetItemResponse, Currency, Decimal, EventBudgetResponse,
+    EventStatus, ReimbursementStatus, SubmitReimbursementRequest,
+};
+use crate::components::{BudgetTotals, EventAttachments};
+use crate::wallet::sign_transaction;
+use crate::pages::event_status_label;
+use crate::routing::Route;
+use crate::services::ApiClient;
+
+#[derive(PartialEq, Clone)]
+pub enum EventBudgetState {
+    Loading,
+    Loaded(Box<EventBudgetResponse>),
+    Error(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct EventBudgetPageProps {
+    pub id: String,
+}
+
+/// Parses an amount typed by the user; empty input clears the amount.
+fn parse_amount(value: &str, currency: Currency) -> Result<Option<Decimal>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    let amount = Decimal::from_str(value).map_err(|_| format!("\"{}\" is not an amount", value))?;
+    currency.check_amount(amount)?;
+    Ok(Some(amount))
+}
+
+fn reimbursement_label(status: ReimbursementStatus) -> &'static str {
+    match status {
+        ReimbursementStatus::AwaitingSignature => "Payout awaiting signature",
+        ReimbursementStatus::Submitted => "Payout submitted",
+        ReimbursementStatus::Paid => "Paid on Stellar",
+        ReimbursementStatus::Failed => "Payout failed",
+    }
+}
+
+/// Prepares the item's payout if needed, has the wallet sign it and submits it. Returns
+/// the reloaded budget, and why the payout did not go through if it did not.
+async fn pay_out(
+    event_id: &str,
+    item_id: &str,
+    status: Option<ReimbursementStatus>,
+) -> Result<(EventBudgetResponse, Option<String>), String> {
+    let client = ApiClient::default();
+    let prepared = match status {
+        None | Some(ReimbursementStatus::Failed) => client.prepare_reimbursement(event_id, item_id).await,
+        _ => client.get_reimbursement(event_id, item_id).await,
+    }
+    .map_err(|e| e.to_string())?;
+
+    let signed_transaction_xdr = sign_transaction(&prepared.transaction_xdr, &prepared.network_passphrase).await?;
+    let request = SubmitReimbursementRequest { signed_transaction_xdr };
+    let submitted = ApiClient::default()
+        .submit_reimbursement(event_id, item_id, &request)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let budget = client.get_event_budget(event_id).await.map_err(|e| e.to_string())?;
+    let problem = (submitted.status != ReimbursementStatus::Paid).then(|| {
+        format!("{}: {}", reimbursement_label(submitted.status), submitted.error.unwrap_or_default())
+    });
+    Ok((budget, problem))
+}
+
+#[function_component(EventBudgetPage)]
+pub fn event_budget_page(props: &EventBudgetPageProps) -> Html {
+    let state = use_state(|| EventBudgetState::Loading);
+    let action_error = use_state(|| None::<String>);
+    let busy = use_state(|| false);
+
+    let category = use_state(|| BudgetCategory::Venue);
+    let description = use_state(String::new);
+    let currency = use_state(|| Currency::EUR);
+    let requested_amount = use_state(String::new);
+
+    {
+        let state = state.clone();
+        use_effect_with(props.id.clone(), move |id| {
+            let id = id.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().get_event_budget(&id).await {
+                    Ok(budget) => state.set(EventBudgetState::Loaded(Box::new(budget))),
+                    Err(e) => state.set(EventBudgetState::Error(format!("Failed to load budget: {}", e))),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_updated = {
+        let (state, action_error) = (state.clone(), action_error.clone());
+        Callback::from(move |budget: EventBudgetResponse| {
+            action_error.set(None);
+            state.set(EventBudgetState::Loaded(Box::new(budget)));
+        })
+    };
+    let on_error = {
+        let action_error = action_error.clone();
+        Callback::from(move |message: String| action_error.set(Some(message)))
+    };
+
+    let on_add_item = {
+        let id = props.id.clone();
+        let (category, description, currency, requested_amount) =
+            (category.clone(), description.clone(), currency.clone(), requested_amount.clone());
+        let (busy, on_updated, on_error) = (busy.clone(), on_updated.clone(), on_error.clone());
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let amount = match parse_amount(&requested_amount, *currency) {
+                Ok(Some(amount)) => amount,
+                Ok(None) => return on_error.emit("Enter the amount you request".to_string()),
+                Err(message) => return on_error.emit(message),
+            };
+            let request = BudgetItemRequest {
+                category: *category,
+                description: description.trim().to_string(),
+                currency: *currency,
+                requested_amount: amount,
+            };
+            let id = id.clone();
+            let (description, requested_amount, busy, on_updated, on_error) =
+                (description.clone(), requested_amount.clone(), busy.clone(), on_updated.clone(), on_error.clone());
+            busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().add_budget_item(&id, &request).await {
+                    Ok(budget) => {
+                        description.set(String::new());
+                        requested_amount.set(String::new());
+                        on_updated.emit(budget);
+                    }
+                    Err(e) => on_error.emit(e.to_string()),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let content = match &*state {
+        EventBudgetState::Loading => html! {
+            <div class="loading-container">
+                <div class="spinner"></div>
+                <h2>{"Loading budget..."}</h2>
+            </div>
+        },
+        EventBudgetState::Error(error) => html! {
+            <div class="error-container">
+                <div class="error-icon">{"⚠️"}</div>
+                <h2>{"Budget Unavailable"}</h2>
+                <p>{error}</p>
+                <Link<Route> to={Route::EventOutput} classes="primary-button">{"Back to Events"}</Link<Route>>
+            </div>
+        },
+        EventBudgetState::Loaded(budget) => {
+            let is_draft = budget.event_status == EventStatus::Draft;
+            html! {
+                <>
+                    <div class="chapters-header">
+                        <Link<Route> to={Route::EventOutput} classes="back-link">{"← Events"}</Link<Route>>
+                        <h1 class="page-title">{"Event Budget"}</h1>
+                        <p class="page-subtitle">
+                            {format!("{} · {}", budget.event_title, event_status_label(budget.event_status))}
+                        </p>
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <small class="form-help">{"The organizer edits the request and reports spending; a Chapter Lead approves and a programme admin pays out, each signed in."}</small>
+                        if let Some(error) = &*action_error {
+                            <div class="form-error">{error}</div>
+                        }
+
+                        <h2>{"Line Items"}</h2>
+                        if budget.items.is_empty() {
+                            <p class="form-help">{"No line items yet."}</p>
+                        }
+                        <ul class="member-list">
+                            {budget.items.iter().map(|item| html! {
+                                <BudgetItemRow
+                                    key={item.id.clone()}
+                                    event_id={props.id.clone()}
+                                    event_status={budget.event_status}
+                                    item={item.clone()}
+                                    on_updated={on_updated.clone()}
+                                    on_error={on_error.clone()}
+                                />
+                            }).collect::<Html>()}
+                        </ul>
+
+                        if is_draft {
+                            <form class="budget-item-form" onsubmit={on_add_item}>
+                                <select onchange={{
+                                    let category = category.clone();
+                                    Callback::from(move |e: Event| {
+                                        let select: HtmlInputElement = e.target_unchecked_into();
+                                        category.set(BudgetCategory::from_name(&select.value()).unwrap_or(BudgetCategory::Other));
+                                    })
+                                }}>
+                                    {BudgetCategory::ALL.iter().map(|option| html! {
+                                        <option value={option.to_string()} selected={*category == *option}>{option.to_string()}</option>
+                                    }).collect::<Html>()}
+                                </select>
+                                <input
+                                    type="text"
+                                    placeholder="What it pays for"
+                                    value={(*description).clone()}
+                                    onchange={{
+                                        let description = description.clone();
+                                        Callback::from(move |e: Event| {
+                                            let input: HtmlInputElement = e.target_unchecked_into();
+                                            description.set(input.value());
+                                        })
+                                    }}
+                                />
+                                <input
+                                    type="text"
+                                    inputmode="decimal"
+                                    placeholder="Amount"
+                                    value={(*requested_amount).clone()}
+                                    onchange={{
+                                        let requested_amount = requested_amount.clone();
+                                        Callback::from(move |e: Event| {
+                                            let input: HtmlInputElement = e.target_unchecked_into();
+                                            requested_amount.set(input.value());
+                                        })
+                                    }}
+                                />
+                                <select onchange={{
+                                    let currency = currency.clone();
+                                    Callback::from(move |e: Event| {
+                                        let select: HtmlInputElement = e.target_unchecked_into();
+                                        currency.set(Currency::from_name(&select.value()).unwrap_or(Currency::EUR));
+                                    })
+                                }}>
+                                    {Currency::ALL.iter().map(|option| html! {
+                                        <option value={option.to_string()} selected={*currency == *option}>{option.to_string()}</option>
+                                    }).collect::<Html>()}
+                                </select>
+                                <button type="submit" class="primary-button" disabled={*busy}>{"Add"}</button>
+                            </form>
+                            <small class="form-help">{"The request can be changed while the event is a draft; submit the event to have it reviewed."}</small>
+                        }
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <h2>{"Totals"}</h2>
+                        <BudgetTotals totals={budget.totals.clone()} />
+                        <small class="form-help">{"Costs per attendee and per SCF referral use the spending and the actuals recorded for the event."}</small>
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <h2>{"Receipts, Photos & Slides"}</h2>
+                        <EventAttachments
+                            event_id={props.id.clone()}
+                            items={budget.items.clone()}
+                        />
+                    </div>
+                </>
+            }
+        }
+    };
+
+    html! {
+        <div class="chapters-container">
+            {content}
+
+            <style>
+                {include_str!("chapterspage.css")}
+                {include_str!("eventbudgetpage.css")}
+            </style>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct BudgetItemRowProps {
+    event_id: String,
+    event_status: EventStatus,
+    item: BudgetItemResponse,
+    on_updated: Callback<EventBudgetResponse>,
+    on_error: Callback<String>,
+}
+
+/// One line item, with the action its event's status allows next to it.
+#[function_component(BudgetItemRow)]
+fn budget_item_row(props: &BudgetItemRowProps) -> Html {
+    let approved = use_state(|| props.item.approved.map(|money| money.amount.to_string()).unwrap_or_default());
+    let spent = use_state(|| props.item.spent.map(|money| money.amount.to_string()).unwrap_or_default());
+    let paying = use_state(|| false);
+    let item = &props.item;
+    let currency = item.requested.currency;
+
+    let on_input = |field: UseStateHandle<String>| {
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            field.set(input.value());
+        })
+    };
+
+    // `spend` picks the endpoint: reporting spending, or approving
+    let on_save = |value: UseStateHandle<String>, spend: bool| {
+        let (event_id, item_id) = (props.event_id.clone(), item.id.clone());
+        let (on_updated, on_error) = (props.on_updated.clone(), props.on_error.clone());
+        Callback::from(move |_: MouseEvent| {
+            let amount = match parse_amount(&value, currency) {
+                Ok(amount) => amount,
+                Err(message) => return on_error.emit(message),
+            };
+            let request = BudgetAmountRequest { amount };
+            let (event_id, item_id, on_updated, on_error) =
+                (event_id.clone(), item_id.clone(), on_updated.clone(), on_error.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = ApiClient::default();
+                let result = if spend {
+                    client.record_budget_spending(&event_id, &item_id, &request).await
+                } else {
+                    client.approve_budget_item(&event_id, &item_id, &request).await
+                };
+                match result {
+                    Ok(budget) => on_updated.emit(budget),
+                    Err(e) => on_error.emit(e.to_string()),
+                }
+            });
+        })
+    };
+
+    let on_remove = {
+        let (event_id, item_id) = (props.event_id.clone(), item.id.clone());
+        let (on_updated, on_error) = (props.on_updated.clone(), props.on_error.clone());
+        Callback::from(move |_: MouseEvent| {
+            let (event_id, item_id) = (event_id.clone(), item_id.clone());
+            let (on_updated, on_error) = (on_updated.clone(), on_error.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = ApiClient::default();
+                let result = match client.delete_budget_item(&event_id, &item_id).await {
+                    Ok(()) => client.get_event_budget(&event_id).await,
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(budget) => on_updated.emit(budget),
+                    Err(e) => on_error.emit(e.to_string()),
+                }
+            });
+        })
+    };
+
+    let on_pay = {
+        let (event_id, item_id) = (props.event_id.clone(), item.id.clone());
+        let (on_updated, on_error) = (props.on_updated.clone(), props.on_error.clone());
+        let status = item.reimbursement_status;
+        let paying = paying.clone();
+        Callback::from(move |_: MouseEvent| {
+            let (event_id, item_id) = (event_id.clone(), item_id.clone());
+            let (on_updated, on_error, paying) = (on_updated.clone(), on_error.clone(), paying.clone());
+            paying.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match pay_out(&event_id, &item_id, status).await {
+                    Ok((budget, problem)) => {
+                        on_updated.emit(budget);
+                        if let Some(problem) = problem {
+                            on_error.emit(problem);
+                        }
+                    }
+                    Err(error) => on_error.emit(error),
+                }
+                paying.set(false);
+            });
+        })
+    };
+
+    let can_approve = matches!(props.event_status, EventStatus::Submitted | EventStatus::Approved | EventStatus::Published);
+    let can_spend = matches!(
+        props.event_status,
+        EventStatus::Approved | EventStatus::Published | EventStatus::Completed | EventStatus::Cancelled
+    );
+    // Spending in USDC or XLM is paid back on Stellar by a programme admin
+    let can_pay = currency.is_stellar_asset()
+        && item.approved.is_some()
+        && item.spent.is_some()
+        && item.reimbursement_status != Some(ReimbursementStatus::Paid);
+
+    html! {
+        <li class="member-item budget-item">
+            <div>
+                <strong>{item.category.to_string()}</strong>
+                if !item.description.is_empty() {
+                    <span class="budget-item-description">{&item.description}</span>
+                }
+                <small class="member-wallet">
+                    {format!(
+                        "Requested {} · approved {} · spent {}",
+                        item.requested,
+                        item.approved.map(|money| money.to_string()).unwrap_or_else(|| "—".to_string()),
+                        item.spent.map(|money| money.to_string()).unwrap_or_else(|| "—".to_string()),
+                    )}
+                </small>
+                if let Some(status) = item.reimbursement_status {
+                    <span class={classes!("reimbursement-badge", format!("reimbursement-badge--{}", status.to_string().to_lowercase()))}>
+                        {reimbursement_label(status)}
+                    </span>
+                }
+            </div>
+            <div class="budget-item-actions">
+                if props.event_status == EventStatus::Draft {
+                    <button class="link-button" onclick={on_remove}>{"Remove"}</button>
+                }
+                if can_approve {
+                    <input type="text" inputmode="decimal" value={(*approved).clone()} onchange={on_input(approved.clone())} placeholder={currency.to_string()} />
+                    <button class="secondary-button" onclick={on_save(approved.clone(), false)}>{"Approve"}</button>
+                }
+                if can_spend {
+                    <input type="text" inputmode="decimal" value={(*spent).clone()} onchange={on_input(spent.clone())} placeholder={currency.to_string()} />
+                    <button class="secondary-button" onclick={on_save(spent.clone(), true)}>{"Record Spent"}</button>
+                }
+                if can_pay {
+                    <button class="primary-button" onclick={on_pay} disabled={*paying}>
+                        {if *paying { "Paying..." } else { "Pay Out with Wallet" }}
+                    </button>
+                }
+            </div>
+        </li>
+    }
+}
\ No newline at end of file
@@ -1,7 +1,8 @@
 use yew::prelude::*;
 use web_sys::HtmlInputElement;
-use shared::dto::{EventRequest, EventType, StrategicFocusArea, KPIEstimates};
-use crate::services::api;
+use shared::dto::{EventRequest, EventType, StrategicFocusArea, KPIEstimates, ChapterResponse};
+use crate::services::ApiClient;
+use crate::utils::{browser_time_zone, zoned_input_to_utc, EUROPEAN_TIME_ZONES};
 
 #[derive(PartialEq, Clone)]
 pub enum EventFormState {
@@ -18,11 +19,15 @@ pub fn event_form_page() -> Html {
     let description = use_state(|| String::new());
     let event_type = use_state(|| EventType::Meetup);
     let date = use_state(|| String::new());
+    let end_date = use_state(String::new);
+    let timezone = use_state(browser_time_zone);
     let location = use_state(|| String::new());
     let max_participants = use_state(|| String::new());
     let registration_required = use_state(|| false);
     let contact_email = use_state(|| String::new());
     let external_link = use_state(|| String::new());
+    let chapter_id = use_state(String::new);
+    let chapters = use_state(Vec::<ChapterResponse>::new);
 
     // KPI Planning fields
     let strategic_focus_areas = use_state(|| vec![false, false, false, false, false]); // [community, onchain, scf, ecosystem, developer]
@@ -37,6 +42,18 @@ pub fn event_form_page() -> Html {
     let strategic_purpose = use_state(|| String::new());
     let success_metrics = use_state(|| String::new());
 
+    {
+        let chapters = chapters.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Ok(response) = ApiClient::default().list_chapters().await {
+                    chapters.set(response.chapters);
+                }
+            });
+            || ()
+        });
+    }
+
     let on_title_change = {
         let title = title.clone();
         Callback::from(move |e: Event| {
@@ -76,6 +93,30 @@ pub fn event_form_page() -> Html {
         })
     };
 
+    let on_end_date_change = {
+        let end_date = end_date.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            end_date.set(input.value());
+        })
+    };
+
+    let on_timezone_change = {
+        let timezone = timezone.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            timezone.set(input.value());
+        })
+    };
+
+    let on_chapter_change = {
+        let chapter_id = chapter_id.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            chapter_id.set(input.value());
+        })
+    };
+
     let on_location_change = {
         let location = location.clone();
         Callback::from(move |e: Event| {
@@ -214,11 +255,14 @@ pub fn event_form_page() -> Html {
         let description = description.clone();
         let event_type = event_type.clone();
         let date = date.clone();
+        let end_date = end_date.clone();
+        let timezone = timezone.clone();
         let location = location.clone();
         let max_participants = max_participants.clone();
         let registration_required = registration_required.clone();
         let contact_email = contact_email.clone();
         let external_link = external_link.clone();
+        let chapter_id = chapter_id.clone();
         let strategic_focus_areas = strategic_focus_areas.clone();
         let monthly_active_ambassadors = monthly_active_ambassadors.clone();
         let monthly_active_accounts = monthly_active_accounts.clone();
@@ -234,6 +278,20 @@ pub fn event_form_page() -> Html {
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
 
+            // Inputs are wall-clock times in the event's zone, not the browser's
+            let Some(event_date) = zoned_input_to_utc(&date, &timezone) else {
+                state.set(EventFormState::Error(format!("Please choose a valid start time in {}.", *timezone)));
+                return;
+            };
+            let Some(event_end_date) = zoned_input_to_utc(&end_date, &timezone) else {
+                state.set(EventFormState::Error(format!("Please choose a valid end time in {}.", *timezone)));
+                return;
+            };
+            if event_end_date <= event_date {
+                state.set(EventFormState::Error("The event must end after it starts.".to_string()));
+                return;
+            }
+
             let max_participants_num = if max_participants.is_empty() {
                 None
             } else {
@@ -271,12 +329,15 @@ pub fn event_form_page() -> Html {
                 title: (*title).clone(),
                 description: (*description).clone(),
                 event_type: (*event_type).clone(),
-                date: (*date).clone(),
+                date: event_date,
+                end_date: Some(event_end_date),
+                timezone: Some((*timezone).clone()),
                 location: (*location).clone(),
                 max_participants: max_participants_num,
                 registration_required: *registration_required,
                 contact_email: (*contact_email).clone(),
                 external_link: if external_link.is_empty() { None } else { Some((*external_link).clone()) },
+                chapter_id: if chapter_id.is_empty() { None } else { Some((*chapter_id).clone()) },
                 strategic_focus_areas: focus_areas,
                 kpi_estimates,
                 target_audience: (*target_audience).clone(),
@@ -289,7 +350,7 @@ pub fn event_form_page() -> Html {
 
             let state_clone = state.clone();
             wasm_bindgen_futures::spawn_local(async move {
-                match api::create_event(request).await {
+                match ApiClient::default().create_event(&request).await {
                     Ok(message) => {
                         state_clone.set(EventFormState::Success(message));
                     }
@@ -350,7 +411,24 @@ pub fn event_form_page() -> Html {
                                     </div>
 
                                     <div class="form-group">
-                                        <label for="date">{"Date & Time *"}</label>
+                                        <label for="timezone">{"Time Zone *"}</label>
+                                        <select id="timezone" onchange={on_timezone_change}>
+                                            {if EUROPEAN_TIME_ZONES.contains(&timezone.as_str()) {
+                                                html! {}
+                                            } else {
+                                                html! { <option value={(*timezone).clone()} selected=true>{(*timezone).clone()}</option> }
+                                            }}
+                                            {EUROPEAN_TIME_ZONES.iter().map(|zone| html! {
+                                                <option value={*zone} selected={*timezone == *zone}>{*zone}</option>
+                                            }).collect::<Html>()}
+                                        </select>
+                                        <small class="form-help">{"Start and end times are local to the event."}</small>
+                                    </div>
+                                </div>
+
+                                <div class="form-row">
+                                    <div class="form-group">
+                                        <label for="date">{"Starts *"}</label>
                                         <input
                                             type="datetime-local"
                                             id="date"
@@ -359,6 +437,17 @@ pub fn event_form_page() -> Html {
                                             required=true
                                         />
                                     </div>
+
+                                    <div class="form-group">
+                                        <label for="end-date">{"Ends *"}</label>
+                                        <input
+                                            type="datetime-local"
+                                            id="end-date"
+                                            value={(*end_date).clone()}
+                                            onchange={on_end_date_change}
+                                            required=true
+                                        />
+                                    </div>
                                 </div>
 
                                 <div class="form-group">
@@ -372,6 +461,19 @@ pub fn event_form_page() -> Html {
                                         required=true
                                     />
                                 </div>
+
+                                <div class="form-group">
+                                    <label for="chapter">{"Chapter"}</label>
+                                    <select id="chapter" onchange={on_chapter_change}>
+                                        <option value="" selected={chapter_id.is_empty()}>{"Not organised by a chapter"}</option>
+                                        {chapters.iter().map(|chapter| html! {
+                                            <option value={chapter.id.clone()} selected={*chapter_id == chapter.id}>
+                                                {format!("{} ({}, {})", chapter.name, chapter.city, chapter.country)}
+                                            </option>
+                                        }).collect::<Html>()}
+                                    </select>
+                                    <small class="form-help">{"Chapter events show up on the chapter's page and calendar."}</small>
+                                </div>
                             </div>
 
                             <div class="form-section">
@@ -659,6 +761,7 @@ pub fn event_form_page() -> Html {
                             <div class="success-icon">{"✅"}</div>
                             <h2>{"Event Created Successfully!"}</h2>
                             <p class="success-message">{message}</p>
+                            <p class="success-message">{"It is saved as a draft. Submit it for review from the events page; it is listed once a Chapter Lead approves it."}</p>
                             <div class="action-buttons">
                                 <button class="primary-button" onclick={Callback::from(move |_| {
                                     web_sys::window().unwrap().location().set_href("/events").unwrap();
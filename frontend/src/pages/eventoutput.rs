@@ -1,8 +1,12 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
-use shared::dto::{EventResponse, EventListResponse};
-use crate::services::api;
+use web_sys::HtmlInputElement;
+use serde::{Deserialize, Serialize};
+use shared::dto::{EventListResponse, ChapterResponse, EventStatus, EventStatusRequest, SignInRequest};
+use crate::services::{ApiClient, ApiError};
 use crate::routing::Route;
+use crate::utils::{browser_time_zone, format_in_zone, format_local};
+use crate::wallet::sign_message;
 
 #[derive(PartialEq, Clone)]
 pub enum EventListState {
@@ -11,18 +15,83 @@ pub enum EventListState {
     Error(String),
 }
 
+/// Query string of the events page, e.g. `/events?chapter_id=3&status=Submitted`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EventsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chapter_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+pub fn event_status_label(status: EventStatus) -> &'static str {
+    match status {
+        EventStatus::Draft => "Draft",
+        EventStatus::Submitted => "Awaiting review",
+        EventStatus::Approved => "Approved",
+        EventStatus::Published => "Published",
+        EventStatus::Cancelled => "Cancelled",
+        EventStatus::Completed => "Completed",
+    }
+}
+
+/// Button text for moving an event to `status`.
+fn event_action_label(status: EventStatus) -> &'static str {
+    match status {
+        EventStatus::Draft => "Back to Draft",
+        EventStatus::Submitted => "Submit for Review",
+        EventStatus::Approved => "Approve",
+        EventStatus::Published => "Publish",
+        EventStatus::Cancelled => "Cancel Event",
+        EventStatus::Completed => "Mark Completed",
+    }
+}
+
 #[function_component(EventOutputPage)]
 pub fn event_output_page() -> Html {
     let state = use_state(|| EventListState::Loading);
+    let chapters = use_state(Vec::<ChapterResponse>::new);
+    let action_error = use_state(|| None::<String>);
+    let reload = use_state(|| 0u32);
+    // Wallet address of the signed-in user, who also sees their own drafts and can manage events
+    let signed_in = use_state(ApiClient::session_wallet);
+    let sign_in_wallet = use_state(String::new);
+    let navigator = use_navigator();
+    let query = use_location()
+        .and_then(|location| location.query::<EventsQuery>().ok())
+        .unwrap_or_default();
+    let chapter_filter = query.chapter_id.clone().filter(|id| !id.is_empty());
+    let status_filter = query.status.as_deref().and_then(EventStatus::from_name);
 
     {
-        let state = state.clone();
+        let chapters = chapters.clone();
         use_effect_with((), move |_| {
             wasm_bindgen_futures::spawn_local(async move {
-                match api::list_events(Some(50), Some(0)).await {
+                // The filter still works by id if chapters fail to load
+                if let Ok(response) = ApiClient::default().list_chapters().await {
+                    chapters.set(response.chapters);
+                }
+            });
+            || ()
+        });
+    }
+
+    {
+        let (state, signed_in) = (state.clone(), signed_in.clone());
+        use_effect_with((chapter_filter.clone(), (*signed_in).clone(), status_filter, *reload), move |deps| {
+            let (chapter_filter, _, status_filter, _) = deps.clone();
+            state.set(EventListState::Loading);
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = ApiClient::default();
+                match client.list_events(chapter_filter.as_deref(), status_filter, Some(50), Some(0)).await {
                     Ok(events) => {
                         state.set(EventListState::Loaded(events));
                     }
+                    // The session expired; list the public events instead
+                    Err(ApiError::Http { status: 401, .. }) => {
+                        ApiClient::clear_auth_token();
+                        signed_in.set(None);
+                    }
                     Err(e) => {
                         state.set(EventListState::Error(format!("Failed to load events: {}", e)));
                     }
@@ -32,15 +101,100 @@ pub fn event_output_page() -> Html {
         });
     }
 
-    let format_date = |date_str: &str| -> String {
-        // Simple date formatting - in a real app you'd use chrono
-        if let Ok(parsed) = chrono::DateTime::parse_from_rfc3339(date_str) {
-            parsed.format("%B %d, %Y at %I:%M %p").to_string()
-        } else {
-            date_str.to_string()
+    let viewer_zone = browser_time_zone();
+
+    // Each filter lives in the query string; changing one keeps the others
+    let on_filter_change = |update: fn(&mut EventsQuery, Option<String>)| {
+        let (navigator, query) = (navigator.clone(), query.clone());
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let value = input.value().trim().to_string();
+            let mut query = query.clone();
+            update(&mut query, if value.is_empty() { None } else { Some(value) });
+            if let Some(navigator) = &navigator {
+                let _ = navigator.push_with_query(&Route::EventOutput, &query);
+            }
+        })
+    };
+    let on_chapter_filter_change = on_filter_change(|query, value| query.chapter_id = value);
+    let on_status_filter_change = on_filter_change(|query, value| query.status = value);
+
+    let on_sign_in_wallet_change = {
+        let sign_in_wallet = sign_in_wallet.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            sign_in_wallet.set(input.value());
+        })
+    };
+
+    // Signing a nonce the backend issued proves the wallet is the user's
+    let on_sign_in = {
+        let (sign_in_wallet, signed_in, action_error) = (sign_in_wallet.clone(), signed_in.clone(), action_error.clone());
+        Callback::from(move |_: MouseEvent| {
+            let wallet_address = sign_in_wallet.trim().to_string();
+            let (signed_in, action_error) = (signed_in.clone(), action_error.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = ApiClient::default();
+                let challenge = match client.signin_challenge(&wallet_address).await {
+                    Ok(challenge) => challenge,
+                    Err(e) => return action_error.set(Some(format!("Sign-in failed: {}", e))),
+                };
+                let signature = match sign_message(&challenge.message, &wallet_address).await {
+                    Ok(signature) => signature,
+                    Err(e) => return action_error.set(Some(format!("Your wallet did not sign in: {}", e))),
+                };
+                let request = SignInRequest { wallet_address, nonce: challenge.nonce, signature };
+                match client.create_session(&request).await {
+                    Ok(session) => {
+                        action_error.set(None);
+                        signed_in.set(Some(session.user.wallet_address));
+                    }
+                    Err(e) => action_error.set(Some(format!("Sign-in failed: {}", e))),
+                }
+            });
+        })
+    };
+
+    let on_sign_out = {
+        let signed_in = signed_in.clone();
+        Callback::from(move |_: MouseEvent| {
+            ApiClient::clear_auth_token();
+            signed_in.set(None);
+        })
+    };
+
+    let on_status_action = {
+        let (action_error, reload) = (action_error.clone(), reload.clone());
+        move |event_id: String, status: EventStatus| {
+            let (action_error, reload) = (action_error.clone(), reload.clone());
+            Callback::from(move |_: MouseEvent| {
+                let reason = if status == EventStatus::Cancelled {
+                    match gloo::dialogs::prompt("Why is the event cancelled? The reason is shown on the event.", None) {
+                        Some(reason) => Some(reason),
+                        None => return,
+                    }
+                } else {
+                    None
+                };
+                let request = EventStatusRequest { status, reason };
+                let (event_id, action_error, reload) = (event_id.clone(), action_error.clone(), reload.clone());
+                wasm_bindgen_futures::spawn_local(async move {
+                    match ApiClient::default().update_event_status(&event_id, &request).await {
+                        Ok(_) => {
+                            action_error.set(None);
+                            reload.set(*reload + 1);
+                        }
+                        Err(e) => action_error.set(Some(e.to_string())),
+                    }
+                });
+            })
         }
     };
 
+    let chapter_name = |chapter_id: &str| -> Option<String> {
+        chapters.iter().find(|chapter| chapter.id == chapter_id).map(|chapter| chapter.name.clone())
+    };
+
     let get_event_type_color = |event_type: &shared::dto::EventType| -> &'static str {
         match event_type {
             shared::dto::EventType::Workshop => "#00d4ff",
@@ -59,6 +213,55 @@ pub fn event_output_page() -> Html {
                 <Link<Route> to={Route::EventForm} classes="create-event-button">
                     {"+ Create New Event"}
                 </Link<Route>>
+                <a href={ApiClient::default().calendar_subscription_url(chapter_filter.as_deref())} class="subscribe-button">
+                    {"📅 Subscribe to Calendar"}
+                </a>
+
+                <div class="events-filter">
+                    <label for="chapter-filter">{"Chapter"}</label>
+                    <select id="chapter-filter" onchange={on_chapter_filter_change}>
+                        <option value="" selected={chapter_filter.is_none()}>{"All chapters"}</option>
+                        {chapters.iter().map(|chapter| html! {
+                            <option
+                                value={chapter.id.clone()}
+                                selected={chapter_filter.as_deref() == Some(chapter.id.as_str())}
+                            >
+                                {format!("{} ({})", chapter.name, chapter.city)}
+                            </option>
+                        }).collect::<Html>()}
+                    </select>
+
+                    <label for="status-filter">{"Status"}</label>
+                    <select id="status-filter" onchange={on_status_filter_change}>
+                        <option value="" selected={status_filter.is_none()}>{"Listed events"}</option>
+                        {EventStatus::ALL.iter().map(|status| html! {
+                            <option value={status.to_string()} selected={status_filter == Some(*status)}>
+                                {event_status_label(*status)}
+                            </option>
+                        }).collect::<Html>()}
+                    </select>
+                </div>
+
+                <div class="events-filter">
+                    if let Some(wallet_address) = &*signed_in {
+                        <span class="signed-in-as">{format!("Signed in as {}", wallet_address)}</span>
+                        <button class="status-button" onclick={on_sign_out}>{"Sign out"}</button>
+                    } else {
+                        <label for="sign-in-wallet">{"My events"}</label>
+                        <input
+                            type="text"
+                            id="sign-in-wallet"
+                            value={(*sign_in_wallet).clone()}
+                            oninput={on_sign_in_wallet_change}
+                            placeholder="Your wallet address, to see your drafts and manage events"
+                        />
+                        <button class="status-button" onclick={on_sign_in}>{"Sign in with wallet"}</button>
+                    }
+                </div>
+
+                if let Some(error) = &*action_error {
+                    <div class="event-action-error">{error}</div>
+                }
             </div>
 
             {match &*state {
@@ -86,8 +289,17 @@ pub fn event_output_page() -> Html {
                             <div class="events-grid">
                                 {response.events.iter().map(|event| {
                                     let event_color = get_event_type_color(&event.event_type);
+                                    let is_listed = !matches!(event.status, EventStatus::Draft | EventStatus::Submitted);
                                     html! {
-                                        <div class="event-card" key={event.id.clone()}>
+                                        <div class={classes!("event-card", (event.status == EventStatus::Cancelled).then_some("event-card--cancelled"))} key={event.id.clone()}>
+                                            if event.status == EventStatus::Cancelled {
+                                                <div class="event-cancelled-banner">
+                                                    <strong>{"Cancelled"}</strong>
+                                                    if let Some(reason) = &event.cancellation_reason {
+                                                        {format!(" – {}", reason)}
+                                                    }
+                                                </div>
+                                            }
                                             <div class="event-header">
                                                 <span
                                                     class="event-type-badge"
@@ -95,8 +307,11 @@ pub fn event_output_page() -> Html {
                                                 >
                                                     {format!("{:?}", event.event_type)}
                                                 </span>
+                                                if !matches!(event.status, EventStatus::Published | EventStatus::Cancelled) {
+                                                    <span class="event-status-badge">{event_status_label(event.status)}</span>
+                                                }
                                                 <span class="event-date">
-                                                    {format_date(&event.date)}
+                                                    {format_in_zone(&event.date, &event.timezone)}
                                                 </span>
                                             </div>
 
@@ -104,6 +319,26 @@ pub fn event_output_page() -> Html {
                                             <p class="event-description">{&event.description}</p>
 
                                             <div class="event-details">
+                                                <div class="detail-item">
+                                                    <span class="detail-icon">{"🕒"}</span>
+                                                    <span class="detail-text">
+                                                        {format!("Until {} ({})", format_in_zone(&event.end_date, &event.timezone), event.timezone)}
+                                                    </span>
+                                                </div>
+
+                                                {if event.timezone != viewer_zone {
+                                                    html! {
+                                                        <div class="detail-item">
+                                                            <span class="detail-icon">{"🌍"}</span>
+                                                            <span class="detail-text">
+                                                                {format!("Your time: {} – {}", format_local(&event.date), format_local(&event.end_date))}
+                                                            </span>
+                                                        </div>
+                                                    }
+                                                } else {
+                                                    html! {}
+                                                }}
+
                                                 <div class="detail-item">
                                                     <span class="detail-icon">{"📍"}</span>
                                                     <span class="detail-text">{&event.location}</span>
@@ -114,6 +349,19 @@ pub fn event_output_page() -> Html {
                                                     <span class="detail-text">{"Organized by "}{&event.organizer}</span>
                                                 </div>
 
+                                                {if let Some(chapter_id) = &event.chapter_id {
+                                                    html! {
+                                                        <div class="detail-item">
+                                                            <span class="detail-icon">{"🏛️"}</span>
+                                                            <Link<Route> to={Route::ChapterDetail { id: chapter_id.clone() }} classes="detail-text chapter-link">
+                                                                {chapter_name(chapter_id).unwrap_or_else(|| "Chapter".to_string())}
+                                                            </Link<Route>>
+                                                        </div>
+                                                    }
+                                                } else {
+                                                    html! {}
+                                                }}
+
                                                 {if let Some(max_participants) = event.max_participants {
                                                     html! {
                                                         <div class="detail-item">
@@ -142,6 +390,31 @@ pub fn event_output_page() -> Html {
                                                     {"Contact Organizer"}
                                                 </a>
 
+                                                if is_listed {
+                                                    <a href={ApiClient::default().event_calendar_url(&event.id)} class="calendar-button">
+                                                        {"📅 Add to Calendar"}
+                                                    </a>
+                                                }
+
+                                                <Link<Route> to={Route::EventBudget { id: event.id.clone() }} classes="calendar-button">
+                                                    {"💰 Budget"}
+                                                </Link<Route>>
+
+                                                if event.status == EventStatus::Published {
+                                                    <Link<Route> to={Route::EventRegistration { id: event.id.clone() }} classes="calendar-button">
+                                                        {"✍️ Register"}
+                                                    </Link<Route>>
+                                                }
+
+                                                if is_listed {
+                                                    <Link<Route> to={Route::EventAccounts { id: event.id.clone() }} classes="calendar-button">
+                                                        {"🔗 Accounts"}
+                                                    </Link<Route>>
+                                                    <Link<Route> to={Route::EventAttendance { id: event.id.clone() }} classes="calendar-button">
+                                                        {"🎟️ Attendance"}
+                                                    </Link<Route>>
+                                                }
+
                                                 {if let Some(ref external_link) = event.external_link {
                                                     html! {
                                                         <a href={external_link.clone()} target="_blank" class="external-button">
@@ -152,6 +425,19 @@ pub fn event_output_page() -> Html {
                                                     html! {}
                                                 }}
                                             </div>
+
+                                            if signed_in.is_some() && !event.status.next_statuses().is_empty() {
+                                                <div class="event-status-actions">
+                                                    {event.status.next_statuses().iter().map(|next| html! {
+                                                        <button
+                                                            class={classes!("status-button", (*next == EventStatus::Cancelled).then_some("status-button--cancel"))}
+                                                            onclick={on_status_action(event.id.clone(), *next)}
+                                                        >
+                                                            {event_action_label(*next)}
+                                                        </button>
+                                                    }).collect::<Html>()}
+                                                </div>
+                                            }
                                         </div>
                                     }
                                 }).collect::<Html>()}
@@ -0,0 +1,250 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use web_sys::HtmlInputElement;
+use shared::dto::{EventRegistrationsResponse, EventStatus, RegistrationRequest, RegistrationResponse, RegistrationStatus};
+use crate::pages::event_status_label;
+use crate::routing::Route;
+use crate::services::ApiClient;
+use crate::utils::format_in_zone;
+
+#[derive(PartialEq, Clone)]
+pub enum EventRegistrationState {
+    Loading,
+    Loaded(Box<EventRegistrationsResponse>),
+    Error(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct EventRegistrationPageProps {
+    pub id: String,
+}
+
+/// Members register for a published event; once it is full they join the waitlist
+/// and are promoted in order as places free up.
+#[function_component(EventRegistrationPage)]
+pub fn event_registration_page(props: &EventRegistrationPageProps) -> Html {
+    let state = use_state(|| EventRegistrationState::Loading);
+    let wallet_address = use_state(String::new);
+    let action_error = use_state(|| None::<String>);
+    let busy = use_state(|| false);
+
+    let reload = {
+        let (id, state) = (props.id.clone(), state.clone());
+        move || {
+            let (id, state) = (id.clone(), state.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().list_registrations(&id).await {
+                    Ok(registrations) => state.set(EventRegistrationState::Loaded(Box::new(registrations))),
+                    Err(e) => state.set(EventRegistrationState::Error(format!("Failed to load registrations: {}", e))),
+                }
+            });
+        }
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with(props.id.clone(), move |_| {
+            reload();
+            || ()
+        });
+    }
+
+    let on_wallet_change = {
+        let wallet_address = wallet_address.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            wallet_address.set(input.value().trim().to_string());
+        })
+    };
+
+    let on_register = {
+        let id = props.id.clone();
+        let (wallet_address, action_error, busy, reload) =
+            (wallet_address.clone(), action_error.clone(), busy.clone(), reload.clone());
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let request = RegistrationRequest { wallet_address: (*wallet_address).clone() };
+            let id = id.clone();
+            let (action_error, busy, reload) = (action_error.clone(), busy.clone(), reload.clone());
+            busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().register_for_event(&id, &request).await {
+                    Ok(_) => {
+                        action_error.set(None);
+                        reload();
+                    }
+                    Err(e) => action_error.set(Some(e.to_string())),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_cancel = {
+        let id = props.id.clone();
+        let (wallet_address, action_error, reload) = (wallet_address.clone(), action_error.clone(), reload.clone());
+        Callback::from(move |registration_id: String| {
+            let (id, wallet_address) = (id.clone(), (*wallet_address).clone());
+            let (action_error, reload) = (action_error.clone(), reload.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().cancel_registration(&id, &registration_id, &wallet_address).await {
+                    Ok(_) => {
+                        action_error.set(None);
+                        reload();
+                    }
+                    Err(e) => action_error.set(Some(e.to_string())),
+                }
+            });
+        })
+    };
+
+    let content = match &*state {
+        EventRegistrationState::Loading => html! {
+            <div class="loading-container">
+                <div class="spinner"></div>
+                <h2>{"Loading registrations..."}</h2>
+            </div>
+        },
+        EventRegistrationState::Error(error) => html! {
+            <div class="error-container">
+                <div class="error-icon">{"⚠️"}</div>
+                <h2>{"Registrations Unavailable"}</h2>
+                <p>{error}</p>
+                <Link<Route> to={Route::EventOutput} classes="primary-button">{"Back to Events"}</Link<Route>>
+            </div>
+        },
+        EventRegistrationState::Loaded(registrations) => {
+            let event = &registrations.event;
+            let open = event.status == EventStatus::Published;
+            let full = registrations.waitlisted > 0 || event.max_participants.is_some_and(|max| registrations.registered >= max);
+            let (registered, waitlisted): (Vec<_>, Vec<_>) = registrations
+                .registrations
+                .iter()
+                .partition(|registration| registration.status == RegistrationStatus::Registered);
+
+            html! {
+                <>
+                    <div class="chapters-header">
+                        <Link<Route> to={Route::EventOutput} classes="back-link">{"← Events"}</Link<Route>>
+                        <h1 class="page-title">{&event.title}</h1>
+                        <p class="page-subtitle">
+                            {format!(
+                                "{} ({}) · {} · {}",
+                                format_in_zone(&event.date, &event.timezone),
+                                event.timezone,
+                                event.location,
+                                event_status_label(event.status)
+                            )}
+                        </p>
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <p class="form-help">
+                            {match event.max_participants {
+                                Some(max) => format!(
+                                    "{} of {} places taken, {} on the waitlist.",
+                                    registrations.registered, max, registrations.waitlisted
+                                ),
+                                None => format!("{} registered; there is no limit on places.", registrations.registered),
+                            }}
+                        </p>
+
+                        <form class="budget-item-form" onsubmit={on_register}>
+                            <input
+                                type="text"
+                                placeholder="Your wallet address"
+                                value={(*wallet_address).clone()}
+                                onchange={on_wallet_change}
+                            />
+                            if open {
+                                <button type="submit" class="primary-button" disabled={*busy || wallet_address.is_empty()}>
+                                    {if full { "Join Waitlist" } else { "Register" }}
+                                </button>
+                            }
+                        </form>
+                        <p class="form-help">
+                            {if open {
+                                "Attendees and the organizer can cancel a registration with their wallet address."
+                            } else {
+                                "Registration is open while the event is published."
+                            }}
+                        </p>
+                        if let Some(error) = &*action_error {
+                            <div class="form-error">{error}</div>
+                        }
+
+                        <h2>{format!("Registered ({})", registered.len())}</h2>
+                        if registered.is_empty() {
+                            <p class="form-help">{"Nobody has registered yet."}</p>
+                        }
+                        <ul class="member-list">
+                            {registered.iter().map(|registration| html! {
+                                <RegistrationRow key={registration.id.clone()} registration={(*registration).clone()} on_cancel={on_cancel.clone()} />
+                            }).collect::<Html>()}
+                        </ul>
+
+                        if !waitlisted.is_empty() {
+                            <h2>{format!("Waitlist ({})", waitlisted.len())}</h2>
+                            <ol class="member-list">
+                                {waitlisted.iter().map(|registration| html! {
+                                    <RegistrationRow key={registration.id.clone()} registration={(*registration).clone()} on_cancel={on_cancel.clone()} />
+                                }).collect::<Html>()}
+                            </ol>
+                        }
+                    </div>
+                </>
+            }
+        }
+    };
+
+    html! {
+        <div class="chapters-container">
+            {content}
+
+            <style>
+                {include_str!("chapterspage.css")}
+                {include_str!("eventbudgetpage.css")}
+            </style>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct RegistrationRowProps {
+    registration: RegistrationResponse,
+    on_cancel: Callback<String>,
+}
+
+#[function_component(RegistrationRow)]
+fn registration_row(props: &RegistrationRowProps) -> Html {
+    let registration = &props.registration;
+    let on_cancel = {
+        let (id, on_cancel) = (registration.id.clone(), props.on_cancel.clone());
+        Callback::from(move |_: MouseEvent| on_cancel.emit(id.clone()))
+    };
+
+    html! {
+        <li class="member-item budget-item">
+            <div>
+                <strong>{&registration.attendee.username}</strong>
+                <small class="member-wallet">
+                    {match registration.promoted_at {
+                        Some(promoted_at) => format!(
+                            "{} · off the waitlist {}",
+                            registration.attendee.wallet_address,
+                            promoted_at.format("%-d %b %Y %H:%M")
+                        ),
+                        None => format!(
+                            "{} · joined {}",
+                            registration.attendee.wallet_address,
+                            registration.registered_at.format("%-d %b %Y %H:%M")
+                        ),
+                    }}
+                </small>
+            </div>
+            <div class="budget-item-actions">
+                <button class="link-button" onclick={on_cancel}>{"Cancel"}</button>
+            </div>
+        </li>
+    }
+}
\ No newline at end of file
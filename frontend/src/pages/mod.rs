@@ -3,9 +3,33 @@ pub mod signuppage;
 pub mod aboutpage;
 pub mod eventform;
 pub mod eventoutput;
+pub mod chapterspage;
+pub mod chapterdetailpage;
+pub mod chapterdashboardpage;
+pub mod planspage;
+pub mod planreviewpage;
+pub mod eventbudgetpage;
+pub mod eventaccountspage;
+pub mod eventattendancepage;
+pub mod eventregistrationpage;
+pub mod profilepage;
+pub mod verifyemailpage;
+pub mod notificationspage;
 
 pub use homepage::*;
 pub use signuppage::*;
 pub use aboutpage::*;
 pub use eventform::*;
-pub use eventoutput::*;
\ No newline at end of file
+pub use eventoutput::*;
+pub use chapterspage::*;
+pub use chapterdetailpage::*;
+pub use chapterdashboardpage::*;
+pub use planspage::*;
+pub use planreviewpage::*;
+pub use eventbudgetpage::*;
+pub use eventaccountspage::*;
+pub use eventattendancepage::*;
+pub use eventregistrationpage::*;
+pub use profilepage::*;
+pub use verifyemailpage::*;
+pub use notificationspage::*;
\ No newline at end of file
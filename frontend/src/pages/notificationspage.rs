@@ -0,0 +1,297 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use web_sys::HtmlInputElement;
+use shared::dto::{
+    NotificationKind, NotificationListResponse, NotificationPreference, NotificationPreferencesRequest,
+    NotificationPreferencesResponse, NotificationResponse, NOTIFICATION_LOCALES,
+};
+use crate::routing::Route;
+use crate::services::ApiClient;
+
+#[derive(PartialEq, Clone)]
+pub enum NotificationsState {
+    Idle,
+    Loading,
+    Loaded(Box<NotificationListResponse>),
+    Error(String),
+}
+
+pub fn notification_kind_label(kind: NotificationKind) -> &'static str {
+    match kind {
+        NotificationKind::RegistrationConfirmed => "Registration confirmed",
+        NotificationKind::WaitlistPromoted => "Off the waitlist",
+        NotificationKind::EventUpdated => "Event changed",
+        NotificationKind::EventCancelled => "Event cancelled",
+        NotificationKind::EventReminder => "Event coming up",
+        NotificationKind::PlanApproved => "Quarterly plan approved",
+        NotificationKind::ExpensePaid => "Expense paid",
+    }
+}
+
+/// The signed-in member's inbox, and how and in which language they want to be told
+/// about each kind of notification.
+#[function_component(NotificationsPage)]
+pub fn notifications_page() -> Html {
+    let state = use_state(|| NotificationsState::Idle);
+    let preferences = use_state(|| None::<NotificationPreferencesResponse>);
+    let wallet_address = use_state(|| ApiClient::session_wallet().unwrap_or_default());
+    let action_error = use_state(|| None::<String>);
+    let saved = use_state(|| false);
+
+    {
+        let (state, preferences) = (state.clone(), preferences.clone());
+        use_effect_with((*wallet_address).clone(), move |wallet_address| {
+            if !wallet_address.is_empty() {
+                let wallet_address = wallet_address.clone();
+                state.set(NotificationsState::Loading);
+                wasm_bindgen_futures::spawn_local(async move {
+                    let client = ApiClient::default();
+                    match client.list_notifications(&wallet_address, false).await {
+                        Ok(inbox) => state.set(NotificationsState::Loaded(Box::new(inbox))),
+                        Err(e) => {
+                            state.set(NotificationsState::Error(format!("Failed to load notifications: {}", e)));
+                            return;
+                        }
+                    }
+                    preferences.set(client.get_notification_preferences(&wallet_address).await.ok());
+                });
+            }
+            || ()
+        });
+    }
+
+    let on_mark_read = {
+        let (state, wallet_address, action_error) = (state.clone(), wallet_address.clone(), action_error.clone());
+        Callback::from(move |notification_ids: Vec<String>| {
+            let (state, wallet_address, action_error) = (state.clone(), (*wallet_address).clone(), action_error.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().mark_notifications_read(&wallet_address, notification_ids).await {
+                    Ok(inbox) => {
+                        action_error.set(None);
+                        state.set(NotificationsState::Loaded(Box::new(inbox)));
+                    }
+                    Err(e) => action_error.set(Some(e.to_string())),
+                }
+            });
+        })
+    };
+
+    let on_save_preferences = {
+        let (preferences, wallet_address, action_error, saved) =
+            (preferences.clone(), wallet_address.clone(), action_error.clone(), saved.clone());
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let Some(current) = (*preferences).clone() else { return };
+            let request = NotificationPreferencesRequest { locale: Some(current.locale), preferences: current.preferences };
+            let (preferences, wallet_address) = (preferences.clone(), (*wallet_address).clone());
+            let (action_error, saved) = (action_error.clone(), saved.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().update_notification_preferences(&wallet_address, &request).await {
+                    Ok(updated) => {
+                        action_error.set(None);
+                        preferences.set(Some(updated));
+                        saved.set(true);
+                    }
+                    Err(e) => action_error.set(Some(e.to_string())),
+                }
+            });
+        })
+    };
+
+    let on_preference_change = {
+        let (preferences, saved) = (preferences.clone(), saved.clone());
+        Callback::from(move |changed: NotificationPreference| {
+            let Some(mut current) = (*preferences).clone() else { return };
+            for preference in current.preferences.iter_mut().filter(|preference| preference.kind == changed.kind) {
+                *preference = changed;
+            }
+            preferences.set(Some(current));
+            saved.set(false);
+        })
+    };
+
+    let on_locale_change = {
+        let (preferences, saved) = (preferences.clone(), saved.clone());
+        Callback::from(move |e: Event| {
+            let select: HtmlInputElement = e.target_unchecked_into();
+            let Some(current) = (*preferences).clone() else { return };
+            preferences.set(Some(NotificationPreferencesResponse { locale: select.value(), ..current }));
+            saved.set(false);
+        })
+    };
+
+    let content = match &*state {
+        NotificationsState::Idle => html! {
+            <div class="chapter-form-card">
+                <p class="form-help">
+                    {"Sign in with your wallet on the "}
+                    <Link<Route> to={Route::EventOutput}>{"events page"}</Link<Route>>
+                    {" to see your notifications."}
+                </p>
+            </div>
+        },
+        NotificationsState::Loading => html! {
+            <div class="loading-container">
+                <div class="spinner"></div>
+                <h2>{"Loading notifications..."}</h2>
+            </div>
+        },
+        NotificationsState::Error(error) => html! {
+            <div class="form-error">{error}</div>
+        },
+        NotificationsState::Loaded(inbox) => {
+            let on_mark_all_read = {
+                let on_mark_read = on_mark_read.clone();
+                Callback::from(move |_: MouseEvent| on_mark_read.emit(Vec::new()))
+            };
+
+            html! {
+                <>
+                    <div class="chapter-form-card">
+                        <h2>{format!("Inbox ({} unread)", inbox.unread)}</h2>
+                        if let Some(error) = &*action_error {
+                            <div class="form-error">{error}</div>
+                        }
+                        if inbox.notifications.is_empty() {
+                            <p class="form-help">{"Nothing here yet."}</p>
+                        }
+                        <ul class="member-list">
+                            {inbox.notifications.iter().map(|notification| html! {
+                                <NotificationRow
+                                    key={notification.id.clone()}
+                                    notification={notification.clone()}
+                                    on_mark_read={on_mark_read.clone()}
+                                />
+                            }).collect::<Html>()}
+                        </ul>
+                        if inbox.unread > 0 {
+                            <button class="secondary-button" onclick={on_mark_all_read}>{"Mark All Read"}</button>
+                        }
+                    </div>
+
+                    if let Some(current) = &*preferences {
+                        <div class="chapter-form-card">
+                            <h2>{"Preferences"}</h2>
+                            <p class="form-help">{"Emails only go to a verified address."}</p>
+                            <form onsubmit={on_save_preferences}>
+                                <div class="form-group">
+                                    <label for="notification-locale">{"Language"}</label>
+                                    <select id="notification-locale" onchange={on_locale_change}>
+                                        {NOTIFICATION_LOCALES.iter().map(|(code, name)| html! {
+                                            <option value={*code} selected={current.locale == *code}>{*name}</option>
+                                        }).collect::<Html>()}
+                                    </select>
+                                </div>
+                                <ul class="member-list">
+                                    {current.preferences.iter().map(|preference| html! {
+                                        <PreferenceRow
+                                            key={preference.kind.to_string()}
+                                            preference={*preference}
+                                            on_change={on_preference_change.clone()}
+                                        />
+                                    }).collect::<Html>()}
+                                </ul>
+                                <button type="submit" class="primary-button">{"Save Preferences"}</button>
+                                if *saved {
+                                    <p class="form-help">{"Saved."}</p>
+                                }
+                            </form>
+                        </div>
+                    }
+                </>
+            }
+        }
+    };
+
+    html! {
+        <div class="chapters-container">
+            <div class="chapters-header">
+                <h1 class="page-title">{"Notifications"}</h1>
+                <p class="page-subtitle">{"Registrations, event changes, plan approvals and payouts"}</p>
+            </div>
+
+            {content}
+
+            <style>
+                {include_str!("chapterspage.css")}
+                {include_str!("eventbudgetpage.css")}
+            </style>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct NotificationRowProps {
+    notification: NotificationResponse,
+    on_mark_read: Callback<Vec<String>>,
+}
+
+#[function_component(NotificationRow)]
+fn notification_row(props: &NotificationRowProps) -> Html {
+    let notification = &props.notification;
+    let on_mark_read = {
+        let (id, on_mark_read) = (notification.id.clone(), props.on_mark_read.clone());
+        Callback::from(move |_: MouseEvent| on_mark_read.emit(vec![id.clone()]))
+    };
+    let unread = notification.read_at.is_none();
+
+    html! {
+        <li class="member-item budget-item">
+            <div>
+                if unread {
+                    <strong>{&notification.subject}</strong>
+                } else {
+                    <span>{&notification.subject}</span>
+                }
+                <small class="member-wallet">
+                    {format!("{} · {}", notification_kind_label(notification.kind), notification.created_at.format("%-d %b %Y %H:%M"))}
+                </small>
+                <p class="form-help" style="white-space: pre-line;">{&notification.body}</p>
+                if let Some(link) = &notification.link {
+                    <a href={link.clone()}>{"Open"}</a>
+                }
+            </div>
+            <div class="budget-item-actions">
+                if unread {
+                    <button class="link-button" onclick={on_mark_read}>{"Mark Read"}</button>
+                }
+            </div>
+        </li>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct PreferenceRowProps {
+    preference: NotificationPreference,
+    on_change: Callback<NotificationPreference>,
+}
+
+#[function_component(PreferenceRow)]
+fn preference_row(props: &PreferenceRowProps) -> Html {
+    let preference = props.preference;
+    let toggle = |update: fn(&mut NotificationPreference, bool)| {
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut changed = preference;
+            update(&mut changed, input.checked());
+            on_change.emit(changed);
+        })
+    };
+
+    html! {
+        <li class="member-item budget-item">
+            <strong>{notification_kind_label(preference.kind)}</strong>
+            <div class="budget-item-actions">
+                <label class="checkbox-label">
+                    <input type="checkbox" checked={preference.in_app} onchange={toggle(|p, on| p.in_app = on)} />
+                    {"In app"}
+                </label>
+                <label class="checkbox-label">
+                    <input type="checkbox" checked={preference.email} onchange={toggle(|p, on| p.email = on)} />
+                    {"Email"}
+                </label>
+            </div>
+        </li>
+    }
+}
\ No newline at end of file
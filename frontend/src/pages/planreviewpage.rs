@@ -0,0 +1,424 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use web_sys::HtmlInputElement;
+use shared::dto::{
+    EventResponse, PlanAction, PlanCommentRequest, PlanTransitionRequest, QuarterlyPlanRequest, QuarterlyPlanResponse,
+};
+use crate::components::BudgetTotals;
+use crate::pages::{plan_status_class, plan_status_label};
+use crate::routing::Route;
+use crate::services::{ApiClient, ApiError};
+use crate::utils::{format_in_zone, format_local};
+
+#[derive(PartialEq, Clone)]
+pub enum PlanReviewState {
+    Loading,
+    Loaded(Box<QuarterlyPlanResponse>),
+    Error(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PlanReviewPageProps {
+    pub id: String,
+}
+
+fn action_label(action: PlanAction) -> &'static str {
+    match action {
+        PlanAction::Submit => "Submit for Review",
+        PlanAction::Approve => "Approve",
+        PlanAction::RequestChanges => "Request Changes",
+    }
+}
+
+fn error_message(error: &ApiError, fields: &[&str]) -> String {
+    fields.iter()
+        .find_map(|field| error.field_error(field))
+        .map(str::to_string)
+        .unwrap_or_else(|| error.to_string())
+}
+
+#[function_component(PlanReviewPage)]
+pub fn plan_review_page(props: &PlanReviewPageProps) -> Html {
+    let state = use_state(|| PlanReviewState::Loading);
+    let review_comment = use_state(String::new);
+    let new_comment = use_state(String::new);
+    let action_error = use_state(|| None::<String>);
+    let comment_error = use_state(|| None::<String>);
+    let busy = use_state(|| false);
+
+    // Editing the summary and picking events, while the plan is editable
+    let editing = use_state(|| false);
+    let edit_purpose = use_state(String::new);
+    let edit_goals = use_state(String::new);
+    let selected_events = use_state(Vec::<String>::new);
+    let candidate_events = use_state(Vec::<EventResponse>::new);
+    let edit_error = use_state(|| None::<String>);
+
+    let reload = {
+        let state = state.clone();
+        let id = props.id.clone();
+        Callback::from(move |_: ()| {
+            let state = state.clone();
+            let id = id.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().get_plan(&id).await {
+                    Ok(plan) => state.set(PlanReviewState::Loaded(Box::new(plan))),
+                    Err(e) => state.set(PlanReviewState::Error(format!("Failed to load plan: {}", e))),
+                }
+            });
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with(props.id.clone(), move |_| {
+            reload.emit(());
+            || ()
+        });
+    }
+
+    let on_input = |field: UseStateHandle<String>| {
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            field.set(input.value());
+        })
+    };
+
+    let content = match &*state {
+        PlanReviewState::Loading => html! {
+            <div class="loading-container">
+                <div class="spinner"></div>
+                <h2>{"Loading plan..."}</h2>
+            </div>
+        },
+        PlanReviewState::Error(error) => html! {
+            <div class="error-container">
+                <div class="error-icon">{"⚠️"}</div>
+                <h2>{"Plan Unavailable"}</h2>
+                <p>{error}</p>
+                <Link<Route> to={Route::Plans} classes="primary-button">{"Back to Plans"}</Link<Route>>
+            </div>
+        },
+        PlanReviewState::Loaded(plan) => {
+            let on_start_edit = {
+                let plan = plan.clone();
+                let (editing, edit_purpose, edit_goals, selected_events, candidate_events, edit_error) = (
+                    editing.clone(),
+                    edit_purpose.clone(),
+                    edit_goals.clone(),
+                    selected_events.clone(),
+                    candidate_events.clone(),
+                    edit_error.clone(),
+                );
+                Callback::from(move |_: MouseEvent| {
+                    edit_purpose.set(plan.strategic_purpose.clone());
+                    edit_goals.set(plan.quarterly_goals.clone());
+                    selected_events.set(plan.events.iter().map(|event| event.id.clone()).collect());
+                    edit_error.set(None);
+                    editing.set(true);
+
+                    // Offer every chapter event starting in the plan's quarter
+                    let plan = plan.clone();
+                    let candidate_events = candidate_events.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let period = Some((plan.year, plan.quarter));
+                        if let Ok(dashboard) = ApiClient::default().chapter_dashboard(&plan.chapter_id, period).await {
+                            candidate_events.set(dashboard.events);
+                        }
+                    });
+                })
+            };
+
+            let on_toggle_event = |event_id: String| {
+                let selected_events = selected_events.clone();
+                Callback::from(move |_: Event| {
+                    let mut selected = (*selected_events).clone();
+                    match selected.iter().position(|id| *id == event_id) {
+                        Some(index) => {
+                            selected.remove(index);
+                        }
+                        None => selected.push(event_id.clone()),
+                    }
+                    selected_events.set(selected);
+                })
+            };
+
+            let on_save = {
+                let plan = plan.clone();
+                let (editing, edit_purpose, edit_goals, selected_events, edit_error, busy, reload) = (
+                    editing.clone(),
+                    edit_purpose.clone(),
+                    edit_goals.clone(),
+                    selected_events.clone(),
+                    edit_error.clone(),
+                    busy.clone(),
+                    reload.clone(),
+                );
+                Callback::from(move |e: SubmitEvent| {
+                    e.prevent_default();
+                    let request = QuarterlyPlanRequest {
+                        chapter_id: plan.chapter_id.clone(),
+                        year: plan.year,
+                        quarter: plan.quarter,
+                        strategic_purpose: edit_purpose.trim().to_string(),
+                        quarterly_goals: edit_goals.trim().to_string(),
+                        event_ids: (*selected_events).clone(),
+                    };
+                    let plan_id = plan.id.clone();
+                    let (editing, edit_error, busy, reload) = (editing.clone(), edit_error.clone(), busy.clone(), reload.clone());
+                    busy.set(true);
+                    wasm_bindgen_futures::spawn_local(async move {
+                        match ApiClient::default().update_plan(&plan_id, &request).await {
+                            Ok(_) => {
+                                editing.set(false);
+                                reload.emit(());
+                            }
+                            Err(e) => edit_error.set(Some(error_message(&e, &["strategic_purpose", "quarterly_goals", "event_ids"]))),
+                        }
+                        busy.set(false);
+                    });
+                })
+            };
+
+            let on_action = |action: PlanAction| {
+                let plan_id = plan.id.clone();
+                let (review_comment, action_error, busy, reload) =
+                    (review_comment.clone(), action_error.clone(), busy.clone(), reload.clone());
+                Callback::from(move |_: MouseEvent| {
+                    let comment = review_comment.trim().to_string();
+                    let request = PlanTransitionRequest {
+                        action,
+                        comment: if comment.is_empty() { None } else { Some(comment) },
+                    };
+                    let plan_id = plan_id.clone();
+                    let (review_comment, action_error, busy, reload) =
+                        (review_comment.clone(), action_error.clone(), busy.clone(), reload.clone());
+                    busy.set(true);
+                    wasm_bindgen_futures::spawn_local(async move {
+                        match ApiClient::default().transition_plan(&plan_id, &request).await {
+                            Ok(_) => {
+                                review_comment.set(String::new());
+                                action_error.set(None);
+                                reload.emit(());
+                            }
+                            Err(e) => action_error.set(Some(error_message(&e, &["comment", "event_ids"]))),
+                        }
+                        busy.set(false);
+                    });
+                })
+            };
+
+            let on_comment = {
+                let plan_id = plan.id.clone();
+                let (new_comment, comment_error, reload) = (new_comment.clone(), comment_error.clone(), reload.clone());
+                Callback::from(move |e: SubmitEvent| {
+                    e.prevent_default();
+                    let request = PlanCommentRequest { body: new_comment.trim().to_string() };
+                    let plan_id = plan_id.clone();
+                    let (new_comment, comment_error, reload) = (new_comment.clone(), comment_error.clone(), reload.clone());
+                    wasm_bindgen_futures::spawn_local(async move {
+                        match ApiClient::default().add_plan_comment(&plan_id, &request).await {
+                            Ok(_) => {
+                                new_comment.set(String::new());
+                                comment_error.set(None);
+                                reload.emit(());
+                            }
+                            Err(e) => comment_error.set(Some(error_message(&e, &["body"]))),
+                        }
+                    });
+                })
+            };
+
+            let actions = plan.status.allowed_actions();
+
+            html! {
+                <>
+                    <div class="chapters-header">
+                        <Link<Route> to={Route::Plans} classes="back-link">{"← All plans"}</Link<Route>>
+                        <h1 class="page-title">{format!("{} · Q{} {}", plan.chapter_name, plan.quarter, plan.year)}</h1>
+                        <span class={plan_status_class(plan.status)}>{plan_status_label(plan.status)}</span>
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <div class="dashboard-section-header">
+                            <h2>{"Plan Summary"}</h2>
+                            if plan.status.is_editable() && !*editing {
+                                <button class="secondary-button" onclick={on_start_edit}>{"Edit Plan"}</button>
+                            }
+                        </div>
+
+                        if *editing {
+                            <form class="chapter-form" onsubmit={on_save}>
+                                if let Some(error) = &*edit_error {
+                                    <div class="form-error">{error}</div>
+                                }
+                                <div class="form-group">
+                                    <label for="edit-purpose">{"Strategic Purpose *"}</label>
+                                    <textarea id="edit-purpose" rows="3" value={(*edit_purpose).clone()} onchange={on_input(edit_purpose.clone())}></textarea>
+                                </div>
+                                <div class="form-group">
+                                    <label for="edit-goals">{"Quarterly Goals *"}</label>
+                                    <textarea id="edit-goals" rows="3" value={(*edit_goals).clone()} onchange={on_input(edit_goals.clone())}></textarea>
+                                </div>
+                                <div class="form-group">
+                                    <label>{"Events in this plan"}</label>
+                                    if candidate_events.is_empty() {
+                                        <small class="form-help">{"The chapter has no events starting in this quarter yet."}</small>
+                                    }
+                                    {candidate_events.iter().map(|event| html! {
+                                        <label class="plan-event-option">
+                                            <input
+                                                type="checkbox"
+                                                checked={selected_events.contains(&event.id)}
+                                                onchange={on_toggle_event(event.id.clone())}
+                                            />
+                                            {format!("{} — {}", event.title, format_in_zone(&event.date, &event.timezone))}
+                                        </label>
+                                    }).collect::<Html>()}
+                                </div>
+                                <div class="form-actions">
+                                    <button type="button" class="secondary-button" onclick={let editing = editing.clone(); move |_| editing.set(false)}>
+                                        {"Cancel"}
+                                    </button>
+                                    <button type="submit" class="primary-button" disabled={*busy}>{"Save Plan"}</button>
+                                </div>
+                            </form>
+                        } else {
+                            <p class="plan-text-label">{"Strategic purpose"}</p>
+                            <p class="plan-text">{&plan.strategic_purpose}</p>
+                            <p class="plan-text-label">{"Quarterly goals"}</p>
+                            <p class="plan-text">{&plan.quarterly_goals}</p>
+                            <p class="plan-text-label">{"Focus areas"}</p>
+                            <div class="focus-area-list">
+                                if plan.focus_areas.is_empty() {
+                                    <small class="form-help">{"No focus areas yet: add events to the plan."}</small>
+                                }
+                                {plan.focus_areas.iter().map(|summary| html! {
+                                    <span class="focus-area-chip">{format!("{:?} · {} events", summary.focus_area, summary.event_count)}</span>
+                                }).collect::<Html>()}
+                            </div>
+                        }
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <h2>{format!("Planned Events ({})", plan.events.len())}</h2>
+                        <ul class="member-list">
+                            {plan.events.iter().map(|event| html! {
+                                <li class="member-item" key={event.id.clone()}>
+                                    <div>
+                                        <strong>{&event.title}</strong>
+                                        <small class="member-wallet">
+                                            {format!("{:?} · {} · {}", event.event_type, format_in_zone(&event.date, &event.timezone), event.location)}
+                                        </small>
+                                        <small class="member-wallet">
+                                            {event.strategic_focus_areas.iter().map(|area| format!("{:?}", area)).collect::<Vec<_>>().join(", ")}
+                                        </small>
+                                    </div>
+                                    <span class="member-type">
+                                        {event.kpi_estimates.expected_attendance.map(|n| format!("👥 {} expected", n)).unwrap_or_default()}
+                                    </span>
+                                </li>
+                            }).collect::<Html>()}
+                        </ul>
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <h2>{"Budget"}</h2>
+                        <BudgetTotals totals={plan.budget.clone()} show_costs={false} />
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <h2>{"Review"}</h2>
+                        <div class="plan-review-form">
+                            <small class="form-help">{"Chapter members submit; Chapter Leads of other chapters approve or request changes."}</small>
+                            if !actions.is_empty() {
+                                <textarea
+                                    rows="2"
+                                    value={(*review_comment).clone()}
+                                    onchange={on_input(review_comment.clone())}
+                                    placeholder="Comment for the history (required when requesting changes)"
+                                ></textarea>
+                                <div class="plan-review-actions">
+                                    {actions.iter().map(|action| html! {
+                                        <button
+                                            class={if *action == PlanAction::RequestChanges { "danger-button" } else { "primary-button" }}
+                                            disabled={*busy}
+                                            onclick={on_action(*action)}
+                                        >
+                                            {action_label(*action)}
+                                        </button>
+                                    }).collect::<Html>()}
+                                </div>
+                            }
+                            if let Some(error) = &*action_error {
+                                <small class="field-error">{error}</small>
+                            }
+                        </div>
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <h2>{format!("Comments ({})", plan.comments.len())}</h2>
+                        {plan.comments.iter().map(|comment| html! {
+                            <div class="plan-comment" key={comment.id.clone()}>
+                                <strong>{comment.author.as_ref().map(|author| author.username.clone()).unwrap_or_else(|| "Former member".to_string())}</strong>
+                                {" "}<small>{format_local(&comment.created_at)}</small>
+                                <p>{&comment.body}</p>
+                            </div>
+                        }).collect::<Html>()}
+                        <form class="plan-review-form" onsubmit={on_comment}>
+                            <textarea
+                                rows="3"
+                                value={(*new_comment).clone()}
+                                onchange={on_input(new_comment.clone())}
+                                placeholder="Add a comment"
+                            ></textarea>
+                            <div class="form-actions">
+                                <button type="submit" class="secondary-button">{"Comment"}</button>
+                            </div>
+                            if let Some(error) = &*comment_error {
+                                <small class="field-error">{error}</small>
+                            }
+                        </form>
+                    </div>
+
+                    <div class="chapter-form-card">
+                        <h2>{"History"}</h2>
+                        <ul class="plan-timeline">
+                            <li>
+                                {"Draft created "}
+                                <small>{plan.created_at.as_ref().map(format_local).unwrap_or_default()}</small>
+                            </li>
+                            {plan.transitions.iter().map(|transition| html! {
+                                <li>
+                                    {format!(
+                                        "{} → {} by {} ",
+                                        plan_status_label(transition.from_status),
+                                        plan_status_label(transition.to_status),
+                                        transition.actor.as_ref().map(|actor| actor.username.as_str()).unwrap_or("a former member"),
+                                    )}
+                                    <small>{format_local(&transition.created_at)}</small>
+                                    if let Some(comment) = &transition.comment {
+                                        <p class="plan-text">{comment}</p>
+                                    }
+                                </li>
+                            }).collect::<Html>()}
+                        </ul>
+                    </div>
+                </>
+            }
+        }
+    };
+
+    html! {
+        <div class="chapters-container">
+            {content}
+
+            <style>
+                {include_str!("chapterspage.css")}
+                {include_str!("chapterdashboardpage.css")}
+                {include_str!("planspage.css")}
+                {include_str!("eventbudgetpage.css")}
+            </style>
+        </div>
+    }
+}
\ No newline at end of file
@@ -0,0 +1,283 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use web_sys::HtmlInputElement;
+use chrono::{Datelike, Utc};
+use shared::dto::{ChapterResponse, PlanStatus, QuarterlyPlanListResponse, QuarterlyPlanRequest};
+use crate::routing::Route;
+use crate::services::{ApiClient, ApiError};
+
+#[derive(PartialEq, Clone)]
+pub enum PlanListState {
+    Loading,
+    Loaded(QuarterlyPlanListResponse),
+    Error(String),
+}
+
+pub fn plan_status_label(status: PlanStatus) -> &'static str {
+    match status {
+        PlanStatus::Draft => "Draft",
+        PlanStatus::Submitted => "Awaiting review",
+        PlanStatus::Approved => "Approved",
+        PlanStatus::ChangesRequested => "Changes requested",
+    }
+}
+
+pub fn plan_status_class(status: PlanStatus) -> &'static str {
+    match status {
+        PlanStatus::Draft => "plan-status plan-status--draft",
+        PlanStatus::Submitted => "plan-status plan-status--submitted",
+        PlanStatus::Approved => "plan-status plan-status--approved",
+        PlanStatus::ChangesRequested => "plan-status plan-status--changes",
+    }
+}
+
+#[function_component(PlansPage)]
+pub fn plans_page() -> Html {
+    let state = use_state(|| PlanListState::Loading);
+    // Reviewers land on the plans waiting for them
+    let status_filter = use_state(|| Some(PlanStatus::Submitted));
+    let chapters = use_state(Vec::<ChapterResponse>::new);
+    let show_form = use_state(|| false);
+    let saving = use_state(|| false);
+    let form_error = use_state(|| None::<ApiError>);
+    let navigator = use_navigator();
+
+    let today = Utc::now().date_naive();
+    let chapter_id = use_state(String::new);
+    let year = use_state(|| today.year().to_string());
+    let quarter = use_state(|| (today.month0() / 3 + 1).to_string());
+    let strategic_purpose = use_state(String::new);
+    let quarterly_goals = use_state(String::new);
+
+    {
+        let state = state.clone();
+        use_effect_with(*status_filter, move |status_filter| {
+            let status_filter = *status_filter;
+            state.set(PlanListState::Loading);
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().list_plans(None, status_filter).await {
+                    Ok(plans) => state.set(PlanListState::Loaded(plans)),
+                    Err(e) => state.set(PlanListState::Error(format!("Failed to load plans: {}", e))),
+                }
+            });
+            || ()
+        });
+    }
+
+    {
+        let chapters = chapters.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Ok(response) = ApiClient::default().list_chapters().await {
+                    chapters.set(response.chapters);
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_status_filter_change = {
+        let status_filter = status_filter.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlInputElement = e.target_unchecked_into();
+            status_filter.set(PlanStatus::from_name(&select.value()));
+        })
+    };
+
+    let on_toggle_form = {
+        let (show_form, form_error) = (show_form.clone(), form_error.clone());
+        Callback::from(move |_: MouseEvent| {
+            form_error.set(None);
+            show_form.set(!*show_form);
+        })
+    };
+
+    let on_input = |field: UseStateHandle<String>| {
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            field.set(input.value());
+        })
+    };
+
+    let on_create = {
+        let (chapter_id, year, quarter, strategic_purpose, quarterly_goals) = (
+            chapter_id.clone(),
+            year.clone(),
+            quarter.clone(),
+            strategic_purpose.clone(),
+            quarterly_goals.clone(),
+        );
+        let (saving, form_error) = (saving.clone(), form_error.clone());
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let request = QuarterlyPlanRequest {
+                chapter_id: (*chapter_id).clone(),
+                year: year.parse().unwrap_or_default(),
+                quarter: quarter.parse().unwrap_or_default(),
+                strategic_purpose: strategic_purpose.trim().to_string(),
+                quarterly_goals: quarterly_goals.trim().to_string(),
+                event_ids: Vec::new(),
+            };
+            let (saving, form_error, navigator) = (saving.clone(), form_error.clone(), navigator.clone());
+            saving.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().create_plan(&request).await {
+                    Ok(plan) => {
+                        if let Some(navigator) = navigator {
+                            navigator.push(&Route::PlanReview { id: plan.id });
+                        }
+                    }
+                    Err(e) => form_error.set(Some(e)),
+                }
+                saving.set(false);
+            });
+        })
+    };
+
+    let field_error = |field: &str| -> Html {
+        match form_error.as_ref().and_then(|error| error.field_error(field)) {
+            Some(message) => html! { <small class="field-error">{message}</small> },
+            None => html! {},
+        }
+    };
+
+    html! {
+        <div class="chapters-container">
+            <div class="chapters-header">
+                <h1 class="page-title">{"Quarterly Plans"}</h1>
+                <p class="page-subtitle">{"Chapters submit their planned events for the quarterly budget review"}</p>
+                <div class="chapter-actions">
+                    if !*show_form {
+                        <button class="primary-button" onclick={on_toggle_form.clone()}>{"+ New Plan"}</button>
+                    }
+                    <div class="plans-filter">
+                        <label for="plan-status-filter">{"Status"}</label>
+                        <select id="plan-status-filter" onchange={on_status_filter_change}>
+                            <option value="" selected={status_filter.is_none()}>{"All plans"}</option>
+                            {PlanStatus::ALL.iter().map(|status| html! {
+                                <option value={status.to_string()} selected={*status_filter == Some(*status)}>
+                                    {plan_status_label(*status)}
+                                </option>
+                            }).collect::<Html>()}
+                        </select>
+                    </div>
+                </div>
+            </div>
+
+            if *show_form {
+                <div class="chapter-form-card">
+                    <h2>{"New Quarterly Plan"}</h2>
+                    <form class="chapter-form" onsubmit={on_create}>
+                        if let Some(error) = form_error.as_ref().filter(|error| !matches!(error, ApiError::Validation { .. })) {
+                            <div class="form-error">{error.to_string()}</div>
+                        }
+
+                        <div class="form-group">
+                            <label for="plan-chapter">{"Chapter *"}</label>
+                            <select id="plan-chapter" onchange={on_input(chapter_id.clone())} required=true>
+                                <option value="" selected={chapter_id.is_empty()}>{"Select a chapter"}</option>
+                                {chapters.iter().map(|chapter| html! {
+                                    <option value={chapter.id.clone()} selected={*chapter_id == chapter.id}>
+                                        {format!("{} ({})", chapter.name, chapter.city)}
+                                    </option>
+                                }).collect::<Html>()}
+                            </select>
+                            {field_error("chapter_id")}
+                        </div>
+
+                        <div class="form-row">
+                            <div class="form-group">
+                                <label for="plan-year">{"Year *"}</label>
+                                <input type="number" id="plan-year" value={(*year).clone()} onchange={on_input(year.clone())} required=true />
+                            </div>
+                            <div class="form-group">
+                                <label for="plan-quarter">{"Quarter *"}</label>
+                                <select id="plan-quarter" onchange={on_input(quarter.clone())}>
+                                    {(1..=4).map(|q| html! {
+                                        <option value={q.to_string()} selected={*quarter == q.to_string()}>{format!("Q{}", q)}</option>
+                                    }).collect::<Html>()}
+                                </select>
+                                {field_error("quarter")}
+                            </div>
+                        </div>
+
+                        <div class="form-group">
+                            <label for="plan-purpose">{"Strategic Purpose *"}</label>
+                            <textarea
+                                id="plan-purpose"
+                                value={(*strategic_purpose).clone()}
+                                onchange={on_input(strategic_purpose.clone())}
+                                placeholder="Why these events, and how they serve the programme's focus areas"
+                                rows="3"
+                                required=true
+                            ></textarea>
+                            {field_error("strategic_purpose")}
+                        </div>
+
+                        <div class="form-group">
+                            <label for="plan-goals">{"Quarterly Goals *"}</label>
+                            <textarea
+                                id="plan-goals"
+                                value={(*quarterly_goals).clone()}
+                                onchange={on_input(quarterly_goals.clone())}
+                                placeholder="What the chapter aims to achieve this quarter"
+                                rows="3"
+                                required=true
+                            ></textarea>
+                            {field_error("quarterly_goals")}
+                        </div>
+
+                        <small class="form-help">{"You add the quarter's events to the plan on the next page."}</small>
+
+                        <div class="form-actions">
+                            <button type="button" class="secondary-button" onclick={on_toggle_form}>{"Cancel"}</button>
+                            <button type="submit" class="primary-button" disabled={*saving}>
+                                {if *saving { "Saving..." } else { "Create Draft" }}
+                            </button>
+                        </div>
+                    </form>
+                </div>
+            }
+
+            {match &*state {
+                PlanListState::Loading => html! {
+                    <div class="loading-container">
+                        <div class="spinner"></div>
+                        <h2>{"Loading plans..."}</h2>
+                    </div>
+                },
+                PlanListState::Loaded(response) if response.plans.is_empty() => html! {
+                    <div class="empty-state">
+                        <div class="empty-icon">{"🗂️"}</div>
+                        <h2>{"No Plans Here"}</h2>
+                        <p>{"No quarterly plans match this status."}</p>
+                    </div>
+                },
+                PlanListState::Loaded(response) => html! {
+                    <div class="chapters-grid">
+                        {response.plans.iter().map(|plan| html! {
+                            <Link<Route> to={Route::PlanReview { id: plan.id.clone() }} classes="chapter-card">
+                                <span class={plan_status_class(plan.status)}>{plan_status_label(plan.status)}</span>
+                                <h3 class="chapter-name">{&plan.chapter_name}</h3>
+                                <p class="chapter-location">{format!("🗓️ Q{} {}", plan.quarter, plan.year)}</p>
+                                <p class="chapter-members">{format!("🎪 {} events", plan.event_count)}</p>
+                            </Link<Route>>
+                        }).collect::<Html>()}
+                    </div>
+                },
+                PlanListState::Error(error) => html! {
+                    <div class="error-container">
+                        <div class="error-icon">{"⚠️"}</div>
+                        <h2>{"Failed to Load Plans"}</h2>
+                        <p>{error}</p>
+                    </div>
+                },
+            }}
+
+            <style>
+                {include_str!("chapterspage.css")}
+                {include_str!("planspage.css")}
+            </style>
+        </div>
+    }
+}
\ No newline at end of file
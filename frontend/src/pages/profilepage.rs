@@ -0,0 +1,168 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use web_sys::HtmlInputElement;
+use shared::dto::{AttendanceRecord, AttendanceTokenStatus, UserAttendanceResponse};
+use crate::pages::token_status_label;
+use crate::routing::Route;
+use crate::services::ApiClient;
+
+#[derive(PartialEq, Clone)]
+pub enum ProfileState {
+    Idle,
+    Loading,
+    Loaded(Box<UserAttendanceResponse>),
+    Error(String),
+}
+
+/// Shows the events a member was checked in at and where each proof-of-attendance
+/// token is.
+#[function_component(ProfilePage)]
+pub fn profile_page() -> Html {
+    let state = use_state(|| ProfileState::Idle);
+    let wallet_address = use_state(String::new);
+    let resend_status = use_state(|| None::<Result<String, String>>);
+
+    let on_wallet_change = {
+        let wallet_address = wallet_address.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            wallet_address.set(input.value().trim().to_string());
+        })
+    };
+
+    let on_submit = {
+        let (state, wallet_address, resend_status) = (state.clone(), wallet_address.clone(), resend_status.clone());
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let (state, wallet_address) = (state.clone(), (*wallet_address).clone());
+            state.set(ProfileState::Loading);
+            resend_status.set(None);
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().get_user_attendance(&wallet_address).await {
+                    Ok(profile) => state.set(ProfileState::Loaded(Box::new(profile))),
+                    Err(e) => state.set(ProfileState::Error(format!("Failed to load profile: {}", e))),
+                }
+            });
+        })
+    };
+
+    let on_resend = {
+        let (state, resend_status) = (state.clone(), resend_status.clone());
+        Callback::from(move |_: MouseEvent| {
+            let ProfileState::Loaded(profile) = &*state else { return };
+            let (state, resend_status, profile) = (state.clone(), resend_status.clone(), profile.clone());
+            wasm_bindgen_futures::spawn_local(async move {
+                match ApiClient::default().resend_verification_email(&profile.user.wallet_address).await {
+                    Ok(response) => {
+                        resend_status.set(Some(Ok(response.message)));
+                        state.set(ProfileState::Loaded(Box::new(UserAttendanceResponse { user: response.user, ..*profile })));
+                    }
+                    Err(e) => resend_status.set(Some(Err(e.to_string()))),
+                }
+            });
+        })
+    };
+
+    let content = match &*state {
+        ProfileState::Idle => html! {},
+        ProfileState::Loading => html! {
+            <div class="loading-container">
+                <div class="spinner"></div>
+                <h2>{"Loading profile..."}</h2>
+            </div>
+        },
+        ProfileState::Error(error) => html! {
+            <div class="form-error">{error}</div>
+        },
+        ProfileState::Loaded(profile) => html! {
+            <div class="chapter-form-card">
+                <h2>{&profile.user.username}</h2>
+                <p class="form-help">{format!("{} · {}", profile.user.user_type, profile.user.wallet_address)}</p>
+                if !profile.user.email_verified {
+                    <div class="form-error">
+                        {format!("{} is not verified yet, so you can't create events.", profile.user.email)}
+                        <button class="secondary-button" onclick={on_resend}>{"Resend verification email"}</button>
+                    </div>
+                }
+                {match &*resend_status {
+                    Some(Ok(message)) => html! { <p class="form-help">{message}</p> },
+                    Some(Err(error)) => html! { <div class="form-error">{error}</div> },
+                    None => html! {},
+                }}
+
+                <h2>{"Events Attended"}</h2>
+                if profile.attendance.is_empty() {
+                    <p class="form-help">{"You haven't been checked in at an event yet."}</p>
+                }
+                <ul class="member-list">
+                    {profile.attendance.iter().map(|record| html! {
+                        <AttendanceRow key={record.event_id.clone()} record={record.clone()} />
+                    }).collect::<Html>()}
+                </ul>
+            </div>
+        },
+    };
+
+    html! {
+        <div class="chapters-container">
+            <div class="chapters-header">
+                <h1 class="page-title">{"Profile"}</h1>
+                <p class="page-subtitle">{"Your events and proof-of-attendance tokens"}</p>
+            </div>
+
+            <div class="chapter-form-card">
+                <form class="budget-item-form" onsubmit={on_submit}>
+                    <input
+                        type="text"
+                        placeholder="The wallet you signed up with"
+                        value={(*wallet_address).clone()}
+                        onchange={on_wallet_change}
+                    />
+                    <button type="submit" class="primary-button" disabled={wallet_address.is_empty()}>{"Show Profile"}</button>
+                </form>
+            </div>
+
+            {content}
+
+            <style>
+                {include_str!("chapterspage.css")}
+                {include_str!("eventbudgetpage.css")}
+            </style>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct AttendanceRowProps {
+    record: AttendanceRecord,
+}
+
+#[function_component(AttendanceRow)]
+fn attendance_row(props: &AttendanceRowProps) -> Html {
+    let record = &props.record;
+    let status = record.token_status;
+
+    html! {
+        <li class="member-item budget-item">
+            <div>
+                <Link<Route> to={Route::EventAttendance { id: record.event_id.clone() }}>
+                    <strong>{&record.event_title}</strong>
+                </Link<Route>>
+                <small class="member-wallet">
+                    {format!("{} · checked in {}", record.event_date.format("%-d %B %Y"), record.checked_in_at.format("%-d %b %Y %H:%M"))}
+                </small>
+                <span class={classes!("reimbursement-badge", format!("token-badge--{}", status.to_string().to_lowercase()))}>
+                    {format!("{} · {}", record.asset_code, token_status_label(status))}
+                </span>
+                if status == AttendanceTokenStatus::Issued {
+                    <small class="form-help">
+                        {match &record.asset_issuer {
+                            Some(issuer) => format!("Trust {} from {} in your wallet, then claim the balance.", record.asset_code, issuer),
+                            None => "Claim the balance from your wallet.".to_string(),
+                        }}
+                    </small>
+                }
+            </div>
+        </li>
+    }
+}
\ No newline at end of file
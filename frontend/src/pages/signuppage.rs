@@ -1,12 +1,16 @@
 use yew::prelude::*;
+use web_sys::HtmlInputElement;
 use shared::dto::{SignUpRequest, SignUpResponse, UserType};
-use crate::services::api;
+use crate::services::{ApiClient, ApiError};
 use crate::components::{Card, Input, WalletInput, FormSection, Button, ButtonVariant, ButtonSize};
 use crate::hooks::use_form;
+use crate::wallet::sign_message;
 
 #[derive(PartialEq, Clone)]
 pub enum SignupState {
     Form,
+    /// Waiting for the wallet to sign the signup nonce
+    Signing,
     Loading,
     Success(SignUpResponse),
     Error(String),
@@ -20,7 +24,8 @@ pub fn signup_page() -> Html {
 
     let on_user_type_change = {
         let user_type = user_type.clone();
-        Callback::from(move |value: String| {
+        Callback::from(move |e: Event| {
+            let value = e.target_unchecked_into::<HtmlInputElement>().value();
             let selected_type = if value == "ChapterLead" {
                 UserType::ChapterLead
             } else {
@@ -42,10 +47,11 @@ pub fn signup_page() -> Html {
                 return;
             }
 
-            let request = SignUpRequest {
+            let wallet_address = form.get_value("wallet_address").trim().to_string();
+            let mut request = SignUpRequest {
                 username: form.get_value("username"),
                 email: form.get_value("email"),
-                wallet_address: form.get_value("wallet_address"),
+                wallet_address: wallet_address.clone(),
                 user_type: (*user_type).clone(),
                 organization: {
                     let org = form.get_value("organization");
@@ -55,16 +61,55 @@ pub fn signup_page() -> Html {
                     let bio = form.get_value("bio");
                     if bio.is_empty() { None } else { Some(bio) }
                 },
+                nonce: String::new(),
+                signature: String::new(),
             };
 
-            state.set(SignupState::Loading);
+            state.set(SignupState::Signing);
 
             let state_clone = state.clone();
+            let form = form.clone();
             wasm_bindgen_futures::spawn_local(async move {
-                match api::signup(request).await {
+                let client = ApiClient::default();
+
+                // Prove the wallet is the user's by signing a nonce the backend issued to it
+                let challenge = match client.signup_challenge(&wallet_address).await {
+                    Ok(challenge) => challenge,
+                    Err(ApiError::Validation { field_errors, .. }) => {
+                        for field_error in field_errors {
+                            form.set_error(&field_error.field, Some(field_error.message));
+                        }
+                        state_clone.set(SignupState::Form);
+                        return;
+                    }
+                    Err(e) => {
+                        state_clone.set(SignupState::Error(format!("Signup failed: {}", e)));
+                        return;
+                    }
+                };
+                match sign_message(&challenge.message, &wallet_address).await {
+                    Ok(signature) => {
+                        request.nonce = challenge.nonce;
+                        request.signature = signature;
+                    }
+                    Err(e) => {
+                        state_clone.set(SignupState::Error(format!("Your wallet did not sign the signup: {}", e)));
+                        return;
+                    }
+                }
+
+                state_clone.set(SignupState::Loading);
+                match client.signup(&request).await {
                     Ok(response) => {
                         state_clone.set(SignupState::Success(response));
                     }
+                    Err(ApiError::Validation { field_errors, .. }) => {
+                        // Surface server-side validation next to the offending inputs
+                        for field_error in field_errors {
+                            form.set_error(&field_error.field, Some(field_error.message));
+                        }
+                        state_clone.set(SignupState::Form);
+                    }
                     Err(e) => {
                         state_clone.set(SignupState::Error(format!("Signup failed: {}", e)));
                     }
@@ -182,6 +227,13 @@ pub fn signup_page() -> Html {
                             </Button>
                         </form>
                     },
+                    SignupState::Signing => html! {
+                        <div class="loading-state">
+                            <div class="spinner"></div>
+                            <h2>{"Confirm in your wallet..."}</h2>
+                            <p>{"Sign the message in your wallet to prove the address is yours. It does not move any funds."}</p>
+                        </div>
+                    },
                     SignupState::Loading => html! {
                         <div class="loading-state">
                             <div class="spinner"></div>
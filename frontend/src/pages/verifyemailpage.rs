@@ -0,0 +1,85 @@
+use serde::Deserialize;
+use yew::prelude::*;
+use yew_router::prelude::*;
+use shared::dto::EmailVerificationResponse;
+use crate::routing::Route;
+use crate::services::ApiClient;
+
+#[derive(PartialEq, Clone)]
+pub enum VerifyEmailState {
+    Verifying,
+    Verified(Box<EmailVerificationResponse>),
+    Error(String),
+}
+
+/// Query string of the link mailed at signup, e.g. `/verify-email?token=...`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct VerifyEmailQuery {
+    pub token: Option<String>,
+}
+
+/// Landing page of the verification link: confirms the token as soon as it opens.
+#[function_component(VerifyEmailPage)]
+pub fn verify_email_page() -> Html {
+    let state = use_state(|| VerifyEmailState::Verifying);
+    let token = use_location()
+        .and_then(|location| location.query::<VerifyEmailQuery>().ok())
+        .and_then(|query| query.token)
+        .filter(|token| !token.trim().is_empty());
+
+    {
+        let state = state.clone();
+        use_effect_with(token, move |token| {
+            match token.clone() {
+                Some(token) => {
+                    state.set(VerifyEmailState::Verifying);
+                    wasm_bindgen_futures::spawn_local(async move {
+                        match ApiClient::default().verify_email(&token).await {
+                            Ok(response) => state.set(VerifyEmailState::Verified(Box::new(response))),
+                            Err(e) => state.set(VerifyEmailState::Error(e.to_string())),
+                        }
+                    });
+                }
+                None => state.set(VerifyEmailState::Error("This link has no verification token; open the one from your email again.".to_string())),
+            }
+            || ()
+        });
+    }
+
+    let content = match &*state {
+        VerifyEmailState::Verifying => html! {
+            <div class="loading-container">
+                <div class="spinner"></div>
+                <h2>{"Verifying your email address..."}</h2>
+            </div>
+        },
+        VerifyEmailState::Verified(response) => html! {
+            <div class="chapter-form-card">
+                <h2>{format!("Thanks, {}!", response.user.username)}</h2>
+                <p class="form-help">{&response.message}</p>
+                <Link<Route> to={Route::EventForm} classes="primary-button">{"Create an Event"}</Link<Route>>
+            </div>
+        },
+        VerifyEmailState::Error(error) => html! {
+            <div class="chapter-form-card">
+                <div class="form-error">{error}</div>
+                <p class="form-help">{"You can ask for a new link from your profile."}</p>
+                <Link<Route> to={Route::Profile} classes="secondary-button">{"Go to Profile"}</Link<Route>>
+            </div>
+        },
+    };
+
+    html! {
+        <div class="chapters-container">
+            <div class="chapters-header">
+                <h1 class="page-title">{"Email Verification"}</h1>
+            </div>
+
+            {content}
+
+            <style>
+                {include_str!("chapterspage.css")}
+            </style>
+        </div>
+    }
+}
\ No newline at end of file
@@ -1,9 +1,9 @@
 use crate::components::Navbar;
-use crate::pages::{HomePage, SignupPage, AboutPage, EventFormPage, EventOutputPage};
+use crate::pages::{HomePage, SignupPage, AboutPage, EventFormPage, EventOutputPage, ChaptersPage, ChapterDetailPage, ChapterDashboardPage, PlansPage, PlanReviewPage, EventBudgetPage, EventAccountsPage, EventAttendancePage, EventRegistrationPage, ProfilePage, VerifyEmailPage, NotificationsPage};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
-#[derive(Debug, Clone, Copy, PartialEq, Routable)]
+#[derive(Debug, Clone, PartialEq, Routable)]
 pub enum Route {
     #[at("/")]
     Home,
@@ -17,9 +17,45 @@ pub enum Route {
     #[at("/events/new")]
     EventForm,
 
+    #[at("/events/:id/budget")]
+    EventBudget { id: String },
+
+    #[at("/events/:id/accounts")]
+    EventAccounts { id: String },
+
+    #[at("/events/:id/attendance")]
+    EventAttendance { id: String },
+
+    #[at("/events/:id/registration")]
+    EventRegistration { id: String },
+
     #[at("/events")]
     EventOutput,
 
+    #[at("/chapters")]
+    Chapters,
+
+    #[at("/chapters/:id")]
+    ChapterDetail { id: String },
+
+    #[at("/chapters/:id/dashboard")]
+    ChapterDashboard { id: String },
+
+    #[at("/plans")]
+    Plans,
+
+    #[at("/plans/:id")]
+    PlanReview { id: String },
+
+    #[at("/profile")]
+    Profile,
+
+    #[at("/verify-email")]
+    VerifyEmail,
+
+    #[at("/notifications")]
+    Notifications,
+
     #[not_found]
     #[at("/404")]
     NotFound,
@@ -85,6 +121,155 @@ fn event_output_page_with_nav() -> Html {
     }
 }
 
+#[function_component(ChaptersPageWithNav)]
+fn chapters_page_with_nav() -> Html {
+    html! {
+        <div class="page-layout">
+            <Navbar />
+            <div class="page-content">
+                <ChaptersPage />
+            </div>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ChapterDetailPageWithNavProps {
+    id: String,
+}
+
+#[function_component(ChapterDetailPageWithNav)]
+fn chapter_detail_page_with_nav(props: &ChapterDetailPageWithNavProps) -> Html {
+    html! {
+        <div class="page-layout">
+            <Navbar />
+            <div class="page-content">
+                <ChapterDetailPage id={props.id.clone()} />
+            </div>
+        </div>
+    }
+}
+
+#[function_component(ChapterDashboardPageWithNav)]
+fn chapter_dashboard_page_with_nav(props: &ChapterDetailPageWithNavProps) -> Html {
+    html! {
+        <div class="page-layout">
+            <Navbar />
+            <div class="page-content">
+                <ChapterDashboardPage id={props.id.clone()} />
+            </div>
+        </div>
+    }
+}
+
+#[function_component(PlansPageWithNav)]
+fn plans_page_with_nav() -> Html {
+    html! {
+        <div class="page-layout">
+            <Navbar />
+            <div class="page-content">
+                <PlansPage />
+            </div>
+        </div>
+    }
+}
+
+#[function_component(PlanReviewPageWithNav)]
+fn plan_review_page_with_nav(props: &ChapterDetailPageWithNavProps) -> Html {
+    html! {
+        <div class="page-layout">
+            <Navbar />
+            <div class="page-content">
+                <PlanReviewPage id={props.id.clone()} />
+            </div>
+        </div>
+    }
+}
+
+#[function_component(EventBudgetPageWithNav)]
+fn event_budget_page_with_nav(props: &ChapterDetailPageWithNavProps) -> Html {
+    html! {
+        <div class="page-layout">
+            <Navbar />
+            <div class="page-content">
+                <EventBudgetPage id={props.id.clone()} />
+            </div>
+        </div>
+    }
+}
+
+#[function_component(EventAccountsPageWithNav)]
+fn event_accounts_page_with_nav(props: &ChapterDetailPageWithNavProps) -> Html {
+    html! {
+        <div class="page-layout">
+            <Navbar />
+            <div class="page-content">
+                <EventAccountsPage id={props.id.clone()} />
+            </div>
+        </div>
+    }
+}
+
+#[function_component(EventAttendancePageWithNav)]
+fn event_attendance_page_with_nav(props: &ChapterDetailPageWithNavProps) -> Html {
+    html! {
+        <div class="page-layout">
+            <Navbar />
+            <div class="page-content">
+                <EventAttendancePage id={props.id.clone()} />
+            </div>
+        </div>
+    }
+}
+
+#[function_component(EventRegistrationPageWithNav)]
+fn event_registration_page_with_nav(props: &ChapterDetailPageWithNavProps) -> Html {
+    html! {
+        <div class="page-layout">
+            <Navbar />
+            <div class="page-content">
+                <EventRegistrationPage id={props.id.clone()} />
+            </div>
+        </div>
+    }
+}
+
+#[function_component(ProfilePageWithNav)]
+fn profile_page_with_nav() -> Html {
+    html! {
+        <div class="page-layout">
+            <Navbar />
+            <div class="page-content">
+                <ProfilePage />
+            </div>
+        </div>
+    }
+}
+
+#[function_component(VerifyEmailPageWithNav)]
+fn verify_email_page_with_nav() -> Html {
+    html! {
+        <div class="page-layout">
+            <Navbar />
+            <div class="page-content">
+                <VerifyEmailPage />
+            </div>
+        </div>
+    }
+}
+
+#[function_component(NotificationsPageWithNav)]
+fn notifications_page_with_nav() -> Html {
+    html! {
+        <div class="page-layout">
+            <Navbar />
+            <div class="page-content">
+                <NotificationsPage />
+            </div>
+        </div>
+    }
+}
+
 pub fn switch(routes: Route) -> Html {
     match routes {
         Route::Home => html! { <HomePageWithNav /> },
@@ -92,6 +277,18 @@ pub fn switch(routes: Route) -> Html {
         Route::About => html! { <AboutPageWithNav /> },
         Route::EventForm => html! { <EventFormPageWithNav /> },
         Route::EventOutput => html! { <EventOutputPageWithNav /> },
+        Route::EventBudget { id } => html! { <EventBudgetPageWithNav {id} /> },
+        Route::EventAccounts { id } => html! { <EventAccountsPageWithNav {id} /> },
+        Route::EventAttendance { id } => html! { <EventAttendancePageWithNav {id} /> },
+        Route::EventRegistration { id } => html! { <EventRegistrationPageWithNav {id} /> },
+        Route::Chapters => html! { <ChaptersPageWithNav /> },
+        Route::ChapterDetail { id } => html! { <ChapterDetailPageWithNav {id} /> },
+        Route::ChapterDashboard { id } => html! { <ChapterDashboardPageWithNav {id} /> },
+        Route::Plans => html! { <PlansPageWithNav /> },
+        Route::PlanReview { id } => html! { <PlanReviewPageWithNav {id} /> },
+        Route::Profile => html! { <ProfilePageWithNav /> },
+        Route::VerifyEmail => html! { <VerifyEmailPageWithNav /> },
+        Route::Notifications => html! { <NotificationsPageWithNav /> },
         Route::NotFound => html! {
             <div class="page-layout">
                 <Navbar />
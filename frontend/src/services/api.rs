@@ -1,119 +1,697 @@
-use reqwest::Client;
-use shared::dto::{SignUpRequest, SignUpResponse, EventRequest, EventListResponse};
-use wasm_bindgen_futures::JsFuture;
-use wasm_bindgen::JsValue;
-use serde_wasm_bindgen::{to_value, from_value};
-
-const API_BASE_URL: &str = "http://127.0.0.1:8081/api";
-
-pub async fn signup(request: SignUpRequest) -> Result<SignUpResponse, String> {
-    let client = Client::new();
-
-    match client
-        .post(&format!("{}/signup", API_BASE_URL))
-        .header("Content-Type", "application/json")
-        .json(&request)
-        .send()
-        .await
-    {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.json::<SignUpResponse>().await {
-                    Ok(signup_response) => Ok(signup_response),
-                    Err(e) => Err(format!("Failed to parse response: {}", e)),
-                }
-            } else {
-                let status = response.status();
-                match response.text().await {
-                    Ok(error_text) => Err(error_text),
-                    Err(_) => Err(format!("HTTP error: {}", status))
-                }
+use std::fmt;
+use std::time::Duration;
+
+use gloo::file::Blob;
+use gloo::timers::future::TimeoutFuture;
+use gloo_storage::{LocalStorage, Storage};
+use reqwest::{multipart::{Form, Part}, Client, Method, RequestBuilder, Response, StatusCode};
+use serde::{de::DeserializeOwned, Serialize};
+use shared::dto::{
+    SignUpRequest, SignUpResponse, SignUpChallengeRequest, SignUpChallengeResponse, SignInRequest, SessionResponse, EventRequest, EventListResponse, ErrorResponse, FieldError,
+    ChapterRequest, ChapterResponse, ChapterListResponse, ChapterMembersResponse, MembershipRequest,
+    ChapterDashboardResponse, ChapterTargetsResponse, EventActualsRequest, EventResponse, KpiValues,
+    QuarterlyPlanRequest, QuarterlyPlanResponse, QuarterlyPlanListResponse, PlanStatus, PlanTransitionRequest,
+    PlanCommentRequest, PlanComment, EventStatus, EventStatusRequest,
+    EventBudgetResponse, BudgetItemRequest, BudgetAmountRequest,
+    ReimbursementResponse, SubmitReimbursementRequest,
+    AttachmentKind, AttachmentListResponse, AttachmentResponse,
+    LinkAccountRequest, LinkedAccountListResponse, LinkedAccountResponse,
+    EventAttendanceResponse, CheckInRequest, CheckInResponse, AttendanceIssuanceResponse,
+    SubmitAttendanceTokensRequest, UserAttendanceResponse,
+    StellarNetworkResponse, VerifyEmailRequest, ResendVerificationRequest, EmailVerificationResponse,
+    EventUpdateRequest, EventRegistrationsResponse, RegistrationRequest, RegistrationResponse,
+    NotificationListResponse, MarkNotificationsReadRequest, NotificationPreferencesRequest, NotificationPreferencesResponse,
+};
+
+const API_BASE_URL: &str = "http://127.0.0.1:8081";
+/// API version this build of the frontend was written against.
+pub const API_VERSION: &str = "v1";
+const AUTH_TOKEN_KEY: &str = "stellar_europe.auth_token";
+const SESSION_WALLET_KEY: &str = "stellar_europe.session_wallet";
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(15);
+const DEFAULT_MAX_RETRIES: u32 = 2;
+const RETRY_BASE_DELAY_MS: u32 = 300;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    Network(String),
+    Timeout,
+    Http { status: u16, message: String },
+    Decode(String),
+    Validation { message: String, field_errors: Vec<FieldError> },
+}
+
+impl ApiError {
+    /// Whether retrying the same idempotent request could succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ApiError::Network(_) | ApiError::Timeout => true,
+            ApiError::Http { status, .. } => *status >= 500,
+            ApiError::Decode(_) | ApiError::Validation { .. } => false,
+        }
+    }
+
+    /// The error an unsuccessful response with `status` and `body` stands for. The body is
+    /// the API's `ErrorResponse`, or failing that plain text or a JSON string.
+    pub fn from_response(status: u16, body: &str) -> Self {
+        match serde_json::from_str::<ErrorResponse>(body) {
+            Ok(error) if !error.field_errors.is_empty() => ApiError::Validation {
+                message: error.message,
+                field_errors: error.field_errors,
+            },
+            Ok(error) => ApiError::Http { status, message: error.message },
+            Err(_) => {
+                let message = match serde_json::from_str::<String>(body) {
+                    Ok(message) => message,
+                    Err(_) if !body.trim().is_empty() => body.to_string(),
+                    Err(_) => StatusCode::from_u16(status)
+                        .ok()
+                        .and_then(|status| status.canonical_reason())
+                        .unwrap_or("Unknown error")
+                        .to_string(),
+                };
+                ApiError::Http { status, message }
             }
         }
-        Err(e) => Err(format!("Network error: {}", e)),
+    }
+
+    pub fn field_error(&self, field: &str) -> Option<&str> {
+        match self {
+            ApiError::Validation { field_errors, .. } => field_errors
+                .iter()
+                .find(|error| error.field == field)
+                .map(|error| error.message.as_str()),
+            _ => None,
+        }
     }
 }
 
-pub async fn create_event(request: EventRequest) -> Result<String, String> {
-    let client = Client::new();
-
-    match client
-        .post(&format!("{}/events", API_BASE_URL))
-        .header("Content-Type", "application/json")
-        .json(&request)
-        .send()
-        .await
-    {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.text().await {
-                    Ok(message) => Ok(message),
-                    Err(e) => Err(format!("Failed to parse response: {}", e)),
-                }
-            } else {
-                let status = response.status();
-                match response.text().await {
-                    Ok(error_text) => Err(error_text),
-                    Err(_) => Err(format!("HTTP error: {}", status))
-                }
-            }
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Network(e) => write!(f, "Network error: {}", e),
+            ApiError::Timeout => write!(f, "The server took too long to respond"),
+            ApiError::Http { status, message } => write!(f, "HTTP error {}: {}", status, message),
+            ApiError::Decode(e) => write!(f, "Failed to parse response: {}", e),
+            ApiError::Validation { message, .. } => write!(f, "{}", message),
         }
-        Err(e) => Err(format!("Network error: {}", e)),
     }
 }
 
-pub async fn list_events(limit: Option<u32>, offset: Option<u32>) -> Result<EventListResponse, String> {
-    let client = Client::new();
+impl From<reqwest::Error> for ApiError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            ApiError::Timeout
+        } else if e.is_decode() {
+            ApiError::Decode(e.to_string())
+        } else {
+            ApiError::Network(e.to_string())
+        }
+    }
+}
 
-    let mut url = format!("{}/events", API_BASE_URL);
-    let mut params = Vec::new();
+#[derive(Clone, Debug)]
+pub struct ApiClient {
+    client: Client,
+    base_url: String,
+    timeout: Duration,
+    max_retries: u32,
+}
 
-    if let Some(limit) = limit {
-        params.push(format!("limit={}", limit));
+impl Default for ApiClient {
+    fn default() -> Self {
+        Self::new(API_BASE_URL)
     }
-    if let Some(offset) = offset {
-        params.push(format!("offset={}", offset));
+}
+
+impl ApiClient {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            timeout: DEFAULT_TIMEOUT,
+            max_retries: DEFAULT_MAX_RETRIES,
+        }
     }
 
-    if !params.is_empty() {
-        url.push_str("?");
-        url.push_str(&params.join("&"));
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
     }
 
-    match client.get(&url).send().await {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.json::<EventListResponse>().await {
-                    Ok(events_response) => Ok(events_response),
-                    Err(e) => Err(format!("Failed to parse response: {}", e)),
-                }
-            } else {
-                let status = response.status();
-                match response.text().await {
-                    Ok(error_text) => Err(error_text),
-                    Err(_) => Err(format!("HTTP error: {}", status))
-                }
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn auth_token() -> Option<String> {
+        LocalStorage::get(AUTH_TOKEN_KEY).ok()
+    }
+
+    pub fn set_auth_token(token: &str) {
+        let _ = LocalStorage::set(AUTH_TOKEN_KEY, token);
+    }
+
+    pub fn clear_auth_token() {
+        LocalStorage::delete(AUTH_TOKEN_KEY);
+        LocalStorage::delete(SESSION_WALLET_KEY);
+    }
+
+    /// Wallet address of the signed-in user, while a session token is kept.
+    pub fn session_wallet() -> Option<String> {
+        Self::auth_token().and_then(|_| LocalStorage::get(SESSION_WALLET_KEY).ok())
+    }
+
+    /// Full URL of `path` on the server this client talks to.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    // Endpoints
+
+    /// A nonce for `wallet_address` to sign, proving at signup that the wallet is the user's.
+    pub async fn signup_challenge(&self, wallet_address: &str) -> Result<SignUpChallengeResponse, ApiError> {
+        let request = SignUpChallengeRequest { wallet_address: wallet_address.to_string() };
+        self.post(&endpoint("/signup/challenge"), &request).await
+    }
+
+    pub async fn signup(&self, request: &SignUpRequest) -> Result<SignUpResponse, ApiError> {
+        self.post(&endpoint("/signup"), request).await
+    }
+
+    /// A nonce for a registered `wallet_address` to sign in with.
+    pub async fn signin_challenge(&self, wallet_address: &str) -> Result<SignUpChallengeResponse, ApiError> {
+        let request = SignUpChallengeRequest { wallet_address: wallet_address.to_string() };
+        self.post(&endpoint("/sessions/challenge"), &request).await
+    }
+
+    /// Starts a session; its token is kept and sent with every later request.
+    pub async fn create_session(&self, request: &SignInRequest) -> Result<SessionResponse, ApiError> {
+        let session: SessionResponse = self.post(&endpoint("/sessions"), request).await?;
+        Self::set_auth_token(&session.token);
+        let _ = LocalStorage::set(SESSION_WALLET_KEY, &session.user.wallet_address);
+        Ok(session)
+    }
+
+    pub async fn verify_email(&self, token: &str) -> Result<EmailVerificationResponse, ApiError> {
+        let request = VerifyEmailRequest { token: token.to_string() };
+        self.post(&endpoint("/email/verify"), &request).await
+    }
+
+    pub async fn resend_verification_email(&self, wallet_address: &str) -> Result<EmailVerificationResponse, ApiError> {
+        let request = ResendVerificationRequest { wallet_address: wallet_address.to_string() };
+        self.post(&endpoint("/email/verification"), &request).await
+    }
+
+    pub async fn create_event(&self, request: &EventRequest) -> Result<String, ApiError> {
+        self.post(&endpoint("/events"), request).await
+    }
+
+    /// Lists publicly listed events, plus the signed-in user's own and, for Chapter Leads,
+    /// the events awaiting review.
+    pub async fn list_events(
+        &self,
+        chapter_id: Option<&str>,
+        status: Option<EventStatus>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<EventListResponse, ApiError> {
+        let mut params = Vec::new();
+        if let Some(chapter_id) = chapter_id {
+            params.push(format!("chapter_id={}", chapter_id));
+        }
+        if let Some(status) = status {
+            params.push(format!("status={}", status));
+        }
+        if let Some(limit) = limit {
+            params.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = offset {
+            params.push(format!("offset={}", offset));
+        }
+
+        if params.is_empty() {
+            self.get(&endpoint("/events")).await
+        } else {
+            self.get(&endpoint(&format!("/events?{}", params.join("&")))).await
+        }
+    }
+
+    pub async fn update_event_status(
+        &self,
+        event_id: &str,
+        request: &EventStatusRequest,
+    ) -> Result<EventResponse, ApiError> {
+        self.post(&endpoint(&format!("/events/{}/status", event_id)), request).await
+    }
+
+    pub async fn update_event_details(&self, event_id: &str, request: &EventUpdateRequest) -> Result<EventResponse, ApiError> {
+        self.put(&endpoint(&format!("/events/{}/details", event_id)), request).await
+    }
+
+    pub async fn list_registrations(&self, event_id: &str) -> Result<EventRegistrationsResponse, ApiError> {
+        self.get(&endpoint(&format!("/events/{}/registrations", event_id))).await
+    }
+
+    pub async fn register_for_event(&self, event_id: &str, request: &RegistrationRequest) -> Result<RegistrationResponse, ApiError> {
+        self.post(&endpoint(&format!("/events/{}/registrations", event_id)), request).await
+    }
+
+    pub async fn cancel_registration(
+        &self,
+        event_id: &str,
+        registration_id: &str,
+        actor_wallet_address: &str,
+    ) -> Result<RegistrationResponse, ApiError> {
+        let response = self
+            .send(self.request(
+                Method::DELETE,
+                &endpoint(&format!(
+                    "/events/{}/registrations/{}?actor_wallet_address={}",
+                    event_id, registration_id, actor_wallet_address
+                )),
+            ))
+            .await?;
+        Self::decode(response).await
+    }
+
+    pub async fn get_event_budget(&self, event_id: &str) -> Result<EventBudgetResponse, ApiError> {
+        self.get(&endpoint(&format!("/events/{}/budget", event_id))).await
+    }
+
+    pub async fn add_budget_item(
+        &self,
+        event_id: &str,
+        request: &BudgetItemRequest,
+    ) -> Result<EventBudgetResponse, ApiError> {
+        self.post(&endpoint(&format!("/events/{}/budget", event_id)), request).await
+    }
+
+    pub async fn update_budget_item(
+        &self,
+        event_id: &str,
+        item_id: &str,
+        request: &BudgetItemRequest,
+    ) -> Result<EventBudgetResponse, ApiError> {
+        self.put(&endpoint(&format!("/events/{}/budget/{}", event_id, item_id)), request).await
+    }
+
+    pub async fn delete_budget_item(&self, event_id: &str, item_id: &str) -> Result<(), ApiError> {
+        self.delete(&endpoint(&format!("/events/{}/budget/{}", event_id, item_id))).await
+    }
+
+    pub async fn approve_budget_item(
+        &self,
+        event_id: &str,
+        item_id: &str,
+        request: &BudgetAmountRequest,
+    ) -> Result<EventBudgetResponse, ApiError> {
+        self.put(&endpoint(&format!("/events/{}/budget/{}/approved", event_id, item_id)), request).await
+    }
+
+    pub async fn record_budget_spending(
+        &self,
+        event_id: &str,
+        item_id: &str,
+        request: &BudgetAmountRequest,
+    ) -> Result<EventBudgetResponse, ApiError> {
+        self.put(&endpoint(&format!("/events/{}/budget/{}/spent", event_id, item_id)), request).await
+    }
+
+    pub async fn get_reimbursement(&self, event_id: &str, item_id: &str) -> Result<ReimbursementResponse, ApiError> {
+        self.get(&endpoint(&format!("/events/{}/budget/{}/reimbursement", event_id, item_id))).await
+    }
+
+    /// Builds the payout as the signed-in programme admin; the request has no body.
+    pub async fn prepare_reimbursement(&self, event_id: &str, item_id: &str) -> Result<ReimbursementResponse, ApiError> {
+        self.post(&endpoint(&format!("/events/{}/budget/{}/reimbursement", event_id, item_id)), &()).await
+    }
+
+    /// The backend waits for the payment to reach a ledger, so give it longer than the default.
+    pub async fn submit_reimbursement(
+        self,
+        event_id: &str,
+        item_id: &str,
+        request: &SubmitReimbursementRequest,
+    ) -> Result<ReimbursementResponse, ApiError> {
+        let client = self.with_timeout(Duration::from_secs(60));
+        client.post(&endpoint(&format!("/events/{}/budget/{}/reimbursement/submit", event_id, item_id)), request).await
+    }
+
+    pub async fn list_attachments(&self, event_id: &str) -> Result<AttachmentListResponse, ApiError> {
+        self.get(&endpoint(&format!("/events/{}/attachments", event_id))).await
+    }
+
+    /// Uploads can take a while on a slow connection, so give them longer than the default.
+    pub async fn upload_attachment(
+        self,
+        event_id: &str,
+        kind: AttachmentKind,
+        budget_item_id: Option<&str>,
+        file_name: String,
+        bytes: Vec<u8>,
+    ) -> Result<AttachmentResponse, ApiError> {
+        let mut form = Form::new().text("kind", kind.to_string());
+        if let Some(budget_item_id) = budget_item_id {
+            form = form.text("budget_item_id", budget_item_id.to_string());
+        }
+        form = form.part("file", Part::bytes(bytes).file_name(file_name));
+
+        let client = self.with_timeout(Duration::from_secs(120));
+        let path = endpoint(&format!("/events/{}/attachments", event_id));
+        let response = client.send(client.request(Method::POST, &path).multipart(form)).await?;
+        Self::decode(response).await
+    }
+
+    pub async fn delete_attachment(&self, event_id: &str, attachment_id: &str) -> Result<(), ApiError> {
+        self.delete(&endpoint(&format!("/events/{}/attachments/{}", event_id, attachment_id))).await
+    }
+
+    /// Fetches an uploaded file with the session, for receipts that a plain link cannot open.
+    pub async fn attachment_content(&self, event_id: &str, attachment_id: &str) -> Result<Blob, ApiError> {
+        let path = endpoint(&format!("/events/{}/attachments/{}/content", event_id, attachment_id));
+        let response = self.send_with_retry(Method::GET, &path).await?;
+        let content_type = response
+            .headers()
+            .get("content-type")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let bytes = response.bytes().await?;
+        Ok(Blob::new_with_options(bytes.as_ref(), content_type.as_deref()))
+    }
+
+    pub async fn list_linked_accounts(&self, event_id: &str) -> Result<LinkedAccountListResponse, ApiError> {
+        self.get(&endpoint(&format!("/events/{}/accounts", event_id))).await
+    }
+
+    pub async fn link_account(&self, event_id: &str, request: &LinkAccountRequest) -> Result<LinkedAccountResponse, ApiError> {
+        self.post(&endpoint(&format!("/events/{}/accounts", event_id)), request).await
+    }
+
+    pub async fn unlink_account(&self, event_id: &str, link_id: &str) -> Result<(), ApiError> {
+        self.delete(&endpoint(&format!("/events/{}/accounts/{}", event_id, link_id))).await
+    }
+
+    pub async fn get_event_attendance(&self, event_id: &str) -> Result<EventAttendanceResponse, ApiError> {
+        self.get(&endpoint(&format!("/events/{}/attendance", event_id))).await
+    }
+
+    pub async fn check_in_attendee(&self, event_id: &str, request: &CheckInRequest) -> Result<CheckInResponse, ApiError> {
+        self.post(&endpoint(&format!("/events/{}/attendance", event_id)), request).await
+    }
+
+    pub async fn remove_check_in(&self, event_id: &str, check_in_id: &str) -> Result<(), ApiError> {
+        self.delete(&endpoint(&format!("/events/{}/attendance/{}", event_id, check_in_id))).await
+    }
+
+    /// Builds the tokens as the signed-in programme admin; the request has no body.
+    pub async fn prepare_attendance_tokens(&self, event_id: &str) -> Result<AttendanceIssuanceResponse, ApiError> {
+        self.post(&endpoint(&format!("/events/{}/attendance/tokens", event_id)), &()).await
+    }
+
+    /// The backend waits for the balances to reach a ledger, so give it longer than the default.
+    pub async fn submit_attendance_tokens(
+        self,
+        event_id: &str,
+        issuance_id: &str,
+        request: &SubmitAttendanceTokensRequest,
+    ) -> Result<AttendanceIssuanceResponse, ApiError> {
+        let client = self.with_timeout(Duration::from_secs(60));
+        client.post(&endpoint(&format!("/events/{}/attendance/tokens/{}/submit", event_id, issuance_id)), request).await
+    }
+
+    pub async fn get_user_attendance(&self, wallet_address: &str) -> Result<UserAttendanceResponse, ApiError> {
+        self.get(&endpoint(&format!("/users/{}/attendance", wallet_address))).await
+    }
+
+    pub async fn list_notifications(&self, wallet_address: &str, unread_only: bool) -> Result<NotificationListResponse, ApiError> {
+        self.get(&endpoint(&format!("/users/{}/notifications?unread_only={}", wallet_address, unread_only))).await
+    }
+
+    /// Marks the given notifications read, or the whole inbox when `notification_ids` is empty.
+    pub async fn mark_notifications_read(
+        &self,
+        wallet_address: &str,
+        notification_ids: Vec<String>,
+    ) -> Result<NotificationListResponse, ApiError> {
+        let request = MarkNotificationsReadRequest { notification_ids };
+        self.post(&endpoint(&format!("/users/{}/notifications/read", wallet_address)), &request).await
+    }
+
+    pub async fn get_notification_preferences(&self, wallet_address: &str) -> Result<NotificationPreferencesResponse, ApiError> {
+        self.get(&endpoint(&format!("/users/{}/notification-preferences", wallet_address))).await
+    }
+
+    pub async fn update_notification_preferences(
+        &self,
+        wallet_address: &str,
+        request: &NotificationPreferencesRequest,
+    ) -> Result<NotificationPreferencesResponse, ApiError> {
+        self.put(&endpoint(&format!("/users/{}/notification-preferences", wallet_address)), request).await
+    }
+
+    pub async fn get_stellar_network(&self) -> Result<StellarNetworkResponse, ApiError> {
+        self.get(&endpoint("/stellar/network")).await
+    }
+
+    /// Link to an uploaded photo, slide deck or document, shown in the browser if it is an image or PDF.
+    /// Receipts need the session, so they are fetched with [`ApiClient::attachment_content`] instead.
+    pub fn attachment_content_url(&self, event_id: &str, attachment_id: &str) -> String {
+        self.url(&endpoint(&format!("/events/{}/attachments/{}/content", event_id, attachment_id)))
+    }
+
+    pub fn attachment_thumbnail_url(&self, event_id: &str, attachment_id: &str) -> String {
+        self.url(&endpoint(&format!("/events/{}/attachments/{}/thumbnail", event_id, attachment_id)))
+    }
+
+    /// Download link for a single event's `.ics` file.
+    pub fn event_calendar_url(&self, event_id: &str) -> String {
+        self.url(&endpoint(&format!("/events/{}/calendar.ics", event_id)))
+    }
+
+    /// `webcal://` link that calendar apps subscribe to and keep refreshing,
+    /// optionally limited to one chapter's events.
+    pub fn calendar_subscription_url(&self, chapter_id: Option<&str>) -> String {
+        let path = match chapter_id {
+            Some(chapter_id) => format!("/calendar.ics?chapter_id={}", chapter_id),
+            None => "/calendar.ics".to_string(),
+        };
+        let feed = self.url(&endpoint(&path));
+        match feed.split_once("://") {
+            Some((_, rest)) => format!("webcal://{}", rest),
+            None => feed,
+        }
+    }
+
+    pub async fn list_chapters(&self) -> Result<ChapterListResponse, ApiError> {
+        self.get(&endpoint("/chapters")).await
+    }
+
+    pub async fn get_chapter(&self, chapter_id: &str) -> Result<ChapterResponse, ApiError> {
+        self.get(&endpoint(&format!("/chapters/{}", chapter_id))).await
+    }
+
+    pub async fn create_chapter(&self, request: &ChapterRequest) -> Result<ChapterResponse, ApiError> {
+        self.post(&endpoint("/chapters"), request).await
+    }
+
+    pub async fn update_chapter(&self, chapter_id: &str, request: &ChapterRequest) -> Result<ChapterResponse, ApiError> {
+        self.put(&endpoint(&format!("/chapters/{}", chapter_id)), request).await
+    }
+
+    pub async fn delete_chapter(&self, chapter_id: &str) -> Result<(), ApiError> {
+        self.delete(&endpoint(&format!("/chapters/{}", chapter_id))).await
+    }
+
+    pub async fn list_chapter_members(&self, chapter_id: &str) -> Result<ChapterMembersResponse, ApiError> {
+        self.get(&endpoint(&format!("/chapters/{}/members", chapter_id))).await
+    }
+
+    pub async fn add_chapter_member(
+        &self,
+        chapter_id: &str,
+        request: &MembershipRequest,
+    ) -> Result<ChapterMembersResponse, ApiError> {
+        self.post(&endpoint(&format!("/chapters/{}/members", chapter_id)), request).await
+    }
+
+    pub async fn remove_chapter_member(&self, chapter_id: &str, user_id: &str) -> Result<(), ApiError> {
+        self.delete(&endpoint(&format!("/chapters/{}/members/{}", chapter_id, user_id))).await
+    }
+
+    /// Dashboard of one quarter; the server picks the current quarter when `period` is `None`.
+    pub async fn chapter_dashboard(
+        &self,
+        chapter_id: &str,
+        period: Option<(i32, u32)>,
+    ) -> Result<ChapterDashboardResponse, ApiError> {
+        match period {
+            Some((year, quarter)) => {
+                self.get(&endpoint(&format!("/chapters/{}/dashboard?year={}&quarter={}", chapter_id, year, quarter))).await
             }
+            None => self.get(&endpoint(&format!("/chapters/{}/dashboard", chapter_id))).await,
         }
-        Err(e) => Err(format!("Network error: {}", e)),
     }
-}
 
-pub async fn health_check() -> Result<String, String> {
-    let client = Client::new();
+    pub async fn get_chapter_targets(
+        &self,
+        chapter_id: &str,
+        year: i32,
+        quarter: u32,
+    ) -> Result<ChapterTargetsResponse, ApiError> {
+        self.get(&endpoint(&format!("/chapters/{}/targets/{}/{}", chapter_id, year, quarter))).await
+    }
+
+    pub async fn update_chapter_targets(
+        &self,
+        chapter_id: &str,
+        year: i32,
+        quarter: u32,
+        targets: &KpiValues,
+    ) -> Result<ChapterTargetsResponse, ApiError> {
+        self.put(&endpoint(&format!("/chapters/{}/targets/{}/{}", chapter_id, year, quarter)), targets).await
+    }
+
+    pub async fn record_event_actuals(&self, event_id: &str, request: &EventActualsRequest) -> Result<EventResponse, ApiError> {
+        self.put(&endpoint(&format!("/events/{}/actuals", event_id)), request).await
+    }
+
+    pub async fn list_plans(
+        &self,
+        chapter_id: Option<&str>,
+        status: Option<PlanStatus>,
+    ) -> Result<QuarterlyPlanListResponse, ApiError> {
+        let mut params = Vec::new();
+        if let Some(chapter_id) = chapter_id {
+            params.push(format!("chapter_id={}", chapter_id));
+        }
+        if let Some(status) = status {
+            params.push(format!("status={}", status));
+        }
+
+        if params.is_empty() {
+            self.get(&endpoint("/plans")).await
+        } else {
+            self.get(&endpoint(&format!("/plans?{}", params.join("&")))).await
+        }
+    }
+
+    pub async fn get_plan(&self, plan_id: &str) -> Result<QuarterlyPlanResponse, ApiError> {
+        self.get(&endpoint(&format!("/plans/{}", plan_id))).await
+    }
+
+    pub async fn create_plan(&self, request: &QuarterlyPlanRequest) -> Result<QuarterlyPlanResponse, ApiError> {
+        self.post(&endpoint("/plans"), request).await
+    }
+
+    pub async fn update_plan(
+        &self,
+        plan_id: &str,
+        request: &QuarterlyPlanRequest,
+    ) -> Result<QuarterlyPlanResponse, ApiError> {
+        self.put(&endpoint(&format!("/plans/{}", plan_id)), request).await
+    }
+
+    pub async fn transition_plan(
+        &self,
+        plan_id: &str,
+        request: &PlanTransitionRequest,
+    ) -> Result<QuarterlyPlanResponse, ApiError> {
+        self.post(&endpoint(&format!("/plans/{}/transitions", plan_id)), request).await
+    }
+
+    pub async fn add_plan_comment(&self, plan_id: &str, request: &PlanCommentRequest) -> Result<PlanComment, ApiError> {
+        self.post(&endpoint(&format!("/plans/{}/comments", plan_id)), request).await
+    }
+
+    pub async fn health_check(&self) -> Result<String, ApiError> {
+        let response = self.send_with_retry(Method::GET, "/health").await?;
+        response.text().await.map_err(ApiError::from)
+    }
+
+    // Request pipeline
+
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, ApiError> {
+        let response = self.send_with_retry(Method::GET, path).await?;
+        Self::decode(response).await
+    }
+
+    pub async fn post<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T, ApiError> {
+        let response = self.send(self.request(Method::POST, path).json(body)).await?;
+        Self::decode(response).await
+    }
+
+    pub async fn put<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T, ApiError> {
+        let response = self.send(self.request(Method::PUT, path).json(body)).await?;
+        Self::decode(response).await
+    }
+
+    /// Sends a DELETE, which answers with an empty body on success.
+    pub async fn delete(&self, path: &str) -> Result<(), ApiError> {
+        self.send(self.request(Method::DELETE, path)).await?;
+        Ok(())
+    }
 
-    match client.get("http://127.0.0.1:8081/health").send().await {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.text().await {
-                    Ok(text) => Ok(text),
-                    Err(e) => Err(format!("Failed to parse response: {}", e)),
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let mut builder = self.client
+            .request(method, self.url(path))
+            .header("Accept", "application/json")
+            .timeout(self.timeout);
+
+        if let Some(token) = Self::auth_token() {
+            builder = builder.bearer_auth(token);
+        }
+
+        builder
+    }
+
+    /// Sends an idempotent request, retrying with exponential backoff on
+    /// network failures, timeouts and 5xx responses.
+    async fn send_with_retry(&self, method: Method, path: &str) -> Result<Response, ApiError> {
+        let mut attempt = 0;
+        loop {
+            match self.send(self.request(method.clone(), path)).await {
+                Err(e) if e.is_retryable() && attempt < self.max_retries => {
+                    TimeoutFuture::new(RETRY_BASE_DELAY_MS * 2u32.pow(attempt)).await;
+                    attempt += 1;
                 }
-            } else {
-                let status = response.status();
-                Err(format!("HTTP error: {}", status))
+                result => return result,
             }
         }
-        Err(e) => Err(format!("Network error: {}", e)),
     }
-}
\ No newline at end of file
+
+    async fn send(&self, builder: RequestBuilder) -> Result<Response, ApiError> {
+        let response = builder.send().await?;
+
+        if let Some(deprecation) = response.headers().get("deprecation") {
+            gloo::console::warn!(format!(
+                "{} is deprecated by the API (since {:?}), successor: {:?}",
+                response.url(),
+                deprecation,
+                response.headers().get("link"),
+            ));
+        }
+
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            Err(Self::error_from_response(response).await)
+        }
+    }
+
+    async fn decode<T: DeserializeOwned>(response: Response) -> Result<T, ApiError> {
+        let body = response.text().await?;
+        serde_json::from_str(&body).map_err(|e| ApiError::Decode(e.to_string()))
+    }
+
+    async fn error_from_response(response: Response) -> ApiError {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        ApiError::from_response(status.as_u16(), &body)
+    }
+}
+
+/// Path of an endpoint in the pinned API version, e.g. `/events` -> `/api/v1/events`.
+pub fn endpoint(path: &str) -> String {
+    format!("/api/{}{}", API_VERSION, path)
+}
@@ -0,0 +1,138 @@
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use js_sys::{Array, Date, Intl, Object, Reflect};
+use wasm_bindgen::JsValue;
+
+/// Values produced by `<input type="datetime-local">`; seconds appear when `step` < 60.
+const DATETIME_LOCAL_FORMATS: &[&str] = &["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S"];
+
+/// Zones our chapters organise in, from the Azores (UTC−1) to Istanbul (UTC+3).
+pub const EUROPEAN_TIME_ZONES: &[&str] = &[
+    "Atlantic/Azores",
+    "Atlantic/Canary",
+    "Europe/Lisbon",
+    "Europe/London",
+    "Europe/Dublin",
+    "Europe/Madrid",
+    "Europe/Paris",
+    "Europe/Brussels",
+    "Europe/Amsterdam",
+    "Europe/Berlin",
+    "Europe/Zurich",
+    "Europe/Rome",
+    "Europe/Vienna",
+    "Europe/Prague",
+    "Europe/Warsaw",
+    "Europe/Budapest",
+    "Europe/Belgrade",
+    "Europe/Copenhagen",
+    "Europe/Oslo",
+    "Europe/Stockholm",
+    "Europe/Helsinki",
+    "Europe/Tallinn",
+    "Europe/Riga",
+    "Europe/Vilnius",
+    "Europe/Kyiv",
+    "Europe/Bucharest",
+    "Europe/Sofia",
+    "Europe/Athens",
+    "Europe/Istanbul",
+    "UTC",
+];
+
+/// Converts a `datetime-local` value, read as wall-clock time in the IANA `zone`,
+/// to UTC. Returns `None` for empty or malformed input and for times that fall in
+/// a daylight-saving gap; a time that happens twice when clocks go back resolves to
+/// the earlier of the two.
+pub fn zoned_input_to_utc(value: &str, zone: &str) -> Option<DateTime<Utc>> {
+    let naive = DATETIME_LOCAL_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())?;
+
+    // A DST transition near the input has the zone's offset on either side of it, so
+    // try the offsets a day before and after and keep those that give back the input.
+    let around = Utc.from_utc_datetime(&naive);
+    let resolved = [around - Duration::days(1), around + Duration::days(1)]
+        .iter()
+        .map(|probe| Some(Utc.from_utc_datetime(&(naive - zone_offset(probe, zone)?))))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .filter(|candidate| wall_clock_in_zone(candidate, zone) == Some(naive))
+        .min()?;
+
+    Some(resolved)
+}
+
+/// IANA name of the browser's time zone, e.g. "Europe/Berlin".
+pub fn browser_time_zone() -> String {
+    let options = Intl::DateTimeFormat::new(&Array::new(), &Object::new()).resolved_options();
+
+    Reflect::get(&options, &JsValue::from_str("timeZone"))
+        .ok()
+        .and_then(|zone| zone.as_string())
+        .unwrap_or_else(|| "UTC".to_string())
+}
+
+/// Formats an instant in the viewer's local time.
+pub fn format_local(date: &DateTime<Utc>) -> String {
+    date.with_timezone(&Local).format("%B %d, %Y at %I:%M %p").to_string()
+}
+
+/// Formats an instant as wall-clock time in `zone`, with the zone's abbreviation.
+pub fn format_in_zone(date: &DateTime<Utc>, zone: &str) -> String {
+    let options = date_time_options(zone, &[
+        ("year", "numeric"),
+        ("month", "long"),
+        ("day", "2-digit"),
+        ("hour", "2-digit"),
+        ("minute", "2-digit"),
+        ("timeZoneName", "short"),
+    ]);
+
+    to_js_date(date).to_locale_string("en-US", &options).into()
+}
+
+/// Wall-clock time of `instant` in `zone`. `zone` must be a valid IANA name, as
+/// `Intl.DateTimeFormat` throws on unknown zones.
+fn wall_clock_in_zone(instant: &DateTime<Utc>, zone: &str) -> Option<NaiveDateTime> {
+    let options = date_time_options(zone, &[
+        ("hourCycle", "h23"),
+        ("year", "numeric"),
+        ("month", "2-digit"),
+        ("day", "2-digit"),
+        ("hour", "2-digit"),
+        ("minute", "2-digit"),
+        ("second", "2-digit"),
+    ]);
+    let format = Intl::DateTimeFormat::new(&Array::of1(&JsValue::from_str("en-US")), &options);
+    let parts = format.format_to_parts(&to_js_date(instant));
+
+    let part = |name: &str| -> Option<u32> {
+        parts.iter().find_map(|part| {
+            let kind = Reflect::get(&part, &JsValue::from_str("type")).ok()?.as_string()?;
+            if kind != name {
+                return None;
+            }
+            Reflect::get(&part, &JsValue::from_str("value")).ok()?.as_string()?.parse().ok()
+        })
+    };
+
+    NaiveDate::from_ymd_opt(part("year")? as i32, part("month")?, part("day")?)?
+        .and_hms_opt(part("hour")?, part("minute")?, part("second")?)
+}
+
+fn zone_offset(instant: &DateTime<Utc>, zone: &str) -> Option<Duration> {
+    Some(wall_clock_in_zone(instant, zone)? - instant.naive_utc())
+}
+
+fn date_time_options(zone: &str, fields: &[(&str, &str)]) -> Object {
+    let options = Object::new();
+    let _ = Reflect::set(&options, &JsValue::from_str("timeZone"), &JsValue::from_str(zone));
+    for (key, value) in fields {
+        let _ = Reflect::set(&options, &JsValue::from_str(key), &JsValue::from_str(value));
+    }
+    options
+}
+
+fn to_js_date(date: &DateTime<Utc>) -> Date {
+    Date::new(&JsValue::from_f64(date.timestamp_millis() as f64))
+}
@@ -1,2 +1,3 @@
-// Utility modules for the frontend
-// Add common utilities here as needed
\ No newline at end of file
+pub mod datetime;
+
+pub use datetime::*;
\ No newline at end of file
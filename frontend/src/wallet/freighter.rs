@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use wasm_bindgen::prelude::*;
+use super::{call_method, injected, known_passphrase, options, string_field, Wallet, WalletNetwork};
+
+/// The Freighter browser extension, reached through the `window.freighter` object it injects.
+pub struct FreighterWallet;
+
+fn freighter() -> Result<js_sys::Object, String> {
+    injected("freighter")
+        .ok_or("Freighter not found. Please install the Freighter extension from the Chrome Web Store.".to_string())
+}
+
+/// Older versions answer yes/no questions with a boolean, newer ones with `{ <field>: boolean }`.
+fn flag(answer: &JsValue, field: &str) -> bool {
+    answer.as_bool()
+        .or_else(|| js_sys::Reflect::get(answer, &JsValue::from_str(field)).ok()?.as_bool())
+        .unwrap_or(false)
+}
+
+#[async_trait(?Send)]
+impl Wallet for FreighterWallet {
+    fn id(&self) -> &'static str {
+        "freighter"
+    }
+
+    fn name(&self) -> &'static str {
+        "Freighter"
+    }
+
+    fn is_available(&self) -> bool {
+        injected("freighter").is_some()
+    }
+
+    fn install_url(&self) -> &'static str {
+        "https://www.freighter.app"
+    }
+
+    async fn is_installed(&self) -> bool {
+        let Ok(freighter) = freighter() else {
+            return false;
+        };
+        match call_method(&freighter, "isConnected", &[]).await {
+            Ok(Some(answer)) => flag(&answer, "isConnected"),
+            // Versions without isConnected are installed once their API is on the page
+            Ok(None) => true,
+            Err(_) => false,
+        }
+    }
+
+    async fn is_allowed(&self) -> bool {
+        let Ok(freighter) = freighter() else {
+            return false;
+        };
+        matches!(call_method(&freighter, "isAllowed", &[]).await, Ok(Some(answer)) if flag(&answer, "isAllowed"))
+    }
+
+    async fn connect(&self) -> Result<String, String> {
+        if !self.is_allowed().await {
+            let freighter = freighter()?;
+            // Newer versions grant access and share the account in one prompt
+            if let Some(access) = call_method(&freighter, "requestAccess", &[]).await? {
+                return access.as_string()
+                    .or_else(|| string_field(&access, "address"))
+                    .filter(|address| !address.is_empty())
+                    .ok_or_else(|| string_field(&access, "error").unwrap_or("Freighter did not share an account with this site".to_string()));
+            }
+            if let Some(allowed) = call_method(&freighter, "setAllowed", &[]).await? {
+                if !flag(&allowed, "isAllowed") {
+                    return Err("Freighter did not allow this site to use your account".to_string());
+                }
+            }
+        }
+        self.public_key().await
+    }
+
+    async fn public_key(&self) -> Result<String, String> {
+        let public_key = call_method(&freighter()?, "getPublicKey", &[]).await?
+            .ok_or("Freighter getPublicKey method not found")?;
+
+        // Newer versions resolve to `{ publicKey }`, and to an empty key while locked
+        public_key.as_string()
+            .or_else(|| string_field(&public_key, "publicKey"))
+            .filter(|key| !key.is_empty())
+            .ok_or("Freighter is locked or has not shared an account with this site".to_string())
+    }
+
+    async fn network(&self) -> Result<Option<WalletNetwork>, String> {
+        let freighter = freighter()?;
+
+        if let Some(details) = call_method(&freighter, "getNetworkDetails", &[]).await? {
+            if let (Some(network), Some(network_passphrase)) =
+                (string_field(&details, "network"), string_field(&details, "networkPassphrase"))
+            {
+                return Ok(Some(WalletNetwork { network, network_passphrase }));
+            }
+        }
+
+        // Versions without getNetworkDetails only report the network's name
+        let network = call_method(&freighter, "getNetwork", &[]).await?
+            .and_then(|network| network.as_string().or_else(|| string_field(&network, "network")))
+            .ok_or("Freighter did not say which network it is on")?;
+        let network_passphrase = known_passphrase(&network)
+            .ok_or(format!("Freighter is on {}, whose passphrase it does not report", network))?
+            .to_string();
+        Ok(Some(WalletNetwork { network, network_passphrase }))
+    }
+
+    async fn sign_transaction(&self, transaction_xdr: &str, network_passphrase: &str) -> Result<String, String> {
+        let options = options(&[("networkPassphrase", network_passphrase)])?;
+        let signed = call_method(&freighter()?, "signTransaction", &[JsValue::from_str(transaction_xdr), options.into()])
+            .await
+            .map_err(|_| "Freighter did not sign the transaction. User may have rejected the request.")?
+            .ok_or("Freighter signTransaction method not found")?;
+
+        // Older Freighter versions resolve to the envelope, newer ones to `{ signedTxXdr }`
+        signed.as_string()
+            .or_else(|| string_field(&signed, "signedTxXdr"))
+            .ok_or("Invalid signed transaction format".to_string())
+    }
+
+    async fn sign_message(&self, message: &str) -> Result<String, String> {
+        let signed = call_method(&freighter()?, "signMessage", &[JsValue::from_str(message)])
+            .await
+            .map_err(|_| "Freighter did not sign the message. User may have rejected the request.")?
+            .ok_or("This version of Freighter cannot sign messages. Please update it.")?;
+        if let Some(error) = string_field(&signed, "error") {
+            return Err(error);
+        }
+
+        // Resolves to `{ signedMessage }`, a base64 string or, in some versions, the raw bytes
+        let signature = js_sys::Reflect::get(&signed, &JsValue::from_str("signedMessage"))
+            .map_err(|_| "Invalid signed message format")?;
+        match signature.as_string() {
+            Some(signature) => Ok(signature),
+            None if signature.is_object() => {
+                let bytes = js_sys::Uint8Array::new(&signature).to_vec();
+                let binary: String = bytes.into_iter().map(char::from).collect();
+                gloo::utils::window().btoa(&binary).map_err(|_| "Invalid signed message format".to_string())
+            }
+            None => Err("Invalid signed message format".to_string()),
+        }
+    }
+}
\ No newline at end of file
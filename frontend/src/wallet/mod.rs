@@ -0,0 +1,239 @@
+//! Browser wallets members connect and sign transactions with. Each wallet implements
+//! [`Wallet`]; [`supported_wallets`] lists the ones the app offers.
+
+pub mod freighter;
+pub mod xbull;
+
+pub use freighter::*;
+pub use xbull::*;
+
+use std::rc::Rc;
+use async_trait::async_trait;
+use gloo_storage::{LocalStorage, Storage};
+use wasm_bindgen::prelude::*;
+use shared::dto::StellarNetworkResponse;
+
+const SELECTED_WALLET_KEY: &str = "stellar_europe.wallet";
+const REMEMBER_CONNECTION_KEY: &str = "stellar_europe.wallet.remember_connection";
+
+/// The network a wallet is set to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WalletNetwork {
+    /// `PUBLIC`, `TESTNET`, `FUTURENET` or `STANDALONE`, as Freighter names it
+    pub network: String,
+    pub network_passphrase: String,
+}
+
+/// A Stellar wallet reachable from the browser, such as an extension or a hardware-wallet bridge.
+#[async_trait(?Send)]
+pub trait Wallet {
+    /// Stable identifier, remembered as the user's choice
+    fn id(&self) -> &'static str;
+    /// Name shown in the wallet picker
+    fn name(&self) -> &'static str;
+    /// Whether the wallet has put its API on the page. Extensions may do so after the app starts.
+    fn is_available(&self) -> bool;
+    /// Where to get the wallet
+    fn install_url(&self) -> &'static str;
+    /// Whether the wallet is installed, asking the wallet itself when it can say
+    async fn is_installed(&self) -> bool {
+        self.is_available()
+    }
+    /// Whether this site may read the account without prompting, so a remembered
+    /// connection can be restored silently
+    async fn is_allowed(&self) -> bool {
+        false
+    }
+    /// Asks for access if needed and returns the account's public key
+    async fn connect(&self) -> Result<String, String>;
+    /// The account the wallet currently shares, without prompting the user
+    async fn public_key(&self) -> Result<String, String>;
+    /// The network the wallet is on; `Ok(None)` if the wallet does not say
+    async fn network(&self) -> Result<Option<WalletNetwork>, String>;
+    /// Signs a base64 transaction envelope for the given network and returns the signed envelope
+    async fn sign_transaction(&self, transaction_xdr: &str, network_passphrase: &str) -> Result<String, String>;
+    /// Signs a text message as SEP-53 describes and returns the base64 signature
+    async fn sign_message(&self, _message: &str) -> Result<String, String> {
+        Err(format!("{} cannot sign messages. Please use another wallet.", self.name()))
+    }
+}
+
+pub type WalletRef = Rc<dyn Wallet>;
+
+/// Wallets offered in the picker, in order. Add a wallet by implementing [`Wallet`] and listing it here.
+pub fn supported_wallets() -> Vec<WalletRef> {
+    vec![Rc::new(FreighterWallet), Rc::new(XBullWallet)]
+}
+
+/// The remembered wallet if it is still offered and installed, otherwise the first installed one.
+pub fn pick_wallet(wallets: &[WalletRef], remembered: Option<&str>) -> Option<WalletRef> {
+    wallets
+        .iter()
+        .find(|wallet| Some(wallet.id()) == remembered && wallet.is_available())
+        .or_else(|| wallets.iter().find(|wallet| wallet.is_available()))
+        .cloned()
+}
+
+pub fn remembered_wallet() -> Option<String> {
+    LocalStorage::get(SELECTED_WALLET_KEY).ok()
+}
+
+pub fn remember_wallet(id: &str) {
+    let _ = LocalStorage::set(SELECTED_WALLET_KEY, id);
+}
+
+/// Whether the user asked to reconnect to their wallet on their next visit.
+pub fn remembers_connection() -> bool {
+    LocalStorage::get(REMEMBER_CONNECTION_KEY).unwrap_or(false)
+}
+
+pub fn set_remember_connection(remember: bool) {
+    let _ = LocalStorage::set(REMEMBER_CONNECTION_KEY, remember);
+}
+
+/// Passphrases of the networks wallets may only name.
+pub fn known_passphrase(network: &str) -> Option<&'static str> {
+    match network {
+        "PUBLIC" => Some("Public Global Stellar Network ; September 2015"),
+        "TESTNET" => Some("Test SDF Network ; September 2015"),
+        "FUTURENET" => Some("Test SDF Future Network ; October 2022"),
+        _ => None,
+    }
+}
+
+pub fn network_label(network: &str) -> String {
+    match network {
+        "PUBLIC" => "Mainnet".to_string(),
+        "TESTNET" => "Testnet".to_string(),
+        "FUTURENET" => "Futurenet".to_string(),
+        "CUSTOM" => "a custom network".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn passphrase_label(network_passphrase: &str) -> String {
+    ["PUBLIC", "TESTNET", "FUTURENET"]
+        .into_iter()
+        .find(|network| known_passphrase(network) == Some(network_passphrase))
+        .map(network_label)
+        .unwrap_or_else(|| format!("the network \"{}\"", network_passphrase))
+}
+
+/// Compares passphrases rather than names, since any custom network reports the same name.
+pub fn network_mismatch(wallet: &WalletNetwork, expected: &StellarNetworkResponse) -> Option<String> {
+    (wallet.network_passphrase != expected.network_passphrase).then(|| {
+        format!(
+            "Your wallet is on {} but this site uses {}. Switch networks in your wallet before signing.",
+            network_label(&wallet.network),
+            network_label(&expected.network)
+        )
+    })
+}
+
+/// Connects to `wallet` and reads which network it is on.
+pub async fn connect_wallet(wallet: &dyn Wallet) -> Result<(String, Option<WalletNetwork>), String> {
+    if !wallet.is_installed().await {
+        return Err(format!("{} is not installed", wallet.name()));
+    }
+    let public_key = wallet.connect().await?;
+    Ok((public_key, wallet.network().await?))
+}
+
+/// Reads the account and network of a wallet that already allows this site, without
+/// prompting. `None` if the user would have to be asked.
+pub async fn restore_connection(wallet: &dyn Wallet) -> Option<(String, Option<WalletNetwork>)> {
+    if !wallet.is_installed().await || !wallet.is_allowed().await {
+        return None;
+    }
+    let public_key = wallet.public_key().await.ok()?;
+    Some((public_key, wallet.network().await.ok()?))
+}
+
+/// Signs with `wallet` once it is known to be on the transaction's network. Wallets sign
+/// for whichever network they are on, so a mismatch would otherwise only show at submission.
+pub async fn sign_with(wallet: &dyn Wallet, transaction_xdr: &str, network_passphrase: &str) -> Result<String, String> {
+    if let Some(network) = wallet.network().await? {
+        if network.network_passphrase != network_passphrase {
+            return Err(format!(
+                "{} is on {} but this transaction is for {}. Switch networks in {} and try again.",
+                wallet.name(),
+                network_label(&network.network),
+                passphrase_label(network_passphrase),
+                wallet.name()
+            ));
+        }
+    }
+    wallet.sign_transaction(transaction_xdr, network_passphrase).await
+}
+
+/// Asks the wallet the user picked last, or the first one installed, to sign a base64
+/// transaction envelope for the given network and returns the signed envelope.
+pub async fn sign_transaction(transaction_xdr: &str, network_passphrase: &str) -> Result<String, String> {
+    let wallet = pick_wallet(&supported_wallets(), remembered_wallet().as_deref())
+        .ok_or("No Stellar wallet found. Please install Freighter or xBull.")?;
+    sign_with(wallet.as_ref(), transaction_xdr, network_passphrase).await
+}
+
+/// Asks the wallet the user picked last, or the first one installed, to sign `message` with
+/// the account `public_key`, and returns the base64 signature.
+pub async fn sign_message(message: &str, public_key: &str) -> Result<String, String> {
+    let wallet = pick_wallet(&supported_wallets(), remembered_wallet().as_deref())
+        .ok_or("No Stellar wallet found. Please install Freighter or xBull.")?;
+    let shared = wallet.public_key().await?;
+    if shared != public_key {
+        return Err(format!(
+            "{} is using {} but the form names {}. Switch accounts in {} and try again.",
+            wallet.name(),
+            shared,
+            public_key,
+            wallet.name()
+        ));
+    }
+    wallet.sign_message(message).await
+}
+
+/// The object a wallet extension put on `window`, if it did.
+pub(crate) fn injected(name: &str) -> Option<js_sys::Object> {
+    let window = web_sys::window()?;
+    js_sys::Reflect::get(&window, &JsValue::from_str(name))
+        .ok()
+        .filter(|object| !object.is_undefined() && !object.is_null())?
+        .dyn_into::<js_sys::Object>()
+        .ok()
+}
+
+/// Calls a wallet method and waits for its promise. `Ok(None)` if this version of the
+/// wallet does not have the method.
+pub(crate) async fn call_method(object: &js_sys::Object, method: &str, args: &[JsValue]) -> Result<Option<JsValue>, String> {
+    let function = js_sys::Reflect::get(object, &JsValue::from_str(method))
+        .map_err(|_| format!("Wallet method {} not found", method))?;
+    if function.is_undefined() {
+        return Ok(None);
+    }
+
+    let promise = function.dyn_into::<js_sys::Function>()
+        .map_err(|_| format!("Invalid {} function", method))?
+        .apply(object, &args.iter().collect::<js_sys::Array>())
+        .map_err(|_| format!("Failed to call {}", method))?;
+
+    // Some wallets answer synchronously
+    let result = match promise.dyn_into::<js_sys::Promise>() {
+        Ok(promise) => wasm_bindgen_futures::JsFuture::from(promise).await
+            .map_err(|_| format!("The wallet refused {}. User may have rejected the request.", method))?,
+        Err(value) => value,
+    };
+    Ok(Some(result))
+}
+
+pub(crate) fn string_field(value: &JsValue, field: &str) -> Option<String> {
+    js_sys::Reflect::get(value, &JsValue::from_str(field)).ok()?.as_string()
+}
+
+pub(crate) fn options(fields: &[(&str, &str)]) -> Result<js_sys::Object, String> {
+    let options = js_sys::Object::new();
+    for (key, value) in fields {
+        js_sys::Reflect::set(&options, &JsValue::from_str(key), &JsValue::from_str(value))
+            .map_err(|_| "Failed to build wallet options")?;
+    }
+    Ok(options)
+}
\ No newline at end of file
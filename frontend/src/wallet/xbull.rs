@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use wasm_bindgen::prelude::*;
+use super::{call_method, injected, options, string_field, Wallet, WalletNetwork};
+
+/// The xBull browser extension, reached through the `window.xBullSDK` object it injects.
+/// xBull does not say which network it is on; it signs for the passphrase it is given.
+pub struct XBullWallet;
+
+fn xbull() -> Result<js_sys::Object, String> {
+    injected("xBullSDK").ok_or("xBull not found. Please install the xBull Wallet extension.".to_string())
+}
+
+#[async_trait(?Send)]
+impl Wallet for XBullWallet {
+    fn id(&self) -> &'static str {
+        "xbull"
+    }
+
+    fn name(&self) -> &'static str {
+        "xBull"
+    }
+
+    fn is_available(&self) -> bool {
+        injected("xBullSDK").is_some()
+    }
+
+    fn install_url(&self) -> &'static str {
+        "https://xbull.app"
+    }
+
+    async fn connect(&self) -> Result<String, String> {
+        let xbull = xbull()?;
+        let permissions = js_sys::Object::new();
+        for permission in ["canRequestPublicKey", "canRequestSign"] {
+            js_sys::Reflect::set(&permissions, &JsValue::from_str(permission), &JsValue::TRUE)
+                .map_err(|_| "Failed to build wallet options")?;
+        }
+        call_method(&xbull, "connect", &[permissions.into()]).await?;
+        self.public_key().await
+    }
+
+    async fn public_key(&self) -> Result<String, String> {
+        call_method(&xbull()?, "getPublicKey", &[]).await?
+            .and_then(|public_key| public_key.as_string())
+            .filter(|key| !key.is_empty())
+            .ok_or("xBull has not shared an account with this site".to_string())
+    }
+
+    async fn network(&self) -> Result<Option<WalletNetwork>, String> {
+        Ok(None)
+    }
+
+    async fn sign_transaction(&self, transaction_xdr: &str, network_passphrase: &str) -> Result<String, String> {
+        let options = options(&[("network", network_passphrase)])?;
+        let signed = call_method(&xbull()?, "signXDR", &[JsValue::from_str(transaction_xdr), options.into()])
+            .await
+            .map_err(|_| "xBull did not sign the transaction. User may have rejected the request.")?
+            .ok_or("xBull signXDR method not found")?;
+
+        signed.as_string()
+            .or_else(|| string_field(&signed, "signedXDR"))
+            .ok_or("Invalid signed transaction format".to_string())
+    }
+}
\ No newline at end of file
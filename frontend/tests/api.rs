@@ -0,0 +1,61 @@
+use frontend::services::{endpoint, ApiClient, ApiError, API_VERSION};
+use shared::dto::FieldError;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn endpoints_are_joined_to_the_base_url_once() {
+    assert_eq!(API_VERSION, "v1");
+    assert_eq!(endpoint("/events"), "/api/v1/events");
+
+    let client = ApiClient::new("https://api.example.org/");
+    assert_eq!(client.url(&endpoint("/events/7")), "https://api.example.org/api/v1/events/7");
+    assert_eq!(ApiClient::new("https://api.example.org///").url("/health"), "https://api.example.org/health");
+    assert_eq!(ApiClient::new("http://127.0.0.1:8081").url(&endpoint("/chapters")), "http://127.0.0.1:8081/api/v1/chapters");
+    assert_eq!(
+        client.attachment_content_url("7", "3"),
+        "https://api.example.org/api/v1/events/7/attachments/3/content"
+    );
+}
+
+#[wasm_bindgen_test]
+fn error_responses_become_api_errors() {
+    assert_eq!(
+        ApiError::from_response(404, r#"{"message": "Event not found"}"#),
+        ApiError::Http { status: 404, message: "Event not found".to_string() }
+    );
+
+    let validation = ApiError::from_response(
+        400,
+        r#"{"message": "Invalid event", "field_errors": [{"field": "title", "message": "Title is required"}]}"#,
+    );
+    assert_eq!(
+        validation,
+        ApiError::Validation {
+            message: "Invalid event".to_string(),
+            field_errors: vec![FieldError { field: "title".to_string(), message: "Title is required".to_string() }],
+        }
+    );
+    assert_eq!(validation.field_error("title"), Some("Title is required"));
+    assert_eq!(validation.field_error("date"), None);
+    assert!(!validation.is_retryable());
+}
+
+#[wasm_bindgen_test]
+fn bodies_that_are_not_error_responses_keep_their_text() {
+    assert_eq!(
+        ApiError::from_response(409, r#""Already registered""#),
+        ApiError::Http { status: 409, message: "Already registered".to_string() }
+    );
+    assert_eq!(
+        ApiError::from_response(502, "Bad gateway from the proxy"),
+        ApiError::Http { status: 502, message: "Bad gateway from the proxy".to_string() }
+    );
+    // An empty body falls back to the status's reason
+    assert_eq!(
+        ApiError::from_response(503, "  "),
+        ApiError::Http { status: 503, message: "Service Unavailable".to_string() }
+    );
+    assert_eq!(ApiError::from_response(599, ""), ApiError::Http { status: 599, message: "Unknown error".to_string() });
+    assert!(ApiError::from_response(503, "").is_retryable());
+    assert!(!ApiError::from_response(401, "").is_retryable());
+}
\ No newline at end of file
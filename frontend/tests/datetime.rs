@@ -0,0 +1,62 @@
+use chrono::{DateTime, TimeZone, Utc};
+use frontend::utils::{zoned_input_to_utc, EUROPEAN_TIME_ZONES};
+use wasm_bindgen_test::*;
+
+fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> Option<DateTime<Utc>> {
+    Some(Utc.with_ymd_and_hms(year, month, day, hour, minute, 0).unwrap())
+}
+
+// Berlin moves from CET (UTC+1) to CEST (UTC+2) at 02:00 on 29 March 2026
+#[wasm_bindgen_test]
+fn spring_forward_gap_is_rejected() {
+    assert_eq!(zoned_input_to_utc("2026-03-29T01:30", "Europe/Berlin"), utc(2026, 3, 29, 0, 30));
+    assert_eq!(zoned_input_to_utc("2026-03-29T02:00", "Europe/Berlin"), None);
+    assert_eq!(zoned_input_to_utc("2026-03-29T02:30", "Europe/Berlin"), None);
+    assert_eq!(zoned_input_to_utc("2026-03-29T03:00", "Europe/Berlin"), utc(2026, 3, 29, 1, 0));
+    assert_eq!(zoned_input_to_utc("2026-03-29T03:30", "Europe/Berlin"), utc(2026, 3, 29, 1, 30));
+}
+
+// Berlin moves back from CEST to CET at 03:00 on 25 October 2026, so 02:00–02:59 happens twice
+#[wasm_bindgen_test]
+fn fall_back_overlap_resolves_to_the_earlier_instant() {
+    assert_eq!(zoned_input_to_utc("2026-10-25T01:30", "Europe/Berlin"), utc(2026, 10, 24, 23, 30));
+    assert_eq!(zoned_input_to_utc("2026-10-25T02:30", "Europe/Berlin"), utc(2026, 10, 25, 0, 30));
+    assert_eq!(zoned_input_to_utc("2026-10-25T03:00", "Europe/Berlin"), utc(2026, 10, 25, 2, 0));
+    assert_eq!(zoned_input_to_utc("2026-10-25T03:30", "Europe/Berlin"), utc(2026, 10, 25, 2, 30));
+}
+
+// London changes an hour earlier in wall-clock time, between GMT and BST
+#[wasm_bindgen_test]
+fn transitions_follow_the_chosen_zone_not_the_browser() {
+    assert_eq!(zoned_input_to_utc("2026-03-29T01:30", "Europe/London"), None);
+    assert_eq!(zoned_input_to_utc("2026-03-29T02:30", "Europe/London"), utc(2026, 3, 29, 1, 30));
+    assert_eq!(zoned_input_to_utc("2026-10-25T01:30", "Europe/London"), utc(2026, 10, 25, 0, 30));
+    assert_eq!(zoned_input_to_utc("2026-10-25T02:30", "Europe/London"), utc(2026, 10, 25, 2, 30));
+}
+
+// The offset is probed per instant: summer and winter differ, and Istanbul keeps UTC+3
+#[wasm_bindgen_test]
+fn offsets_are_probed_for_the_date_given() {
+    assert_eq!(zoned_input_to_utc("2026-01-15T18:00", "Atlantic/Azores"), utc(2026, 1, 15, 19, 0));
+    assert_eq!(zoned_input_to_utc("2026-07-15T18:00", "Atlantic/Azores"), utc(2026, 7, 15, 18, 0));
+    assert_eq!(zoned_input_to_utc("2026-01-15T18:00", "Europe/Istanbul"), utc(2026, 1, 15, 15, 0));
+    assert_eq!(zoned_input_to_utc("2026-07-15T18:00", "Europe/Istanbul"), utc(2026, 7, 15, 15, 0));
+    assert_eq!(zoned_input_to_utc("2026-07-15T18:00", "UTC"), utc(2026, 7, 15, 18, 0));
+}
+
+#[wasm_bindgen_test]
+fn every_offered_zone_round_trips_a_summer_evening() {
+    for zone in EUROPEAN_TIME_ZONES {
+        assert!(zoned_input_to_utc("2026-07-15T18:00", zone).is_some(), "{} did not resolve", zone);
+    }
+}
+
+#[wasm_bindgen_test]
+fn input_with_seconds_is_accepted_and_malformed_input_is_not() {
+    assert_eq!(
+        zoned_input_to_utc("2026-07-15T18:00:30", "Europe/Paris"),
+        Some(Utc.with_ymd_and_hms(2026, 7, 15, 16, 0, 30).unwrap())
+    );
+    assert_eq!(zoned_input_to_utc("", "Europe/Paris"), None);
+    assert_eq!(zoned_input_to_utc("15/07/2026 18:00", "Europe/Paris"), None);
+}
\ No newline at end of file
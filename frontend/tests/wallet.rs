@@ -0,0 +1,250 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use async_trait::async_trait;
+use frontend::components::{WalletInput, WalletInputProps};
+use frontend::hooks::WalletList;
+use frontend::wallet::{
+    connect_wallet, network_mismatch, pick_wallet, restore_connection, set_remember_connection, sign_with, Wallet,
+    WalletNetwork, WalletRef,
+};
+use gloo::timers::future::TimeoutFuture;
+use shared::dto::StellarNetworkResponse;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use yew::Callback;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const TESTNET: &str = "Test SDF Network ; September 2015";
+const PUBLIC: &str = "Public Global Stellar Network ; September 2015";
+const ALICE: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+/// Stands in for a browser wallet: shares `public_key`, reports `network` and records what it signs.
+/// `allowed` is whether the site was granted access on an earlier visit.
+struct MockWallet {
+    id: &'static str,
+    installed: bool,
+    allowed: bool,
+    network: Option<WalletNetwork>,
+    signed: RefCell<Vec<(String, String)>>,
+}
+
+impl MockWallet {
+    fn on(id: &'static str, network: Option<(&str, &str)>) -> Self {
+        Self {
+            id,
+            installed: true,
+            allowed: false,
+            network: network.map(|(network, network_passphrase)| WalletNetwork {
+                network: network.to_string(),
+                network_passphrase: network_passphrase.to_string(),
+            }),
+            signed: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn missing(id: &'static str) -> Self {
+        Self { installed: false, ..Self::on(id, None) }
+    }
+
+    fn allowed(self) -> Self {
+        Self { allowed: true, ..self }
+    }
+}
+
+#[async_trait(?Send)]
+impl Wallet for MockWallet {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn name(&self) -> &'static str {
+        "Mock Wallet"
+    }
+
+    fn is_available(&self) -> bool {
+        self.installed
+    }
+
+    fn install_url(&self) -> &'static str {
+        "https://wallet.example"
+    }
+
+    async fn is_allowed(&self) -> bool {
+        self.allowed
+    }
+
+    async fn connect(&self) -> Result<String, String> {
+        self.public_key().await
+    }
+
+    async fn public_key(&self) -> Result<String, String> {
+        if self.installed { Ok(ALICE.to_string()) } else { Err("not installed".to_string()) }
+    }
+
+    async fn network(&self) -> Result<Option<WalletNetwork>, String> {
+        Ok(self.network.clone())
+    }
+
+    async fn sign_transaction(&self, transaction_xdr: &str, network_passphrase: &str) -> Result<String, String> {
+        self.signed.borrow_mut().push((transaction_xdr.to_string(), network_passphrase.to_string()));
+        Ok(format!("{}+signed", transaction_xdr))
+    }
+}
+
+fn ids(wallet: Option<WalletRef>) -> Option<&'static str> {
+    wallet.map(|wallet| wallet.id())
+}
+
+#[wasm_bindgen_test]
+fn picks_the_remembered_wallet_while_it_is_installed() {
+    let wallets: Vec<WalletRef> = vec![
+        Rc::new(MockWallet::missing("first")),
+        Rc::new(MockWallet::on("second", None)),
+        Rc::new(MockWallet::on("third", None)),
+    ];
+
+    assert_eq!(ids(pick_wallet(&wallets, Some("third"))), Some("third"));
+    assert_eq!(ids(pick_wallet(&wallets, Some("first"))), Some("second"));
+    assert_eq!(ids(pick_wallet(&wallets, None)), Some("second"));
+    assert_eq!(ids(pick_wallet(&wallets[..1], None)), None);
+}
+
+#[wasm_bindgen_test]
+async fn connecting_reads_the_account_and_network() {
+    let wallet = MockWallet::on("mock", Some(("TESTNET", TESTNET)));
+
+    let (public_key, network) = connect_wallet(&wallet).await.unwrap();
+    assert_eq!(public_key, ALICE);
+    assert_eq!(network.unwrap().network_passphrase, TESTNET);
+}
+
+#[wasm_bindgen_test]
+async fn connecting_to_a_missing_wallet_fails() {
+    let error = connect_wallet(&MockWallet::missing("mock")).await.unwrap_err();
+    assert_eq!(error, "Mock Wallet is not installed");
+}
+
+#[wasm_bindgen_test]
+async fn only_allowed_wallets_are_restored() {
+    assert_eq!(restore_connection(&MockWallet::on("mock", None)).await, None);
+    assert_eq!(restore_connection(&MockWallet::missing("mock").allowed()).await, None);
+
+    let (public_key, network) = restore_connection(&MockWallet::on("mock", Some(("TESTNET", TESTNET))).allowed())
+        .await
+        .unwrap();
+    assert_eq!(public_key, ALICE);
+    assert_eq!(network.unwrap().network, "TESTNET");
+}
+
+#[wasm_bindgen_test]
+fn warns_only_when_the_passphrases_differ() {
+    let backend = StellarNetworkResponse {
+        network: "TESTNET".to_string(),
+        network_passphrase: TESTNET.to_string(),
+        horizon_url: "https://horizon-testnet.stellar.org".to_string(),
+    };
+    let on = |network: &str, network_passphrase: &str| WalletNetwork {
+        network: network.to_string(),
+        network_passphrase: network_passphrase.to_string(),
+    };
+
+    assert_eq!(network_mismatch(&on("TESTNET", TESTNET), &backend), None);
+    let warning = network_mismatch(&on("PUBLIC", PUBLIC), &backend).unwrap();
+    assert!(warning.contains("Mainnet") && warning.contains("Testnet"), "{}", warning);
+}
+
+#[wasm_bindgen_test]
+async fn signing_is_refused_on_another_network() {
+    let wallet = MockWallet::on("mock", Some(("PUBLIC", PUBLIC)));
+
+    let error = sign_with(&wallet, "AAAA", TESTNET).await.unwrap_err();
+    assert!(error.contains("Mock Wallet is on Mainnet"), "{}", error);
+    assert!(wallet.signed.borrow().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn wallets_that_do_not_report_a_network_sign_for_the_given_one() {
+    let wallet = MockWallet::on("mock", None);
+
+    assert_eq!(sign_with(&wallet, "AAAA", TESTNET).await.unwrap(), "AAAA+signed");
+    assert_eq!(*wallet.signed.borrow(), vec![("AAAA".to_string(), TESTNET.to_string())]);
+}
+
+fn wallet_input_props(entered: &Rc<RefCell<Vec<String>>>, wallets: Vec<WalletRef>) -> WalletInputProps {
+    let entered = entered.clone();
+    WalletInputProps {
+        value: String::new(),
+        on_change: Callback::from(move |value: String| entered.borrow_mut().push(value)),
+        label: None,
+        error: None,
+        required: false,
+        help_text: None,
+        wallets: WalletList::new(wallets),
+    }
+}
+
+#[wasm_bindgen_test]
+async fn wallet_input_offers_the_wallets_and_fills_in_the_connected_account() {
+    set_remember_connection(false);
+    let root = gloo::utils::document().create_element("div").unwrap();
+    gloo::utils::body().append_child(&root).unwrap();
+
+    let entered = Rc::new(RefCell::new(Vec::<String>::new()));
+    let wallets: Vec<WalletRef> = vec![
+        Rc::new(MockWallet::on("mock", Some(("TESTNET", TESTNET)))),
+        Rc::new(MockWallet::missing("absent")),
+    ];
+    let props = wallet_input_props(&entered, wallets);
+    yew::Renderer::<WalletInput>::with_root_and_props(root.clone(), props).render();
+    TimeoutFuture::new(50).await;
+
+    let html = root.inner_html();
+    assert!(html.contains("Connect Mock Wallet"), "{}", html);
+    assert!(html.contains("Mock Wallet (not installed)"), "{}", html);
+
+    root.query_selector("button")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<web_sys::HtmlElement>()
+        .unwrap()
+        .click();
+    TimeoutFuture::new(50).await;
+
+    assert_eq!(*entered.borrow(), vec![ALICE.to_string()]);
+    assert!(root.inner_html().contains("Wallet connected via Mock Wallet"), "{}", root.inner_html());
+}
+
+#[wasm_bindgen_test]
+async fn wallet_input_restores_a_remembered_connection_on_mount() {
+    set_remember_connection(true);
+    let root = gloo::utils::document().create_element("div").unwrap();
+    gloo::utils::body().append_child(&root).unwrap();
+
+    let entered = Rc::new(RefCell::new(Vec::<String>::new()));
+    let wallets: Vec<WalletRef> = vec![Rc::new(MockWallet::on("mock", None).allowed())];
+    yew::Renderer::<WalletInput>::with_root_and_props(root.clone(), wallet_input_props(&entered, wallets)).render();
+    TimeoutFuture::new(50).await;
+
+    assert_eq!(*entered.borrow(), vec![ALICE.to_string()]);
+    let html = root.inner_html();
+    assert!(html.contains("Disconnect") && html.contains("Remember connection"), "{}", html);
+    set_remember_connection(false);
+}
+
+#[wasm_bindgen_test]
+async fn wallet_input_offers_to_install_a_wallet_when_none_is_found() {
+    let root = gloo::utils::document().create_element("div").unwrap();
+    gloo::utils::body().append_child(&root).unwrap();
+
+    let entered = Rc::new(RefCell::new(Vec::<String>::new()));
+    let wallets: Vec<WalletRef> = vec![Rc::new(MockWallet::missing("mock"))];
+    yew::Renderer::<WalletInput>::with_root_and_props(root.clone(), wallet_input_props(&entered, wallets)).render();
+    TimeoutFuture::new(50).await;
+    assert!(root.inner_html().contains("Looking for wallets"), "{}", root.inner_html());
+
+    // Detection looks again while extensions load before giving up
+    TimeoutFuture::new(2_500).await;
+    assert!(root.inner_html().contains("Install Mock Wallet"), "{}", root.inner_html());
+    assert!(entered.borrow().is_empty());
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use super::user::UserPublic;
+
+/// What an uploaded file is for; decides which file types are accepted.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum AttachmentKind {
+    /// Proof of an expense: an image or PDF, uploaded by the organiser
+    Receipt,
+    /// An image from the event
+    Photo,
+    /// A talk's slides: PDF, PowerPoint or OpenDocument
+    Slides,
+    /// Anything else of an accepted type
+    Document,
+}
+
+impl AttachmentKind {
+    pub const ALL: [AttachmentKind; 4] = [
+        AttachmentKind::Receipt,
+        AttachmentKind::Photo,
+        AttachmentKind::Slides,
+        AttachmentKind::Document,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.to_string() == name)
+    }
+}
+
+impl std::fmt::Display for AttachmentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttachmentKind::Receipt => write!(f, "Receipt"),
+            AttachmentKind::Photo => write!(f, "Photo"),
+            AttachmentKind::Slides => write!(f, "Slides"),
+            AttachmentKind::Document => write!(f, "Document"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AttachmentResponse {
+    pub id: String,
+    pub event_id: String,
+    /// Budget item a receipt belongs to
+    pub budget_item_id: Option<String>,
+    pub kind: AttachmentKind,
+    pub file_name: String,
+    /// Type detected from the file's contents
+    pub content_type: String,
+    pub size_bytes: u64,
+    /// Whether a downscaled preview can be fetched from the thumbnail endpoint
+    pub has_thumbnail: bool,
+    pub uploaded_by: Option<UserPublic>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AttachmentListResponse {
+    pub attachments: Vec<AttachmentResponse>,
+    /// Largest file the server accepts
+    pub max_upload_bytes: u64,
+}
\ No newline at end of file
@@ -0,0 +1,137 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use super::event::EventStatus;
+use super::user::UserPublic;
+
+/// Where an attendee's proof-of-attendance token is.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum AttendanceTokenStatus {
+    /// Checked in; no token prepared yet
+    NotIssued,
+    /// In a transaction waiting for a programme admin to sign it
+    AwaitingSignature,
+    /// Sent to the network without a definite answer; submit it again to find out
+    Submitted,
+    /// The claimable balance exists; the attendee can claim it from their wallet
+    Issued,
+    /// Refused by the network; the attendee is included in the next issuance
+    Failed,
+}
+
+impl AttendanceTokenStatus {
+    pub const ALL: [AttendanceTokenStatus; 5] = [
+        AttendanceTokenStatus::NotIssued,
+        AttendanceTokenStatus::AwaitingSignature,
+        AttendanceTokenStatus::Submitted,
+        AttendanceTokenStatus::Issued,
+        AttendanceTokenStatus::Failed,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|status| status.to_string() == name)
+    }
+}
+
+impl std::fmt::Display for AttendanceTokenStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttendanceTokenStatus::NotIssued => write!(f, "NotIssued"),
+            AttendanceTokenStatus::AwaitingSignature => write!(f, "AwaitingSignature"),
+            AttendanceTokenStatus::Submitted => write!(f, "Submitted"),
+            AttendanceTokenStatus::Issued => write!(f, "Issued"),
+            AttendanceTokenStatus::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+/// Checks a registered user in at an event, as the signed-in organizer or a programme admin.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CheckInRequest {
+    pub attendee_wallet_address: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CheckInResponse {
+    pub id: String,
+    pub event_id: String,
+    pub attendee: UserPublic,
+    pub checked_in_by: Option<UserPublic>,
+    pub checked_in_at: DateTime<Utc>,
+    pub token_status: AttendanceTokenStatus,
+    /// Transaction creating the attendee's claimable balance, once prepared
+    pub transaction_hash: Option<String>,
+}
+
+/// The prepared issuance after a signed-in programme admin signed it in the wallet.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SubmitAttendanceTokensRequest {
+    /// Base64 transaction envelope returned by the wallet
+    pub signed_transaction_xdr: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AttendanceIssuanceResponse {
+    pub id: String,
+    pub event_id: String,
+    /// Code of the event's proof-of-attendance asset, e.g. `POA42`
+    pub asset_code: String,
+    /// Account issuing the asset; it must sign the transaction
+    pub issuer: String,
+    /// Never `NotIssued`
+    pub status: AttendanceTokenStatus,
+    /// Attendees the transaction creates a claimable balance for
+    pub token_count: u32,
+    /// Unsigned base64 transaction envelope to sign
+    pub transaction_xdr: String,
+    pub transaction_hash: String,
+    /// Network the transaction is for, to pass to the wallet when signing
+    pub network_passphrase: String,
+    /// Ledger the balances were created in, once issued
+    pub ledger: Option<u32>,
+    /// Why the issuance failed, or why the outcome of its submission is unknown
+    pub error: Option<String>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub issued_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EventAttendanceResponse {
+    pub event_title: String,
+    pub event_status: EventStatus,
+    pub asset_code: String,
+    /// Programme account issuing the tokens; `None` if issuance is not configured
+    pub asset_issuer: Option<String>,
+    pub check_ins: Vec<CheckInResponse>,
+    /// The issuance waiting to be signed or for its outcome, if any
+    pub open_issuance: Option<AttendanceIssuanceResponse>,
+}
+
+/// An event a user was checked in at, and their token for it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AttendanceRecord {
+    pub event_id: String,
+    pub event_title: String,
+    pub event_date: DateTime<Utc>,
+    pub checked_in_at: DateTime<Utc>,
+    pub asset_code: String,
+    /// Set once a token has been prepared
+    pub asset_issuer: Option<String>,
+    pub token_status: AttendanceTokenStatus,
+    pub transaction_hash: Option<String>,
+    pub issued_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UserAttendanceResponse {
+    pub user: UserPublic,
+    pub attendance: Vec<AttendanceRecord>,
+}
\ No newline at end of file
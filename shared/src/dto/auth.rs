@@ -1,6 +1,9 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use super::user::UserPublic;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum UserType {
     Ambassador,
     ChapterLead,
@@ -16,6 +19,7 @@ impl std::fmt::Display for UserType {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct SignUpRequest {
     pub username: String,
     pub email: String,
@@ -23,4 +27,67 @@ pub struct SignUpRequest {
     pub user_type: UserType,
     pub organization: Option<String>,
     pub bio: Option<String>,
+    /// Nonce from `POST /signup/challenge` for this wallet address
+    pub nonce: String,
+    /// Base64 signature of the challenge's `message` by the wallet, as SEP-53 wallets sign messages
+    pub signature: String,
+}
+
+/// Asks for a nonce to sign with the wallet that is signing up or in.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SignUpChallengeRequest {
+    pub wallet_address: String,
+}
+
+/// What the wallet signs to prove it belongs to whoever signs up.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SignUpChallengeResponse {
+    pub wallet_address: String,
+    pub nonce: String,
+    /// The text to sign, which names the wallet address and the nonce
+    pub message: String,
+    /// The signup or sign-in must be sent before then
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Signs in with a registered wallet by signing a nonce from `POST /sessions/challenge`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SignInRequest {
+    pub wallet_address: String,
+    pub nonce: String,
+    /// Base64 signature of the challenge's `message` by the wallet, as SEP-53 wallets sign messages
+    pub signature: String,
+}
+
+/// A session for the signed-in user. Send the token as `Authorization: Bearer <token>`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SessionResponse {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+    pub user: UserPublic,
+}
+
+/// The token from a verification link.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct VerifyEmailRequest {
+    pub token: String,
+}
+
+/// Asks for a new verification link, mailed to the address the user signed up with.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ResendVerificationRequest {
+    pub wallet_address: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EmailVerificationResponse {
+    pub user: UserPublic,
+    pub message: String,
 }
\ No newline at end of file
@@ -0,0 +1,196 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use super::event::EventStatus;
+use super::reimbursement::ReimbursementStatus;
+
+pub use rust_decimal::Decimal;
+
+/// Currencies budgets are kept in. Fiat for expenses paid locally, USDC and XLM
+/// for reimbursements on Stellar.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum Currency {
+    EUR,
+    USD,
+    GBP,
+    CHF,
+    USDC,
+    XLM,
+}
+
+impl Currency {
+    pub const ALL: [Currency; 6] =
+        [Currency::EUR, Currency::USD, Currency::GBP, Currency::CHF, Currency::USDC, Currency::XLM];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|currency| currency.to_string() == name)
+    }
+
+    /// Smallest unit amounts are kept in: cents, or stroops for XLM.
+    pub fn decimal_places(self) -> u32 {
+        match self {
+            Currency::XLM => 7,
+            _ => 2,
+        }
+    }
+
+    /// Whether spending in this currency can be reimbursed with a Stellar payment.
+    pub fn is_stellar_asset(self) -> bool {
+        matches!(self, Currency::USDC | Currency::XLM)
+    }
+
+    /// Checks `amount` is not negative and has no more decimals than the currency allows.
+    pub fn check_amount(self, amount: Decimal) -> Result<(), String> {
+        if amount.is_sign_negative() && !amount.is_zero() {
+            return Err("Amount cannot be negative".to_string());
+        }
+        if amount.normalize().scale() > self.decimal_places() {
+            return Err(format!("{} amounts have at most {} decimal places", self, self.decimal_places()));
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Currency::EUR => write!(f, "EUR"),
+            Currency::USD => write!(f, "USD"),
+            Currency::GBP => write!(f, "GBP"),
+            Currency::CHF => write!(f, "CHF"),
+            Currency::USDC => write!(f, "USDC"),
+            Currency::XLM => write!(f, "XLM"),
+        }
+    }
+}
+
+/// An exact amount in one currency. The amount is serialised as a decimal string,
+/// e.g. `{"amount": "120.50", "currency": "EUR"}`, so it never passes through a float.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Money {
+    pub amount: Decimal,
+    pub currency: Currency,
+}
+
+impl Money {
+    /// Rounds `amount` to the currency's decimal places, so `300` and `300.0000000`
+    /// both become `300.00` in euros.
+    pub fn new(amount: Decimal, currency: Currency) -> Self {
+        let mut amount = amount.round_dp(currency.decimal_places());
+        amount.rescale(currency.decimal_places());
+        Money { amount, currency }
+    }
+
+    pub fn zero(currency: Currency) -> Self {
+        Money::new(Decimal::ZERO, currency)
+    }
+}
+
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let places = self.currency.decimal_places() as usize;
+        write!(f, "{:.*} {}", places, self.amount, self.currency)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum BudgetCategory {
+    Venue,
+    Food,
+    Swag,
+    Travel,
+    Other,
+}
+
+impl BudgetCategory {
+    pub const ALL: [BudgetCategory; 5] = [
+        BudgetCategory::Venue,
+        BudgetCategory::Food,
+        BudgetCategory::Swag,
+        BudgetCategory::Travel,
+        BudgetCategory::Other,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|category| category.to_string() == name)
+    }
+}
+
+impl std::fmt::Display for BudgetCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BudgetCategory::Venue => write!(f, "Venue"),
+            BudgetCategory::Food => write!(f, "Food"),
+            BudgetCategory::Swag => write!(f, "Swag"),
+            BudgetCategory::Travel => write!(f, "Travel"),
+            BudgetCategory::Other => write!(f, "Other"),
+        }
+    }
+}
+
+/// Adds or edits a line item as the signed-in organiser.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BudgetItemRequest {
+    pub category: BudgetCategory,
+    #[serde(default)]
+    pub description: String,
+    pub currency: Currency,
+    pub requested_amount: Decimal,
+}
+
+/// Sets the approved or spent amount of a line item, in the item's currency, as the
+/// signed-in reviewer or organiser. `None` clears it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BudgetAmountRequest {
+    #[serde(default)]
+    pub amount: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BudgetItemResponse {
+    pub id: String,
+    pub event_id: String,
+    pub category: BudgetCategory,
+    pub description: String,
+    pub requested: Money,
+    /// `None` until a reviewer approves the item
+    pub approved: Option<Money>,
+    /// `None` until the organiser reports what was spent
+    pub spent: Option<Money>,
+    /// `None` until a programme admin prepares a payout of the spending
+    pub reimbursement_status: Option<ReimbursementStatus>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Budget totals in one currency, with what the spending achieved. Amounts in
+/// different currencies are never added together.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BudgetRollup {
+    pub currency: Currency,
+    pub requested: Money,
+    pub approved: Money,
+    pub spent: Money,
+    /// Recorded attendance of the events that reported spending in this currency
+    pub attendance: u32,
+    /// Recorded SCF referrals of the same events
+    pub scf_referrals: u32,
+    pub cost_per_attendee: Option<Money>,
+    pub cost_per_scf_referral: Option<Money>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EventBudgetResponse {
+    pub event_id: String,
+    pub event_title: String,
+    pub event_status: EventStatus,
+    pub items: Vec<BudgetItemResponse>,
+    pub totals: Vec<BudgetRollup>,
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use super::user::UserPublic;
+
+/// Creates or replaces a chapter. The lead is identified by the Stellar wallet
+/// address they signed up with and must be a Chapter Lead.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChapterRequest {
+    pub name: String,
+    pub country: String,
+    pub city: String,
+    #[serde(default)]
+    pub lead_wallet_address: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChapterResponse {
+    pub id: String,
+    pub name: String,
+    pub country: String,
+    pub city: String,
+    pub lead: Option<UserPublic>,
+    pub member_count: u32,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChapterListResponse {
+    pub chapters: Vec<ChapterResponse>,
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChapterMembersResponse {
+    pub chapter_id: String,
+    pub members: Vec<UserPublic>,
+    pub total: usize,
+}
+
+/// Adds a registered user to a chapter by their Stellar wallet address.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MembershipRequest {
+    pub wallet_address: String,
+}
\ No newline at end of file
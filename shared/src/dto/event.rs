@@ -1,6 +1,9 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use super::kpi::KpiValues;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum EventType {
     Workshop,
     Meetup,
@@ -10,6 +13,7 @@ pub enum EventType {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum StrategicFocusArea {
     CommunityParticipation,
     OnChainActivity,
@@ -19,6 +23,7 @@ pub enum StrategicFocusArea {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct KPIEstimates {
     pub monthly_active_ambassadors: Option<u32>,
     pub monthly_active_accounts: Option<u32>,
@@ -28,6 +33,61 @@ pub struct KPIEstimates {
     pub social_growth_target: Option<u32>,
 }
 
+/// Where an event is in its lifecycle. Only approved events are listed publicly.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum EventStatus {
+    Draft,
+    Submitted,
+    Approved,
+    Published,
+    Cancelled,
+    Completed,
+}
+
+impl EventStatus {
+    pub const ALL: [EventStatus; 6] = [
+        EventStatus::Draft,
+        EventStatus::Submitted,
+        EventStatus::Approved,
+        EventStatus::Published,
+        EventStatus::Cancelled,
+        EventStatus::Completed,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|status| status.to_string() == name)
+    }
+
+    /// Statuses the event may move to next. Cancelled and completed events are final.
+    pub fn next_statuses(self) -> &'static [EventStatus] {
+        match self {
+            EventStatus::Draft => &[EventStatus::Submitted, EventStatus::Cancelled],
+            EventStatus::Submitted => &[EventStatus::Approved, EventStatus::Draft, EventStatus::Cancelled],
+            EventStatus::Approved => &[EventStatus::Published, EventStatus::Cancelled],
+            EventStatus::Published => &[EventStatus::Completed, EventStatus::Cancelled],
+            EventStatus::Cancelled | EventStatus::Completed => &[],
+        }
+    }
+
+    pub fn can_move_to(self, next: EventStatus) -> bool {
+        self.next_statuses().contains(&next)
+    }
+}
+
+impl std::fmt::Display for EventStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventStatus::Draft => write!(f, "Draft"),
+            EventStatus::Submitted => write!(f, "Submitted"),
+            EventStatus::Approved => write!(f, "Approved"),
+            EventStatus::Published => write!(f, "Published"),
+            EventStatus::Cancelled => write!(f, "Cancelled"),
+            EventStatus::Completed => write!(f, "Completed"),
+        }
+    }
+}
+
 impl std::fmt::Display for EventType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -53,16 +113,23 @@ impl std::fmt::Display for StrategicFocusArea {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct EventRequest {
     pub title: String,
     pub description: String,
     pub event_type: EventType,
-    pub date: String, // ISO date string
+    pub date: DateTime<Utc>, // start of the event
+    #[serde(default)]
+    pub end_date: Option<DateTime<Utc>>, // two hours after the start when not given, as for events from before it existed
+    #[serde(default)]
+    pub timezone: Option<String>, // IANA zone the organiser scheduled in, e.g. "Europe/Lisbon"; the configured default when not given
     pub location: String,
     pub max_participants: Option<u32>,
     pub registration_required: bool,
     pub contact_email: String,
     pub external_link: Option<String>,
+    #[serde(default)]
+    pub chapter_id: Option<String>, // chapter the event is organised for, if any
     // KPI Planning fields
     pub strategic_focus_areas: Vec<StrategicFocusArea>,
     pub kpi_estimates: KPIEstimates,
@@ -73,19 +140,28 @@ pub struct EventRequest {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct EventResponse {
     pub id: String,
     pub title: String,
     pub description: String,
     pub event_type: EventType,
-    pub date: String,
+    pub date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub timezone: String,
     pub location: String,
     pub max_participants: Option<u32>,
     pub registration_required: bool,
     pub contact_email: String,
     pub external_link: Option<String>,
     pub organizer: String, // username of organizer
-    pub created_at: String,
+    pub chapter_id: Option<String>,
+    /// Quarterly plan the event is part of
+    pub plan_id: Option<String>,
+    pub status: EventStatus,
+    /// Set when the event was cancelled with a reason
+    pub cancellation_reason: Option<String>,
+    pub created_at: DateTime<Utc>,
     // KPI Planning fields
     pub strategic_focus_areas: Vec<StrategicFocusArea>,
     pub kpi_estimates: KPIEstimates,
@@ -93,10 +169,49 @@ pub struct EventResponse {
     pub quarterly_goals: String,
     pub strategic_purpose: String,
     pub success_metrics: Option<String>,
+    /// What the event achieved, once recorded
+    pub kpi_actuals: KpiValues,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct EventListResponse {
     pub events: Vec<EventResponse>,
     pub total: usize,
+}
+
+/// Moves an event to another status. The actor is the signed-in user.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EventStatusRequest {
+    pub status: EventStatus,
+    /// Shown on the event when cancelling
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Records what an event actually achieved, replacing any actuals recorded before. Only
+/// the organizer, signed in, records them.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EventActualsRequest {
+    pub actuals: KpiValues,
+}
+
+/// Replaces an event's details. Only the organizer, signed in, edits an event until it is
+/// cancelled or completed; registrants are told what changed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EventUpdateRequest {
+    pub title: String,
+    pub description: String,
+    pub date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub timezone: String,
+    pub location: String,
+    /// Raising it promotes people from the waitlist; lowering it keeps everyone already registered
+    pub max_participants: Option<u32>,
+    pub registration_required: bool,
+    pub contact_email: String,
+    pub external_link: Option<String>,
 }
\ No newline at end of file
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use super::budget::BudgetRollup;
+use super::chapter::ChapterResponse;
+use super::event::{EventResponse, KPIEstimates};
+
+/// One value per tracked KPI. Used for what events achieved, for chapter
+/// targets and for totals over a period; `None` means "not recorded".
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct KpiValues {
+    #[serde(default)]
+    pub monthly_active_ambassadors: Option<u32>,
+    #[serde(default)]
+    pub monthly_active_accounts: Option<u32>,
+    #[serde(default)]
+    pub scf_referrals: Option<u32>,
+    #[serde(default)]
+    pub content_produced: Option<u32>,
+    #[serde(default)]
+    pub attendance: Option<u32>,
+    #[serde(default)]
+    pub social_growth: Option<u32>,
+}
+
+impl KpiValues {
+    /// Adds `other` to these values. A KPI stays `None` only if neither side recorded it.
+    pub fn add(&mut self, other: &KpiValues) {
+        fn sum(total: &mut Option<u32>, value: Option<u32>) {
+            if let Some(value) = value {
+                *total = Some(total.unwrap_or(0).saturating_add(value));
+            }
+        }
+
+        sum(&mut self.monthly_active_ambassadors, other.monthly_active_ambassadors);
+        sum(&mut self.monthly_active_accounts, other.monthly_active_accounts);
+        sum(&mut self.scf_referrals, other.scf_referrals);
+        sum(&mut self.content_produced, other.content_produced);
+        sum(&mut self.attendance, other.attendance);
+        sum(&mut self.social_growth, other.social_growth);
+    }
+}
+
+impl From<&KPIEstimates> for KpiValues {
+    fn from(estimates: &KPIEstimates) -> Self {
+        KpiValues {
+            monthly_active_ambassadors: estimates.monthly_active_ambassadors,
+            monthly_active_accounts: estimates.monthly_active_accounts,
+            scf_referrals: estimates.scf_referrals,
+            content_produced: estimates.content_produced,
+            attendance: estimates.expected_attendance,
+            social_growth: estimates.social_growth_target,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChapterTargetsResponse {
+    pub chapter_id: String,
+    pub year: i32,
+    pub quarter: u32,
+    pub targets: KpiValues,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// KPI totals of a chapter's events starting in one month of the quarter.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MonthlyKpis {
+    pub year: i32,
+    pub month: u32,
+    pub event_count: u32,
+    pub estimated: KpiValues,
+    pub actual: KpiValues,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChapterDashboardResponse {
+    pub chapter: ChapterResponse,
+    pub year: i32,
+    pub quarter: u32,
+    pub events: Vec<EventResponse>,
+    pub estimated: KpiValues,
+    pub actual: KpiValues,
+    pub targets: KpiValues,
+    pub months: Vec<MonthlyKpis>,
+    /// Budget of the quarter's events, per currency
+    pub budget: Vec<BudgetRollup>,
+}
\ No newline at end of file
@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use super::event::EventStatus;
+use super::user::UserPublic;
+
+/// Where a linked account stands in verification.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum LinkedAccountStatus {
+    /// Not yet seen active in the event's month
+    Pending,
+    /// Created and used in the event's month; counts towards monthly active accounts
+    Verified,
+    /// The event's month ended without the account becoming active
+    Unverified,
+}
+
+impl LinkedAccountStatus {
+    pub const ALL: [LinkedAccountStatus; 3] = [
+        LinkedAccountStatus::Pending,
+        LinkedAccountStatus::Verified,
+        LinkedAccountStatus::Unverified,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|status| status.to_string() == name)
+    }
+}
+
+impl std::fmt::Display for LinkedAccountStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinkedAccountStatus::Pending => write!(f, "Pending"),
+            LinkedAccountStatus::Verified => write!(f, "Verified"),
+            LinkedAccountStatus::Unverified => write!(f, "Unverified"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct LinkAccountRequest {
+    /// The Stellar account (G...) they created at the event
+    pub account_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct LinkedAccountResponse {
+    pub id: String,
+    pub event_id: String,
+    pub account_id: String,
+    pub status: LinkedAccountStatus,
+    pub linked_by: Option<UserPublic>,
+    pub funded_at: Option<DateTime<Utc>>,
+    /// Latest transaction the account made itself in the event's month
+    pub last_transaction_at: Option<DateTime<Utc>>,
+    pub checked_at: Option<DateTime<Utc>>,
+    /// Why the last check failed or the account was not verified
+    pub check_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct LinkedAccountListResponse {
+    pub event_title: String,
+    pub event_status: EventStatus,
+    pub accounts: Vec<LinkedAccountResponse>,
+    pub verified_count: u32,
+    /// Start of the month accounts must be active in (UTC)
+    pub window_start: DateTime<Utc>,
+    /// End of that month, exclusive
+    pub window_end: DateTime<Utc>,
+}
\ No newline at end of file
@@ -1,7 +1,31 @@
 pub mod auth;
 pub mod user;
 pub mod event;
+pub mod error;
+pub mod chapter;
+pub mod kpi;
+pub mod plan;
+pub mod budget;
+pub mod reimbursement;
+pub mod attachment;
+pub mod linked_account;
+pub mod attendance;
+pub mod network;
+pub mod registration;
+pub mod notification;
 
 pub use auth::*;
 pub use user::*;
-pub use event::*;
\ No newline at end of file
+pub use event::*;
+pub use error::*;
+pub use chapter::*;
+pub use kpi::*;
+pub use plan::*;
+pub use budget::*;
+pub use reimbursement::*;
+pub use attachment::*;
+pub use linked_account::*;
+pub use attendance::*;
+pub use network::*;
+pub use registration::*;
+pub use notification::*;
\ No newline at end of file
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// The Stellar network the backend builds and submits transactions on. Wallets must be
+/// on the same network to sign them.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct StellarNetworkResponse {
+    /// `PUBLIC`, `TESTNET`, or `CUSTOM` for any other passphrase
+    pub network: String,
+    pub network_passphrase: String,
+    pub horizon_url: String,
+}
\ No newline at end of file
@@ -0,0 +1,113 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// What a notification is about. Each kind has its own template and preferences.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum NotificationKind {
+    /// The user has a place at an event
+    RegistrationConfirmed,
+    /// A place freed up for a waitlisted user
+    WaitlistPromoted,
+    /// The organizer changed an event the user registered for
+    EventUpdated,
+    /// An event the user registered for was cancelled
+    EventCancelled,
+    /// An event the user has a place at is coming up
+    EventReminder,
+    /// The quarterly plan of the user's chapter was approved
+    PlanApproved,
+    /// A reimbursement to the user's wallet was paid
+    ExpensePaid,
+}
+
+impl NotificationKind {
+    pub const ALL: [NotificationKind; 7] = [
+        NotificationKind::RegistrationConfirmed,
+        NotificationKind::WaitlistPromoted,
+        NotificationKind::EventUpdated,
+        NotificationKind::EventCancelled,
+        NotificationKind::EventReminder,
+        NotificationKind::PlanApproved,
+        NotificationKind::ExpensePaid,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.to_string() == name)
+    }
+}
+
+impl std::fmt::Display for NotificationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationKind::RegistrationConfirmed => write!(f, "RegistrationConfirmed"),
+            NotificationKind::WaitlistPromoted => write!(f, "WaitlistPromoted"),
+            NotificationKind::EventUpdated => write!(f, "EventUpdated"),
+            NotificationKind::EventCancelled => write!(f, "EventCancelled"),
+            NotificationKind::EventReminder => write!(f, "EventReminder"),
+            NotificationKind::PlanApproved => write!(f, "PlanApproved"),
+            NotificationKind::ExpensePaid => write!(f, "ExpensePaid"),
+        }
+    }
+}
+
+/// Languages notifications are written in, by code and name. The first is the default.
+pub const NOTIFICATION_LOCALES: [(&str, &str); 3] = [("en", "English"), ("es", "Español"), ("fr", "Français")];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct NotificationResponse {
+    pub id: String,
+    pub kind: NotificationKind,
+    pub subject: String,
+    pub body: String,
+    /// Page of the app the notification is about
+    pub link: Option<String>,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A user's inbox, newest first.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct NotificationListResponse {
+    pub notifications: Vec<NotificationResponse>,
+    pub unread: u32,
+}
+
+/// Marks notifications read; without ids, the whole inbox.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MarkNotificationsReadRequest {
+    #[serde(default)]
+    pub notification_ids: Vec<String>,
+}
+
+/// How a user gets one kind of notification.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct NotificationPreference {
+    pub kind: NotificationKind,
+    /// Mailed, to a verified address
+    pub email: bool,
+    /// Kept in the inbox
+    pub in_app: bool,
+}
+
+/// The user's language and a preference for every kind of notification.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct NotificationPreferencesResponse {
+    pub locale: String,
+    pub preferences: Vec<NotificationPreference>,
+}
+
+/// Changes the language and the preferences listed; kinds left out keep theirs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct NotificationPreferencesRequest {
+    #[serde(default)]
+    pub locale: Option<String>,
+    #[serde(default)]
+    pub preferences: Vec<NotificationPreference>,
+}
\ No newline at end of file
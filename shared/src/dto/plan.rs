@@ -0,0 +1,170 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use super::budget::BudgetRollup;
+use super::event::{EventResponse, StrategicFocusArea};
+use super::user::UserPublic;
+
+/// Where a quarterly plan is in the review workflow.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum PlanStatus {
+    Draft,
+    Submitted,
+    Approved,
+    ChangesRequested,
+}
+
+/// A step a chapter or reviewer takes on a plan.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum PlanAction {
+    /// Chapter sends a draft, or a plan with changes requested, for review
+    Submit,
+    /// Reviewer accepts a submitted plan
+    Approve,
+    /// Reviewer sends a submitted plan back to the chapter
+    RequestChanges,
+}
+
+impl PlanStatus {
+    pub const ALL: [PlanStatus; 4] =
+        [PlanStatus::Draft, PlanStatus::Submitted, PlanStatus::Approved, PlanStatus::ChangesRequested];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|status| status.to_string() == name)
+    }
+
+    /// Status after `action`, or `None` if the action is not allowed from this status.
+    pub fn apply(self, action: PlanAction) -> Option<PlanStatus> {
+        match (self, action) {
+            (PlanStatus::Draft | PlanStatus::ChangesRequested, PlanAction::Submit) => Some(PlanStatus::Submitted),
+            (PlanStatus::Submitted, PlanAction::Approve) => Some(PlanStatus::Approved),
+            (PlanStatus::Submitted, PlanAction::RequestChanges) => Some(PlanStatus::ChangesRequested),
+            _ => None,
+        }
+    }
+
+    pub fn allowed_actions(self) -> Vec<PlanAction> {
+        [PlanAction::Submit, PlanAction::Approve, PlanAction::RequestChanges]
+            .into_iter()
+            .filter(|action| self.apply(*action).is_some())
+            .collect()
+    }
+
+    /// Whether the chapter may still change the plan's summary and events.
+    pub fn is_editable(self) -> bool {
+        matches!(self, PlanStatus::Draft | PlanStatus::ChangesRequested)
+    }
+}
+
+impl std::fmt::Display for PlanStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanStatus::Draft => write!(f, "Draft"),
+            PlanStatus::Submitted => write!(f, "Submitted"),
+            PlanStatus::Approved => write!(f, "Approved"),
+            PlanStatus::ChangesRequested => write!(f, "ChangesRequested"),
+        }
+    }
+}
+
+/// Creates or edits a plan. Events must belong to the chapter and start in the quarter.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct QuarterlyPlanRequest {
+    pub chapter_id: String,
+    pub year: i32,
+    pub quarter: u32,
+    pub strategic_purpose: String,
+    pub quarterly_goals: String,
+    #[serde(default)]
+    pub event_ids: Vec<String>,
+}
+
+/// Moves a plan through the workflow as the signed-in user.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PlanTransitionRequest {
+    pub action: PlanAction,
+    /// Required when requesting changes
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+/// A comment on a plan by the signed-in user.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PlanCommentRequest {
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PlanComment {
+    pub id: String,
+    /// `None` if the author's account was deleted
+    pub author: Option<UserPublic>,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One audited status change.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PlanTransition {
+    pub from_status: PlanStatus,
+    pub to_status: PlanStatus,
+    pub actor: Option<UserPublic>,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// How many of the plan's events serve a focus area.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FocusAreaSummary {
+    pub focus_area: StrategicFocusArea,
+    pub event_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct QuarterlyPlanResponse {
+    pub id: String,
+    pub chapter_id: String,
+    pub chapter_name: String,
+    pub year: i32,
+    pub quarter: u32,
+    pub status: PlanStatus,
+    pub strategic_purpose: String,
+    pub quarterly_goals: String,
+    pub events: Vec<EventResponse>,
+    pub focus_areas: Vec<FocusAreaSummary>,
+    /// Budget the plan's events request, per currency
+    pub budget: Vec<BudgetRollup>,
+    pub comments: Vec<PlanComment>,
+    pub transitions: Vec<PlanTransition>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A plan in listings, without its events, comments and history.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct QuarterlyPlanSummary {
+    pub id: String,
+    pub chapter_id: String,
+    pub chapter_name: String,
+    pub year: i32,
+    pub quarter: u32,
+    pub status: PlanStatus,
+    pub event_count: u32,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct QuarterlyPlanListResponse {
+    pub plans: Vec<QuarterlyPlanSummary>,
+    pub total: usize,
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use super::event::EventResponse;
+use super::user::UserPublic;
+
+/// Where someone's registration for an event is.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum RegistrationStatus {
+    /// Has a place at the event
+    Registered,
+    /// Joined after the event was full; promoted in order as places free up
+    Waitlisted,
+    /// Withdrawn by the attendee or the organizer
+    Cancelled,
+}
+
+impl RegistrationStatus {
+    pub const ALL: [RegistrationStatus; 3] = [
+        RegistrationStatus::Registered,
+        RegistrationStatus::Waitlisted,
+        RegistrationStatus::Cancelled,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|status| status.to_string() == name)
+    }
+}
+
+impl std::fmt::Display for RegistrationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegistrationStatus::Registered => write!(f, "Registered"),
+            RegistrationStatus::Waitlisted => write!(f, "Waitlisted"),
+            RegistrationStatus::Cancelled => write!(f, "Cancelled"),
+        }
+    }
+}
+
+/// Registers a user for a published event, identified by their wallet address.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RegistrationRequest {
+    pub wallet_address: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RegistrationResponse {
+    pub id: String,
+    pub event_id: String,
+    pub attendee: UserPublic,
+    pub status: RegistrationStatus,
+    pub registered_at: DateTime<Utc>,
+    /// When a place freed up for someone who was waitlisted
+    pub promoted_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+}
+
+/// An event with everyone registered for or waiting on it, each group in the order they joined.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EventRegistrationsResponse {
+    pub event: EventResponse,
+    pub registered: u32,
+    pub waitlisted: u32,
+    pub registrations: Vec<RegistrationResponse>,
+}
\ No newline at end of file
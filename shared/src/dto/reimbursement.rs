@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use super::budget::Money;
+
+/// Where a Stellar payout of a budget item's spending is.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum ReimbursementStatus {
+    /// Built; waiting for a programme admin to sign it
+    AwaitingSignature,
+    /// Sent to the network without a definite answer; submit it again to find out
+    Submitted,
+    Paid,
+    /// Refused by the network; prepare it again to build a new transaction
+    Failed,
+}
+
+impl ReimbursementStatus {
+    pub const ALL: [ReimbursementStatus; 4] = [
+        ReimbursementStatus::AwaitingSignature,
+        ReimbursementStatus::Submitted,
+        ReimbursementStatus::Paid,
+        ReimbursementStatus::Failed,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|status| status.to_string() == name)
+    }
+}
+
+impl std::fmt::Display for ReimbursementStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReimbursementStatus::AwaitingSignature => write!(f, "AwaitingSignature"),
+            ReimbursementStatus::Submitted => write!(f, "Submitted"),
+            ReimbursementStatus::Paid => write!(f, "Paid"),
+            ReimbursementStatus::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+/// The prepared transaction after a signed-in programme admin signed it in the wallet.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SubmitReimbursementRequest {
+    /// Base64 transaction envelope returned by the wallet
+    pub signed_transaction_xdr: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ReimbursementResponse {
+    pub id: String,
+    pub budget_item_id: String,
+    /// The event organiser's wallet
+    pub recipient_wallet_address: String,
+    /// What was spent, capped at the approved amount
+    pub amount: Money,
+    pub status: ReimbursementStatus,
+    /// Account the payout is sent from; it must sign the transaction
+    pub source_account: String,
+    /// Unsigned base64 transaction envelope to sign
+    pub transaction_xdr: String,
+    pub transaction_hash: String,
+    /// Network the transaction is for, to pass to the wallet when signing
+    pub network_passphrase: String,
+    /// Ledger the payment was included in, once paid
+    pub ledger: Option<u32>,
+    /// Why the payout failed, or why the outcome of its submission is unknown
+    pub error: Option<String>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
\ No newline at end of file
@@ -1,7 +1,9 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use super::auth::UserType;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserPublic {
     pub id: String,
     pub username: String,
@@ -10,10 +12,14 @@ pub struct UserPublic {
     pub user_type: UserType,
     pub organization: Option<String>,
     pub bio: Option<String>,
-    pub created_at: String,
+    pub created_at: Option<DateTime<Utc>>,
+    /// Whether the user followed the link mailed to `email`; only verified users create events
+    #[serde(default)]
+    pub email_verified: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct SignUpResponse {
     pub user: UserPublic,
     pub message: String,